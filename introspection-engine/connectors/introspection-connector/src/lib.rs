@@ -16,7 +16,9 @@ pub trait IntrospectionConnector: Send + Sync + 'static {
 
     async fn get_database_description(&self) -> ConnectorResult<String>;
 
-    async fn introspect(&self) -> ConnectorResult<IntrospectionResult>;
+    /// Introspects the database. Hand-made changes of the previous datamodel that introspection can't infer
+    /// from the database, like ignored models and fields, are kept.
+    async fn introspect(&self, previous_data_model: &Datamodel) -> ConnectorResult<IntrospectionResult>;
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1,5 +1,6 @@
 use crate::commenting_out_guardrails::commenting_out_guardrails;
 use crate::misc_helpers::*;
+use crate::native_types::calculate_native_type;
 use crate::prisma_1_defaults::*;
 use crate::sanitize_datamodel_names::sanitize_datamodel_names;
 use crate::version_checker::VersionChecker;
@@ -12,6 +13,23 @@ use tracing::debug;
 
 /// Calculate a data model from a database schema.
 pub fn calculate_datamodel(schema: &SqlSchema, family: &SqlFamily) -> SqlIntrospectionResult<IntrospectionResult> {
+    calculate_datamodel_internal(schema, family, false)
+}
+
+/// Calculate a data model from a database schema, keeping the native types of the columns
+/// as `@db.` attributes where they differ from the default type of the field's scalar type.
+pub fn calculate_datamodel_with_native_types(
+    schema: &SqlSchema,
+    family: &SqlFamily,
+) -> SqlIntrospectionResult<IntrospectionResult> {
+    calculate_datamodel_internal(schema, family, true)
+}
+
+fn calculate_datamodel_internal(
+    schema: &SqlSchema,
+    family: &SqlFamily,
+    native_types: bool,
+) -> SqlIntrospectionResult<IntrospectionResult> {
     debug!("Calculating data model.");
 
     let mut version_check = VersionChecker::new(family.clone(), schema);
@@ -30,7 +48,14 @@ pub fn calculate_datamodel(schema: &SqlSchema, family: &SqlFamily) -> SqlIntrosp
 
         for column in &table.columns {
             version_check.uses_non_prisma_type(&column.tpe);
-            let field = calculate_scalar_field(&table, &column);
+            let mut field = calculate_scalar_field(&table, &column);
+
+            if native_types {
+                if let Some(native_type) = calculate_native_type(&column, &field.field_type, family) {
+                    field.field_type = FieldType::ConnectorSpecific(native_type);
+                }
+            }
+
             model.add_field(field);
         }
 
@@ -60,12 +85,37 @@ pub fn calculate_datamodel(schema: &SqlSchema, family: &SqlFamily) -> SqlIntrosp
             model.id_fields = table.primary_key_columns();
         }
 
+        model.check_constraints = table.check_constraints.iter().map(calculate_check_constraint).collect();
+        model.schema = table.schema.clone();
+
         version_check.always_has_created_at_updated_at(table, &model);
         version_check.has_p1_compatible_primary_key_column(table);
 
         data_model.add_model(model);
     }
 
+    for view in schema.views.iter() {
+        debug!("Calculating view: {}", view.name);
+        let table = view_as_table(view);
+        let mut model = Model::new(view.name.clone(), None);
+        model.is_view = true;
+        model.schema = view.schema.clone();
+
+        for column in &table.columns {
+            let mut field = calculate_scalar_field(&table, &column);
+
+            if native_types {
+                if let Some(native_type) = calculate_native_type(&column, &field.field_type, family) {
+                    field.field_type = FieldType::ConnectorSpecific(native_type);
+                }
+            }
+
+            model.add_field(field);
+        }
+
+        data_model.add_model(model);
+    }
+
     for e in schema.enums.iter() {
         data_model.add_enum(dml::Enum {
             name: e.name.clone(),
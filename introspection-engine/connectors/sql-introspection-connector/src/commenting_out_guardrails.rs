@@ -130,9 +130,10 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
         };
     }
 
-    // models without uniques / ids
+    // models without uniques / ids, views can do without them
     for model in &mut datamodel.models {
-        if model.id_fields.is_empty()
+        if !model.is_view
+            && model.id_fields.is_empty()
             && !model
                 .fields
                 .iter()
@@ -140,7 +141,7 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
             && !model.indices.iter().any(|i| i.is_unique())
             && !models_with_one_to_one_relation.contains(&model.name)
         {
-            model.is_commented_out = true;
+            model.is_ignored = true;
             model.documentation = Some(
                 "The underlying table does not contain a unique identifier and can therefore currently not be handled."
                     .to_string(),
@@ -151,12 +152,14 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
         }
     }
 
-    // remove their backrelations
+    // ignore their backrelations
     for model_without_identifier in &models_without_identifiers {
-        for model in &mut datamodel.models {
-            model
-                .fields
-                .retain(|f| !f.points_to_model(model_without_identifier.model.as_ref()));
+        for model in datamodel.models.iter_mut().filter(|model| !model.is_ignored) {
+            for field in &mut model.fields {
+                if field.points_to_model(model_without_identifier.model.as_ref()) {
+                    field.is_ignored = true;
+                }
+            }
         }
     }
 
@@ -165,7 +168,7 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
     if !models_without_identifiers.is_empty() {
         warnings.push(Warning {
             code: 1,
-            message: "These models do not have a unique identifier or id and are therefore commented out.".into(),
+            message: "These models do not have a unique identifier or id and are therefore ignored.".into(),
             affected: serde_json::to_value(&models_without_identifiers).unwrap(),
         })
     }
@@ -2,11 +2,14 @@ pub mod calculate_datamodel; // only exported to be able to unit test it
 mod commenting_out_guardrails;
 mod error;
 mod misc_helpers;
+mod native_types;
 mod prisma_1_defaults;
+pub mod re_introspection; // only exported to be able to unit test it
 mod sanitize_datamodel_names;
 mod schema_describer_loading;
 mod version_checker;
 
+use datamodel::Datamodel;
 use introspection_connector::{
     ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResult,
 };
@@ -22,6 +25,8 @@ pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    native_types: bool,
+    schemas: Vec<String>,
 }
 
 impl SqlIntrospectionConnector {
@@ -40,9 +45,23 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            native_types: false,
+            schemas: Vec::new(),
         })
     }
 
+    /// Render the native types of columns as `@db.` attributes when introspecting.
+    pub fn with_native_types(mut self, native_types: bool) -> Self {
+        self.native_types = native_types;
+        self
+    }
+
+    /// Introspect the given schemas next to the one from the connection string.
+    pub fn with_schemas(mut self, schemas: &[String]) -> Self {
+        self.schemas = schemas.to_vec();
+        self
+    }
+
     async fn catch<O>(&self, fut: impl Future<Output = Result<O, SqlError>>) -> ConnectorResult<O> {
         fut.await
             .map_err(|sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info))
@@ -62,7 +81,12 @@ impl SqlIntrospectionConnector {
     }
 
     async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
-        Ok(self.describer.describe(self.connection_info.schema_name()).await?)
+        Ok(sql_schema_describer::describe_schemas(
+            self.describer.as_ref(),
+            self.connection_info.schema_name(),
+            &self.schemas,
+        )
+        .await?)
     }
 }
 
@@ -83,14 +107,21 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
         Ok(description)
     }
 
-    async fn introspect(&self) -> ConnectorResult<IntrospectionResult> {
+    async fn introspect(&self, previous_data_model: &Datamodel) -> ConnectorResult<IntrospectionResult> {
         let sql_schema = self.catch(self.describe()).await?;
         tracing::debug!("SQL Schema Describer is done: {:?}", sql_schema);
 
         let family = self.connection_info.sql_family();
 
-        let introspection_result = calculate_datamodel::calculate_datamodel(&sql_schema, &family).unwrap();
+        let mut introspection_result = if self.native_types {
+            calculate_datamodel::calculate_datamodel_with_native_types(&sql_schema, &family).unwrap()
+        } else {
+            calculate_datamodel::calculate_datamodel(&sql_schema, &family).unwrap()
+        };
         tracing::debug!("Calculating datamodel is done: {:?}", sql_schema);
+
+        re_introspection::keep_ignore_attributes(previous_data_model, &mut introspection_result.datamodel);
+
         Ok(introspection_result)
     }
 }
@@ -1,9 +1,10 @@
 use datamodel::{
-    Datamodel, DefaultValue as DMLDef, Field, FieldArity, FieldType, IndexDefinition, Model, OnDeleteStrategy,
-    RelationInfo, ScalarType, ValueGenerator as VG,
+    CheckConstraint as DMLCheckConstraint, Datamodel, DefaultValue as DMLDef, Field, FieldArity, FieldType,
+    IndexDefinition, Model, ReferentialAction, RelationInfo, ScalarType, ValueGenerator as VG,
 };
 use sql_schema_describer::{
-    Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, Index, IndexType, SqlSchema, Table,
+    CheckConstraint, Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, ForeignKeyAction,
+    Index, IndexType, SqlSchema, Table, View,
 };
 use tracing::debug;
 
@@ -87,7 +88,8 @@ pub fn calculate_many_to_many_field(foreign_key: &ForeignKey, relation_name: Str
         fields: vec![],
         to: foreign_key.referenced_table.clone(),
         to_fields: foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
+        on_delete: None,
+        on_update: None,
     });
 
     let basename = foreign_key.referenced_table.clone();
@@ -109,6 +111,7 @@ pub fn calculate_many_to_many_field(foreign_key: &ForeignKey, relation_name: Str
         is_generated: false,
         is_updated_at: false,
         is_commented_out: false,
+        is_ignored: false,
     }
 }
 
@@ -126,6 +129,14 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
     index_definition
 }
 
+pub(crate) fn calculate_check_constraint(check_constraint: &CheckConstraint) -> DMLCheckConstraint {
+    debug!("Handling check constraint {:?}", check_constraint);
+    DMLCheckConstraint {
+        name: check_constraint.name.clone(),
+        expression: check_constraint.expression.clone(),
+    }
+}
+
 pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> Field {
     debug!("Handling column {:?}", column);
     let field_type = calculate_scalar_field_type(&column);
@@ -157,20 +168,26 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> Field {
         is_generated: false,
         is_updated_at: false,
         is_commented_out,
+        is_ignored: false,
+    }
+}
+
+/// Views have no keys or indices, so their columns are handled like the columns of a table without any.
+pub(crate) fn view_as_table(view: &View) -> Table {
+    Table {
+        name: view.name.clone(),
+        columns: view.columns.clone(),
+        indices: vec![],
+        primary_key: None,
+        foreign_keys: vec![],
+        check_constraints: vec![],
+        schema: view.schema.clone(),
     }
 }
 
 pub(crate) fn calculate_relation_field(schema: &SqlSchema, table: &Table, foreign_key: &ForeignKey) -> Field {
     debug!("Handling foreign key  {:?}", foreign_key);
 
-    let field_type = FieldType::Relation(RelationInfo {
-        name: calculate_relation_name(schema, foreign_key, table),
-        fields: foreign_key.columns.clone(),
-        to: foreign_key.referenced_table.clone(),
-        to_fields: foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
-    });
-
     let columns: Vec<&Column> = foreign_key
         .columns
         .iter()
@@ -182,6 +199,21 @@ pub(crate) fn calculate_relation_field(schema: &SqlSchema, table: &Table, foreig
         false => FieldArity::Required,
     };
 
+    // The actions the migration engine creates for relations without referential actions.
+    let default_on_delete = match arity {
+        FieldArity::Required => ForeignKeyAction::Cascade,
+        _ => ForeignKeyAction::SetNull,
+    };
+
+    let field_type = FieldType::Relation(RelationInfo {
+        name: calculate_relation_name(schema, foreign_key, table),
+        fields: foreign_key.columns.clone(),
+        to: foreign_key.referenced_table.clone(),
+        to_fields: foreign_key.referenced_columns.clone(),
+        on_delete: calculate_referential_action(&foreign_key.on_delete_action, &default_on_delete),
+        on_update: calculate_referential_action(&foreign_key.on_update_action, &ForeignKeyAction::Cascade),
+    });
+
     // todo Should this be an extra type? It uses just a small subset of the features of a scalar field
     Field {
         name: foreign_key.referenced_table.clone(),
@@ -195,6 +227,20 @@ pub(crate) fn calculate_relation_field(schema: &SqlSchema, table: &Table, foreig
         is_generated: false,
         is_updated_at: false,
         is_commented_out: false,
+        is_ignored: false,
+    }
+}
+
+/// Referential actions are only introspected if they differ from the action the migration engine
+/// would create anyway, so that migrating the introspected schema keeps the foreign keys as they are.
+fn calculate_referential_action(action: &ForeignKeyAction, default: &ForeignKeyAction) -> Option<ReferentialAction> {
+    match action {
+        _ if action == default => None,
+        ForeignKeyAction::NoAction => Some(ReferentialAction::NoAction),
+        ForeignKeyAction::Restrict => Some(ReferentialAction::Restrict),
+        ForeignKeyAction::Cascade => Some(ReferentialAction::Cascade),
+        ForeignKeyAction::SetNull => Some(ReferentialAction::SetNull),
+        ForeignKeyAction::SetDefault => Some(ReferentialAction::SetDefault),
     }
 }
 
@@ -212,7 +258,8 @@ pub(crate) fn calculate_backrelation_field(
         to: model.name.clone(),
         fields: vec![],
         to_fields: vec![],
-        on_delete: OnDeleteStrategy::None,
+        on_delete: None,
+        on_update: None,
     });
 
     let other_is_unique = || match &relation_info.fields.len() {
@@ -251,6 +298,7 @@ pub(crate) fn calculate_backrelation_field(
         is_generated: false,
         is_updated_at: false,
         is_commented_out: false,
+        is_ignored: false,
     }
 }
 
@@ -326,15 +374,26 @@ pub(crate) fn calculate_scalar_field_type(column: &Column) -> FieldType {
         ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean, None),
         ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime, None),
         ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float, None),
+        // Floats are migrated to `Decimal(65,30)` columns.
+        ColumnTypeFamily::Decimal if is_float_column(column) => FieldType::Base(ScalarType::Float, None),
+        ColumnTypeFamily::Decimal => FieldType::Base(ScalarType::Decimal, None),
         ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int, None),
+        ColumnTypeFamily::BigInt => FieldType::Base(ScalarType::BigInt, None),
         ColumnTypeFamily::String => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Enum(name) => FieldType::Enum(name.clone()),
         ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json, None),
+        ColumnTypeFamily::Binary => FieldType::Base(ScalarType::Bytes, None),
         x => FieldType::Unsupported(x.to_string()),
     }
 }
 
+fn is_float_column(column: &Column) -> bool {
+    let full_data_type = column.tpe.full_data_type.to_lowercase().replace(' ', "");
+
+    (column.tpe.precision == Some(65) && column.tpe.scale == Some(30)) || full_data_type.starts_with("decimal(65,30)")
+}
+
 // misc
 
 pub fn deduplicate_field_names(datamodel: &mut Datamodel) {
@@ -0,0 +1,127 @@
+use datamodel::{dml::ScalarFieldType, FieldType};
+use datamodel_connector::{BuiltinConnectors, Connector};
+use quaint::connector::SqlFamily;
+use sql_schema_describer::Column;
+
+/// Returns the native type of a column, as long as it differs from the type the migration engine
+/// would create for the field's scalar type. Columns with default types stay plain scalar fields.
+pub(crate) fn calculate_native_type(
+    column: &Column,
+    field_type: &FieldType,
+    family: &SqlFamily,
+) -> Option<ScalarFieldType> {
+    let scalar_type = match field_type {
+        FieldType::Base(scalar_type, _) => *scalar_type,
+        _ => return None,
+    };
+
+    let (name, args) = match family {
+        SqlFamily::Postgres => postgres_native_type(column)?,
+        SqlFamily::Mysql => mysql_native_type(column)?,
+        SqlFamily::Sqlite => sqlite_native_type(column)?,
+    };
+
+    let native_type = match family {
+        SqlFamily::Postgres => BuiltinConnectors::postgres().calculate_type(name, args),
+        SqlFamily::Mysql => BuiltinConnectors::mysql().calculate_type(name, args),
+        SqlFamily::Sqlite => BuiltinConnectors::sqlite().calculate_type(name, args),
+    }?;
+
+    if native_type.prisma_type() == scalar_type {
+        Some(native_type)
+    } else {
+        None
+    }
+}
+
+fn postgres_native_type(column: &Column) -> Option<(&'static str, Vec<i32>)> {
+    let length = || column.tpe.character_maximum_length.map(|len| vec![len as i32]);
+    // Time types default to a precision of 6.
+    let time_precision = || match column.tpe.precision {
+        Some(6) | None => vec![],
+        Some(precision) => vec![precision as i32],
+    };
+
+    let native_type = match column.tpe.full_data_type.trim_start_matches('_') {
+        "int2" => ("SmallInt", vec![]),
+        "float4" => ("Real", vec![]),
+        "float8" => ("DoublePrecision", vec![]),
+        "numeric" => match (column.tpe.precision, column.tpe.scale) {
+            (Some(65), Some(30)) => return None,
+            (Some(precision), Some(scale)) => ("Numeric", vec![precision as i32, scale as i32]),
+            _ => ("Numeric", vec![]),
+        },
+        "varchar" => ("VarChar", length()?),
+        "bpchar" => ("Char", length()?),
+        "timestamp" if column.tpe.precision == Some(3) => return None,
+        "timestamp" => ("Timestamp", time_precision()),
+        "timestamptz" => ("Timestamptz", time_precision()),
+        "date" => ("Date", vec![]),
+        "time" => ("Time", time_precision()),
+        "json" => ("Json", vec![]),
+        _ => return None,
+    };
+
+    Some(native_type)
+}
+
+fn mysql_native_type(column: &Column) -> Option<(&'static str, Vec<i32>)> {
+    let args = parse_args(&column.tpe.full_data_type)?;
+
+    let native_type = match (column.tpe.data_type.as_str(), args.as_slice()) {
+        ("tinyint", [1]) => return None,
+        ("tinyint", _) => ("TinyInt", vec![]),
+        ("smallint", _) => ("SmallInt", vec![]),
+        ("mediumint", _) => ("MediumInt", vec![]),
+        ("float", _) => ("Float", vec![]),
+        ("double", _) => ("Double", vec![]),
+        ("decimal", [65, 30]) => return None,
+        ("decimal", _) => ("Decimal", args.clone()),
+        ("varchar", [191]) => return None,
+        ("varchar", _) => ("VarChar", args.clone()),
+        ("char", _) => ("Char", args.clone()),
+        ("tinytext", _) => ("TinyText", vec![]),
+        ("text", _) => ("Text", vec![]),
+        ("mediumtext", _) => ("MediumText", vec![]),
+        ("longtext", _) => ("LongText", vec![]),
+        ("datetime", []) | ("datetime", [3]) => return None,
+        ("datetime", _) => ("DateTime", args.clone()),
+        ("timestamp", _) => ("Timestamp", args.clone()),
+        ("date", _) => ("Date", vec![]),
+        ("time", _) => ("Time", args.clone()),
+        ("binary", _) => ("Binary", args.clone()),
+        ("varbinary", _) => ("VarBinary", args.clone()),
+        ("tinyblob", _) => ("TinyBlob", vec![]),
+        ("blob", _) => ("Blob", vec![]),
+        ("mediumblob", _) => ("MediumBlob", vec![]),
+        _ => return None,
+    };
+
+    Some(native_type)
+}
+
+fn sqlite_native_type(column: &Column) -> Option<(&'static str, Vec<i32>)> {
+    let data_type = column.tpe.data_type.to_lowercase();
+
+    if data_type.contains("numeric") || data_type.contains("decimal") {
+        Some(("Numeric", vec![]))
+    } else {
+        None
+    }
+}
+
+/// Parses the arguments of a full data type like `decimal(10,2)`.
+/// Display widths like the `11` in `int(11)` are parsed as well and ignored by the callers.
+fn parse_args(full_data_type: &str) -> Option<Vec<i32>> {
+    let start = match full_data_type.find('(') {
+        Some(start) => start,
+        None => return Some(vec![]),
+    };
+
+    let end = full_data_type[start..].find(')')? + start;
+
+    full_data_type[start + 1..end]
+        .split(',')
+        .map(|arg| arg.trim().parse().ok())
+        .collect()
+}
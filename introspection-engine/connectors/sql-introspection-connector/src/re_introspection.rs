@@ -0,0 +1,45 @@
+use datamodel::{Datamodel, WithDatabaseName};
+
+/// Keeps the `@ignore` and `@@ignore` attributes of the previous datamodel on the introspected one.
+/// Models and fields are matched by their database names.
+pub fn keep_ignore_attributes(previous_data_model: &Datamodel, data_model: &mut Datamodel) {
+    for previous_model in previous_data_model.models() {
+        let model = match data_model
+            .models
+            .iter_mut()
+            .find(|model| model.final_database_name() == previous_model.final_database_name())
+        {
+            Some(model) => model,
+            None => continue,
+        };
+
+        if previous_model.is_ignored {
+            model.is_ignored = true;
+        }
+
+        for previous_field in previous_model.fields().filter(|field| field.is_ignored) {
+            if let Some(field) = model
+                .fields
+                .iter_mut()
+                .find(|field| field.final_database_name() == previous_field.final_database_name())
+            {
+                field.is_ignored = true;
+            }
+        }
+    }
+
+    // Relation fields pointing to ignored models must be ignored as well.
+    let ignored_models: Vec<String> = data_model
+        .models()
+        .filter(|model| model.is_ignored)
+        .map(|model| model.name.clone())
+        .collect();
+
+    for model in data_model.models.iter_mut().filter(|model| !model.is_ignored) {
+        for field in model.fields.iter_mut() {
+            if ignored_models.iter().any(|name| field.points_to_model(name)) {
+                field.is_ignored = true;
+            }
+        }
+    }
+}
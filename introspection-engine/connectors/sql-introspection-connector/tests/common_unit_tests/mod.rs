@@ -1,11 +1,12 @@
 use datamodel::{
     common::ScalarType, dml, Datamodel, DefaultValue as DMLDefault, Field, FieldArity, FieldType, IndexDefinition,
-    Model, OnDeleteStrategy, RelationInfo, ValueGenerator,
+    Model, ReferentialAction, RelationInfo, ValueGenerator,
 };
 use pretty_assertions::assert_eq;
 use prisma_value::PrismaValue;
 use quaint::connector::SqlFamily;
-use sql_introspection_connector::calculate_datamodel::calculate_datamodel;
+use sql_introspection_connector::calculate_datamodel::{calculate_datamodel, calculate_datamodel_with_native_types};
+use sql_introspection_connector::re_introspection::keep_ignore_attributes;
 use sql_schema_describer::*;
 
 #[test]
@@ -34,8 +35,11 @@ fn a_data_model_can_be_generated_from_a_schema() {
                     .to_string(),
             ),
             is_embedded: false,
+            is_view: false,
+            check_constraints: vec![],
             is_generated: false,
-            is_commented_out: true,
+            is_commented_out: false,
+            is_ignored: true,
             indices: vec![],
             id_fields: vec![],
             fields: col_types
@@ -50,6 +54,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         ColumnTypeFamily::Enum(name) => (FieldType::Enum(name.clone()), false, None),
                         ColumnTypeFamily::Uuid => (FieldType::Base(ScalarType::String, None), false, None),
                         ColumnTypeFamily::Json => (FieldType::Base(ScalarType::Json, None), false, None),
+                        ColumnTypeFamily::Binary => (FieldType::Base(ScalarType::Bytes, None), false, None),
                         x => (
                             FieldType::Unsupported(x.to_string()),
                             true,
@@ -68,11 +73,14 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out,
+                        is_ignored: false,
                     }
                 })
                 .collect(),
+            schema: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -90,9 +98,12 @@ fn a_data_model_can_be_generated_from_a_schema() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -107,7 +118,10 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            check_constraints: vec![],
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field {
                     name: "optional".to_string(),
@@ -121,6 +135,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "required".to_string(),
@@ -134,6 +149,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "list".to_string(),
@@ -147,13 +163,16 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
             ],
             is_generated: false,
             indices: vec![],
             id_fields: vec![],
+            schema: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -185,9 +204,12 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -202,7 +224,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            check_constraints: vec![],
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field {
                     name: "no_default".to_string(),
@@ -216,6 +241,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "int_default".to_string(),
@@ -229,6 +255,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "bool_default".to_string(),
@@ -242,6 +269,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "float_default".to_string(),
@@ -255,6 +283,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "string_default".to_string(),
@@ -268,6 +297,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
             ],
             is_generated: false,
@@ -277,8 +307,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                 tpe: dml::IndexType::Unique,
             }],
             id_fields: vec![],
+            schema: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -323,9 +355,12 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             }],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -342,7 +377,10 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -355,10 +393,12 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 }],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                schema: None,
             },
             // Model with non-auto-incrementing primary key
             Model {
@@ -366,7 +406,10 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table2".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -379,10 +422,12 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 }],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                schema: None,
             },
             // Model with primary key seeded by sequence
             Model {
@@ -390,7 +435,10 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table3".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -403,13 +451,16 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 }],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                schema: None,
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -422,6 +473,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -434,6 +487,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                schema: None,
             },
             Table {
                 name: "Table2".to_string(),
@@ -443,6 +498,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -455,6 +512,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                schema: None,
             },
             Table {
                 name: "Table3".to_string(),
@@ -464,6 +523,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -481,10 +542,13 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     }),
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                schema: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -499,7 +563,10 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            check_constraints: vec![],
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field {
                     name: "non_unique".to_string(),
@@ -513,6 +580,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "unique".to_string(),
@@ -526,13 +594,16 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
             ],
             is_generated: false,
             indices: vec![],
             id_fields: vec![],
+            schema: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -559,9 +630,12 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             }],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -577,7 +651,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -591,6 +668,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "name".to_string(),
@@ -604,6 +682,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "User".to_string(),
@@ -612,7 +691,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             fields: vec![],
                             to_fields: vec![],
                             name: "CityToUser".to_string(),
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: None,
+                            on_update: None,
                         }),
                         arity: FieldArity::List,
                         database_name: None,
@@ -623,18 +703,23 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                schema: None,
             },
             Model {
                 database_name: None,
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -648,6 +733,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "city_id".to_string(),
@@ -661,6 +747,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "city_name".to_string(),
@@ -674,6 +761,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "City".to_string(),
@@ -683,7 +771,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             to: "City".to_string(),
                             fields: vec!["city_id".to_string(), "city_name".to_string()],
                             to_fields: vec!["id".to_string(), "name".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: Some(ReferentialAction::NoAction),
+                            on_update: Some(ReferentialAction::NoAction),
                         }),
                         database_name: None,
                         default_value: None,
@@ -693,14 +782,17 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                schema: None,
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -714,6 +806,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -727,6 +821,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "text".to_string(),
                             full_data_type: "text".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Required,
@@ -741,6 +837,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                schema: None,
             },
             Table {
                 name: "User".to_string(),
@@ -751,6 +849,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -764,6 +864,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -777,6 +879,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "text".to_string(),
                             full_data_type: "text".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Required,
@@ -796,12 +900,16 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     columns: vec!["city-id".to_string(), "city-name".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
+                check_constraints: vec![],
+                schema: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -816,7 +924,10 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
             name: "User".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            check_constraints: vec![],
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field {
                     name: "id".to_string(),
@@ -830,6 +941,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "name".to_string(),
@@ -843,6 +955,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
                 Field {
                     name: "lastname".to_string(),
@@ -856,6 +969,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
                 },
             ],
             is_generated: false,
@@ -865,8 +979,10 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 tpe: datamodel::dml::IndexType::Unique,
             }],
             id_fields: vec![],
+            schema: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -879,6 +995,8 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -892,6 +1010,8 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                         data_type: "text".to_string(),
                         full_data_type: "text".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::String,
                         arity: ColumnArity::Required,
@@ -905,6 +1025,8 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                         data_type: "text".to_string(),
                         full_data_type: "text".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::String,
                         arity: ColumnArity::Required,
@@ -923,9 +1045,12 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -941,7 +1066,10 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -955,6 +1083,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "name".to_string(),
@@ -968,6 +1097,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "User".to_string(),
@@ -977,7 +1107,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             fields: vec![],
                             to_fields: vec![],
                             name: "CityToUser".to_string(),
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: None,
+                            on_update: None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -987,18 +1118,23 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                schema: None,
             },
             Model {
                 database_name: None,
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -1012,6 +1148,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "city_id".to_string(),
@@ -1025,6 +1162,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                     Field {
                         name: "City".to_string(),
@@ -1034,7 +1172,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             to: "City".to_string(),
                             fields: vec!["city_id".to_string()],
                             to_fields: vec!["id".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: Some(ReferentialAction::NoAction),
+                            on_update: Some(ReferentialAction::NoAction),
                         }),
                         database_name: None,
                         default_value: None,
@@ -1044,14 +1183,17 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
                     },
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                schema: None,
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
@@ -1065,6 +1207,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -1078,6 +1222,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "text".to_string(),
                             full_data_type: "text".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Required,
@@ -1092,6 +1238,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                schema: None,
             },
             Table {
                 name: "User".to_string(),
@@ -1102,6 +1250,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -1115,6 +1265,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -1133,12 +1285,16 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["city_id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
+                check_constraints: vec![],
+                schema: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -1168,6 +1324,7 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
                 },
             ],
         }],
+        composite_types: vec![],
     };
 
     let enum_values = vec!["a".to_string(), "b".to_string()];
@@ -1178,8 +1335,496 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
             values: enum_values,
         }],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
     assert_eq!(introspection_result.datamodel, ref_data_model);
 }
+
+#[test]
+fn views_are_introspected_as_view_models() {
+    let ref_data_model = Datamodel {
+        models: vec![Model {
+            database_name: None,
+            name: "UserSummary".to_string(),
+            documentation: None,
+            is_embedded: false,
+            is_view: true,
+            check_constraints: vec![],
+            is_generated: false,
+            is_commented_out: false,
+            is_ignored: false,
+            indices: vec![],
+            id_fields: vec![],
+            fields: vec![
+                Field {
+                    name: "name".to_string(),
+                    arity: FieldArity::Optional,
+                    field_type: FieldType::Base(ScalarType::String, None),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    is_id: false,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    is_commented_out: false,
+                    is_ignored: false,
+                },
+                Field {
+                    name: "post_count".to_string(),
+                    arity: FieldArity::Optional,
+                    field_type: FieldType::Base(ScalarType::Int, None),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    is_id: false,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    is_commented_out: false,
+                    is_ignored: false,
+                },
+            ],
+            schema: None,
+        }],
+        enums: vec![],
+        composite_types: vec![],
+    };
+
+    let schema = SqlSchema {
+        tables: vec![],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![View {
+            name: "UserSummary".to_string(),
+            definition: Some("SELECT name, count(*) AS post_count FROM \"User\" GROUP BY name".to_string()),
+            columns: vec![
+                Column {
+                    name: "name".to_string(),
+                    tpe: ColumnType {
+                        data_type: "text".to_string(),
+                        full_data_type: "text".to_string(),
+                        character_maximum_length: None,
+                        precision: None,
+                        scale: None,
+
+                        family: ColumnTypeFamily::String,
+                        arity: ColumnArity::Nullable,
+                    },
+                    default: None,
+                    auto_increment: false,
+                },
+                Column {
+                    name: "post_count".to_string(),
+                    tpe: ColumnType {
+                        data_type: "integer".to_string(),
+                        full_data_type: "int4".to_string(),
+                        character_maximum_length: None,
+                        precision: None,
+                        scale: None,
+
+                        family: ColumnTypeFamily::Int,
+                        arity: ColumnArity::Nullable,
+                    },
+                    default: None,
+                    auto_increment: false,
+                },
+            ],
+            schema: None,
+        }],
+    };
+    let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
+
+    assert_eq!(introspection_result.datamodel, ref_data_model);
+    assert!(introspection_result.warnings.is_empty());
+}
+
+#[test]
+fn native_types_are_only_introspected_when_they_differ_from_the_defaults() {
+    let column = |name: &str, family: ColumnTypeFamily, full_data_type: &str, character_maximum_length| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            data_type: full_data_type.to_string(),
+            full_data_type: full_data_type.to_string(),
+            character_maximum_length,
+            precision: None,
+            scale: None,
+            family,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Blog".to_string(),
+            columns: vec![
+                column("id", ColumnTypeFamily::Int, "int4", None),
+                column("views", ColumnTypeFamily::Int, "int2", None),
+                column("likes", ColumnTypeFamily::BigInt, "int8", None),
+                column("title", ColumnTypeFamily::String, "varchar", Some(255)),
+                column("content", ColumnTypeFamily::String, "text", None),
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let introspection_result =
+        calculate_datamodel_with_native_types(&schema, &SqlFamily::Postgres).expect("calculate data model");
+    let model = introspection_result.datamodel.find_model("Blog").unwrap();
+    let native_type = |name: &str| match &model.find_field(name).unwrap().field_type {
+        FieldType::ConnectorSpecific(native_type) => Some(native_type.datasource_type().to_owned()),
+        _ => None,
+    };
+
+    assert_eq!(native_type("id"), None);
+    assert_eq!(native_type("views"), Some("smallint".to_owned()));
+    assert_eq!(native_type("likes"), None);
+    assert_eq!(native_type("title"), Some("varchar(255)".to_owned()));
+    assert_eq!(native_type("content"), None);
+
+    let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
+    let model = introspection_result.datamodel.find_model("Blog").unwrap();
+
+    assert_eq!(
+        model.find_field("views").unwrap().field_type,
+        FieldType::Base(ScalarType::Int, None)
+    );
+    assert_eq!(
+        model.find_field("likes").unwrap().field_type,
+        FieldType::Base(ScalarType::BigInt, None)
+    );
+}
+
+#[test]
+fn numeric_columns_are_introspected_as_decimals_unless_they_store_floats() {
+    let column = |name: &str, precision, scale| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            data_type: "numeric".to_string(),
+            full_data_type: "numeric".to_string(),
+            character_maximum_length: None,
+            precision,
+            scale,
+            family: ColumnTypeFamily::Decimal,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Product".to_string(),
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: true,
+                },
+                column("weight", Some(65), Some(30)),
+                column("price", Some(10), Some(2)),
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let introspection_result =
+        calculate_datamodel_with_native_types(&schema, &SqlFamily::Postgres).expect("calculate data model");
+    let model = introspection_result.datamodel.find_model("Product").unwrap();
+
+    assert_eq!(
+        model.find_field("weight").unwrap().field_type,
+        FieldType::Base(ScalarType::Float, None)
+    );
+
+    match &model.find_field("price").unwrap().field_type {
+        FieldType::ConnectorSpecific(native_type) => {
+            assert_eq!(native_type.prisma_type(), ScalarType::Decimal);
+            assert_eq!(native_type.datasource_type(), "numeric(10,2)");
+        }
+        other => panic!("Expected a native type, got {:?}", other),
+    }
+}
+
+#[test]
+fn postgres_time_precisions_are_introspected_as_native_type_arguments() {
+    let column = |name: &str, full_data_type: &str, precision| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            data_type: full_data_type.to_string(),
+            full_data_type: full_data_type.to_string(),
+            character_maximum_length: None,
+            precision,
+            scale: None,
+            family: ColumnTypeFamily::DateTime,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Event".to_string(),
+            columns: vec![
+                column("created_at", "timestamp", Some(3)),
+                column("updated_at", "timestamp", Some(6)),
+                column("starts_at", "timestamptz", Some(0)),
+                column("time", "time", Some(6)),
+            ],
+            indices: vec![],
+            primary_key: None,
+            foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let introspection_result =
+        calculate_datamodel_with_native_types(&schema, &SqlFamily::Postgres).expect("calculate data model");
+    let model = introspection_result.datamodel.find_model("Event").unwrap();
+    let native_type = |name: &str| match &model.find_field(name).unwrap().field_type {
+        FieldType::ConnectorSpecific(native_type) => Some(native_type.datasource_type().to_owned()),
+        _ => None,
+    };
+
+    assert_eq!(native_type("created_at"), None);
+    assert_eq!(native_type("updated_at"), Some("timestamp".to_owned()));
+    assert_eq!(native_type("starts_at"), Some("timestamptz(0)".to_owned()));
+    assert_eq!(native_type("time"), Some("time".to_owned()));
+}
+
+#[test]
+fn check_constraints_are_introspected() {
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Product".to_string(),
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: true,
+                },
+                Column {
+                    name: "price".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: false,
+                },
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            check_constraints: vec![CheckConstraint {
+                name: Some("Product_price_check".to_string()),
+                expression: "(price > 0)".to_string(),
+            }],
+            schema: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
+    let model = introspection_result.datamodel.find_model("Product").unwrap();
+
+    assert_eq!(
+        model.check_constraints,
+        vec![dml::CheckConstraint {
+            name: Some("Product_price_check".to_string()),
+            expression: "(price > 0)".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn tables_from_additional_schemas_are_introspected_with_their_schema() {
+    let id_column = Column {
+        name: "id".to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+        default: None,
+        auto_increment: true,
+    };
+    let primary_key = PrimaryKey {
+        columns: vec!["id".to_string()],
+        sequence: None,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![
+            Table {
+                name: "User".to_string(),
+                columns: vec![id_column.clone()],
+                indices: vec![],
+                primary_key: Some(primary_key.clone()),
+                foreign_keys: vec![],
+                check_constraints: vec![],
+                schema: None,
+            },
+            Table {
+                name: "Invoice".to_string(),
+                columns: vec![
+                    id_column,
+                    Column {
+                        name: "user_id".to_string(),
+                        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                        default: None,
+                        auto_increment: false,
+                    },
+                ],
+                indices: vec![],
+                primary_key: Some(primary_key),
+                foreign_keys: vec![ForeignKey {
+                    constraint_name: None,
+                    columns: vec!["user_id".to_string()],
+                    referenced_table: "User".to_string(),
+                    on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
+                    referenced_columns: vec!["id".to_string()],
+                }],
+                check_constraints: vec![],
+                schema: Some("billing".to_string()),
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
+    let datamodel = introspection_result.datamodel;
+
+    assert_eq!(datamodel.find_model("User").unwrap().schema, None);
+
+    let invoice = datamodel.find_model("Invoice").unwrap();
+    assert_eq!(invoice.schema, Some("billing".to_string()));
+    assert!(invoice
+        .fields()
+        .any(|field| matches!(&field.field_type, FieldType::Relation(info) if info.to == "User")));
+}
+
+#[test]
+fn re_introspection_keeps_ignored_models_and_fields() {
+    let id_column = Column {
+        name: "id".to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+        default: None,
+        auto_increment: true,
+    };
+    let primary_key = PrimaryKey {
+        columns: vec!["id".to_string()],
+        sequence: None,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![
+            Table {
+                name: "User".to_string(),
+                columns: vec![id_column.clone()],
+                indices: vec![],
+                primary_key: Some(primary_key.clone()),
+                foreign_keys: vec![],
+                check_constraints: vec![],
+                schema: None,
+            },
+            Table {
+                name: "Post".to_string(),
+                columns: vec![
+                    id_column,
+                    Column {
+                        name: "user_id".to_string(),
+                        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                        default: None,
+                        auto_increment: false,
+                    },
+                    Column {
+                        name: "secret".to_string(),
+                        tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+                        default: None,
+                        auto_increment: false,
+                    },
+                ],
+                indices: vec![],
+                primary_key: Some(primary_key),
+                foreign_keys: vec![ForeignKey {
+                    constraint_name: None,
+                    columns: vec!["user_id".to_string()],
+                    referenced_table: "User".to_string(),
+                    on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
+                    referenced_columns: vec!["id".to_string()],
+                }],
+                check_constraints: vec![],
+                schema: None,
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let previous_data_model = datamodel::parse_datamodel(
+        r#"
+        model User {
+          id   Int    @id
+          Post Post[]
+
+          @@ignore
+        }
+
+        model Post {
+          id      Int    @id
+          user_id Int
+          secret  String @ignore
+          User    User   @relation(fields: [user_id], references: [id]) @ignore
+        }
+        "#,
+    )
+    .unwrap()
+    .subject;
+
+    let mut introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
+    keep_ignore_attributes(&previous_data_model, &mut introspection_result.datamodel);
+    let datamodel = introspection_result.datamodel;
+
+    assert!(datamodel.find_model("User").unwrap().is_ignored);
+
+    let post = datamodel.find_model("Post").unwrap();
+    assert!(!post.is_ignored);
+    assert!(post.find_field("secret").unwrap().is_ignored);
+    assert!(post.find_field("User").unwrap().is_ignored);
+    assert!(!post.find_field("user_id").unwrap().is_ignored);
+}
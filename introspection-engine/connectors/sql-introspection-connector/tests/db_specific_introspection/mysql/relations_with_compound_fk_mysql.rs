@@ -28,7 +28,7 @@ async fn compound_foreign_keys_should_work_for_one_to_one_relations(api: &TestAp
                 id       Int   @default(autoincrement()) @id
                 user_age Int?
                 user_id  Int?
-                User     User? @relation(fields: [user_id, user_age], references: [id, age])
+                User     User? @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@unique([user_id, user_age], name: "post_user_unique")
             }
@@ -71,7 +71,7 @@ async fn compound_foreign_keys_should_work_for_required_one_to_one_relations(api
                 id       Int  @default(autoincrement()) @id
                 user_age Int
                 user_id  Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@unique([user_id, user_age], name: "post_user_unique")
             }
@@ -114,7 +114,7 @@ async fn compound_foreign_keys_should_work_for_one_to_many_relations(api: &TestA
                 id       Int   @default(autoincrement()) @id
                 user_age Int?
                 user_id  Int?
-                User     User? @relation(fields: [user_id, user_age], references: [id, age])
+                User     User? @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@index([user_id, user_age], name: "user_id")
             }
@@ -157,7 +157,7 @@ async fn compound_foreign_keys_should_work_for_required_one_to_many_relations(ap
                 id       Int  @default(autoincrement()) @id
                 user_age Int
                 user_id  Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@index([user_id, user_age], name: "user_id")
             }
@@ -197,7 +197,7 @@ async fn compound_foreign_keys_should_work_for_required_self_relations(api: &Tes
                 id           Int      @default(autoincrement()) @id
                 partner_age  Int
                 partner_id   Int
-                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                         
                 @@index([partner_id, partner_age], name: "partner_id")
@@ -231,7 +231,7 @@ async fn compound_foreign_keys_should_work_for_self_relations(api: &TestApi) {
                 id           Int      @default(autoincrement()) @id
                 partner_age  Int?
                 partner_id   Int?
-                Person       Person?  @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person?  @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                 
                 @@index([partner_id, partner_age], name: "partner_id")
@@ -265,7 +265,7 @@ async fn compound_foreign_keys_should_work_with_defaults(api: &TestApi) {
                 id           Int      @default(autoincrement()) @id
                 partner_age  Int      @default(0)
                 partner_id   Int      @default(0)
-                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                 
                 @@index([partner_id, partner_age], name: "partner_id")
@@ -346,7 +346,7 @@ async fn compound_foreign_keys_should_work_for_one_to_many_relations_with_non_un
                 id       Int  @default(autoincrement()) @id
                 user_age Int
                 user_id  Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@index([user_id, user_age], name: "user_id")
             }
@@ -92,7 +92,7 @@ async fn remapping_models_in_relations_should_work(api: &TestApi) {
             model Post {
                 id              Int             @default(autoincrement()) @id
                 user_id         Int             @unique
-                User_with_Space User_with_Space @relation(fields: [user_id], references: [id])
+                User_with_Space User_with_Space @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
                   
             model User_with_Space {
@@ -129,7 +129,7 @@ async fn remapping_models_in_relations_should_not_map_virtual_fields(api: &TestA
             model Post_With_Space {
                 id      Int  @default(autoincrement()) @id
                 user_id Int  @unique
-                User    User @relation(fields: [user_id], references: [id])
+                User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@map("Post With Space")
             }
@@ -170,7 +170,7 @@ async fn remapping_models_in_compound_relations_should_work(api: &TestApi) {
                 id              Int             @default(autoincrement()) @id
                 user_age        Int
                 user_id         Int
-                User_with_Space User_with_Space @relation(fields: [user_id, user_age], references: [id, age])
+                User_with_Space User_with_Space @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@unique([user_id, user_age], name: "post_user_unique")
             }
@@ -214,7 +214,7 @@ async fn remapping_fields_in_compound_relations_should_work(api: &TestApi) {
                 id       Int  @default(autoincrement()) @id
                 user_age Int
                 user_id  Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age_that_is_invalid])
+                User     User @relation(fields: [user_id, user_age], references: [id, age_that_is_invalid], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@unique([user_id, user_age], name: "post_user_unique")
             }
@@ -292,7 +292,7 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
 }
 
 #[test_each_connector(tags("mysql"))]
-async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestApi) {
+async fn introspecting_a_table_without_uniques_should_ignore_it(api: &TestApi) {
     api.barrel()
         .execute(|migration| {
             migration.create_table("User", |t| {
@@ -308,7 +308,7 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestA
         })
         .await;
 
-    let dm = "// The underlying table does not contain a unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n\n  // @@index([user_id], name: \"user_id\")\n// }\n\nmodel User {\n  id Int @default(autoincrement()) @id\n}";
+    let dm = "/// The underlying table does not contain a unique identifier and can therefore currently not be handled.\nmodel Post {\n  id      Int\n  user_id Int\n  User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)\n\n  @@index([user_id], name: \"user_id\")\n  @@ignore\n}\n\nmodel User {\n  id   Int    @default(autoincrement()) @id\n  Post Post[] @ignore\n}";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
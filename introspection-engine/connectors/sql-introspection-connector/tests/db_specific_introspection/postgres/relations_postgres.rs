@@ -21,7 +21,7 @@ async fn introspecting_a_one_to_one_req_relation_should_work(api: &TestApi) {
              model Post {
                id       Int @id @default(autoincrement())
                user_id  Int  @unique
-               User     User @relation(fields: [user_id], references: [id])
+               User     User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -55,14 +55,14 @@ async fn introspecting_two_one_to_one_relations_between_the_same_models_should_w
             model Post {
             id                      Int   @default(autoincrement()) @id
             user_id                 Int   @unique
-            User_Post_user_idToUser User  @relation("Post_user_idToUser", fields: [user_id], references: [id])
+            User_Post_user_idToUser User  @relation("Post_user_idToUser", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             User_PostToUser_post_id User? @relation("PostToUser_post_id")
         }
 
         model User {
             id                      Int   @default(autoincrement()) @id
             post_id                 Int   @unique
-            Post_PostToUser_post_id Post  @relation("PostToUser_post_id", fields: [post_id], references: [id])
+            Post_PostToUser_post_id Post  @relation("PostToUser_post_id", fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             Post_Post_user_idToUser Post? @relation("Post_user_idToUser")
         }
         "#;
@@ -88,7 +88,7 @@ async fn introspecting_a_one_to_one_relation_should_work(api: &TestApi) {
                model Post {
                 id      Int   @default(autoincrement()) @id
                 user_id Int?  @unique
-                User    User? @relation(fields: [user_id], references: [id])
+                User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -119,7 +119,7 @@ async fn introspecting_a_one_to_one_relation_referencing_non_id_should_work(api:
             model Post {
                 id         Int     @default(autoincrement()) @id
                 user_email String? @unique
-                User       User?   @relation(fields: [user_email], references: [email])
+                User       User?   @relation(fields: [user_email], references: [email], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -150,7 +150,7 @@ async fn introspecting_a_one_to_many_relation_should_work(api: &TestApi) {
              model Post {
                 id      Int   @default(autoincrement()) @id
                 user_id Int?
-                User    User? @relation(fields: [user_id], references: [id])
+                User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -192,7 +192,7 @@ async fn introspecting_duplicate_fks_should_ignore_one_of_them(api: &TestApi) {
              model Post {
                  id      Int   @default(autoincrement()) @id
                  user_id Int?
-                 User    User? @relation("Post_user_idToUser", fields: [user_id], references: [id])
+                 User    User? @relation("Post_user_idToUser", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
              }
 
              model User {
@@ -222,7 +222,7 @@ async fn introspecting_a_one_req_to_many_relation_should_work(api: &TestApi) {
             model Post {
                 id      Int  @default(autoincrement()) @id
                 user_id Int
-                User    User @relation(fields: [user_id], references: [id])
+                User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -405,8 +405,8 @@ async fn introspecting_a_many_to_many_relation_with_an_id_should_work(api: &Test
                 id      Int  @id
                 post_id Int
                 user_id Int
-                Post    Post @relation(fields: [post_id], references: [id])
-                User    User @relation(fields: [user_id], references: [id])
+                Post    Post @relation(fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -436,8 +436,8 @@ async fn introspecting_a_self_relation_should_work(api: &TestApi) {
                 direct_report                       Int?
                 id                                  Int    @default(autoincrement()) @id
                 recruited_by                        Int?
-                User_UserToUser_direct_report       User?  @relation("UserToUser_direct_report", fields: [direct_report], references: [id])
-                User_UserToUser_recruited_by        User?  @relation("UserToUser_recruited_by", fields: [recruited_by], references: [id])
+                User_UserToUser_direct_report       User?  @relation("UserToUser_direct_report", fields: [direct_report], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                User_UserToUser_recruited_by        User?  @relation("UserToUser_recruited_by", fields: [recruited_by], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 other_User_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                 other_User_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
             }
@@ -446,38 +446,36 @@ async fn introspecting_a_self_relation_should_work(api: &TestApi) {
     custom_assert(&result, dm);
 }
 
-// on delete cascade
-
-// TODO: bring `onDelete` back once `prisma migrate` is a thing
-//#[test_each_connector(tags("postgres"))]
-// async fn introspecting_cascading_delete_behaviour_should_work(api: &TestApi) {
-//     let barrel = api.barrel();
-//     barrel
-//         .execute(|migration| {
-//             migration.create_table("User", |t| {
-//                 t.add_column("id", types::primary());
-//             });
-//             migration.create_table("Post", |t| {
-//                 t.add_column("id", types::primary());
-//                 t.inject_custom("user_id INTEGER REFERENCES \"User\"(\"id\") ON DELETE CASCADE");
-//             });
-//         })
-//         .await;
-//
-//     let dm = r#"
-//             model Post {
-//                id      Int @id @default(autoincrement())
-//                user_id User?
-//             }
-//
-//             model User {
-//                id    Int @id @default(autoincrement())
-//                Post  Post[] @relation(onDelete: CASCADE)
-//             }
-//         "#;
-//     let result = dbg!(api.introspect().await);
-//     custom_assert(&result, dm);
-// }
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_referential_actions_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+            });
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("user_id INTEGER REFERENCES \"User\"(\"id\") ON DELETE RESTRICT ON UPDATE SET NULL");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Post {
+                id      Int   @default(autoincrement()) @id
+                user_id Int?
+                User    User? @relation(fields: [user_id], references: [id], onDelete: Restrict, onUpdate: SetNull)
+            }
+
+            model User {
+                id   Int    @default(autoincrement()) @id
+                Post Post[]
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
 
 #[test_each_connector(tags("postgres"))]
 async fn introspecting_default_values_on_relations_should_work(api: &TestApi) {
@@ -498,7 +496,7 @@ async fn introspecting_default_values_on_relations_should_work(api: &TestApi) {
             model Post {
                 id      Int   @default(autoincrement()) @id
                 user_id Int?  @default(0)
-                User    User? @relation(fields: [user_id], references: [id])
+                User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -529,7 +527,7 @@ async fn introspecting_id_fields_with_foreign_key_should_work(api: &TestApi) {
             model Post {
                 test    String
                 user_id Int    @id
-                User    User   @relation(fields: [user_id], references: [id])
+                User    User   @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -617,7 +615,7 @@ async fn introspecting_relations_should_avoid_name_clashes(api: &TestApi) {
             model x {
                 id String @id
                 y  String
-                y_xToy  y      @relation(fields: [y], references: [id])
+                y_xToy  y      @relation(fields: [y], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model y {
@@ -706,7 +704,7 @@ async fn introspecting_relations_should_avoid_name_clashes_2(api: &TestApi) {
             model x {
                 id                   String @id
                 y                    String
-                y_x_yToy             y      @relation("x_yToy", fields: [y], references: [id])
+                y_x_yToy             y      @relation("x_yToy", fields: [y], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 y_xToy_fk_x_1_fk_x_2 y[]    @relation("xToy_fk_x_1_fk_x_2")
 
                 @@unique([id, y], name: "unique_y_id")
@@ -717,7 +715,7 @@ async fn introspecting_relations_should_avoid_name_clashes_2(api: &TestApi) {
                fk_x_2               String
                id                   String @id
                x                    String
-               x_xToy_fk_x_1_fk_x_2 x      @relation("xToy_fk_x_1_fk_x_2", fields: [fk_x_1, fk_x_2], references: [y, id])
+               x_xToy_fk_x_1_fk_x_2 x      @relation("xToy_fk_x_1_fk_x_2", fields: [fk_x_1, fk_x_2], references: [y, id], onDelete: NoAction, onUpdate: NoAction)
                x_x_yToy             x[]    @relation("x_yToy")
             }
         "#;
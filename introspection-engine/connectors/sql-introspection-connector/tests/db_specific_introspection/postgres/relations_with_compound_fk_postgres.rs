@@ -28,7 +28,7 @@ async fn compound_foreign_keys_should_work_for_required_one_to_one_relations(api
                 id        Int    @default(autoincrement()) @id
                 user_id   Int
                 user_name String
-                User      User   @relation(fields: [user_id, user_name], references: [id, name])
+                User      User   @relation(fields: [user_id, user_name], references: [id, name], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@unique([user_id, user_name], name: "post_user_unique")
             }
@@ -71,7 +71,7 @@ async fn compound_foreign_keys_should_work_for_one_to_one_relations(api: &TestAp
                 id        Int     @default(autoincrement()) @id
                 user_id   Int?
                 user_name String?
-                User      User?   @relation(fields: [user_id, user_name], references: [id, name])
+                User      User?   @relation(fields: [user_id, user_name], references: [id, name], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@unique([user_id, user_name], name: "post_user_unique")
             }
@@ -113,7 +113,7 @@ async fn compound_foreign_keys_should_work_for_one_to_many_relations(api: &TestA
                 id        Int     @default(autoincrement()) @id
                 user_id   Int?
                 user_name String?
-                User      User?   @relation(fields: [user_id, user_name], references: [id, name])
+                User      User?   @relation(fields: [user_id, user_name], references: [id, name], onDelete: NoAction, onUpdate: NoAction)
            }
            
            model User {
@@ -153,7 +153,7 @@ async fn compound_foreign_keys_should_work_for_required_one_to_many_relations(ap
                 id        Int    @default(autoincrement()) @id
                 user_id   Int
                 user_name String
-                User      User   @relation(fields: [user_id, user_name], references: [id, name])
+                User      User   @relation(fields: [user_id, user_name], references: [id, name], onDelete: NoAction, onUpdate: NoAction)
             }
             
             model User {
@@ -193,7 +193,7 @@ async fn compound_foreign_keys_should_work_for_self_relations(api: &TestApi) {
                 name         String
                 partner_id   Int
                 partner_name String
-                Person       Person   @relation("PersonToPerson_partner_id_partner_name", fields: [partner_id, partner_name], references: [id, name])
+                Person       Person   @relation("PersonToPerson_partner_id_partner_name", fields: [partner_id, partner_name], references: [id, name], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_name")
                 
                 @@unique([id, name], name: "person_unique")
@@ -229,7 +229,7 @@ async fn compound_foreign_keys_should_work_with_defaults(api: &TestApi) {
                     name         String
                     partner_id   Int      @default(0)
                     partner_name String   @default("")
-                    Person       Person   @relation("PersonToPerson_partner_id_partner_name", fields: [partner_id, partner_name], references: [id, name])
+                    Person       Person   @relation("PersonToPerson_partner_id_partner_name", fields: [partner_id, partner_name], references: [id, name], onDelete: NoAction, onUpdate: NoAction)
                     other_Person Person[] @relation("PersonToPerson_partner_id_partner_name")
                     
                     @@unique([id, name], name: "person_unique")
@@ -310,7 +310,7 @@ async fn compound_foreign_keys_should_work_for_one_to_many_relations_with_non_un
                 id       Int  @default(autoincrement()) @id
                 user_age Int
                 user_id  Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@index([user_id, user_age], name: "test")
             }
@@ -361,7 +361,7 @@ async fn repro_matt_references_on_wrong_side(api: &TestApi) {
                 one Int
                 two Int
                 
-                a   a   @relation(fields: [one, two], references: [one, two])
+                a   a   @relation(fields: [one, two], references: [one, two], onDelete: NoAction, onUpdate: NoAction)
             }             
         "#;
     let result = dbg!(api.introspect().await);
@@ -402,7 +402,7 @@ async fn introspecting_a_compound_fk_pk_with_overlapping_primary_key_should_work
                 dummy Int
                 one   Int
                 two   Int
-                a     a   @relation(fields: [one, two], references: [one, two])
+                a     a   @relation(fields: [one, two], references: [one, two], onDelete: NoAction, onUpdate: NoAction)
             
                 @@id([dummy, one, two])
             }
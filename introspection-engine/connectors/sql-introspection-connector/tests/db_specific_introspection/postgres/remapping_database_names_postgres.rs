@@ -96,7 +96,7 @@ async fn remapping_fk_columns_with_invalid_characters_should_work(api: &TestApi)
             model User {
                 id      Int  @default(autoincrement()) @id
                 post_id Int  @map("post id")
-                Post    Post @relation(fields: [post_id], references: [id])
+                Post    Post @relation(fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -125,7 +125,7 @@ async fn remapping_models_in_relations_should_work(api: &TestApi) {
             model Post {
                 id              Int             @default(autoincrement()) @id
                 user_id         Int             @unique
-                User_with_Space User_with_Space @relation(fields: [user_id], references: [id])
+                User_with_Space User_with_Space @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
             
             model User_with_Space {
@@ -169,7 +169,7 @@ async fn remapping_models_in_compound_relations_should_work(api: &TestApi) {
                 id              Int             @default(autoincrement()) @id
                 user_id         Int
                 user_name       String
-                User_with_Space User_with_Space @relation(fields: [user_id, user_name], references: [id, name])
+                User_with_Space User_with_Space @relation(fields: [user_id, user_name], references: [id, name], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@unique([user_id, user_name], name: "post_user_unique")
             }
@@ -216,7 +216,7 @@ async fn remapping_fields_in_compound_relations_should_work(api: &TestApi) {
                 id        Int    @default(autoincrement()) @id
                 user_id   Int
                 user_name String
-                User      User   @relation(fields: [user_id, user_name], references: [id, name_that_is_invalid])
+                User      User   @relation(fields: [user_id, user_name], references: [id, name_that_is_invalid], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@unique([user_id, user_name], name: "post_user_unique")
             }
@@ -287,7 +287,7 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestApi) {
+async fn introspecting_a_table_without_uniques_should_ignore_it(api: &TestApi) {
     api.barrel()
         .execute(|migration| {
             migration.create_table("User", |t| {
@@ -300,7 +300,7 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestA
         })
         .await;
 
-    let dm = "// The underlying table does not contain a unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n// }\n\nmodel User {\n  id Int @default(autoincrement()) @id\n}";
+    let dm = "/// The underlying table does not contain a unique identifier and can therefore currently not be handled.\nmodel Post {\n  id      Int\n  user_id Int\n  User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)\n\n  @@ignore\n}\n\nmodel User {\n  id   Int    @default(autoincrement()) @id\n  Post Post[] @ignore\n}";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
@@ -567,7 +567,7 @@ async fn introspecting_an_unsupported_type_should_and_commenting_it_out_should_a
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_a_table_with_only_an_unsupported_id_type_should_comment_it_out(api: &TestApi) {
+async fn introspecting_a_table_with_only_an_unsupported_id_type_should_ignore_it(api: &TestApi) {
     let barrel = api.barrel();
     let _setup_schema = barrel
         .execute(|migration| {
@@ -581,9 +581,9 @@ async fn introspecting_a_table_with_only_an_unsupported_id_type_should_comment_i
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":1,\"message\":\"These models do not have a unique identifier or id and are therefore commented out.\",\"affected\":[{\"model\":\"Test\"}]},{\"code\":3,\"message\":\"These fields were commented out because we currently do not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":1,\"message\":\"These models do not have a unique identifier or id and are therefore ignored.\",\"affected\":[{\"model\":\"Test\"}]},{\"code\":3,\"message\":\"These fields were commented out because we currently do not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
     );
 
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "// The underlying table does not contain a unique identifier and can therefore currently not be handled.\n// model Test {\n  // dummy       Int\n  // This type is currently not supported.\n  // network_mac macaddr @id\n// }");
+    assert_eq!(&result, "/// The underlying table does not contain a unique identifier and can therefore currently not be handled.\nmodel Test {\n  dummy       Int\n  // This type is currently not supported.\n  // network_mac macaddr @id\n\n  @@ignore\n}");
 }
@@ -36,7 +36,7 @@ async fn compound_foreign_keys_should_work_for_one_to_one_relations(api: &TestAp
                 id       Int   @default(autoincrement()) @id
                 user_age Int?
                 user_id  Int?
-                User     User? @relation(fields: [user_id, user_age], references: [id, age])
+                User     User? @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@unique([user_id, user_age], name: "sqlite_autoindex_Post_1")
             }
@@ -80,7 +80,7 @@ async fn compound_foreign_keys_should_work_for_required_one_to_one_relations(api
                 id       Int  @default(autoincrement()) @id
                 user_age Int
                 user_id  Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@unique([user_id, user_age], name: "sqlite_autoindex_Post_1")
             }
@@ -123,7 +123,7 @@ async fn compound_foreign_keys_should_work_for_one_to_many_relations(api: &TestA
                   id       Int   @default(autoincrement()) @id
                   user_age Int?
                   user_id  Int?
-                  User     User? @relation(fields: [user_id, user_age], references: [id, age])
+                  User     User? @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             }
 
         "#;
@@ -170,8 +170,8 @@ async fn compound_foreign_keys_should_work_for_duplicate_one_to_many_relations(a
                other_user_id                                    Int?
                user_age                                         Int?
                user_id                                          Int?
-               User_Post_other_user_id_other_user_ageToUser     User? @relation("Post_other_user_id_other_user_ageToUser", fields: [other_user_id, other_user_age], references: [id, age])
-               User_Post_user_id_user_ageToUser                 User? @relation("Post_user_id_user_ageToUser", fields: [user_id, user_age], references: [id, age])
+               User_Post_other_user_id_other_user_ageToUser     User? @relation("Post_other_user_id_other_user_ageToUser", fields: [other_user_id, other_user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
+               User_Post_user_id_user_ageToUser                 User? @relation("Post_user_id_user_ageToUser", fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             }
 
         "#;
@@ -212,7 +212,7 @@ async fn compound_foreign_keys_should_work_for_required_one_to_many_relations(ap
                 id       Int  @default(autoincrement()) @id
                 user_age Int
                 user_id  Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -242,7 +242,7 @@ async fn compound_foreign_keys_should_work_for_required_self_relations(api: &Tes
                 id           Int      @default(autoincrement()) @id
                 partner_age  Int
                 partner_id   Int
-                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                 
                 @@unique([id, age], name: "sqlite_autoindex_Person_1")
@@ -276,7 +276,7 @@ async fn compound_foreign_keys_should_work_for_self_relations(api: &TestApi) {
                 id           Int      @default(autoincrement()) @id
                 partner_age  Int?
                 partner_id   Int?
-                Person       Person?  @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person?  @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                 
                 @@unique([id, age], name: "sqlite_autoindex_Person_1")
@@ -310,7 +310,7 @@ async fn compound_foreign_keys_should_work_with_defaults(api: &TestApi) {
               id           Int      @default(autoincrement()) @id
               partner_age  Int      @default(0)
               partner_id   Int      @default(0)
-              Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+              Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
               other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
               
               @@unique([id, age], name: "sqlite_autoindex_Person_1")
@@ -401,7 +401,7 @@ async fn compound_foreign_keys_should_work_for_one_to_many_relations_with_non_un
                 id       Int  @default(autoincrement()) @id
                 user_age Int
                 user_id  Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@index([user_id, user_age], name: "test")
             }
@@ -100,7 +100,7 @@ async fn remapping_models_in_relations_should_work(api: &TestApi) {
             model Post {
                 id              Int             @default(autoincrement()) @id
                 user_id         Int             @unique
-                User_with_Space User_with_Space @relation(fields: [user_id], references: [id])
+                User_with_Space User_with_Space @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
             
         "#;
@@ -143,7 +143,7 @@ async fn remapping_models_in_compound_relations_should_work(api: &TestApi) {
                 id              Int             @default(autoincrement()) @id
                 user_age        Int
                 user_id         Int
-                User_with_Space User_with_Space  @relation(fields: [user_id, user_age], references: [id, age])
+                User_with_Space User_with_Space  @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                               
                 @@unique([user_id, user_age], name: "sqlite_autoindex_Post_1")
             }
@@ -186,7 +186,7 @@ async fn remapping_fields_in_compound_relations_should_work(api: &TestApi) {
                 id       Int  @default(autoincrement()) @id
                 user_age Int
                 user_id  Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age_that_is_invalid])
+                User     User @relation(fields: [user_id, user_age], references: [id, age_that_is_invalid], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@unique([user_id, user_age], name: "sqlite_autoindex_Post_1")
             }
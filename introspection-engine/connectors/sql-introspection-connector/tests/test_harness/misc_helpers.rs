@@ -6,7 +6,7 @@ use std::sync::Arc;
 //todo split into barrel and assert???
 
 pub(crate) fn custom_assert(left: &str, right: &str) {
-    let parsed_expected = datamodel::parse_datamodel(&right).unwrap();
+    let parsed_expected = datamodel::parse_datamodel(&right).unwrap().subject;
     let reformatted_expected =
         datamodel::render_datamodel_to_string(&parsed_expected).expect("Datamodel rendering failed");
 
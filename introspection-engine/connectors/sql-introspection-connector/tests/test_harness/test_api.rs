@@ -1,4 +1,5 @@
 use super::misc_helpers::*;
+use datamodel::Datamodel;
 use introspection_connector::{DatabaseMetadata, IntrospectionConnector, Version};
 use quaint::{
     prelude::{Queryable, SqlFamily},
@@ -26,17 +27,29 @@ impl TestApi {
     }
 
     pub async fn introspect(&self) -> String {
-        let introspection_result = self.introspection_connector.introspect().await.unwrap();
+        let introspection_result = self
+            .introspection_connector
+            .introspect(&Datamodel::new())
+            .await
+            .unwrap();
         datamodel::render_datamodel_to_string(&introspection_result.datamodel).expect("Datamodel rendering failed")
     }
 
     pub async fn introspect_version(&self) -> Version {
-        let introspection_result = self.introspection_connector.introspect().await.unwrap();
+        let introspection_result = self
+            .introspection_connector
+            .introspect(&Datamodel::new())
+            .await
+            .unwrap();
         introspection_result.version
     }
 
     pub async fn introspection_warnings(&self) -> String {
-        let introspection_result = self.introspection_connector.introspect().await.unwrap();
+        let introspection_result = self
+            .introspection_connector
+            .introspect(&Datamodel::new())
+            .await
+            .unwrap();
         serde_json::to_string(&introspection_result.warnings).unwrap()
     }
 
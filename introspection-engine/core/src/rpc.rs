@@ -30,19 +30,27 @@ pub struct RpcImpl;
 
 impl Rpc for RpcImpl {
     fn list_databases(&self, input: IntrospectionInput) -> RpcFutureResult<Vec<String>> {
-        Box::new(Self::list_databases_internal(input.schema).boxed().compat())
+        Box::new(Self::list_databases_internal(input.schema_source()).boxed().compat())
     }
 
     fn get_database_metadata(&self, input: IntrospectionInput) -> RpcFutureResult<DatabaseMetadata> {
-        Box::new(Self::get_database_metadata_internal(input.schema).boxed().compat())
+        Box::new(
+            Self::get_database_metadata_internal(input.schema_source())
+                .boxed()
+                .compat(),
+        )
     }
 
     fn get_database_description(&self, input: IntrospectionInput) -> RpcFutureResult<String> {
-        Box::new(Self::get_database_description(input.schema).boxed().compat())
+        Box::new(Self::get_database_description(input.schema_source()).boxed().compat())
     }
 
     fn introspect(&self, input: IntrospectionInput) -> RpcFutureResult<IntrospectionResultOutput> {
-        Box::new(Self::introspect_internal(input.schema).boxed().compat())
+        Box::new(
+            Self::introspect_internal(input.schema_source(), input.native_types)
+                .boxed()
+                .compat(),
+        )
     }
 }
 
@@ -51,19 +59,22 @@ impl RpcImpl {
         RpcImpl
     }
 
-    async fn load_connector(schema: &String) -> Result<Box<dyn IntrospectionConnector>, Error> {
+    async fn load_connector(schema: &String, native_types: bool) -> Result<Box<dyn IntrospectionConnector>, Error> {
         let config = datamodel::parse_configuration(&schema)?;
-        let url = config
+        let datasource = config
             .datasources
             .first()
-            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?
-            .url()
-            .to_owned()
-            .value;
-        Ok(Box::new(SqlIntrospectionConnector::new(&url).await?))
+            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?;
+        let url = datasource.url().to_owned().value;
+        Ok(Box::new(
+            SqlIntrospectionConnector::new(&url)
+                .await?
+                .with_native_types(native_types)
+                .with_schemas(datasource.schemas()),
+        ))
     }
 
-    pub async fn introspect_internal(schema: String) -> RpcResult<IntrospectionResultOutput> {
+    pub async fn introspect_internal(schema: String, native_types: bool) -> RpcResult<IntrospectionResultOutput> {
         let config = datamodel::parse_configuration(&schema).map_err(Error::from)?;
         let url = config
             .datasources
@@ -73,8 +84,9 @@ impl RpcImpl {
             .url()
             .to_owned()
             .value;
-        let connector = RpcImpl::load_connector(&schema).await?;
-        let data_model = connector.introspect().await;
+        let previous_data_model = datamodel::parse_datamodel(&schema).map_err(Error::from)?.subject;
+        let connector = RpcImpl::load_connector(&schema, native_types).await?;
+        let data_model = connector.introspect(&previous_data_model).await;
 
         match data_model {
             Ok(introspection_result)
@@ -103,22 +115,52 @@ impl RpcImpl {
     }
 
     pub async fn list_databases_internal(schema: String) -> RpcResult<Vec<String>> {
-        let connector = RpcImpl::load_connector(&schema).await?;
+        let connector = RpcImpl::load_connector(&schema, false).await?;
         Ok(connector.list_databases().await.map_err(Error::from)?)
     }
 
     pub async fn get_database_description(schema: String) -> RpcResult<String> {
-        let connector = RpcImpl::load_connector(&schema).await?;
+        let connector = RpcImpl::load_connector(&schema, false).await?;
         Ok(connector.get_database_description().await.map_err(Error::from)?)
     }
 
     pub async fn get_database_metadata_internal(schema: String) -> RpcResult<DatabaseMetadata> {
-        let connector = RpcImpl::load_connector(&schema).await?;
+        let connector = RpcImpl::load_connector(&schema, false).await?;
         Ok(connector.get_metadata().await.map_err(Error::from)?)
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IntrospectionInput {
+    #[serde(default)]
     pub(crate) schema: String,
+    /// Further files of a schema split across multiple files, merged after `schema`.
+    #[serde(default, rename = "schemaFiles")]
+    pub(crate) schema_files: Vec<SchemaFileInput>,
+    /// Keep the native types of columns as `@db.` attributes.
+    #[serde(default, rename = "nativeTypes")]
+    pub(crate) native_types: bool,
+}
+
+impl IntrospectionInput {
+    /// The merged source of all schema files.
+    fn schema_source(&self) -> String {
+        if self.schema_files.is_empty() {
+            return self.schema.clone();
+        }
+
+        let mut files = datamodel::SchemaFiles::single("schema.prisma", &self.schema);
+
+        for file in &self.schema_files {
+            files.add(&file.name, &file.content);
+        }
+
+        files.source().to_owned()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SchemaFileInput {
+    pub(crate) name: String,
+    pub(crate) content: String,
 }
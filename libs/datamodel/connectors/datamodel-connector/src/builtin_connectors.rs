@@ -0,0 +1,108 @@
+use super::{declarative_connector::*, ScalarType};
+
+/// The connectors of the builtin sources, with the native types of each database.
+/// Native types are used with the `@db.` attribute, e.g. `@db.VarChar(255)`.
+pub struct BuiltinConnectors {}
+
+impl BuiltinConnectors {
+    pub fn sqlite() -> DeclarativeConnector {
+        // SQLite only knows type affinities: https://www.sqlite.org/datatype3.html
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("Integer", "INTEGER", ScalarType::Int),
+            FieldTypeConstructor::without_args("Real", "REAL", ScalarType::Float),
+            FieldTypeConstructor::without_args("Numeric", "NUMERIC", ScalarType::Decimal),
+            FieldTypeConstructor::without_args("Text", "TEXT", ScalarType::String),
+            FieldTypeConstructor::without_args("Blob", "BLOB", ScalarType::Bytes),
+        ];
+
+        DeclarativeConnector {
+            type_aliases: vec![],
+            field_type_constructors,
+            supports_scalar_lists: false,
+            supports_relations_over_non_unique_criteria: false,
+            supports_enums: false,
+            supports_json: false,
+        }
+    }
+
+    pub fn mysql() -> DeclarativeConnector {
+        let type_aliases = vec![TypeAlias::new("Numeric", "Decimal")];
+
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("TinyInt", "tinyint", ScalarType::Int),
+            FieldTypeConstructor::without_args("SmallInt", "smallint", ScalarType::Int),
+            FieldTypeConstructor::without_args("MediumInt", "mediumint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Int", "int", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::BigInt),
+            FieldTypeConstructor::without_args("Float", "float", ScalarType::Float),
+            FieldTypeConstructor::without_args("Double", "double", ScalarType::Float),
+            FieldTypeConstructor::with_optional_args("Decimal", "decimal", ScalarType::Decimal, 2),
+            FieldTypeConstructor::with_args("VarChar", "varchar", ScalarType::String, 1),
+            FieldTypeConstructor::with_args("Char", "char", ScalarType::String, 1),
+            FieldTypeConstructor::without_args("TinyText", "tinytext", ScalarType::String),
+            FieldTypeConstructor::without_args("Text", "text", ScalarType::String),
+            FieldTypeConstructor::without_args("MediumText", "mediumtext", ScalarType::String),
+            FieldTypeConstructor::without_args("LongText", "longtext", ScalarType::String),
+            FieldTypeConstructor::with_optional_args("DateTime", "datetime", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("Timestamp", "timestamp", ScalarType::DateTime, 1),
+            FieldTypeConstructor::without_args("Date", "date", ScalarType::DateTime),
+            FieldTypeConstructor::with_optional_args("Time", "time", ScalarType::DateTime, 1),
+            FieldTypeConstructor::without_args("Json", "json", ScalarType::Json),
+            FieldTypeConstructor::with_args("Binary", "binary", ScalarType::Bytes, 1),
+            FieldTypeConstructor::with_args("VarBinary", "varbinary", ScalarType::Bytes, 1),
+            FieldTypeConstructor::without_args("TinyBlob", "tinyblob", ScalarType::Bytes),
+            FieldTypeConstructor::without_args("Blob", "blob", ScalarType::Bytes),
+            FieldTypeConstructor::without_args("MediumBlob", "mediumblob", ScalarType::Bytes),
+            FieldTypeConstructor::without_args("LongBlob", "longblob", ScalarType::Bytes),
+        ];
+
+        DeclarativeConnector {
+            type_aliases,
+            field_type_constructors,
+            supports_scalar_lists: false,
+            supports_relations_over_non_unique_criteria: true,
+            supports_enums: true,
+            supports_json: true,
+        }
+    }
+
+    pub fn postgres() -> DeclarativeConnector {
+        let type_aliases = vec![
+            TypeAlias::new("Int2", "SmallInt"),
+            TypeAlias::new("Int4", "Integer"),
+            TypeAlias::new("Int8", "BigInt"),
+            TypeAlias::new("Float4", "Real"),
+            TypeAlias::new("Float8", "DoublePrecision"),
+            TypeAlias::new("Decimal", "Numeric"),
+        ];
+
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("SmallInt", "smallint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Integer", "integer", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::BigInt),
+            FieldTypeConstructor::without_args("Real", "real", ScalarType::Float),
+            FieldTypeConstructor::without_args("DoublePrecision", "double precision", ScalarType::Float),
+            FieldTypeConstructor::with_optional_args("Numeric", "numeric", ScalarType::Decimal, 2),
+            FieldTypeConstructor::with_args("VarChar", "varchar", ScalarType::String, 1),
+            FieldTypeConstructor::with_args("Char", "char", ScalarType::String, 1),
+            FieldTypeConstructor::without_args("Text", "text", ScalarType::String),
+            FieldTypeConstructor::without_args("Boolean", "boolean", ScalarType::Boolean),
+            FieldTypeConstructor::with_optional_args("Timestamp", "timestamp", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("Timestamptz", "timestamptz", ScalarType::DateTime, 1),
+            FieldTypeConstructor::without_args("Date", "date", ScalarType::DateTime),
+            FieldTypeConstructor::with_optional_args("Time", "time", ScalarType::DateTime, 1),
+            FieldTypeConstructor::without_args("Json", "json", ScalarType::Json),
+            FieldTypeConstructor::without_args("JsonB", "jsonb", ScalarType::Json),
+            FieldTypeConstructor::without_args("ByteA", "bytea", ScalarType::Bytes),
+        ];
+
+        DeclarativeConnector {
+            type_aliases,
+            field_type_constructors,
+            supports_scalar_lists: true,
+            supports_relations_over_non_unique_criteria: false,
+            supports_enums: true,
+            supports_json: true,
+        }
+    }
+}
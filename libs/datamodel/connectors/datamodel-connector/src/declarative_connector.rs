@@ -14,15 +14,26 @@ impl Connector for DeclarativeConnector {
     fn calculate_type(&self, name: &str, args: Vec<i32>) -> Option<ScalarFieldType> {
         match self.get_type_alias(name) {
             Some(alias) => self.calculate_type(&alias.aliased_to, args),
-            None => self.get_field_type_constructor(&name).map(|constructor| {
-                let datasource_type = constructor.datasource_type(&args);
-
-                ScalarFieldType {
-                    name: name.to_string(),
-                    prisma_type: constructor.prisma_type,
-                    datasource_type,
-                }
-            }),
+            None => self
+                .get_field_type_constructor(&name)
+                .filter(|constructor| constructor.accepts_number_of_args(args.len()))
+                .map(|constructor| {
+                    let datasource_type = constructor.datasource_type(&args);
+
+                    ScalarFieldType {
+                        name: name.to_string(),
+                        args,
+                        prisma_type: constructor.prisma_type,
+                        datasource_type,
+                    }
+                }),
+        }
+    }
+
+    fn find_field_type_constructor(&self, name: &str) -> Option<&FieldTypeConstructor> {
+        match self.get_type_alias(name) {
+            Some(alias) => self.find_field_type_constructor(&alias.aliased_to),
+            None => self.get_field_type_constructor(name),
         }
     }
 
@@ -72,6 +83,7 @@ pub struct FieldTypeConstructor {
     name: String,
     datasource_type: String,
     number_of_args: usize,
+    args_are_optional: bool,
     prisma_type: ScalarType,
 }
 
@@ -82,6 +94,7 @@ impl FieldTypeConstructor {
             datasource_type: datasource_type.to_string(),
             prisma_type,
             number_of_args: 0,
+            args_are_optional: false,
         }
     }
 
@@ -96,6 +109,24 @@ impl FieldTypeConstructor {
             datasource_type: datasource_type.to_string(),
             prisma_type,
             number_of_args,
+            args_are_optional: false,
+        }
+    }
+
+    /// A constructor that can be used with either no arguments or the given number of arguments,
+    /// e.g. `Timestamp` and `Timestamp(3)`.
+    pub fn with_optional_args(
+        name: &str,
+        datasource_type: &str,
+        prisma_type: ScalarType,
+        number_of_args: usize,
+    ) -> FieldTypeConstructor {
+        FieldTypeConstructor {
+            name: name.to_string(),
+            datasource_type: datasource_type.to_string(),
+            prisma_type,
+            number_of_args,
+            args_are_optional: true,
         }
     }
 
@@ -107,8 +138,16 @@ impl FieldTypeConstructor {
         self.number_of_args
     }
 
+    pub fn args_are_optional(&self) -> bool {
+        self.args_are_optional
+    }
+
+    pub fn accepts_number_of_args(&self, number_of_args: usize) -> bool {
+        number_of_args == self.number_of_args || (self.args_are_optional && number_of_args == 0)
+    }
+
     pub fn datasource_type(&self, args: &Vec<i32>) -> String {
-        if self.number_of_args != args.len() {
+        if !self.accepts_number_of_args(args.len()) {
             panic!(
                 "Did not provide the required number of arguments. {} were required, but were {} provided.",
                 self.number_of_args,
@@ -3,15 +3,20 @@ use crate::scalars::ScalarType;
 pub mod error;
 pub mod scalars;
 
+mod builtin_connectors;
 mod declarative_connector;
 mod example_connector;
 
-pub use declarative_connector::DeclarativeConnector;
+pub use builtin_connectors::BuiltinConnectors;
+pub use declarative_connector::{DeclarativeConnector, FieldTypeConstructor};
 pub use example_connector::ExampleConnector;
 
 pub trait Connector {
     fn calculate_type(&self, name: &str, args: Vec<i32>) -> Option<ScalarFieldType>;
 
+    /// Finds the constructor of the native type with the given name, resolving type aliases.
+    fn find_field_type_constructor(&self, name: &str) -> Option<&FieldTypeConstructor>;
+
     fn supports_scalar_lists(&self) -> bool;
 
     fn supports_relations_over_non_unique_criteria(&self) -> bool;
@@ -24,6 +29,7 @@ pub trait Connector {
 #[derive(Debug, Clone, PartialEq)]
 pub struct ScalarFieldType {
     name: String,
+    args: Vec<i32>,
     prisma_type: scalars::ScalarType,
     datasource_type: String,
 }
@@ -32,11 +38,22 @@ impl ScalarFieldType {
     pub fn new(name: &str, prisma_type: scalars::ScalarType, datasource_type: &str) -> Self {
         ScalarFieldType {
             name: name.to_string(),
+            args: vec![],
             prisma_type,
             datasource_type: datasource_type.to_string(),
         }
     }
 
+    /// The name of the native type, as used in the `@db.` attribute.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The arguments the native type was constructed with, e.g. the length of a `VarChar`.
+    pub fn args(&self) -> &[i32] {
+        &self.args
+    }
+
     pub fn prisma_type(&self) -> scalars::ScalarType {
         self.prisma_type
     }
@@ -0,0 +1,513 @@
+//! Quick fixes for common problems in a schema.
+//!
+//! Code actions are computed for a location in the schema, usually the span of an error, and
+//! returned as text edits. They are never applied automatically, tools have to opt in to them.
+use super::reformat::{find_all_missing_fields, MissingField};
+use super::renderer::{Renderer, TableFormat};
+use super::text_edit::TextEdit;
+use super::*;
+
+/// A fix for a problem in the schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeAction {
+    /// A short, human readable description of the fix.
+    pub title: String,
+    /// The edits to apply to the schema string.
+    pub edits: Vec<TextEdit>,
+}
+
+impl CodeAction {
+    fn new(title: String, edits: Vec<TextEdit>) -> CodeAction {
+        CodeAction { title, edits }
+    }
+}
+
+/// Computes all code actions for the models and fields at the start of the given span.
+///
+/// Schemas that can not be parsed have no code actions. Adding missing opposite relation fields
+/// is only offered for schemas that are valid otherwise.
+pub fn code_actions(schema_string: &str, span: Span) -> Vec<CodeAction> {
+    let schema = match crate::parse_schema_ast(schema_string) {
+        Ok(schema) => schema,
+        Err(_) => return Vec::new(),
+    };
+
+    let missing_fields = find_all_missing_fields(schema_string).unwrap_or_default();
+    let context = Context {
+        text: schema_string,
+        missing_fields: &missing_fields,
+    };
+
+    let mut actions = Vec::new();
+
+    for model in schema
+        .models()
+        .into_iter()
+        .filter(|model| !model.is_composite_type && model.span.contains(span.start))
+    {
+        for field in model.fields.iter().filter(|field| field.span.contains(span.start)) {
+            let related_model = match schema.find_model(&field.field_type.name) {
+                Some(related_model) if !related_model.is_composite_type => related_model,
+                _ => continue,
+            };
+
+            let relation = RelationField {
+                model,
+                field,
+                related_model,
+            };
+
+            actions.extend(context.add_opposite_relation_field(&relation));
+            actions.extend(context.add_relation_arguments(&relation));
+            actions.extend(context.add_unique_to_referenced_fields(&relation));
+            actions.extend(context.rename_duplicate_relation(&relation));
+        }
+
+        actions.extend(context.add_id(model));
+    }
+
+    actions
+}
+
+struct Context<'a> {
+    text: &'a str,
+    missing_fields: &'a [MissingField],
+}
+
+/// A relation field together with the model it is declared on and the model it points to.
+struct RelationField<'a> {
+    model: &'a Model,
+    field: &'a Field,
+    related_model: &'a Model,
+}
+
+impl RelationField<'_> {
+    /// The fields on the related model that belong to the same relation.
+    fn opposite_fields(&self) -> Vec<&Field> {
+        let name = relation_name(self.field);
+
+        self.related_model
+            .fields
+            .iter()
+            .filter(|other| other.field_type.name == self.model.name.name)
+            .filter(|other| relation_name(other) == name)
+            .filter(|other| !std::ptr::eq(*other, self.field))
+            .collect()
+    }
+}
+
+impl Context<'_> {
+    /// Adds the opposite relation field, that would otherwise be generated during formatting.
+    fn add_opposite_relation_field(&self, relation: &RelationField<'_>) -> Option<CodeAction> {
+        let missing_field = self.missing_fields.iter().find(|missing_field| {
+            missing_field.model == relation.related_model.name.name
+                && missing_field.field.field_type.name == relation.model.name.name
+                && relation_name(&missing_field.field) == relation_name(relation.field)
+        })?;
+
+        // The scalar fields of the relation are generated along with it.
+        let scalar_fields = find_relation_argument(&missing_field.field, "fields")
+            .map(|argument| constant_list(&argument.value))
+            .unwrap_or_default();
+
+        let missing_scalar_fields = self
+            .missing_fields
+            .iter()
+            .filter(|other| other.model == missing_field.model && scalar_fields.contains(&other.field.name.name));
+
+        let lines: Vec<String> = std::iter::once(missing_field)
+            .chain(missing_scalar_fields)
+            .map(|missing_field| render_field(&missing_field.field))
+            .collect();
+
+        let edit = self.append_lines(relation.related_model, &lines);
+
+        Some(CodeAction::new(
+            format!(
+                "Add the opposite relation field `{}` to model `{}`",
+                missing_field.field.name.name, relation.related_model.name.name
+            ),
+            vec![edit],
+        ))
+    }
+
+    /// Adds the `fields` and `references` arguments to a relation field, generating the scalar fields
+    /// holding the foreign key.
+    fn add_relation_arguments(&self, relation: &RelationField<'_>) -> Option<CodeAction> {
+        let field = relation.field;
+        let directive = find_directive(&field.directives, "relation");
+
+        if field.arity == FieldArity::List || directive.and_then(|d| find_argument(d, "fields")).is_some() {
+            return None;
+        }
+
+        if relation
+            .opposite_fields()
+            .iter()
+            .any(|opposite| find_relation_argument(opposite, "fields").is_some())
+        {
+            return None;
+        }
+
+        let existing_references = directive.and_then(|d| find_argument(d, "references"));
+        let references = match existing_references {
+            Some(argument) => constant_list(&argument.value),
+            None => identifier_fields(relation.related_model)?,
+        };
+
+        if references.is_empty() {
+            return None;
+        }
+
+        let indentation = indentation(self.text, field.span.start);
+        let arity = if field.arity == FieldArity::Optional { "?" } else { "" };
+        let mut fields = Vec::new();
+        let mut new_lines = String::new();
+
+        for reference in &references {
+            let referenced_field = find_field(relation.related_model, reference)?;
+            let name = format!("{}{}", field.name.name, capitalize(reference));
+
+            if find_field(relation.model, &name).is_none() {
+                new_lines.push_str(&format!(
+                    "{}{} {}{}\n",
+                    indentation, name, referenced_field.field_type.name, arity
+                ));
+            }
+
+            fields.push(name);
+        }
+
+        let arguments = match existing_references {
+            Some(_) => format!("fields: [{}]", fields.join(", ")),
+            None => format!(
+                "fields: [{}], references: [{}]",
+                fields.join(", "),
+                references.join(", ")
+            ),
+        };
+
+        let mut edits = vec![self.add_directive_arguments(field, directive, "relation", &arguments)];
+
+        if !new_lines.is_empty() {
+            edits.push(TextEdit::insert(end_of_line(self.text, end_of_field(field)), new_lines));
+        }
+
+        Some(CodeAction::new(
+            format!("Add `{}` to the relation field `{}`", arguments, field.name.name),
+            edits,
+        ))
+    }
+
+    /// Makes the fields referenced by a relation unique.
+    fn add_unique_to_referenced_fields(&self, relation: &RelationField<'_>) -> Option<CodeAction> {
+        let references = constant_list(&find_relation_argument(relation.field, "references")?.value);
+        let related_model = relation.related_model;
+
+        if references.is_empty() || is_unique_criteria(related_model, &references) {
+            return None;
+        }
+
+        let referenced_fields = references
+            .iter()
+            .map(|reference| find_field(related_model, reference))
+            .collect::<Option<Vec<&Field>>>()?;
+
+        match referenced_fields.as_slice() {
+            [referenced_field] => Some(CodeAction::new(
+                format!(
+                    "Add `@unique` to the field `{}` of model `{}`",
+                    referenced_field.name.name, related_model.name.name
+                ),
+                vec![TextEdit::insert(
+                    end_of_field(referenced_field),
+                    String::from(" @unique"),
+                )],
+            )),
+            _ => {
+                let directive = format!("@@unique([{}])", references.join(", "));
+
+                Some(CodeAction::new(
+                    format!("Add `{}` to model `{}`", directive, related_model.name.name),
+                    vec![self.append_lines(related_model, &[directive])],
+                ))
+            }
+        }
+    }
+
+    /// Gives a relation field a name that is distinct from the other relations between the same models.
+    fn rename_duplicate_relation(&self, relation: &RelationField<'_>) -> Option<CodeAction> {
+        let (model, field) = (relation.model, relation.field);
+
+        // Self relations are disambiguated differently.
+        if model.name.name == relation.related_model.name.name {
+            return None;
+        }
+
+        let old_name = relation_name(field);
+        let duplicates: Vec<&Field> = model
+            .fields
+            .iter()
+            .filter(|other| other.field_type.name == field.field_type.name && relation_name(other) == old_name)
+            .collect();
+
+        if duplicates.len() < 2 {
+            return None;
+        }
+
+        let new_name = format!("{}{}", model.name.name, capitalize(&field.name.name));
+        let mut edits = vec![self.set_relation_name(field, &new_name)];
+
+        // The opposite fields can only be matched up when there is one for each duplicate, in the same order.
+        let opposite_fields = relation.opposite_fields();
+        if opposite_fields.len() == duplicates.len() {
+            let index = duplicates.iter().position(|other| std::ptr::eq(*other, field))?;
+            edits.push(self.set_relation_name(opposite_fields[index], &new_name));
+        }
+
+        Some(CodeAction::new(
+            format!("Rename the relation of field `{}` to `{}`", field.name.name, new_name),
+            edits,
+        ))
+    }
+
+    /// Adds an id to a model without any unique criteria.
+    fn add_id(&self, model: &Model) -> Option<CodeAction> {
+        let has_unique_criteria = model
+            .fields
+            .iter()
+            .any(|field| find_directive(&field.directives, "id").is_some())
+            || model
+                .fields
+                .iter()
+                .any(|field| find_directive(&field.directives, "unique").is_some())
+            || find_directive(&model.directives, "id").is_some()
+            || find_directive(&model.directives, "unique").is_some();
+
+        if has_unique_criteria || find_directive(&model.directives, "ignore").is_some() {
+            return None;
+        }
+
+        if let Some(id_field) = find_field(model, "id") {
+            return Some(CodeAction::new(
+                format!("Add `@id` to the field `id` of model `{}`", model.name.name),
+                vec![TextEdit::insert(end_of_field(id_field), String::from(" @id"))],
+            ));
+        }
+
+        let id_field = "id Int @id @default(autoincrement())";
+        let edit = match model.fields.first() {
+            Some(first_field) => TextEdit::insert(
+                line_start(self.text, first_field.span.start),
+                format!("{}{}\n", indentation(self.text, first_field.span.start), id_field),
+            ),
+            None => self.append_lines(model, &[String::from(id_field)]),
+        };
+
+        Some(CodeAction::new(
+            format!("Add an `id` field to model `{}`", model.name.name),
+            vec![edit],
+        ))
+    }
+
+    /// Adds arguments to the directive with the given name, or adds the directive if the field has none.
+    fn add_directive_arguments(
+        &self,
+        field: &Field,
+        directive: Option<&Directive>,
+        directive_name: &str,
+        arguments: &str,
+    ) -> TextEdit {
+        match directive {
+            None => TextEdit::insert(end_of_field(field), format!(" @{}({})", directive_name, arguments)),
+            Some(directive) if !directive.arguments.is_empty() => {
+                TextEdit::insert(directive.span.end - 1, format!(", {}", arguments))
+            }
+            // An empty argument list, e.g. `@relation()`.
+            Some(directive) if self.text.as_bytes()[directive.span.end - 1] == b')' => {
+                TextEdit::insert(directive.span.end - 1, String::from(arguments))
+            }
+            Some(directive) => TextEdit::insert(directive.span.end, format!("({})", arguments)),
+        }
+    }
+
+    fn set_relation_name(&self, field: &Field, name: &str) -> TextEdit {
+        let directive = find_directive(&field.directives, "relation");
+        let name_argument = directive.and_then(|d| d.arguments.iter().find(|a| is_relation_name_argument(a)));
+        let quoted_name = format!("\"{}\"", name);
+
+        match (directive, name_argument) {
+            (_, Some(name_argument)) => TextEdit::replace(name_argument.value.span(), &quoted_name),
+            (Some(directive), None) if !directive.arguments.is_empty() => {
+                TextEdit::insert(directive.arguments[0].span.start, format!("{}, ", quoted_name))
+            }
+            _ => self.add_directive_arguments(field, directive, "relation", &quoted_name),
+        }
+    }
+
+    /// Adds lines at the end of the fields of a model.
+    fn append_lines(&self, model: &Model, lines: &[String]) -> TextEdit {
+        let (position, indentation, line_break) = match model.fields.last() {
+            Some(last_field) => (
+                end_of_line(self.text, end_of_field(last_field)),
+                indentation(self.text, last_field.span.start),
+                "",
+            ),
+            // Right before the closing brace, which can be on the line of the model name, e.g. `model Foo {}`.
+            None => {
+                let closing_brace = model.span.end - 1;
+                let closing_line_start = line_start(self.text, closing_brace);
+
+                if self.text[closing_line_start..closing_brace].trim().is_empty() {
+                    (closing_line_start, "  ", "")
+                } else {
+                    (closing_brace, "  ", "\n")
+                }
+            }
+        };
+
+        let new_lines: String = lines.iter().map(|line| format!("{}{}\n", indentation, line)).collect();
+
+        TextEdit::insert(position, format!("{}{}", line_break, new_lines))
+    }
+}
+
+fn render_field(field: &Field) -> String {
+    let mut table = TableFormat::new();
+    Renderer::render_field(&mut table, field, false);
+
+    let mut buffer = Vec::new();
+    table.render(&mut Renderer::new(&mut buffer, 0));
+
+    String::from_utf8(buffer).expect("Rendered field is not valid UTF-8.")
+}
+
+/// The fields referenced by default from other models: the id, or the first unique field.
+fn identifier_fields(model: &Model) -> Option<Vec<String>> {
+    let single_field = |directive_name: &str| {
+        model
+            .fields
+            .iter()
+            .find(|field| find_directive(&field.directives, directive_name).is_some())
+            .map(|field| vec![field.name.name.clone()])
+    };
+
+    let compound_id = find_directive(&model.directives, "id")
+        .and_then(|directive| directive.arguments.iter().find(|a| is_field_list_argument(a)))
+        .map(|argument| constant_list(&argument.value));
+
+    single_field("id").or(compound_id).or_else(|| single_field("unique"))
+}
+
+/// Whether the given fields are the id or a unique criteria of the model.
+fn is_unique_criteria(model: &Model, field_names: &[String]) -> bool {
+    if let [field_name] = field_names {
+        let is_unique_field = find_field(model, field_name)
+            .map(|field| {
+                find_directive(&field.directives, "id").is_some()
+                    || find_directive(&field.directives, "unique").is_some()
+            })
+            .unwrap_or(false);
+
+        if is_unique_field {
+            return true;
+        }
+    }
+
+    let mut field_names = field_names.to_vec();
+    field_names.sort();
+
+    model
+        .directives
+        .iter()
+        .filter(|directive| directive.name.name == "id" || directive.name.name == "unique")
+        .filter_map(|directive| directive.arguments.iter().find(|a| is_field_list_argument(a)))
+        .any(|argument| {
+            let mut criteria = constant_list(&argument.value);
+            criteria.sort();
+            criteria == field_names
+        })
+}
+
+fn relation_name(field: &Field) -> Option<&str> {
+    let directive = find_directive(&field.directives, "relation")?;
+
+    match &directive.arguments.iter().find(|a| is_relation_name_argument(a))?.value {
+        Expression::StringValue(name, _) => Some(name.as_str()),
+        _ => None,
+    }
+}
+
+fn find_relation_argument<'a>(field: &'a Field, argument_name: &str) -> Option<&'a Argument> {
+    find_argument(find_directive(&field.directives, "relation")?, argument_name)
+}
+
+fn is_relation_name_argument(argument: &Argument) -> bool {
+    argument.is_unnamed() || argument.name.name == "name"
+}
+
+fn is_field_list_argument(argument: &Argument) -> bool {
+    argument.is_unnamed() || argument.name.name == "fields"
+}
+
+/// The names in an array of constants, or a single constant.
+fn constant_list(expression: &Expression) -> Vec<String> {
+    match expression {
+        Expression::ConstantValue(value, _) => vec![value.clone()],
+        Expression::Array(values, _) => values.iter().flat_map(constant_list).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn find_directive<'a>(directives: &'a [Directive], name: &str) -> Option<&'a Directive> {
+    directives.iter().find(|directive| directive.name.name == name)
+}
+
+fn find_argument<'a>(directive: &'a Directive, name: &str) -> Option<&'a Argument> {
+    directive.arguments.iter().find(|argument| argument.name.name == name)
+}
+
+fn find_field<'a>(model: &'a Model, name: &str) -> Option<&'a Field> {
+    model.fields.iter().find(|field| field.name.name == name)
+}
+
+/// The position right after the type and the directives of a field, before any comment.
+fn end_of_field(field: &Field) -> usize {
+    field
+        .directives
+        .iter()
+        .map(|directive| directive.span.end)
+        .fold(field.field_type.span.end, std::cmp::max)
+}
+
+fn line_start(text: &str, position: usize) -> usize {
+    text[..position].rfind('\n').map(|index| index + 1).unwrap_or(0)
+}
+
+/// The position right after the line break ending the line of the given position.
+fn end_of_line(text: &str, position: usize) -> usize {
+    text[position..]
+        .find('\n')
+        .map(|index| position + index + 1)
+        .unwrap_or_else(|| text.len())
+}
+
+/// The whitespace in front of the given position, defaulting to two spaces.
+fn indentation(text: &str, position: usize) -> &str {
+    let whitespace = &text[line_start(text, position)..position];
+
+    if whitespace.chars().all(char::is_whitespace) {
+        whitespace
+    } else {
+        "  "
+    }
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
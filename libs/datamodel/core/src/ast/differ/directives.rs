@@ -1,14 +1,13 @@
-use datamodel::ast;
-use migration_connector::steps::MigrationExpression;
+use crate::ast;
 
 #[derive(Debug)]
-pub(crate) struct DirectiveDiffer<'a> {
-    pub(crate) previous: &'a ast::Directive,
-    pub(crate) next: &'a ast::Directive,
+pub struct DirectiveDiffer<'a> {
+    pub previous: &'a ast::Directive,
+    pub next: &'a ast::Directive,
 }
 
 impl<'a> DirectiveDiffer<'a> {
-    pub(crate) fn deleted_arguments(&self) -> impl Iterator<Item = &ast::Argument> {
+    pub fn deleted_arguments(&self) -> impl Iterator<Item = &ast::Argument> {
         self.previous_arguments().filter(move |previous_argument| {
             self.next_arguments()
                 .find(|next_argument| arguments_match(previous_argument, next_argument))
@@ -16,7 +15,7 @@ impl<'a> DirectiveDiffer<'a> {
         })
     }
 
-    pub(crate) fn created_arguments(&self) -> impl Iterator<Item = &ast::Argument> {
+    pub fn created_arguments(&self) -> impl Iterator<Item = &ast::Argument> {
         self.next_arguments().filter(move |next_argument| {
             self.previous_arguments()
                 .find(|previous_argument| arguments_match(previous_argument, next_argument))
@@ -24,7 +23,7 @@ impl<'a> DirectiveDiffer<'a> {
         })
     }
 
-    pub(crate) fn argument_pairs(&self) -> impl Iterator<Item = (&ast::Argument, &ast::Argument)> {
+    pub fn argument_pairs(&self) -> impl Iterator<Item = (&ast::Argument, &ast::Argument)> {
         self.previous_arguments().filter_map(move |previous_argument| {
             self.next_arguments()
                 .find(|next_argument| arguments_match(previous_argument, next_argument))
@@ -41,7 +40,7 @@ impl<'a> DirectiveDiffer<'a> {
     }
 }
 
-pub(crate) fn directives_match(previous: &ast::Directive, next: &ast::Directive) -> bool {
+pub fn directives_match(previous: &ast::Directive, next: &ast::Directive) -> bool {
     previous.name.name == next.name.name
 }
 
@@ -49,7 +48,7 @@ pub fn arguments_match(previous: &ast::Argument, next: &ast::Argument) -> bool {
     previous.name.name == next.name.name
 }
 
-pub(crate) fn directives_are_identical(previous: &ast::Directive, next: &ast::Directive) -> bool {
+pub fn directives_are_identical(previous: &ast::Directive, next: &ast::Directive) -> bool {
     if previous.name.name != next.name.name {
         return false;
     }
@@ -67,16 +66,14 @@ pub(crate) fn directives_are_identical(previous: &ast::Directive, next: &ast::Di
 }
 
 fn arguments_are_identical(previous: &ast::Argument, next: &ast::Argument) -> bool {
-    previous.name.name == next.name.name
-        && MigrationExpression::from_ast_expression(&previous.value)
-            == MigrationExpression::from_ast_expression(&next.value)
+    previous.name.name == next.name.name && previous.value.render_to_string() == next.value.render_to_string()
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::{ModelDiffer, TopDiffer};
     use super::*;
-    use datamodel::ast::parser::parse;
+    use crate::ast::parser::parse;
 
     fn dog_model_custom_directive_test(test_fn: impl FnOnce(DirectiveDiffer<'_>)) {
         let previous = r#"
@@ -1,13 +1,13 @@
 use super::DirectiveDiffer;
-use datamodel::ast;
+use crate::ast;
 
-pub(crate) struct EnumValueDiffer<'a> {
-    pub(crate) previous: &'a ast::EnumValue,
-    pub(crate) next: &'a ast::EnumValue,
+pub struct EnumValueDiffer<'a> {
+    pub previous: &'a ast::EnumValue,
+    pub next: &'a ast::EnumValue,
 }
 
 impl<'a> EnumValueDiffer<'a> {
-    pub(crate) fn directive_pairs<'b>(&'b self) -> impl Iterator<Item = DirectiveDiffer<'a>> + 'b {
+    pub fn directive_pairs<'b>(&'b self) -> impl Iterator<Item = DirectiveDiffer<'a>> + 'b {
         self.previous_directives().filter_map(move |previous| {
             self.next_directives()
                 .find(|next| enum_value_directives_match(previous, next))
@@ -15,7 +15,7 @@ impl<'a> EnumValueDiffer<'a> {
         })
     }
 
-    pub(crate) fn created_directives(&self) -> impl Iterator<Item = &ast::Directive> {
+    pub fn created_directives(&self) -> impl Iterator<Item = &ast::Directive> {
         self.next_directives().filter(move |next| {
             !self
                 .previous_directives()
@@ -23,7 +23,7 @@ impl<'a> EnumValueDiffer<'a> {
         })
     }
 
-    pub(crate) fn deleted_directives(&self) -> impl Iterator<Item = &ast::Directive> {
+    pub fn deleted_directives(&self) -> impl Iterator<Item = &ast::Directive> {
         self.previous_directives().filter(move |previous| {
             !self
                 .next_directives()
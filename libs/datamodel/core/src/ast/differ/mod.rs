@@ -0,0 +1,17 @@
+//! Pairs up the items of two [Schema ASTs](/ast/struct.SchemaAst.html) by name, to find the items
+//! that were created, deleted or kept between them.
+mod directives;
+mod enum_values;
+mod enums;
+mod fields;
+mod models;
+mod source;
+mod top_level;
+
+pub use directives::DirectiveDiffer;
+pub use enum_values::EnumValueDiffer;
+pub use enums::EnumDiffer;
+pub use fields::FieldDiffer;
+pub use models::{directive_is_regular, directive_is_repeated, ModelDiffer};
+pub use source::SourceArgumentsDiffer;
+pub use top_level::TopDiffer;
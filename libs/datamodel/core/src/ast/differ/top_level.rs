@@ -1,16 +1,15 @@
-use super::{EnumDiffer, FieldDiffer, ModelDiffer};
-use crate::migration::datamodel_differ::source::SourceArgumentsDiffer;
-use datamodel::ast::{self, Top};
+use super::{EnumDiffer, FieldDiffer, ModelDiffer, SourceArgumentsDiffer};
+use crate::ast::{self, Top};
 
 /// Implements the logic to diff top-level items in a pair of [Datamodel ASTs](/datamodel/ast/struct.Datamodel.html).
-pub(crate) struct TopDiffer<'a> {
-    pub(crate) previous: &'a ast::SchemaAst,
-    pub(crate) next: &'a ast::SchemaAst,
+pub struct TopDiffer<'a> {
+    pub previous: &'a ast::SchemaAst,
+    pub next: &'a ast::SchemaAst,
 }
 
 impl<'a> TopDiffer<'a> {
     /// Iterator over the models present in both `previous` and `next`.
-    pub(crate) fn model_pairs(&self) -> impl Iterator<Item = ModelDiffer<'_>> {
+    pub fn model_pairs(&self) -> impl Iterator<Item = ModelDiffer<'_>> {
         self.previous_models().filter_map(move |previous_model| {
             self.next_models()
                 .find(|next_model| models_match(previous_model, next_model))
@@ -22,7 +21,7 @@ impl<'a> TopDiffer<'a> {
     }
 
     /// Iterator over the models present in `next` but not `previous`.
-    pub(crate) fn created_models(&self) -> impl Iterator<Item = &ast::Model> {
+    pub fn created_models(&self) -> impl Iterator<Item = &ast::Model> {
         self.next_models().filter(move |next_model| {
             self.previous_models()
                 .find(|previous_model| models_match(previous_model, next_model))
@@ -31,7 +30,7 @@ impl<'a> TopDiffer<'a> {
     }
 
     /// Iterator over the models present in `previous` but not `next`.
-    pub(crate) fn deleted_models(&self) -> impl Iterator<Item = &ast::Model> {
+    pub fn deleted_models(&self) -> impl Iterator<Item = &ast::Model> {
         self.previous_models().filter(move |previous_model| {
             self.next_models()
                 .find(|next_model| models_match(previous_model, next_model))
@@ -39,7 +38,7 @@ impl<'a> TopDiffer<'a> {
         })
     }
 
-    pub(crate) fn updated_datasources(&self) -> impl Iterator<Item = SourceArgumentsDiffer<'_>> {
+    pub fn updated_datasources(&self) -> impl Iterator<Item = SourceArgumentsDiffer<'_>> {
         self.previous_sources().filter_map(move |previous| {
             self.next_sources()
                 .find(|next| sources_match(previous, next))
@@ -47,7 +46,7 @@ impl<'a> TopDiffer<'a> {
         })
     }
 
-    pub(crate) fn created_datasources(&self) -> impl Iterator<Item = &ast::SourceConfig> {
+    pub fn created_datasources(&self) -> impl Iterator<Item = &ast::SourceConfig> {
         self.next_sources().filter(move |next| {
             self.previous_sources()
                 .find(|previous| sources_match(previous, next))
@@ -55,7 +54,7 @@ impl<'a> TopDiffer<'a> {
         })
     }
 
-    pub(crate) fn deleted_datasources(&self) -> impl Iterator<Item = &ast::SourceConfig> {
+    pub fn deleted_datasources(&self) -> impl Iterator<Item = &ast::SourceConfig> {
         self.previous_sources().filter(move |next| {
             self.next_sources()
                 .find(|previous| sources_match(previous, next))
@@ -64,7 +63,7 @@ impl<'a> TopDiffer<'a> {
     }
 
     /// Iterator over the enums present in both `previous` and `next`.
-    pub(crate) fn enum_pairs(&self) -> impl Iterator<Item = EnumDiffer<'_>> {
+    pub fn enum_pairs(&self) -> impl Iterator<Item = EnumDiffer<'_>> {
         self.previous_enums().filter_map(move |previous_enum| {
             self.next_enums()
                 .find(|next_enum| enums_match(previous_enum, next_enum))
@@ -76,7 +75,7 @@ impl<'a> TopDiffer<'a> {
     }
 
     /// Iterator over the enums present in `next` but not `previous`.
-    pub(crate) fn created_enums(&self) -> impl Iterator<Item = &ast::Enum> {
+    pub fn created_enums(&self) -> impl Iterator<Item = &ast::Enum> {
         self.next_enums().filter(move |next_enum| {
             self.previous_enums()
                 .find(|previous_enum| enums_match(previous_enum, next_enum))
@@ -85,7 +84,7 @@ impl<'a> TopDiffer<'a> {
     }
 
     /// Iterator over the enums present in `previous` but not `next`.
-    pub(crate) fn deleted_enums(&self) -> impl Iterator<Item = &ast::Enum> {
+    pub fn deleted_enums(&self) -> impl Iterator<Item = &ast::Enum> {
         self.previous_enums().filter(move |previous_enum| {
             self.next_enums()
                 .find(|next_enum| enums_match(previous_enum, next_enum))
@@ -94,7 +93,7 @@ impl<'a> TopDiffer<'a> {
     }
 
     /// Iterator over the custom types present in `next` but not `previous`.
-    pub(crate) fn created_type_aliases(&self) -> impl Iterator<Item = &ast::Field> {
+    pub fn created_type_aliases(&self) -> impl Iterator<Item = &ast::Field> {
         self.next_type_aliases().filter(move |next_type_alias| {
             self.previous_type_aliases()
                 .find(|previous_type_alias| type_aliases_match(previous_type_alias, next_type_alias))
@@ -103,7 +102,7 @@ impl<'a> TopDiffer<'a> {
     }
 
     /// Iterator over the custom types present in `previous` but not `next`.
-    pub(crate) fn deleted_type_aliases(&self) -> impl Iterator<Item = &ast::Field> {
+    pub fn deleted_type_aliases(&self) -> impl Iterator<Item = &ast::Field> {
         self.previous_type_aliases().filter(move |previous_type_alias| {
             self.next_type_aliases()
                 .find(|next_type_alias| type_aliases_match(previous_type_alias, next_type_alias))
@@ -111,7 +110,7 @@ impl<'a> TopDiffer<'a> {
         })
     }
 
-    pub(crate) fn type_alias_pairs(&self) -> impl Iterator<Item = FieldDiffer<'_>> {
+    pub fn type_alias_pairs(&self) -> impl Iterator<Item = FieldDiffer<'_>> {
         self.previous_type_aliases().filter_map(move |previous_type_alias| {
             self.next_type_aliases()
                 .find(|next_type_alias| type_aliases_match(previous_type_alias, next_type_alias))
@@ -196,7 +195,7 @@ fn type_aliases_match(previous: &ast::Field, next: &ast::Field) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use datamodel::ast::parser::parse;
+    use crate::ast::parser::parse;
 
     #[test]
     fn datamodel_differ_top_level_methods_work() {
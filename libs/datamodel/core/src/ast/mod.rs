@@ -12,9 +12,13 @@ mod span;
 mod top;
 mod traits;
 
+pub mod code_actions;
+pub mod differ;
 pub mod parser;
 pub mod reformat;
+pub mod rename;
 pub mod renderer;
+pub mod text_edit;
 
 pub use argument::*;
 pub use comment::*;
@@ -74,6 +78,10 @@ impl SchemaAst {
         })
     }
 
+    pub fn find_composite_type(&self, type_name: &str) -> Option<&Model> {
+        self.find_model(type_name).filter(|model| model.is_composite_type)
+    }
+
     pub fn find_type_alias(&self, type_name: &str) -> Option<&Field> {
         self.types().into_iter().find(|t| t.name.name == type_name)
     }
@@ -15,6 +15,10 @@ pub struct Model {
     pub span: Span,
     /// Should this be commented out.
     pub commented_out: bool,
+    /// Was this declared with the `view` keyword.
+    pub is_view: bool,
+    /// Was this declared with the `type` keyword.
+    pub is_composite_type: bool,
 }
 
 impl WithIdentifier for Model {
@@ -278,13 +278,12 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
     let mut directives: Vec<Directive> = vec![];
     let mut fields: Vec<Field> = vec![];
     let mut comment: Option<Comment> = None;
+    let mut is_view = false;
+    let mut is_composite_type = false;
 
     match_children! { token, current,
-        Rule::TYPE_KEYWORD => { errors.push(
-            DatamodelError::new_legacy_parser_error(
-                "Model declarations have to be indicated with the `model` keyword.",
-                Span::from_pest(current.as_span()))
-        ) },
+        Rule::TYPE_KEYWORD => is_composite_type = true,
+        Rule::VIEW_KEYWORD => is_view = true,
         Rule::non_empty_identifier => name = Some(current.to_id()),
         Rule::directive => directives.push(parse_directive(&current)),
         Rule::field_declaration => {
@@ -314,6 +313,8 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
             documentation: comment,
             span: Span::from_pest(token.as_span()),
             commented_out: false,
+            is_view,
+            is_composite_type,
         }),
         _ => panic!(
             "Encountered impossible model declaration during parsing: {:?}",
@@ -686,6 +687,7 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::BLOCK_CLOSE => "End of block (\"}\")",
         Rule::MODEL_KEYWORD => "\"model\" keyword",
         Rule::TYPE_KEYWORD => "\"type\" keyword",
+        Rule::VIEW_KEYWORD => "\"view\" keyword",
         Rule::ENUM_KEYWORD => "\"enum\" keyword",
         Rule::GENERATOR_KEYWORD => "\"generator\" keyword",
         Rule::DATASOURCE_KEYWORD => "\"datasource\" keyword",
@@ -1,4 +1,6 @@
 mod helpers;
+mod missing_fields;
 mod reformatter;
 
+pub(crate) use missing_fields::{find_all_missing_fields, MissingField};
 pub use reformatter::Reformatter;
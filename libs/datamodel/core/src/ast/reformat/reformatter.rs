@@ -4,7 +4,9 @@ use pest::Parser;
 // We have to use RefCell as rust cannot
 // do multiple mutable borrows inside a match statement.
 use super::helpers::*;
+use super::missing_fields::*;
 use crate::common::WritableString;
+use crate::SchemaFiles;
 
 pub struct Reformatter<'a> {
     input: &'a str,
@@ -13,33 +15,26 @@ pub struct Reformatter<'a> {
 
 impl<'a> Reformatter<'a> {
     pub fn new(input: &'a str) -> Self {
-        let missing_fields = Self::find_all_missing_fields(&input);
+        let missing_fields = find_all_missing_fields(&input);
         Reformatter { input, missing_fields }
     }
 
-    // this finds all auto generated fields, that are added during auto generation AND are missing from the original input.
-    fn find_all_missing_fields(schema_string: &str) -> Result<Vec<MissingField>, crate::error::ErrorCollection> {
-        let schema_ast = crate::parse_schema_ast(&schema_string)?;
-        let datamodel = crate::parse_datamodel_and_ignore_env_errors(&schema_string)?;
-        let lowerer = crate::validator::LowerDmlToAst::new();
-        let mut result = Vec::new();
-
-        for model in datamodel.models() {
-            let ast_model = schema_ast.find_model(&model.name).unwrap();
-
-            for field in model.fields() {
-                if ast_model.fields.iter().find(|f| &f.name.name == &field.name).is_none() {
-                    let ast_field = lowerer.lower_field(&field, &datamodel)?;
-
-                    result.push(MissingField {
-                        model: model.name.clone(),
-                        field: ast_field,
-                    });
-                }
-            }
-        }
-
-        Ok(result)
+    /// Reformats every file of a multi-file schema, in the order of `files.files()`. Missing fields
+    /// are found on all files together, so back relations to models of other files are added too.
+    pub fn reformat_files(files: &'a SchemaFiles, ident_width: usize) -> Vec<String> {
+        let mut reformatter = Reformatter {
+            input: "",
+            missing_fields: find_all_missing_fields(files.source()),
+        };
+
+        files
+            .files()
+            .iter()
+            .map(|file| {
+                reformatter.input = &file.contents;
+                reformatter.reformat_internal(ident_width)
+            })
+            .collect()
     }
 
     pub fn reformat_to(&self, output: &mut dyn std::io::Write, ident_width: usize) {
@@ -181,12 +176,23 @@ impl<'a> Reformatter<'a> {
     }
 
     fn reformat_model(&self, target: &mut Renderer, token: &Token) {
+        let keyword = token
+            .clone()
+            .into_inner()
+            .find_map(|current| match current.as_rule() {
+                Rule::VIEW_KEYWORD => Some("view"),
+                Rule::TYPE_KEYWORD => Some("type"),
+                _ => None,
+            })
+            .unwrap_or("model");
+
         self.reformat_block_element_internal(
-            "model",
+            keyword,
             target,
             &token,
             Box::new(|table, renderer, token| {
                 match token.as_rule() {
+                    Rule::VIEW_KEYWORD | Rule::TYPE_KEYWORD => {}
                     Rule::directive => {
                         // model level Directives reset the table. -> .render() does that
                         table.render(renderer);
@@ -585,9 +591,3 @@ impl<'a> Reformatter<'a> {
         }
     }
 }
-
-#[derive(Debug)]
-pub struct MissingField {
-    pub model: String,
-    pub field: crate::ast::Field,
-}
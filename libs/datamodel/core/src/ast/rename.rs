@@ -0,0 +1,362 @@
+//! Schema-wide renaming of models, fields, enums and enum values.
+//!
+//! A rename is computed on the AST and returned as a list of text edits, so that the formatting
+//! and comments of the original schema are left untouched.
+use super::*;
+use crate::common::names::DefaultNames;
+use crate::error::DatamodelError;
+
+pub use super::text_edit::{apply_edits, TextEdit};
+
+/// The declaration to rename.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenameTarget {
+    /// A model, view or composite type with the given name.
+    Model(String),
+    /// A field, given by model and field name.
+    Field(String, String),
+    /// An enum with the given name.
+    Enum(String),
+    /// An enum value, given by enum and value name.
+    EnumValue(String, String),
+}
+
+/// Computes the edits renaming the target and all references to it.
+///
+/// If `keep_database_name` is set, a `@map` or `@@map` directive with the old name is added
+/// where needed, so that the name in the database does not change. Renamed models also keep the
+/// default names of their many-to-many relations, which name the join tables.
+pub fn rename(
+    schema: &SchemaAst,
+    target: &RenameTarget,
+    new_name: &str,
+    keep_database_name: bool,
+) -> Result<Vec<TextEdit>, DatamodelError> {
+    match target {
+        RenameTarget::Model(model_name) => rename_model(schema, model_name, new_name, keep_database_name),
+        RenameTarget::Field(model_name, field_name) => {
+            rename_field(schema, model_name, field_name, new_name, keep_database_name)
+        }
+        RenameTarget::Enum(enum_name) => rename_enum(schema, enum_name, new_name, keep_database_name),
+        RenameTarget::EnumValue(enum_name, value_name) => {
+            rename_enum_value(schema, enum_name, value_name, new_name, keep_database_name)
+        }
+    }
+}
+
+fn rename_model(
+    schema: &SchemaAst,
+    model_name: &str,
+    new_name: &str,
+    keep_database_name: bool,
+) -> Result<Vec<TextEdit>, DatamodelError> {
+    let model = schema
+        .find_model(model_name)
+        .ok_or_else(|| DatamodelError::new_type_not_found_error(model_name, Span::empty()))?;
+
+    ensure_top_name_is_free(schema, new_name)?;
+
+    let mut edits = vec![TextEdit::replace(model.name.span, new_name)];
+    edits.extend(type_reference_edits(schema, model_name, new_name));
+
+    if keep_database_name && !model.is_composite_type {
+        if find_directive(&model.directives, "map").is_none() {
+            edits.push(block_map_directive(model.span, model_name));
+        }
+
+        edits.extend(relation_name_edits(schema, model_name));
+    }
+
+    Ok(edits)
+}
+
+fn rename_enum(
+    schema: &SchemaAst,
+    enum_name: &str,
+    new_name: &str,
+    keep_database_name: bool,
+) -> Result<Vec<TextEdit>, DatamodelError> {
+    let r#enum = schema
+        .find_enum(enum_name)
+        .ok_or_else(|| DatamodelError::new_type_not_found_error(enum_name, Span::empty()))?;
+
+    ensure_top_name_is_free(schema, new_name)?;
+
+    let mut edits = vec![TextEdit::replace(r#enum.name.span, new_name)];
+    edits.extend(type_reference_edits(schema, enum_name, new_name));
+
+    if keep_database_name && find_directive(&r#enum.directives, "map").is_none() {
+        edits.push(block_map_directive(r#enum.span, enum_name));
+    }
+
+    Ok(edits)
+}
+
+fn rename_field(
+    schema: &SchemaAst,
+    model_name: &str,
+    field_name: &str,
+    new_name: &str,
+    keep_database_name: bool,
+) -> Result<Vec<TextEdit>, DatamodelError> {
+    let model = schema
+        .find_model(model_name)
+        .ok_or_else(|| DatamodelError::new_type_not_found_error(model_name, Span::empty()))?;
+
+    let field = model.fields.iter().find(|f| f.name.name == field_name).ok_or_else(|| {
+        DatamodelError::new_model_validation_error(
+            &format!("The field `{}` does not exist.", field_name),
+            model_name,
+            model.span,
+        )
+    })?;
+
+    if let Some(existing) = model.fields.iter().find(|f| f.name.name == new_name) {
+        return Err(DatamodelError::new_duplicate_field_error(
+            model_name,
+            new_name,
+            existing.name.span,
+        ));
+    }
+
+    let mut edits = vec![TextEdit::replace(field.name.span, new_name)];
+
+    // Field lists of the model level directives, e.g. `@@index([field])`.
+    for directive in model
+        .directives
+        .iter()
+        .filter(|d| is_field_list_directive(&d.name.name))
+    {
+        for argument in &directive.arguments {
+            edits.extend(constant_edits(&argument.value, field_name, new_name));
+        }
+    }
+
+    for other_model in schema.models() {
+        for other_field in &other_model.fields {
+            let relation = match find_directive(&other_field.directives, "relation") {
+                Some(relation) => relation,
+                None => continue,
+            };
+
+            for argument in &relation.arguments {
+                // `fields` point to the model of the relation field, `references` to the related model.
+                let points_to_model = match argument.name.name.as_str() {
+                    "fields" => other_model.name.name == model_name,
+                    "references" => other_field.field_type.name == model_name,
+                    _ => false,
+                };
+
+                if points_to_model {
+                    edits.extend(constant_edits(&argument.value, field_name, new_name));
+                }
+            }
+        }
+    }
+
+    let is_relation_field = schema
+        .find_model(&field.field_type.name)
+        .map(|related_model| !related_model.is_composite_type)
+        .unwrap_or(false);
+
+    if keep_database_name && !is_relation_field && find_directive(&field.directives, "map").is_none() {
+        edits.push(TextEdit::insert(
+            end_of_field(field),
+            format!(" @map(\"{}\")", field_name),
+        ));
+    }
+
+    Ok(edits)
+}
+
+fn rename_enum_value(
+    schema: &SchemaAst,
+    enum_name: &str,
+    value_name: &str,
+    new_name: &str,
+    keep_database_name: bool,
+) -> Result<Vec<TextEdit>, DatamodelError> {
+    let r#enum = schema
+        .find_enum(enum_name)
+        .ok_or_else(|| DatamodelError::new_type_not_found_error(enum_name, Span::empty()))?;
+
+    let value = r#enum
+        .values
+        .iter()
+        .find(|v| v.name.name == value_name)
+        .ok_or_else(|| {
+            DatamodelError::new_enum_validation_error(
+                &format!("The enum value `{}` does not exist.", value_name),
+                enum_name,
+                r#enum.span,
+            )
+        })?;
+
+    if let Some(existing) = r#enum.values.iter().find(|v| v.name.name == new_name) {
+        return Err(DatamodelError::new_duplicate_enum_value_error(
+            enum_name,
+            new_name,
+            existing.name.span,
+        ));
+    }
+
+    let mut edits = vec![TextEdit::replace(value.name.span, new_name)];
+
+    // Default values of fields of the enum type, e.g. `@default(VALUE)`.
+    for model in schema.models() {
+        for field in model.fields.iter().filter(|f| f.field_type.name == enum_name) {
+            if let Some(default) = find_directive(&field.directives, "default") {
+                for argument in &default.arguments {
+                    edits.extend(constant_edits(&argument.value, value_name, new_name));
+                }
+            }
+        }
+    }
+
+    if keep_database_name && find_directive(&value.directives, "map").is_none() {
+        let end_of_value = value
+            .directives
+            .iter()
+            .map(|directive| directive.span.end)
+            .fold(value.name.span.end, std::cmp::max);
+
+        edits.push(TextEdit::insert(end_of_value, format!(" @map(\"{}\")", value_name)));
+    }
+
+    Ok(edits)
+}
+
+fn ensure_top_name_is_free(schema: &SchemaAst, new_name: &str) -> Result<(), DatamodelError> {
+    match schema.tops.iter().find(|top| top.name() == new_name) {
+        Some(existing) => Err(DatamodelError::new_validation_error(
+            &format!("The name `{}` is already used by another declaration.", new_name),
+            *existing.span(),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Adds the default relation name to both sides of the unnamed many-to-many relations of the model,
+/// because the default name is derived from the model names and would change with them.
+fn relation_name_edits(schema: &SchemaAst, model_name: &str) -> Vec<TextEdit> {
+    let mut edits = Vec::new();
+
+    for model in schema.models() {
+        for field in model.fields.iter().filter(|field| field.arity == FieldArity::List) {
+            let related_model_name = if model.name.name == model_name {
+                &field.field_type.name
+            } else if field.field_type.name == model_name {
+                &model.name.name
+            } else {
+                continue;
+            };
+
+            if !is_many_to_many(schema, model_name, related_model_name) {
+                continue;
+            }
+
+            let relation_name = DefaultNames::relation_name(model_name, related_model_name);
+
+            match find_directive(&field.directives, "relation") {
+                None => edits.push(TextEdit::insert(
+                    end_of_field(field),
+                    format!(" @relation(\"{}\")", relation_name),
+                )),
+                Some(relation) if relation.arguments.iter().any(is_relation_name_argument) => (),
+                Some(relation) => match relation.arguments.first() {
+                    Some(argument) => edits.push(TextEdit::insert(
+                        argument.span.start,
+                        format!("\"{}\", ", relation_name),
+                    )),
+                    // The span of a directive does not include the `@`.
+                    None => edits.push(TextEdit::replace(
+                        relation.span,
+                        format!("relation(\"{}\")", relation_name),
+                    )),
+                },
+            }
+        }
+    }
+
+    edits
+}
+
+/// Whether both models have list fields pointing to each other. A self-relation needs two of them.
+fn is_many_to_many(schema: &SchemaAst, model_name: &str, related_model_name: &str) -> bool {
+    let list_fields_count = |from: &str, to: &str| {
+        schema
+            .find_model(from)
+            .filter(|model| !model.is_composite_type)
+            .map(|model| {
+                model
+                    .fields
+                    .iter()
+                    .filter(|field| field.arity == FieldArity::List && field.field_type.name == to)
+                    .count()
+            })
+            .unwrap_or(0)
+    };
+
+    if model_name == related_model_name {
+        list_fields_count(model_name, model_name) >= 2
+    } else {
+        list_fields_count(model_name, related_model_name) > 0 && list_fields_count(related_model_name, model_name) > 0
+    }
+}
+
+/// The relation name is the unnamed first argument of `@relation`, or the `name` argument.
+fn is_relation_name_argument(argument: &Argument) -> bool {
+    argument.name.name.is_empty() || argument.name.name == "name"
+}
+
+/// The position after the type and the directives of a field.
+fn end_of_field(field: &Field) -> usize {
+    field
+        .directives
+        .iter()
+        .map(|directive| directive.span.end)
+        .fold(field.field_type.span.end, std::cmp::max)
+}
+
+/// Renames all field types referring to the given model, enum or type.
+fn type_reference_edits(schema: &SchemaAst, old_name: &str, new_name: &str) -> Vec<TextEdit> {
+    schema
+        .models()
+        .into_iter()
+        .flat_map(|model| model.fields.iter())
+        .filter(|field| field.field_type.name == old_name)
+        .map(|field| {
+            // The span of the field type includes the arity, e.g. `?` and `[]`.
+            let start = field.field_type.span.start;
+            TextEdit::replace(Span::new(start, start + old_name.len()), new_name)
+        })
+        .collect()
+}
+
+/// Renames all constants with the old name in the expression, looking into arrays.
+fn constant_edits(expression: &Expression, old_name: &str, new_name: &str) -> Vec<TextEdit> {
+    match expression {
+        Expression::ConstantValue(value, span) if value == old_name => vec![TextEdit::replace(*span, new_name)],
+        Expression::Array(values, _) => values
+            .iter()
+            .flat_map(|value| constant_edits(value, old_name, new_name))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Adds a `@@map` directive with the old name right before the closing brace of a block.
+fn block_map_directive(block_span: Span, old_name: &str) -> TextEdit {
+    TextEdit::insert(block_span.end - 1, format!("  @@map(\"{}\")\n", old_name))
+}
+
+fn find_directive<'a>(directives: &'a [Directive], name: &str) -> Option<&'a Directive> {
+    directives.iter().find(|directive| directive.name.name == name)
+}
+
+fn is_field_list_directive(name: &str) -> bool {
+    match name {
+        "id" | "unique" | "index" => true,
+        _ => false,
+    }
+}
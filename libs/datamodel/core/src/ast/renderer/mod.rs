@@ -170,9 +170,17 @@ impl<'a> Renderer<'a> {
             "".to_string()
         };
 
+        let keyword = if model.is_view {
+            "view"
+        } else if model.is_composite_type {
+            "type"
+        } else {
+            "model"
+        };
+
         Self::render_documentation(self, model);
 
-        self.write(format!("{}model ", comment_out).as_ref());
+        self.write(format!("{}{} ", comment_out, keyword).as_ref());
         self.write(&model.name.name);
         self.write(" {");
         self.end_line();
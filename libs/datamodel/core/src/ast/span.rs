@@ -21,6 +21,11 @@ impl Span {
             end: s.end(),
         }
     }
+
+    /// Checks if the given position is inside the span (boundaries included).
+    pub fn contains(&self, position: usize) -> bool {
+        position >= self.start && position <= self.end
+    }
 }
 
 impl std::fmt::Display for Span {
@@ -0,0 +1,38 @@
+use super::Span;
+
+/// Replaces the text of the given span.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub(crate) fn replace(span: Span, new_text: &str) -> TextEdit {
+        TextEdit {
+            span,
+            new_text: String::from(new_text),
+        }
+    }
+
+    pub(crate) fn insert(position: usize, new_text: String) -> TextEdit {
+        TextEdit {
+            span: Span::new(position, position),
+            new_text,
+        }
+    }
+}
+
+/// Applies the edits to the given schema string.
+pub fn apply_edits(input: &str, edits: &[TextEdit]) -> String {
+    let mut edits: Vec<&TextEdit> = edits.iter().collect();
+    edits.sort_by_key(|edit| std::cmp::Reverse((edit.span.start, edit.span.end)));
+
+    let mut result = String::from(input);
+
+    for edit in edits {
+        result.replace_range(edit.span.start..edit.span.end, &edit.new_text);
+    }
+
+    result
+}
@@ -59,6 +59,9 @@ impl ValueValidator {
             ScalarType::DateTime => self.as_date_time().map(PrismaValue::DateTime),
             ScalarType::String => self.as_str().map(PrismaValue::String),
             ScalarType::Json => self.as_str().map(PrismaValue::String),
+            ScalarType::Decimal => self.as_float().map(PrismaValue::Decimal),
+            ScalarType::BigInt => self.as_int().map(PrismaValue::BigInt),
+            ScalarType::Bytes => self.as_bytes().map(PrismaValue::Bytes),
         }
     }
 
@@ -124,6 +127,15 @@ impl ValueValidator {
         }
     }
 
+    /// Tries to convert the wrapped value to Prisma Bytes, given as a base64 encoded string.
+    pub fn as_bytes(&self) -> Result<Vec<u8>, DatamodelError> {
+        let value = self.as_str()?;
+
+        prisma_value::decode_bytes(&value).map_err(|_| {
+            DatamodelError::new_value_parser_error("base64", "Invalid base64 string.", &self.raw(), self.span())
+        })
+    }
+
     /// Tries to convert the wrapped value to a Prisma Boolean.
     pub fn as_bool(&self) -> Result<bool, DatamodelError> {
         match &self.value {
@@ -199,8 +211,20 @@ impl ValueValidator {
 
     pub fn as_default_value(&self, scalar_type: ScalarType) -> Result<DefaultValue, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => {
-                let generator = self.get_value_generator(&name)?;
+            ast::Expression::Function(name, args, _) => {
+                let generator = self.get_value_generator(&name, &args)?;
+
+                if !generator.generator.supports_scalar_type(scalar_type) {
+                    return Err(DatamodelError::new_functional_evaluation_error(
+                        &format!(
+                            "The function `{}()` cannot be used on fields of type `{}`.",
+                            name,
+                            scalar_type.to_string()
+                        ),
+                        self.span(),
+                    ));
+                }
+
                 Ok(DefaultValue::Expression(generator))
             }
             _ => {
@@ -212,15 +236,29 @@ impl ValueValidator {
 
     pub fn as_value_generator(&self) -> Result<ValueGenerator, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => self.get_value_generator(&name),
+            ast::Expression::Function(name, args, _) => self.get_value_generator(&name, &args),
             _ => Err(self.construct_type_mismatch_error("function")),
         }
     }
 
-    fn get_value_generator(&self, name: &str) -> Result<ValueGenerator, DatamodelError> {
-        ValueGenerator::new(name.to_string(), vec![])
+    fn get_value_generator(&self, name: &str, args: &[ast::Expression]) -> Result<ValueGenerator, DatamodelError> {
+        let args = args
+            .iter()
+            .map(|arg| ValueValidator::new(arg).as_generator_argument())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ValueGenerator::new(name.to_string(), args)
             .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))
     }
+
+    /// Arguments of value generators are either numbers or strings.
+    fn as_generator_argument(&self) -> Result<PrismaValue, DatamodelError> {
+        match &self.value {
+            ast::Expression::NumericValue(_, _) => self.as_int().map(PrismaValue::Int),
+            ast::Expression::StringValue(value, _) => Ok(PrismaValue::String(value.clone())),
+            _ => Err(self.construct_type_mismatch_error("numeric or String")),
+        }
+    }
 }
 
 pub trait ValueListValidator {
@@ -1,3 +1,4 @@
+use super::{get_builtin_option_schemas, GeneratorConfigValue, GeneratorOptionSchema};
 use crate::{
     ast,
     common::{arguments::Arguments, value_validator::ValueListValidator},
@@ -11,16 +12,54 @@ const OUTPUT_KEY: &str = "output";
 const BINARY_TARGETS_KEY: &str = "binaryTargets";
 const FIRST_CLASS_PROPERTIES: &[&str] = &[PROVIDER_KEY, OUTPUT_KEY, BINARY_TARGETS_KEY];
 
-pub struct GeneratorLoader {}
+/// Helper struct to load and validate generator configuration blocks.
+pub struct GeneratorLoader {
+    option_schemas: Vec<GeneratorOptionSchema>,
+}
+
+impl Default for GeneratorLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl GeneratorLoader {
-    pub fn load_generators_from_ast(ast_schema: &ast::SchemaAst) -> Result<Vec<Generator>, ErrorCollection> {
+    /// Creates a new generator loader, which knows the options of the built-in generators.
+    pub fn new() -> Self {
+        Self {
+            option_schemas: get_builtin_option_schemas(),
+        }
+    }
+
+    /// Creates a new generator loader, which knows the options of the built-in generators and the given ones.
+    pub fn with_option_schemas(option_schemas: Vec<GeneratorOptionSchema>) -> Self {
+        let mut loader = Self::new();
+
+        for option_schema in option_schemas {
+            loader.register_option_schema(option_schema);
+        }
+
+        loader
+    }
+
+    /// Registers the options of a generator. The options of generator blocks with the same provider
+    /// are validated against it, replacing any previously registered schema.
+    pub fn register_option_schema(&mut self, option_schema: GeneratorOptionSchema) {
+        self.option_schemas
+            .retain(|existing| existing.provider() != option_schema.provider());
+        self.option_schemas.push(option_schema);
+    }
+
+    pub fn load_generators_from_ast(&self, ast_schema: &ast::SchemaAst) -> Result<Vec<Generator>, ErrorCollection> {
         let mut generators: Vec<Generator> = vec![];
         let mut errors = ErrorCollection::new();
 
         for gen in &ast_schema.generators() {
             match Self::lift_generator(&gen) {
-                Ok(loaded_gen) => generators.push(loaded_gen),
+                Ok(loaded_gen) => {
+                    errors.append(&mut self.validate_options(&gen, &loaded_gen.provider));
+                    generators.push(loaded_gen)
+                }
                 // Lift error.
                 Err(DatamodelError::ArgumentNotFound { argument_name, span }) => errors.push(
                     DatamodelError::new_generator_argument_not_found_error(&argument_name, &gen.name.name, span),
@@ -36,6 +75,22 @@ impl GeneratorLoader {
         }
     }
 
+    /// Validates the options of a generator block against the schema registered for its provider, if any.
+    fn validate_options(&self, ast_generator: &ast::GeneratorConfig, provider: &str) -> ErrorCollection {
+        let option_schema = match self.option_schemas.iter().find(|schema| schema.provider() == provider) {
+            Some(option_schema) => option_schema,
+            None => return ErrorCollection::new(),
+        };
+
+        let options: Vec<&ast::Argument> = ast_generator
+            .properties
+            .iter()
+            .filter(|prop| !FIRST_CLASS_PROPERTIES.iter().any(|k| *k == prop.name.name))
+            .collect();
+
+        option_schema.validate(ast_generator, &options)
+    }
+
     fn lift_generator(ast_generator: &ast::GeneratorConfig) -> Result<Generator, DatamodelError> {
         let mut args = Arguments::new(&ast_generator.properties, ast_generator.span);
 
@@ -46,7 +101,7 @@ impl GeneratorLoader {
             None
         };
 
-        let mut properties: HashMap<String, String> = HashMap::new();
+        let mut properties: HashMap<String, GeneratorConfigValue> = HashMap::new();
 
         let binary_targets = match args.arg(BINARY_TARGETS_KEY).ok() {
             Some(x) => x.as_array().to_str_vec()?,
@@ -59,7 +114,7 @@ impl GeneratorLoader {
                 continue;
             }
 
-            properties.insert(prop.name.name.clone(), prop.value.to_string());
+            properties.insert(prop.name.name.clone(), lift_config_value(&prop.value));
         }
 
         Ok(Generator {
@@ -104,7 +159,7 @@ impl GeneratorLoader {
         }
 
         for (key, value) in &generator.config {
-            arguments.push(ast::Argument::new_string(&key, &value));
+            arguments.push(ast::Argument::new(&key, lower_config_value(value)));
         }
 
         ast::GeneratorConfig {
@@ -115,3 +170,21 @@ impl GeneratorLoader {
         }
     }
 }
+
+fn lift_config_value(value: &ast::Expression) -> GeneratorConfigValue {
+    match value {
+        ast::Expression::BooleanValue(value, _) => GeneratorConfigValue::Boolean(value == "true"),
+        ast::Expression::Array(values, _) => GeneratorConfigValue::List(values.iter().map(lift_config_value).collect()),
+        _ => GeneratorConfigValue::String(value.to_string()),
+    }
+}
+
+fn lower_config_value(value: &GeneratorConfigValue) -> ast::Expression {
+    match value {
+        GeneratorConfigValue::String(value) => ast::Expression::StringValue(value.clone(), ast::Span::empty()),
+        GeneratorConfigValue::Boolean(value) => ast::Expression::BooleanValue(value.to_string(), ast::Span::empty()),
+        GeneratorConfigValue::List(values) => {
+            ast::Expression::Array(values.iter().map(lower_config_value).collect(), ast::Span::empty())
+        }
+    }
+}
@@ -1,5 +1,7 @@
 mod loader;
+mod option_schema;
 pub use loader::*;
+pub use option_schema::*;
 
 use serde::Serialize;
 use std::collections::HashMap;
@@ -12,7 +14,16 @@ pub struct Generator {
     output: Option<String>,
     #[serde(default = "Vec::new")]
     binary_targets: Vec<String>,
-    config: HashMap<String, String>,
+    config: HashMap<String, GeneratorConfigValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     documentation: Option<String>,
 }
+
+/// The value of a generator option. Numbers and constants are kept as strings.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum GeneratorConfigValue {
+    String(String),
+    Boolean(bool),
+    List(Vec<GeneratorConfigValue>),
+}
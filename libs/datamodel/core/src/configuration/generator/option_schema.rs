@@ -0,0 +1,181 @@
+use crate::{ast, common::value_validator::ValueValidator, error::*};
+use serde::Deserialize;
+
+const PRISMA_CLIENT_JS_PROVIDER: &str = "prisma-client-js";
+
+/// The options a generator accepts besides `provider`, `output` and `binaryTargets`.
+///
+/// Generators without a registered schema accept any option.
+///
+/// Schemas of other generators can be read from Json, for example:
+/// `{ "provider": "my-generator", "options": [{ "name": "target", "type": "string", "allowedValues": ["es2020"] }] }`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GeneratorOptionSchema {
+    provider: String,
+    #[serde(default)]
+    options: Vec<GeneratorOption>,
+}
+
+/// A single option of a generator.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorOption {
+    name: String,
+    #[serde(rename = "type")]
+    tpe: GeneratorOptionType,
+    #[serde(default)]
+    required: bool,
+    #[serde(default)]
+    allowed_values: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GeneratorOptionType {
+    String,
+    Boolean,
+    StringList,
+}
+
+impl GeneratorOptionSchema {
+    /// Creates a new schema without any options for the given provider.
+    pub fn new(provider: &str) -> Self {
+        Self {
+            provider: String::from(provider),
+            options: Vec::new(),
+        }
+    }
+
+    /// Adds an option to the schema.
+    pub fn option(mut self, option: GeneratorOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    pub fn options(&self) -> &[GeneratorOption] {
+        &self.options
+    }
+
+    /// Validates the given options of a generator block, which must not include the first class properties.
+    pub(crate) fn validate(&self, generator: &ast::GeneratorConfig, properties: &[&ast::Argument]) -> ErrorCollection {
+        let generator_name = &generator.name.name;
+        let mut errors = ErrorCollection::new();
+
+        for property in properties {
+            match self.options.iter().find(|option| option.name == property.name.name) {
+                Some(option) => {
+                    if let Err(err) = option.validate(generator_name, &property.value) {
+                        errors.push(err);
+                    }
+                }
+                None => errors.push(DatamodelError::new_generator_validation_error(
+                    &format!(
+                        "The option `{}` is not known to the `{}` generator.",
+                        property.name.name, self.provider
+                    ),
+                    generator_name,
+                    property.name.span,
+                )),
+            }
+        }
+
+        for option in self.options.iter().filter(|option| option.required) {
+            if !properties.iter().any(|property| property.name.name == option.name) {
+                errors.push(DatamodelError::new_generator_argument_not_found_error(
+                    &option.name,
+                    generator_name,
+                    generator.span,
+                ));
+            }
+        }
+
+        errors
+    }
+}
+
+impl GeneratorOption {
+    /// Creates a new optional option accepting any value of the given type.
+    pub fn new(name: &str, tpe: GeneratorOptionType) -> Self {
+        Self {
+            name: String::from(name),
+            tpe,
+            required: false,
+            allowed_values: Vec::new(),
+        }
+    }
+
+    /// Marks the option as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Restricts the values of a string or the items of a string list to the given values.
+    pub fn allowed_values(mut self, values: &[&str]) -> Self {
+        self.allowed_values = values.iter().map(|value| String::from(*value)).collect();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn tpe(&self) -> GeneratorOptionType {
+        self.tpe
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn validate(&self, generator_name: &str, value: &ast::Expression) -> Result<(), DatamodelError> {
+        match (self.tpe, value) {
+            (GeneratorOptionType::Boolean, _) => ValueValidator::new(value).as_bool().map(|_| ()),
+            (GeneratorOptionType::String, _) => self.validate_string(generator_name, value),
+            (GeneratorOptionType::StringList, ast::Expression::Array(values, _)) => values
+                .iter()
+                .map(|value| self.validate_string(generator_name, value))
+                .collect(),
+            (GeneratorOptionType::StringList, _) => Err(DatamodelError::new_type_mismatch_error(
+                "list of strings",
+                ast::describe_value_type(value),
+                &value.to_string(),
+                value.span(),
+            )),
+        }
+    }
+
+    fn validate_string(&self, generator_name: &str, value: &ast::Expression) -> Result<(), DatamodelError> {
+        let string = ValueValidator::new(value).as_str()?;
+
+        if self.allowed_values.is_empty() || self.allowed_values.contains(&string) {
+            return Ok(());
+        }
+
+        Err(DatamodelError::new_generator_validation_error(
+            &format!(
+                "`{}` is not a valid value for the option `{}`. Expected one of: {}.",
+                string,
+                self.name,
+                self.allowed_values.join(", ")
+            ),
+            generator_name,
+            value.span(),
+        ))
+    }
+}
+
+pub(crate) fn get_builtin_option_schemas() -> Vec<GeneratorOptionSchema> {
+    let prisma_client_js = GeneratorOptionSchema::new(PRISMA_CLIENT_JS_PROVIDER)
+        .option(GeneratorOption::new("previewFeatures", GeneratorOptionType::StringList))
+        .option(GeneratorOption::new(
+            "experimentalFeatures",
+            GeneratorOptionType::StringList,
+        ));
+
+    vec![prisma_client_js]
+}
@@ -1,5 +1,5 @@
 use crate::configuration::*;
-use datamodel_connector::{Connector, ExampleConnector};
+use datamodel_connector::{BuiltinConnectors, Connector};
 
 pub const MYSQL_SOURCE_NAME: &str = "mysql";
 
@@ -34,6 +34,6 @@ impl Source for MySqlSource {
     }
 
     fn connector(&self) -> Box<dyn Connector> {
-        Box::new(ExampleConnector::mysql())
+        Box::new(BuiltinConnectors::mysql())
     }
 }
@@ -18,6 +18,7 @@ impl SourceDefinition for MySqlSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        _schemas: Vec<String>,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, String> {
         Ok(Box::new(MySqlSource {
@@ -1,11 +1,12 @@
 use crate::configuration::*;
-use datamodel_connector::{Connector, ExampleConnector};
+use datamodel_connector::{BuiltinConnectors, Connector};
 
 pub const POSTGRES_SOURCE_NAME: &str = "postgresql";
 
 pub struct PostgresSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
+    pub(super) schemas: Vec<String>,
     pub(super) documentation: Option<String>,
 }
 
@@ -31,7 +32,11 @@ impl Source for PostgresSource {
         &self.documentation
     }
 
+    fn schemas(&self) -> &[String] {
+        &self.schemas
+    }
+
     fn connector(&self) -> Box<dyn Connector> {
-        Box::new(ExampleConnector::postgres())
+        Box::new(BuiltinConnectors::postgres())
     }
 }
@@ -18,6 +18,7 @@ impl SourceDefinition for PostgresSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        schemas: Vec<String>,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, String> {
         let high_prio_validation = validate_url(name, "postgresql://", url.clone());
@@ -25,6 +26,7 @@ impl SourceDefinition for PostgresSourceDefinition {
         Ok(Box::new(PostgresSource {
             name: String::from(name),
             url: low_prio_validation.or(high_prio_validation)?,
+            schemas,
             documentation: documentation.clone(),
         }))
     }
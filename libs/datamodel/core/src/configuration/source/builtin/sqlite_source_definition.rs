@@ -18,6 +18,7 @@ impl SourceDefinition for SqliteSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        _schemas: Vec<String>,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, String> {
         let validation_with_file_protocol = validate_url(name, "file:", url.clone());
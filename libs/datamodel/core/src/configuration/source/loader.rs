@@ -1,5 +1,5 @@
 use super::{
-    builtin::{MySqlSourceDefinition, PostgresSourceDefinition, SqliteSourceDefinition},
+    builtin::{MySqlSourceDefinition, PostgresSourceDefinition, SqliteSourceDefinition, POSTGRES_SOURCE_NAME},
     traits::{Source, SourceDefinition},
 };
 use crate::ast;
@@ -93,11 +93,35 @@ impl SourceLoader {
             ));
         }
 
+        let schemas_arg = args.optional_arg("schemas");
+        let schemas = match &schemas_arg {
+            Some(schemas_arg) => schemas_arg
+                .as_array()
+                .iter()
+                .map(|schema| schema.as_str())
+                .collect::<Result<Vec<_>, _>>()?,
+            None => Vec::new(),
+        };
+
         for decl in &self.source_declarations {
             // The provider given in the config block identifies the source type.
             // TODO: The second condition is a fallback to mitigate the postgres -> postgresql rename. It should be
             // renamed at some point.
             if provider == decl.connector_type() || (decl.connector_type() == "postgresql" && provider == "postgres") {
+                match &schemas_arg {
+                    Some(schemas_arg) if decl.connector_type() != POSTGRES_SOURCE_NAME => {
+                        return Err(DatamodelError::new_source_validation_error(
+                            &format!(
+                                "The `schemas` property is not supported by the `{}` provider.",
+                                provider
+                            ),
+                            source_name,
+                            schemas_arg.span(),
+                        ))
+                    }
+                    _ => (),
+                }
+
                 let source = decl
                     .create(
                         source_name,
@@ -105,6 +129,7 @@ impl SourceLoader {
                             from_env_var: env_var_for_url,
                             value: url,
                         },
+                        schemas,
                         &ast_source.documentation.clone().map(|comment| comment.text),
                     )
                     .map_err(|err_msg| {
@@ -21,6 +21,11 @@ pub trait Source: Send + Sync {
     /// Documentation of this source.
     fn documentation(&self) -> &Option<String>;
 
+    /// The database schemas, next to the default one from the URL, that this source spans.
+    fn schemas(&self) -> &[String] {
+        &[]
+    }
+
     fn connector(&self) -> Box<dyn Connector>;
 }
 
@@ -35,6 +40,7 @@ pub trait SourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        schemas: Vec<String>,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, String>;
 }
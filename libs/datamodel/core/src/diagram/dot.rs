@@ -0,0 +1,87 @@
+use super::*;
+use std::fmt::Write;
+
+/// Renders the datamodel as a Graphviz DOT graph. Edges point from the model with the foreign key
+/// to the referenced model.
+pub fn render_dot(datamodel: &dml::Datamodel) -> String {
+    let mut out = String::from("digraph datamodel {\n  rankdir=LR;\n  node [shape=record];\n");
+
+    for model in datamodel.models().filter(|model| !model.is_commented_out) {
+        render_node(&mut out, model);
+    }
+
+    for relation in relations(datamodel) {
+        writeln!(
+            out,
+            "  \"{}\" -> \"{}\" [label=\"{}\", taillabel=\"{}\", headlabel=\"{}\"];",
+            relation.model.name,
+            relation.related_model.name,
+            escape(relation.name()),
+            label(relation.related_cardinality()),
+            label(relation.cardinality()),
+        )
+        .unwrap();
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn render_node(out: &mut String, model: &dml::Model) {
+    let mut title = escape(&model.name);
+
+    if let Some(database_name) = mapped_name(model) {
+        write!(title, "\\n({})", escape(database_name)).unwrap();
+    }
+
+    let attributes: String = attribute_fields(model)
+        .map(|field| format!("{}\\l", escape(&attribute(model, field))))
+        .collect();
+
+    writeln!(out, "  \"{}\" [label=\"{{{}|{}}}\"];", model.name, title, attributes).unwrap();
+}
+
+/// An attribute like `email: String? (UK) (db: email_address)`.
+fn attribute(model: &dml::Model, field: &dml::Field) -> String {
+    let arity = match field.arity {
+        dml::FieldArity::Required => "",
+        dml::FieldArity::Optional => "?",
+        dml::FieldArity::List => "[]",
+    };
+
+    let mut attribute = format!("{}: {}{}", field.name, type_name(field), arity);
+    let keys = keys(model, field);
+
+    if !keys.is_empty() {
+        write!(attribute, " ({})", keys.join(", ")).unwrap();
+    }
+
+    if let Some(database_name) = mapped_name(field) {
+        write!(attribute, " (db: {})", database_name).unwrap();
+    }
+
+    attribute
+}
+
+fn label(cardinality: Cardinality) -> &'static str {
+    match cardinality {
+        Cardinality::ExactlyOne => "1",
+        Cardinality::ZeroOrOne => "0..1",
+        Cardinality::Many => "*",
+    }
+}
+
+/// Escapes the characters with a special meaning in record labels.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if "{}|<>\"\\".contains(c) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
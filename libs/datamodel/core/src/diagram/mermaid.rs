@@ -0,0 +1,88 @@
+use super::*;
+use std::fmt::Write;
+
+/// Renders the datamodel as a Mermaid `erDiagram`.
+pub fn render_mermaid(datamodel: &dml::Datamodel) -> String {
+    let mut out = String::from("erDiagram\n");
+
+    for model in datamodel.models().filter(|model| !model.is_commented_out) {
+        render_entity(&mut out, model);
+    }
+
+    for relation in relations(datamodel) {
+        writeln!(
+            out,
+            "  {} {}--{} {} : \"{}\"",
+            relation.related_model.name,
+            left_marker(relation.cardinality()),
+            right_marker(relation.related_cardinality()),
+            relation.model.name,
+            relation.name(),
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+fn render_entity(out: &mut String, model: &dml::Model) {
+    match mapped_name(model) {
+        Some(database_name) => writeln!(out, "  {}[\"{} ({})\"] {{", model.name, model.name, database_name).unwrap(),
+        None => writeln!(out, "  {} {{", model.name).unwrap(),
+    }
+
+    for field in attribute_fields(model) {
+        render_attribute(out, model, field);
+    }
+
+    out.push_str("  }\n");
+}
+
+fn render_attribute(out: &mut String, model: &dml::Model, field: &dml::Field) {
+    let type_name = match field.arity {
+        dml::FieldArity::List => format!("{}[]", type_name(field)),
+        _ => type_name(field),
+    };
+
+    write!(out, "    {} {}", type_name, field.name).unwrap();
+
+    let keys = keys(model, field);
+
+    if !keys.is_empty() {
+        write!(out, " {}", keys.join(", ")).unwrap();
+    }
+
+    let mut comments = Vec::new();
+
+    if field.arity == dml::FieldArity::Optional {
+        comments.push(String::from("nullable"));
+    }
+
+    if let Some(database_name) = mapped_name(field) {
+        comments.push(format!("db: {}", database_name));
+    }
+
+    if !comments.is_empty() {
+        write!(out, " \"{}\"", comments.join(", ")).unwrap();
+    }
+
+    out.push('\n');
+}
+
+/// The marker next to the entity on the left, the one without the foreign key.
+fn left_marker(cardinality: Cardinality) -> &'static str {
+    match cardinality {
+        Cardinality::ExactlyOne => "||",
+        Cardinality::ZeroOrOne => "|o",
+        Cardinality::Many => "}o",
+    }
+}
+
+/// The marker next to the entity on the right, the one with the foreign key.
+fn right_marker(cardinality: Cardinality) -> &'static str {
+    match cardinality {
+        Cardinality::ExactlyOne => "||",
+        Cardinality::ZeroOrOne => "o|",
+        Cardinality::Many => "o{",
+    }
+}
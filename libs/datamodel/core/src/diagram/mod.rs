@@ -0,0 +1,178 @@
+//! Entity relationship diagrams of a datamodel.
+//!
+//! Models become entities with their scalar and enum fields, relations become edges between them.
+//! Each relation is rendered once, from the side holding the foreign key.
+mod dot;
+mod mermaid;
+
+pub use dot::render_dot;
+pub use mermaid::render_mermaid;
+
+use crate::dml::{self, WithDatabaseName};
+
+/// A relation between two models, seen from the field holding the foreign key.
+struct Relation<'a> {
+    model: &'a dml::Model,
+    field: &'a dml::Field,
+    related_model: &'a dml::Model,
+    related_field: Option<&'a dml::Field>,
+}
+
+impl<'a> Relation<'a> {
+    /// How many records of the related model belong to one record of the model.
+    fn cardinality(&self) -> Cardinality {
+        Cardinality::from(self.field.arity)
+    }
+
+    /// How many records of the model belong to one record of the related model.
+    fn related_cardinality(&self) -> Cardinality {
+        self.related_field
+            .map(|field| Cardinality::from(field.arity))
+            .unwrap_or(Cardinality::Many)
+    }
+
+    fn name(&self) -> &str {
+        match &self.field.field_type {
+            dml::FieldType::Relation(relation_info) if !relation_info.name.is_empty() => &relation_info.name,
+            _ => &self.field.name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cardinality {
+    ExactlyOne,
+    ZeroOrOne,
+    Many,
+}
+
+impl From<dml::FieldArity> for Cardinality {
+    fn from(arity: dml::FieldArity) -> Self {
+        match arity {
+            dml::FieldArity::Required => Cardinality::ExactlyOne,
+            dml::FieldArity::Optional => Cardinality::ZeroOrOne,
+            dml::FieldArity::List => Cardinality::Many,
+        }
+    }
+}
+
+/// The relations of the datamodel, each listed once.
+fn relations(datamodel: &dml::Datamodel) -> Vec<Relation<'_>> {
+    let mut relations = Vec::new();
+
+    for model in datamodel.models().filter(|model| !model.is_commented_out) {
+        for field in model.fields().filter(|field| !field.is_commented_out) {
+            let relation_info = match &field.field_type {
+                dml::FieldType::Relation(relation_info) => relation_info,
+                _ => continue,
+            };
+
+            let related_model = match datamodel.find_model(&relation_info.to) {
+                Some(related_model) => related_model,
+                None => continue,
+            };
+
+            let related_field =
+                datamodel.related_field(&model.name, &relation_info.to, &relation_info.name, &field.name);
+
+            if is_rendered_from_related_side(model, field, relation_info, related_field) {
+                continue;
+            }
+
+            relations.push(Relation {
+                model,
+                field,
+                related_model,
+                related_field,
+            });
+        }
+    }
+
+    relations
+}
+
+/// Relations are rendered from the side with the foreign key. If neither or both sides have one,
+/// as for implicit many to many relations, the first side by model and field name is used.
+fn is_rendered_from_related_side(
+    model: &dml::Model,
+    field: &dml::Field,
+    relation_info: &dml::RelationInfo,
+    related_field: Option<&dml::Field>,
+) -> bool {
+    let related_field = match related_field {
+        Some(related_field) => related_field,
+        None => return false,
+    };
+
+    let related_has_foreign_key = match &related_field.field_type {
+        dml::FieldType::Relation(related_info) => !related_info.fields.is_empty(),
+        _ => false,
+    };
+
+    match (relation_info.fields.is_empty(), related_has_foreign_key) {
+        (true, true) => true,
+        (false, false) => false,
+        _ => (model.name.as_str(), field.name.as_str()) > (relation_info.to.as_str(), related_field.name.as_str()),
+    }
+}
+
+/// The fields shown as attributes of an entity. Relation fields are shown as edges instead.
+fn attribute_fields(model: &dml::Model) -> impl Iterator<Item = &dml::Field> {
+    model
+        .fields()
+        .filter(|field| !field.is_commented_out && !field.field_type.is_relation())
+}
+
+fn type_name(field: &dml::Field) -> String {
+    match &field.field_type {
+        dml::FieldType::Base(scalar_type, _) => scalar_type.to_string(),
+        dml::FieldType::ConnectorSpecific(scalar_field_type) => scalar_field_type.prisma_type().to_string(),
+        dml::FieldType::Enum(name) | dml::FieldType::CompositeType(name) => name.clone(),
+        dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
+        dml::FieldType::Unsupported(_) => String::from("Unsupported"),
+    }
+}
+
+/// The keys a field is part of: `PK`, `FK` and `UK`.
+fn keys(model: &dml::Model, field: &dml::Field) -> Vec<&'static str> {
+    let mut keys = Vec::new();
+
+    if is_primary_key(model, field) {
+        keys.push("PK");
+    }
+
+    if is_foreign_key(model, field) {
+        keys.push("FK");
+    }
+
+    if is_unique_key(model, field) {
+        keys.push("UK");
+    }
+
+    keys
+}
+
+fn is_primary_key(model: &dml::Model, field: &dml::Field) -> bool {
+    field.is_id || model.id_fields.contains(&field.name)
+}
+
+fn is_unique_key(model: &dml::Model, field: &dml::Field) -> bool {
+    field.is_unique
+        || model
+            .indices
+            .iter()
+            .any(|index| index.is_unique() && index.fields == [field.name.clone()])
+}
+
+fn is_foreign_key(model: &dml::Model, field: &dml::Field) -> bool {
+    model.fields().any(|relation_field| match &relation_field.field_type {
+        dml::FieldType::Relation(relation_info) => relation_info.fields.contains(&field.name),
+        _ => false,
+    })
+}
+
+/// The database name of a model or field, if it is mapped to a different name.
+fn mapped_name<T: WithDatabaseName>(item: &T) -> Option<&str> {
+    item.database_name()
+        .filter(|database_name| *database_name != item.name())
+}
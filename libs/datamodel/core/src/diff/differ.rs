@@ -0,0 +1,460 @@
+use super::*;
+use crate::{
+    ast::differ::{EnumDiffer, ModelDiffer, TopDiffer},
+    dml::{self, WithDatabaseName},
+};
+
+/// Computes the changes from the `previous` to the `next` version of a datamodel.
+///
+/// Models, composite types, enums and their fields and values are paired up by the AST differ the
+/// migration engine uses as well. Whether a change breaks clients depends on the validated datamodels.
+pub fn diff_datamodels(previous: DatamodelVersion<'_>, next: DatamodelVersion<'_>) -> DatamodelDiff {
+    let top_differ = TopDiffer {
+        previous: previous.ast,
+        next: next.ast,
+    };
+
+    let mut differ = Differ {
+        previous: previous.datamodel,
+        next: next.datamodel,
+        changes: Vec::new(),
+    };
+
+    differ.diff_types(&top_differ);
+    differ.diff_enums(&top_differ);
+
+    DatamodelDiff {
+        changes: differ.changes,
+    }
+}
+
+struct Differ<'a> {
+    previous: &'a dml::Datamodel,
+    next: &'a dml::Datamodel,
+    changes: Vec<Change>,
+}
+
+/// Models and composite types are both declared like models in the AST.
+enum TypeDefinition<'a> {
+    Model(&'a dml::Model),
+    CompositeType(&'a dml::CompositeType),
+}
+
+impl TypeDefinition<'_> {
+    fn subject(&self) -> ChangeSubject {
+        match self {
+            TypeDefinition::Model(model) => ChangeSubject::Model {
+                model: model.name.clone(),
+            },
+            TypeDefinition::CompositeType(composite_type) => ChangeSubject::CompositeType {
+                composite_type: composite_type.name.clone(),
+            },
+        }
+    }
+}
+
+impl<'a> Differ<'a> {
+    fn push(&mut self, kind: ChangeKind, subject: ChangeSubject, breaking: bool) {
+        self.changes.push(Change::new(kind, subject, breaking));
+    }
+
+    fn diff_types(&mut self, differ: &TopDiffer<'_>) {
+        for model_differ in differ.model_pairs() {
+            let name = &model_differ.previous.name.name;
+
+            match (find_type(self.previous, name), find_type(self.next, name)) {
+                (Some(TypeDefinition::Model(previous)), Some(TypeDefinition::Model(next))) => {
+                    self.diff_model(&model_differ, previous, next)
+                }
+                (Some(TypeDefinition::CompositeType(previous)), Some(TypeDefinition::CompositeType(next))) => {
+                    self.diff_composite_type(&model_differ, previous, next)
+                }
+                // A model that became a composite type, or the other way around.
+                (previous, next) => {
+                    self.push_removed_type(previous);
+                    self.push_added_type(next);
+                }
+            }
+        }
+
+        for deleted_model in differ.deleted_models() {
+            self.push_removed_type(find_type(self.previous, &deleted_model.name.name));
+        }
+
+        for created_model in differ.created_models() {
+            self.push_added_type(find_type(self.next, &created_model.name.name));
+        }
+    }
+
+    fn push_removed_type(&mut self, definition: Option<TypeDefinition<'_>>) {
+        if let Some(definition) = definition {
+            self.push(ChangeKind::Removed, definition.subject(), true);
+        }
+    }
+
+    fn push_added_type(&mut self, definition: Option<TypeDefinition<'_>>) {
+        if let Some(definition) = definition {
+            self.push(ChangeKind::Added, definition.subject(), false);
+        }
+    }
+
+    fn diff_model(&mut self, differ: &ModelDiffer<'_>, previous: &dml::Model, next: &dml::Model) {
+        let field_names = differ
+            .field_pairs()
+            .map(|field_differ| field_differ.previous)
+            .chain(differ.deleted_fields())
+            .chain(differ.created_fields())
+            .map(|field| field.name.name.as_str());
+
+        // Back relation fields are added during validation, so they are only part of the datamodels.
+        let is_in_ast = |name: &str| {
+            differ
+                .previous
+                .fields
+                .iter()
+                .chain(differ.next.fields.iter())
+                .any(|field| field.name.name == name)
+        };
+
+        let generated_field_names = previous
+            .fields()
+            .map(|field| field.name.as_str())
+            .chain(
+                next.fields()
+                    .filter(|field| previous.find_field(&field.name).is_none())
+                    .map(|field| field.name.as_str()),
+            )
+            .filter(|name| !is_in_ast(*name));
+
+        for name in field_names.chain(generated_field_names) {
+            let previous_field = previous.find_field(name).filter(|field| is_exposed(field));
+            let next_field = next.find_field(name).filter(|field| is_exposed(field));
+
+            match (previous_field, next_field) {
+                (Some(previous_field), Some(next_field)) => self.diff_field(&previous.name, previous_field, next_field),
+                (Some(previous_field), None) => {
+                    self.push(ChangeKind::Removed, field_subject(&previous.name, previous_field), true)
+                }
+                (None, Some(next_field)) => {
+                    // Creating records fails without a value for the new field.
+                    let breaking = next_field.arity.is_required() && !has_generated_value(next_field);
+                    self.push(ChangeKind::Added, field_subject(&previous.name, next_field), breaking);
+                }
+                (None, None) => (),
+            }
+        }
+
+        self.diff_model_attributes(previous, next);
+    }
+
+    fn diff_field(&mut self, model: &str, previous: &dml::Field, next: &dml::Field) {
+        let previous_type = type_name(previous);
+        let next_type = type_name(next);
+
+        if previous_type != next_type {
+            let details = format!("from `{}` to `{}`", previous_type, next_type);
+            let change = Change::with_details(ChangeKind::Changed, field_subject(model, previous), true, &details);
+            self.changes.push(change);
+        }
+
+        let attribute_subject = |attribute: &str| ChangeSubject::FieldAttribute {
+            model: model.to_owned(),
+            field: previous.name.clone(),
+            attribute: attribute.to_owned(),
+        };
+
+        // The id is part of the unique inputs of the model.
+        if let Some(kind) = flag_change(previous.is_id, next.is_id) {
+            self.push(kind, attribute_subject("@id"), true);
+        }
+
+        if let Some(kind) = flag_change(previous.is_unique, next.is_unique) {
+            self.push(kind, attribute_subject("@unique"), kind == ChangeKind::Removed);
+        }
+
+        // Values that were generated before must be provided when they are not generated anymore.
+        let is_value_required = next.arity.is_required() && !has_generated_value(next);
+
+        if let Some(kind) = option_change(&previous.default_value, &next.default_value) {
+            let breaking = kind == ChangeKind::Removed && is_value_required;
+            self.push(kind, attribute_subject("@default"), breaking);
+        }
+
+        if let Some(kind) = flag_change(previous.is_updated_at, next.is_updated_at) {
+            let breaking = kind == ChangeKind::Removed && is_value_required;
+            self.push(kind, attribute_subject("@updatedAt"), breaking);
+        }
+
+        if let Some(kind) = option_change(&mapped_name(previous), &mapped_name(next)) {
+            self.push(kind, attribute_subject("@map"), false);
+        }
+
+        // Changes to the relation itself, like its name or referential actions, do not affect the API.
+        if let (dml::FieldType::Relation(previous_info), dml::FieldType::Relation(next_info)) =
+            (&previous.field_type, &next.field_type)
+        {
+            if previous_info.to == next_info.to && previous_info != next_info {
+                self.push(ChangeKind::Changed, attribute_subject("@relation"), false);
+            }
+        }
+    }
+
+    fn diff_model_attributes(&mut self, previous: &dml::Model, next: &dml::Model) {
+        let attribute_subject = |attribute: String| ChangeSubject::ModelAttribute {
+            model: previous.name.clone(),
+            attribute,
+        };
+
+        let previous_id = Some(&previous.id_fields).filter(|fields| !fields.is_empty());
+        let next_id = Some(&next.id_fields).filter(|fields| !fields.is_empty());
+
+        if let Some(kind) = option_change(&previous_id, &next_id) {
+            let id_fields = next_id.or(previous_id).unwrap();
+            self.push(
+                kind,
+                attribute_subject(format!("@@id([{}])", id_fields.join(", "))),
+                true,
+            );
+        }
+
+        for previous_index in &previous.indices {
+            let attribute = index_attribute(previous_index);
+
+            match find_index(next, previous_index) {
+                // Compound unique inputs are named after the index.
+                Some(next_index) if next_index.name != previous_index.name => {
+                    let breaking = previous_index.is_unique() && previous_index.fields.len() > 1;
+                    self.push(ChangeKind::Changed, attribute_subject(attribute), breaking);
+                }
+                Some(_) => (),
+                None => self.push(
+                    ChangeKind::Removed,
+                    attribute_subject(attribute),
+                    previous_index.is_unique(),
+                ),
+            }
+        }
+
+        for next_index in &next.indices {
+            if find_index(previous, next_index).is_none() {
+                self.push(ChangeKind::Added, attribute_subject(index_attribute(next_index)), false);
+            }
+        }
+
+        if let Some(kind) = option_change(&mapped_name(previous), &mapped_name(next)) {
+            self.push(kind, attribute_subject(String::from("@@map")), false);
+        }
+    }
+
+    /// Fields of composite types cannot have attributes, only their types can change.
+    fn diff_composite_type(
+        &mut self,
+        differ: &ModelDiffer<'_>,
+        previous: &dml::CompositeType,
+        next: &dml::CompositeType,
+    ) {
+        let field_subject = |field: &dml::Field| ChangeSubject::CompositeTypeField {
+            composite_type: previous.name.clone(),
+            field: field.name.clone(),
+        };
+
+        for field_differ in differ.field_pairs() {
+            let name = &field_differ.previous.name.name;
+
+            if let (Some(previous_field), Some(next_field)) = (previous.find_field(name), next.find_field(name)) {
+                let previous_type = type_name(previous_field);
+                let next_type = type_name(next_field);
+
+                if previous_type != next_type {
+                    let details = format!("from `{}` to `{}`", previous_type, next_type);
+                    let change =
+                        Change::with_details(ChangeKind::Changed, field_subject(previous_field), true, &details);
+                    self.changes.push(change);
+                }
+            }
+        }
+
+        for deleted_field in differ.deleted_fields() {
+            if let Some(previous_field) = previous.find_field(&deleted_field.name.name) {
+                self.push(ChangeKind::Removed, field_subject(previous_field), true);
+            }
+        }
+
+        for created_field in differ.created_fields() {
+            if let Some(next_field) = next.find_field(&created_field.name.name) {
+                // Composite values are written as a whole, so they must contain the new field.
+                let breaking = next_field.arity.is_required();
+                self.push(ChangeKind::Added, field_subject(next_field), breaking);
+            }
+        }
+    }
+
+    fn diff_enums(&mut self, differ: &TopDiffer<'_>) {
+        for enum_differ in differ.enum_pairs() {
+            let name = &enum_differ.previous.name.name;
+
+            if let (Some(previous_enum), Some(next_enum)) = (self.previous.find_enum(name), self.next.find_enum(name)) {
+                self.diff_enum(&enum_differ, previous_enum, next_enum);
+            }
+        }
+
+        for deleted_enum in differ.deleted_enums() {
+            let subject = ChangeSubject::Enum {
+                enum_name: deleted_enum.name.name.clone(),
+            };
+
+            self.push(ChangeKind::Removed, subject, true);
+        }
+
+        for created_enum in differ.created_enums() {
+            let subject = ChangeSubject::Enum {
+                enum_name: created_enum.name.name.clone(),
+            };
+
+            self.push(ChangeKind::Added, subject, false);
+        }
+    }
+
+    fn diff_enum(&mut self, differ: &EnumDiffer<'_>, previous: &dml::Enum, next: &dml::Enum) {
+        let enum_name = &previous.name;
+        let value_subject = |value: &str| ChangeSubject::EnumValue {
+            enum_name: enum_name.clone(),
+            value: value.to_owned(),
+        };
+
+        let value_names = differ
+            .value_pairs()
+            .map(|value_differ| value_differ.previous)
+            .chain(differ.deleted_values())
+            .chain(differ.created_values())
+            .map(|value| value.name.name.as_str());
+
+        for name in value_names {
+            match (find_value(previous, name), find_value(next, name)) {
+                (Some(previous_value), Some(next_value)) => {
+                    if let Some(kind) = option_change(&previous_value.database_name, &next_value.database_name) {
+                        let subject = ChangeSubject::EnumValueAttribute {
+                            enum_name: enum_name.clone(),
+                            value: previous_value.name.clone(),
+                            attribute: String::from("@map"),
+                        };
+
+                        self.push(kind, subject, false);
+                    }
+                }
+                (Some(previous_value), None) => {
+                    self.push(ChangeKind::Removed, value_subject(&previous_value.name), true)
+                }
+                // Clients only ever send values they know about, so new values are not breaking.
+                (None, Some(next_value)) => self.push(ChangeKind::Added, value_subject(&next_value.name), false),
+                (None, None) => (),
+            }
+        }
+
+        if let Some(kind) = option_change(&mapped_name(previous), &mapped_name(next)) {
+            let subject = ChangeSubject::EnumAttribute {
+                enum_name: enum_name.clone(),
+                attribute: String::from("@@map"),
+            };
+
+            self.push(kind, subject, false);
+        }
+    }
+}
+
+/// Ignored models are not exposed to the client, like in the DMMF.
+fn exposed_models(datamodel: &dml::Datamodel) -> impl Iterator<Item = &dml::Model> {
+    datamodel
+        .models()
+        .filter(|model| !model.is_ignored && !model.is_commented_out)
+}
+
+/// Models and composite types share a namespace, so a name refers to at most one of them.
+fn find_type<'a>(datamodel: &'a dml::Datamodel, name: &str) -> Option<TypeDefinition<'a>> {
+    exposed_models(datamodel)
+        .find(|model| model.name == name)
+        .map(TypeDefinition::Model)
+        .or_else(|| datamodel.find_composite_type(name).map(TypeDefinition::CompositeType))
+}
+
+/// Ignored fields and fields of unsupported types are not exposed to the client.
+fn is_exposed(field: &dml::Field) -> bool {
+    match field.field_type {
+        dml::FieldType::Unsupported(_) => false,
+        _ => !field.is_ignored && !field.is_commented_out,
+    }
+}
+
+fn find_value<'a>(enm: &'a dml::Enum, name: &str) -> Option<&'a dml::EnumValue> {
+    enm.values().find(|value| value.name == name && !value.commented_out)
+}
+
+fn field_subject(model: &str, field: &dml::Field) -> ChangeSubject {
+    ChangeSubject::Field {
+        model: model.to_owned(),
+        field: field.name.clone(),
+    }
+}
+
+/// Whether the value of a field can be left out when creating a record.
+fn has_generated_value(field: &dml::Field) -> bool {
+    field.default_value.is_some() || field.is_updated_at
+}
+
+/// The type of a field as exposed by the client, like `String?`. Native types are not exposed.
+fn type_name(field: &dml::Field) -> String {
+    let name = match &field.field_type {
+        dml::FieldType::Base(scalar_type, _) => scalar_type.to_string(),
+        dml::FieldType::ConnectorSpecific(scalar_field_type) => scalar_field_type.prisma_type().to_string(),
+        dml::FieldType::Enum(name) | dml::FieldType::CompositeType(name) => name.clone(),
+        dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
+        dml::FieldType::Unsupported(name) => name.clone(),
+    };
+
+    match field.arity {
+        dml::FieldArity::Required => name,
+        dml::FieldArity::Optional => format!("{}?", name),
+        dml::FieldArity::List => format!("{}[]", name),
+    }
+}
+
+/// Indexes are matched by their fields and type.
+fn find_index<'a>(model: &'a dml::Model, index: &dml::IndexDefinition) -> Option<&'a dml::IndexDefinition> {
+    model
+        .indices
+        .iter()
+        .find(|candidate| candidate.tpe == index.tpe && candidate.fields == index.fields)
+}
+
+fn index_attribute(index: &dml::IndexDefinition) -> String {
+    let name = match index.tpe {
+        dml::IndexType::Unique => "@@unique",
+        dml::IndexType::Normal => "@@index",
+    };
+
+    format!("{}([{}])", name, index.fields.join(", "))
+}
+
+/// The database name of a model, field or enum, if it is mapped to a different name.
+fn mapped_name<T: WithDatabaseName>(item: &T) -> Option<String> {
+    item.database_name()
+        .filter(|database_name| *database_name != item.name())
+        .map(String::from)
+}
+
+fn flag_change(previous: bool, next: bool) -> Option<ChangeKind> {
+    match (previous, next) {
+        (false, true) => Some(ChangeKind::Added),
+        (true, false) => Some(ChangeKind::Removed),
+        _ => None,
+    }
+}
+
+fn option_change<T: PartialEq>(previous: &Option<T>, next: &Option<T>) -> Option<ChangeKind> {
+    match (previous, next) {
+        (None, Some(_)) => Some(ChangeKind::Added),
+        (Some(_), None) => Some(ChangeKind::Removed),
+        (Some(previous), Some(next)) if previous != next => Some(ChangeKind::Changed),
+        _ => None,
+    }
+}
@@ -0,0 +1,221 @@
+//! Differences between two versions of a datamodel, as seen by consumers of the generated client API.
+//!
+//! Models, composite types, fields, enums, enum values and their attributes are matched by name, so
+//! a rename is reported as a removal and an addition. Ignored models and fields are not exposed to
+//! the client and therefore treated as absent.
+mod differ;
+
+pub use differ::diff_datamodels;
+
+use crate::{ast, dml};
+use serde::Serialize;
+use std::fmt;
+
+/// One version of a datamodel: the AST its items are paired up in, and the datamodel validated from it.
+#[derive(Debug, Clone, Copy)]
+pub struct DatamodelVersion<'a> {
+    pub ast: &'a ast::SchemaAst,
+    pub datamodel: &'a dml::Datamodel,
+}
+
+impl<'a> DatamodelVersion<'a> {
+    pub fn new(ast: &'a ast::SchemaAst, datamodel: &'a dml::Datamodel) -> Self {
+        DatamodelVersion { ast, datamodel }
+    }
+}
+
+/// The changes between two versions of a datamodel.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DatamodelDiff {
+    pub changes: Vec<Change>,
+}
+
+impl DatamodelDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.breaking)
+    }
+
+    pub fn non_breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| !change.breaking)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to render JSON")
+    }
+
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("Failed to render JSON")
+    }
+}
+
+/// Renders a human readable report, listing the breaking changes first.
+impl fmt::Display for DatamodelDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes.");
+        }
+
+        let sections = [
+            ("Breaking changes", self.breaking_changes().collect::<Vec<_>>()),
+            ("Non-breaking changes", self.non_breaking_changes().collect::<Vec<_>>()),
+        ];
+
+        let mut is_first = true;
+
+        for (title, changes) in sections.iter().filter(|(_, changes)| !changes.is_empty()) {
+            if !is_first {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "{}:", title)?;
+
+            for change in changes {
+                writeln!(f, "  - {}", change.description)?;
+            }
+
+            is_first = false;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single change, and whether it breaks existing consumers of the client API.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change {
+    pub kind: ChangeKind,
+    #[serde(flatten)]
+    pub subject: ChangeSubject,
+    pub breaking: bool,
+    pub description: String,
+}
+
+impl Change {
+    fn new(kind: ChangeKind, subject: ChangeSubject, breaking: bool) -> Self {
+        let description = format!("{}.", subject.describe(kind));
+
+        Change {
+            kind,
+            subject,
+            breaking,
+            description,
+        }
+    }
+
+    /// A change with details on how the subject changed, e.g. the previous and the next type of a field.
+    fn with_details(kind: ChangeKind, subject: ChangeSubject, breaking: bool, details: &str) -> Self {
+        let description = format!("{} {}.", subject.describe(kind), details);
+
+        Change {
+            kind,
+            subject,
+            breaking,
+            description,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// What changed. Attributes are named like in the schema, e.g. `@default` or `@@unique([a, b])`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "subject", rename_all = "camelCase")]
+pub enum ChangeSubject {
+    Model {
+        model: String,
+    },
+    ModelAttribute {
+        model: String,
+        attribute: String,
+    },
+    Field {
+        model: String,
+        field: String,
+    },
+    FieldAttribute {
+        model: String,
+        field: String,
+        attribute: String,
+    },
+    CompositeType {
+        composite_type: String,
+    },
+    CompositeTypeField {
+        composite_type: String,
+        field: String,
+    },
+    Enum {
+        #[serde(rename = "enum")]
+        enum_name: String,
+    },
+    EnumAttribute {
+        #[serde(rename = "enum")]
+        enum_name: String,
+        attribute: String,
+    },
+    EnumValue {
+        #[serde(rename = "enum")]
+        enum_name: String,
+        value: String,
+    },
+    EnumValueAttribute {
+        #[serde(rename = "enum")]
+        enum_name: String,
+        value: String,
+        attribute: String,
+    },
+}
+
+impl ChangeSubject {
+    fn describe(&self, kind: ChangeKind) -> String {
+        let (verb, preposition) = match kind {
+            ChangeKind::Added => ("Added", "to"),
+            ChangeKind::Removed => ("Removed", "from"),
+            ChangeKind::Changed => ("Changed", "of"),
+        };
+
+        match self {
+            ChangeSubject::Model { model } => format!("{} model `{}`", verb, model),
+            ChangeSubject::ModelAttribute { model, attribute } => {
+                format!("{} `{}` {} model `{}`", verb, attribute, preposition, model)
+            }
+            ChangeSubject::Field { model, field } => format!("{} field `{}.{}`", verb, model, field),
+            ChangeSubject::FieldAttribute {
+                model,
+                field,
+                attribute,
+            } => format!("{} `{}` {} field `{}.{}`", verb, attribute, preposition, model, field),
+            ChangeSubject::CompositeType { composite_type } => format!("{} composite type `{}`", verb, composite_type),
+            ChangeSubject::CompositeTypeField { composite_type, field } => {
+                format!("{} field `{}.{}`", verb, composite_type, field)
+            }
+            ChangeSubject::Enum { enum_name } => format!("{} enum `{}`", verb, enum_name),
+            ChangeSubject::EnumAttribute { enum_name, attribute } => {
+                format!("{} `{}` {} enum `{}`", verb, attribute, preposition, enum_name)
+            }
+            ChangeSubject::EnumValue { enum_name, value } => format!("{} value `{}.{}`", verb, enum_name, value),
+            ChangeSubject::EnumValueAttribute {
+                enum_name,
+                value,
+                attribute,
+            } => format!(
+                "{} `{}` {} value `{}.{}`",
+                verb, attribute, preposition, enum_name, value
+            ),
+        }
+    }
+}
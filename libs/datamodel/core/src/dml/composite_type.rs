@@ -0,0 +1,48 @@
+use super::*;
+
+/// Represents a composite type in the datamodel.
+/// Values of composite types are embedded into the fields of models and stored as Json.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CompositeType {
+    /// Name of the composite type.
+    pub name: String,
+    /// Fields of the composite type.
+    pub fields: Vec<Field>,
+    /// Comments associated with this composite type.
+    pub documentation: Option<String>,
+}
+
+impl CompositeType {
+    /// Creates a new composite type with the given name.
+    pub fn new(name: &str) -> CompositeType {
+        CompositeType {
+            name: String::from(name),
+            fields: vec![],
+            documentation: None,
+        }
+    }
+
+    /// Adds a field to this composite type.
+    pub fn add_field(&mut self, field: Field) {
+        self.fields.push(field)
+    }
+
+    /// Gets an iterator over all fields.
+    pub fn fields(&self) -> std::slice::Iter<Field> {
+        self.fields.iter()
+    }
+
+    /// Finds a field by name.
+    pub fn find_field(&self, name: &str) -> Option<&Field> {
+        self.fields().find(|f| f.name == *name)
+    }
+}
+
+impl WithName for CompositeType {
+    fn name(&self) -> &String {
+        &self.name
+    }
+    fn set_name(&mut self, name: &str) {
+        self.name = String::from(name)
+    }
+}
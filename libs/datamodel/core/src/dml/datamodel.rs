@@ -7,6 +7,8 @@ pub struct Datamodel {
     pub enums: Vec<Enum>,
     /// All models.
     pub models: Vec<Model>,
+    /// All composite types.
+    pub composite_types: Vec<CompositeType>,
 }
 
 /// Type alias for (ModelName, FieldName)
@@ -18,6 +20,7 @@ impl Datamodel {
         Datamodel {
             enums: Vec::new(),
             models: Vec::new(),
+            composite_types: Vec::new(),
         }
     }
 
@@ -46,6 +49,16 @@ impl Datamodel {
         self.enums.retain(|m| m.name != name);
     }
 
+    /// Checks if a composite type with the given name exists.
+    pub fn has_composite_type(&self, name: &str) -> bool {
+        self.find_composite_type(name).is_some()
+    }
+
+    /// Adds a composite type to this datamodel.
+    pub fn add_composite_type(&mut self, composite_type: CompositeType) {
+        self.composite_types.push(composite_type);
+    }
+
     /// Adds a model to this datamodel.
     pub fn add_model(&mut self, model: Model) {
         self.models.push(model);
@@ -66,6 +79,11 @@ impl Datamodel {
         self.enums.iter()
     }
 
+    /// Gets an iterator over all composite types.
+    pub fn composite_types(&self) -> std::slice::Iter<CompositeType> {
+        self.composite_types.iter()
+    }
+
     /// Gets a mutable iterator over all models.
     pub fn models_mut(&mut self) -> std::slice::IterMut<Model> {
         self.models.iter_mut()
@@ -105,6 +123,11 @@ impl Datamodel {
         self.enums().find(|m| m.name == *name)
     }
 
+    /// Finds a composite type by name.
+    pub fn find_composite_type(&self, name: &str) -> Option<&CompositeType> {
+        self.composite_types().find(|ct| ct.name == *name)
+    }
+
     /// Finds a model by name and returns a mutable reference.
     pub fn find_model_mut(&mut self, name: &str) -> Option<&mut Model> {
         self.models_mut().find(|m| m.name == *name)
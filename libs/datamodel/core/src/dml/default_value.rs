@@ -1,8 +1,22 @@
+use super::ScalarType;
 use chrono::Utc;
 use prisma_value::PrismaValue;
+use rand::Rng;
 use std::fmt;
 use uuid::Uuid;
 
+/// The length of ids generated by `nanoid()` without an explicit size.
+const DEFAULT_NANOID_SIZE: usize = 21;
+
+/// The longest id `nanoid(size)` generates.
+const MAX_NANOID_SIZE: i64 = 255;
+
+/// The URL-safe alphabet of `nanoid()`. It has 64 characters, so one random byte maps to a character without bias.
+const NANOID_ALPHABET: &[u8] = b"_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The Crockford base32 alphabet of `ulid()`.
+const ULID_ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
 #[derive(Clone, PartialEq)]
 pub enum DefaultValue {
     Single(PrismaValue),
@@ -30,7 +44,7 @@ pub struct ValueGenerator {
 
 impl ValueGenerator {
     pub fn new(name: String, args: Vec<PrismaValue>) -> std::result::Result<Self, String> {
-        let generator = ValueGeneratorFn::new(name.as_ref())?;
+        let generator = ValueGeneratorFn::new(name.as_ref(), &args)?;
 
         Ok(ValueGenerator { name, args, generator })
     }
@@ -59,43 +73,90 @@ impl ValueGenerator {
         self.generator.invoke()
     }
 
-    fn name(&self) -> &str {
-        &self.name
+    /// The name of the database sequence of a `sequence("name")` generator.
+    pub fn sequence_name(&self) -> Option<&str> {
+        match (self.generator, self.args.first()) {
+            (ValueGeneratorFn::Sequence, Some(PrismaValue::String(name))) => Some(name),
+            _ => None,
+        }
     }
 
-    fn args(&self) -> &[PrismaValue] {
-        &self.args
+    fn name(&self) -> &str {
+        &self.name
     }
 }
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum ValueGeneratorFn {
     UUID,
+    /// A time-ordered UUID, `uuid(7)`.
+    UUIDv7,
     CUID,
+    ULID,
+    /// A random URL-safe id of the given length.
+    Nanoid(usize),
     Now,
     Autoincrement,
     DbGenerated,
+    /// The next value of a database sequence, evaluated by the database.
+    Sequence,
 }
 
 impl ValueGeneratorFn {
-    fn new(name: &str) -> std::result::Result<Self, String> {
-        match name {
-            "cuid" => Ok(Self::CUID),
-            "uuid" => Ok(Self::UUID),
-            "now" => Ok(Self::Now),
-            "autoincrement" => Ok(Self::Autoincrement),
-            "dbgenerated" => Ok(Self::DbGenerated),
+    fn new(name: &str, args: &[PrismaValue]) -> std::result::Result<Self, String> {
+        match (name, args) {
+            ("uuid", []) | ("uuid", [PrismaValue::Int(4)]) => Ok(Self::UUID),
+            ("uuid", [PrismaValue::Int(7)]) => Ok(Self::UUIDv7),
+            ("uuid", [PrismaValue::Int(_)]) => {
+                Err("The function `uuid` only supports the versions 4 and 7.".to_owned())
+            }
+            ("nanoid", []) => Ok(Self::Nanoid(DEFAULT_NANOID_SIZE)),
+            ("nanoid", [PrismaValue::Int(size)]) if *size > 0 && *size <= MAX_NANOID_SIZE => {
+                Ok(Self::Nanoid(*size as usize))
+            }
+            ("nanoid", [PrismaValue::Int(_)]) => Err(format!(
+                "The size of `nanoid` must be between 1 and {}.",
+                MAX_NANOID_SIZE
+            )),
+            ("sequence", [PrismaValue::String(_)]) => Ok(Self::Sequence),
+            ("sequence", _) => {
+                Err("The function `sequence` expects the name of a sequence as its only argument.".to_owned())
+            }
+            ("uuid", _) | ("nanoid", _) => {
+                Err(format!("The function `{}` expects at most one numeric argument.", name))
+            }
+            ("cuid", []) => Ok(Self::CUID),
+            ("ulid", []) => Ok(Self::ULID),
+            ("now", []) => Ok(Self::Now),
+            ("autoincrement", []) => Ok(Self::Autoincrement),
+            ("dbgenerated", []) => Ok(Self::DbGenerated),
+            ("cuid", _) | ("ulid", _) | ("now", _) | ("autoincrement", _) | ("dbgenerated", _) => {
+                Err(format!("The function `{}` does not take any arguments.", name))
+            }
             _ => Err(format!("The function {} is not a known function.", name)),
         }
     }
 
+    /// Whether the generated values fit fields of the given type.
+    pub fn supports_scalar_type(&self, scalar_type: ScalarType) -> bool {
+        match self {
+            Self::UUIDv7 | Self::ULID | Self::Nanoid(_) => scalar_type == ScalarType::String,
+            Self::Sequence => scalar_type == ScalarType::Int || scalar_type == ScalarType::BigInt,
+            _ => true,
+        }
+    }
+
     pub fn invoke(&self) -> Option<PrismaValue> {
         match self {
             Self::UUID => Self::generate_uuid(),
+            Self::UUIDv7 => Self::generate_uuid_v7(),
             Self::CUID => Self::generate_cuid(),
+            Self::ULID => Self::generate_ulid(),
+            Self::Nanoid(size) => Self::generate_nanoid(*size),
             Self::Now => Self::generate_now(),
             Self::Autoincrement => None,
             Self::DbGenerated => None,
+            Self::Sequence => None,
         }
     }
 
@@ -107,14 +168,53 @@ impl ValueGeneratorFn {
         Some(PrismaValue::Uuid(Uuid::new_v4()))
     }
 
+    /// The first 48 bits are the unix timestamp in milliseconds, the rest is random except for the version and
+    /// variant bits.
+    fn generate_uuid_v7() -> Option<PrismaValue> {
+        let mut bytes = [0u8; 16];
+        rand::thread_rng().fill(&mut bytes);
+
+        let timestamp = Utc::now().timestamp_millis() as u64;
+        bytes[..6].copy_from_slice(&timestamp.to_be_bytes()[2..]);
+        bytes[6] = 0x70 | (bytes[6] & 0x0F);
+        bytes[8] = 0x80 | (bytes[8] & 0x3F);
+
+        Some(PrismaValue::Uuid(Uuid::from_bytes(bytes)))
+    }
+
+    /// A 48 bit unix timestamp in milliseconds followed by 80 random bits, encoded as 26 base32 characters.
+    fn generate_ulid() -> Option<PrismaValue> {
+        let timestamp = Utc::now().timestamp_millis() as u128 & ((1 << 48) - 1);
+        let randomness = rand::thread_rng().gen::<u128>() & ((1 << 80) - 1);
+        let value = (timestamp << 80) | randomness;
+
+        let ulid = (0..26)
+            .rev()
+            .map(|idx| ULID_ALPHABET[((value >> (idx * 5)) & 0x1F) as usize] as char)
+            .collect();
+
+        Some(PrismaValue::String(ulid))
+    }
+
+    fn generate_nanoid(size: usize) -> Option<PrismaValue> {
+        let mut rng = rand::thread_rng();
+
+        let nanoid = (0..size)
+            .map(|_| NANOID_ALPHABET[(rng.gen::<u8>() & 0x3F) as usize] as char)
+            .collect();
+
+        Some(PrismaValue::String(nanoid))
+    }
+
     fn generate_now() -> Option<PrismaValue> {
         Some(PrismaValue::DateTime(Utc::now()))
     }
 }
 
+/// Generators are equal if they generate the same kind of values, e.g. `uuid()` and `uuid(4)`.
 impl PartialEq for ValueGenerator {
     fn eq(&self, other: &Self) -> bool {
-        self.name() == other.name() && self.args() == other.args()
+        self.generator == other.generator && self.sequence_name() == other.sequence_name()
     }
 }
 
@@ -1,4 +1,5 @@
 mod comment;
+mod composite_type;
 mod datamodel;
 mod default_value;
 mod r#enum;
@@ -9,6 +10,7 @@ mod relation_info;
 mod traits;
 
 pub use self::datamodel::*;
+pub use composite_type::*;
 pub use default_value::*;
 pub use field::*;
 pub use functions::*;
@@ -18,4 +20,4 @@ pub use relation_info::*;
 pub use traits::*;
 
 // Compatibility exports.
-pub use datamodel_connector::scalars::ScalarType;
+pub use datamodel_connector::{scalars::ScalarType, ScalarFieldType};
@@ -21,6 +21,14 @@ pub struct Model {
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
     pub is_commented_out: bool,
+    /// Indicates if this model is backed by a database view and therefore read-only.
+    pub is_view: bool,
+    /// Indicates if this model is excluded from the client and left alone by migrations.
+    pub is_ignored: bool,
+    /// Describes Check Constraints
+    pub check_constraints: Vec<CheckConstraint>,
+    /// The database schema this model lives in. Falls back to the schema of the datasource if not set.
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -39,6 +47,12 @@ impl IndexDefinition {
     }
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckConstraint {
+    pub name: Option<String>,
+    pub expression: String,
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum IndexType {
     Unique,
@@ -69,6 +83,10 @@ impl Model {
             is_embedded: false,
             is_generated: false,
             is_commented_out: false,
+            is_view: false,
+            is_ignored: false,
+            check_constraints: vec![],
+            schema: None,
         }
     }
 
@@ -265,7 +283,7 @@ impl Model {
                 .find_field(name)
                 .or_else(|| model.find_field(name.to_lowercase().as_ref()))
             {
-                Some(f) => f.field_type == FieldType::Base(ScalarType::DateTime, None),
+                Some(f) => f.field_type.scalar_type() == Some(ScalarType::DateTime),
                 None => false,
             }
         }
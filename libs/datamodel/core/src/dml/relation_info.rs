@@ -11,9 +11,12 @@ pub struct RelationInfo {
     pub to_fields: Vec<String>,
     /// The name of the relation. Internally, an empty string signals no name.
     pub name: String,
-    /// A strategy indicating what happens when
-    /// a related node is deleted.
-    pub on_delete: OnDeleteStrategy,
+    /// What happens to this record when the
+    /// referenced record is deleted.
+    pub on_delete: Option<ReferentialAction>,
+    /// What happens to this record when the
+    /// referenced fields are updated.
+    pub on_update: Option<ReferentialAction>,
 }
 
 impl RelationInfo {
@@ -25,38 +28,54 @@ impl RelationInfo {
             fields: Vec::new(),
             to_fields: Vec::new(),
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: None,
+            on_update: None,
         }
     }
 }
 
-/// Describes what happens when related nodes
-/// are deleted.
+/// Describes what happens to referencing records when
+/// the referenced record is deleted or updated.
 #[derive(Debug, Copy, PartialEq, Clone)]
-pub enum OnDeleteStrategy {
+pub enum ReferentialAction {
+    /// Deletes or updates the referencing records as well.
     Cascade,
-    None,
+    /// Fails the operation while referencing records exist.
+    Restrict,
+    /// Like `Restrict`, but the check may be deferred
+    /// to the end of the transaction.
+    NoAction,
+    /// Sets the referencing fields to null.
+    SetNull,
+    /// Sets the referencing fields to their default values.
+    SetDefault,
 }
 
-impl Parsable for OnDeleteStrategy {
+impl Parsable for ReferentialAction {
     fn parse(s: &str) -> Option<Self> {
         match s {
-            "CASCADE" => Some(OnDeleteStrategy::Cascade),
-            "NONE" => Some(OnDeleteStrategy::None),
+            "Cascade" => Some(ReferentialAction::Cascade),
+            "Restrict" => Some(ReferentialAction::Restrict),
+            "NoAction" => Some(ReferentialAction::NoAction),
+            "SetNull" => Some(ReferentialAction::SetNull),
+            "SetDefault" => Some(ReferentialAction::SetDefault),
             _ => None,
         }
     }
 
     fn descriptor() -> &'static str {
-        "onDelete strategy"
+        "referential action"
     }
 }
 
-impl ToString for OnDeleteStrategy {
+impl ToString for ReferentialAction {
     fn to_string(&self) -> String {
         match self {
-            OnDeleteStrategy::Cascade => String::from("CASCADE"),
-            OnDeleteStrategy::None => String::from("NONE"),
+            ReferentialAction::Cascade => String::from("Cascade"),
+            ReferentialAction::Restrict => String::from("Restrict"),
+            ReferentialAction::NoAction => String::from("NoAction"),
+            ReferentialAction::SetNull => String::from("SetNull"),
+            ReferentialAction::SetDefault => String::from("SetDefault"),
         }
     }
 }
@@ -1,4 +1,5 @@
 use super::DatamodelError;
+use crate::schema_files::SchemaFiles;
 
 /// Represents a list of validation or parser errors.
 ///
@@ -63,6 +64,18 @@ impl ErrorCollection {
 
         String::from_utf8_lossy(&message).into_owned()
     }
+
+    /// Like `to_pretty_string`, for a schema split across multiple files.
+    pub fn to_pretty_string_files(&self, files: &SchemaFiles) -> String {
+        let mut message: Vec<u8> = Vec::new();
+
+        for err in self.to_iter() {
+            err.pretty_print_files(&mut message, files)
+                .expect("printing datamodel error");
+        }
+
+        String::from_utf8_lossy(&message).into_owned()
+    }
 }
 
 impl std::fmt::Display for ErrorCollection {
@@ -1,7 +1,10 @@
 mod collection;
+mod warning;
 pub use collection::*;
+pub use warning::*;
 
 use crate::ast::Span;
+use crate::schema_files::SchemaFiles;
 use colored::Colorize;
 
 // No format for this file, on purpose.
@@ -107,6 +110,9 @@ pub enum DatamodelError {
     #[fail(display = "Error validating datasource `{}`: {}", source, message)]
     SourceValidationError { message: String, source: String, span: Span },
 
+    #[fail(display = "Error validating generator `{}`: {}", generator_name, message)]
+    GeneratorValidationError { message: String, generator_name: String, span: Span },
+
     #[fail(display = "Error validating enum `{}`: {}", enum_name, message)]
     EnumValidationError { message: String, enum_name: String, span: Span },
 
@@ -279,6 +285,14 @@ impl DatamodelError {
         }
     }
 
+    pub fn new_generator_validation_error(message: &str, generator_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::GeneratorValidationError {
+            message: message.to_owned(),
+            generator_name: generator_name.to_owned(),
+            span,
+        }
+    }
+
     pub fn new_validation_error(message: &str, span: Span) -> DatamodelError {
         DatamodelError::ValidationError {
             message: String::from(message),
@@ -371,6 +385,7 @@ impl DatamodelError {
             DatamodelError::ScalarListFieldsAreNotSupported {span, ..} => *span,
             DatamodelError::FieldValidationError {span , ..} => *span,
             DatamodelError::SourceValidationError {span, ..} => *span,
+            DatamodelError::GeneratorValidationError {span, ..} => *span,
             DatamodelError::EnumValidationError {span, ..} => *span,
         }
     }
@@ -379,17 +394,56 @@ impl DatamodelError {
     }
 
     pub fn pretty_print(&self, f: &mut dyn std::io::Write, file_name: &str, text: &str) -> std::io::Result<()> {
-        pretty_print_error(f, file_name, text, self)
+        pretty_print(f, file_name, text, self.span(), &self.description(), Severity::Error)
     }
+
+    /// Pretty prints the error for a schema split across multiple files, showing the file it occurred in.
+    pub fn pretty_print_files(&self, f: &mut dyn std::io::Write, files: &SchemaFiles) -> std::io::Result<()> {
+        pretty_print_files(f, files, self.span(), &self.description(), Severity::Error)
+    }
+}
+
+/// The severity of a pretty printed error or warning.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Severity {
+    Error,
+    Warning,
 }
 
-/// Given the datamodel text representation, pretty prints an error, including
+impl Severity {
+    fn colorize(self, text: &str) -> colored::ColoredString {
+        match self {
+            Severity::Error => text.bright_red(),
+            Severity::Warning => text.bright_yellow(),
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// Pretty prints an error or warning of a schema split across multiple files, using the file the span points into.
+fn pretty_print_files(
+    f: &mut dyn std::io::Write,
+    files: &SchemaFiles,
+    span: Span,
+    description: &str,
+    severity: Severity,
+) -> std::io::Result<()> {
+    match files.locate(span) {
+        Some((file, local_span)) => pretty_print(f, &file.name, &file.contents, local_span, description, severity),
+        None => pretty_print(f, "schema.prisma", files.source(), span, description, severity),
+    }
+}
+
+/// Given the datamodel text representation, pretty prints an error or warning, including
 /// the offending portion of the source code, for human-friendly reading.
 #[rustfmt::skip]
-fn pretty_print_error(f: &mut dyn std::io::Write, file_name: &str, text: &str, error_obj: &DatamodelError) -> std::io::Result<()> {
-    let span = error_obj.span();
-    let error = error_obj.description();
-
+fn pretty_print(f: &mut dyn std::io::Write, file_name: &str, text: &str, span: Span, description: &str, severity: Severity) -> std::io::Result<()> {
     let start_line_number = text[..span.start].matches("\n").count();
     let end_line_number = text[..span.end].matches("\n").count();
     let file_lines = text.split("\n").collect::<Vec<&str>>();
@@ -404,13 +458,13 @@ fn pretty_print_error(f: &mut dyn std::io::Write, file_name: &str, text: &str, e
     let end_in_line = std::cmp::min(start_in_line + (span.end - span.start), line.len());
 
     let prefix = &line[..start_in_line];
-    let offending = &line[start_in_line..end_in_line].bright_red().bold();
+    let offending = &severity.colorize(&line[start_in_line..end_in_line]).bold();
     let suffix = &line[end_in_line..];
 
     let arrow = "-->".bright_blue().bold();
     let file_path = format!("{}:{}", file_name, start_line_number + 1).underline();
 
-    writeln!(f, "{}: {}", "error".bright_red().bold(), error.bold())?;
+    writeln!(f, "{}: {}", severity.colorize(severity.label()).bold(), description.bold())?;
     writeln!(f, "  {}  {}", arrow, file_path)?;
     writeln!(f, "{}", format_line_number(0))?;
     
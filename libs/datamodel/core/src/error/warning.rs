@@ -0,0 +1,85 @@
+use super::{pretty_print, pretty_print_files, Severity};
+use crate::ast::Span;
+use crate::schema_files::SchemaFiles;
+
+// No format for this file, on purpose.
+// Line breaks make the declarations very hard to read.
+#[rustfmt::skip]
+/// Enum for questionable, but valid parts of a datamodel.
+///
+/// Warnings are collected during validation, but never make it fail.
+#[derive(Debug, Fail, Clone, PartialEq)]
+pub enum DatamodelWarning {
+    #[fail(display = "The relation field `{}` on model `{}` uses the fields [{}], which are not covered by an index. Consider adding an `@@index` on them.", field_name, model_name, fields)]
+    MissingRelationIndex { model_name: String, field_name: String, fields: String, span: Span },
+
+    #[fail(display = "The field `{}` on model `{}` looks like it holds an amount of money, but uses the imprecise type `Float`. Consider using `Decimal` instead.", field_name, model_name)]
+    FloatForMoney { model_name: String, field_name: String, span: Span },
+
+    #[fail(display = "The field `{}` on model `{}` defaults to `now()`, but its type `{}` is not based on `DateTime`.", field_name, model_name, type_name)]
+    NowDefaultOnNonDateTime { model_name: String, field_name: String, type_name: String, span: Span },
+
+    #[fail(display = "The enum `{}` is not used by any field.", enum_name)]
+    UnusedEnum { enum_name: String, span: Span },
+}
+
+#[rustfmt::skip]
+impl DatamodelWarning {
+    pub fn new_missing_relation_index_warning(model_name: &str, field_name: &str, fields: &[String], span: Span) -> DatamodelWarning {
+        DatamodelWarning::MissingRelationIndex {
+            model_name: String::from(model_name),
+            field_name: String::from(field_name),
+            fields: fields.join(", "),
+            span,
+        }
+    }
+
+    pub fn new_float_for_money_warning(model_name: &str, field_name: &str, span: Span) -> DatamodelWarning {
+        DatamodelWarning::FloatForMoney { model_name: String::from(model_name), field_name: String::from(field_name), span }
+    }
+
+    pub fn new_now_default_on_non_date_time_warning(model_name: &str, field_name: &str, type_name: &str, span: Span) -> DatamodelWarning {
+        DatamodelWarning::NowDefaultOnNonDateTime {
+            model_name: String::from(model_name),
+            field_name: String::from(field_name),
+            type_name: String::from(type_name),
+            span,
+        }
+    }
+
+    pub fn new_unused_enum_warning(enum_name: &str, span: Span) -> DatamodelWarning {
+        DatamodelWarning::UnusedEnum { enum_name: String::from(enum_name), span }
+    }
+
+    /// A stable identifier of the kind of warning, e.g. for filtering in tools.
+    pub fn code(&self) -> &'static str {
+        match self {
+            DatamodelWarning::MissingRelationIndex { .. } => "missing-relation-index",
+            DatamodelWarning::FloatForMoney { .. } => "float-for-money",
+            DatamodelWarning::NowDefaultOnNonDateTime { .. } => "now-default-on-non-date-time",
+            DatamodelWarning::UnusedEnum { .. } => "unused-enum",
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            DatamodelWarning::MissingRelationIndex { span, .. } => *span,
+            DatamodelWarning::FloatForMoney { span, .. } => *span,
+            DatamodelWarning::NowDefaultOnNonDateTime { span, .. } => *span,
+            DatamodelWarning::UnusedEnum { span, .. } => *span,
+        }
+    }
+
+    pub fn description(&self) -> String {
+        format!("{}", self)
+    }
+
+    pub fn pretty_print(&self, f: &mut dyn std::io::Write, file_name: &str, text: &str) -> std::io::Result<()> {
+        pretty_print(f, file_name, text, self.span(), &self.description(), Severity::Warning)
+    }
+
+    /// Pretty prints the warning for a schema split across multiple files, showing the file it occurred in.
+    pub fn pretty_print_files(&self, f: &mut dyn std::io::Write, files: &SchemaFiles) -> std::io::Result<()> {
+        pretty_print_files(f, files, self.span(), &self.description(), Severity::Warning)
+    }
+}
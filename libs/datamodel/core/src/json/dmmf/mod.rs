@@ -6,6 +6,8 @@ use serde_json;
 pub use to_dmmf::render_to_dmmf;
 pub use to_dmmf::render_to_dmmf_value;
 
+pub(crate) use to_dmmf::prisma_value_to_serde;
+
 // This is a simple JSON serialization using Serde.
 // The JSON format follows the DMMF spec.
 #[serde(rename_all = "camelCase")]
@@ -32,6 +34,8 @@ pub struct Field {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relation_on_delete: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation_on_update: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_generated: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_updated_at: Option<bool>,
@@ -56,6 +60,8 @@ pub struct Model {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_generated: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_view: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
     pub id_fields: Vec<String>,
     pub unique_fields: Vec<Vec<String>>,
@@ -86,8 +92,19 @@ pub struct EnumValue {
     pub db_name: Option<String>,
 }
 
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct CompositeType {
+    pub name: String,
+    pub fields: Vec<Field>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct Datamodel {
     pub enums: Vec<Enum>,
     pub models: Vec<Model>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<CompositeType>,
 }
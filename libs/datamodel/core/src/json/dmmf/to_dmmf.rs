@@ -19,16 +19,22 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
     let mut datamodel = Datamodel {
         models: vec![],
         enums: vec![],
+        types: vec![],
     };
 
     for enum_model in schema.enums() {
         datamodel.enums.push(enum_to_dmmf(&enum_model));
     }
 
-    for model in schema.models() {
+    // Ignored models are not exposed to the client.
+    for model in schema.models().filter(|model| !model.is_ignored) {
         datamodel.models.push(model_to_dmmf(&model));
     }
 
+    for composite_type in schema.composite_types() {
+        datamodel.types.push(composite_type_to_dmmf(&composite_type));
+    }
+
     datamodel
 }
 
@@ -54,13 +60,29 @@ fn enum_value_to_dmmf(en: &dml::EnumValue) -> EnumValue {
     }
 }
 
+fn composite_type_to_dmmf(composite_type: &dml::CompositeType) -> CompositeType {
+    CompositeType {
+        name: composite_type.name.clone(),
+        fields: composite_type
+            .fields()
+            .map(|f| field_to_dmmf(&composite_type.fields, f))
+            .collect(),
+        documentation: composite_type.documentation.clone(),
+    }
+}
+
 fn model_to_dmmf(model: &dml::Model) -> Model {
     Model {
         name: model.name.clone(),
         db_name: model.database_name.clone(),
         is_embedded: model.is_embedded,
-        fields: model.fields().map(|f| field_to_dmmf(model, f)).collect(),
+        fields: model
+            .fields()
+            .filter(|f| !f.is_ignored)
+            .map(|f| field_to_dmmf(&model.fields, f))
+            .collect(),
         is_generated: Some(model.is_generated),
+        is_view: Some(model.is_view).filter(|is_view| *is_view),
         documentation: model.documentation.clone(),
         id_fields: model.id_fields.clone(),
         unique_fields: model
@@ -91,8 +113,8 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
     }
 }
 
-fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
-    let a_relation_field_is_based_on_this_field: bool = model.fields.iter().any(|f| match &f.field_type {
+fn field_to_dmmf(fields: &[dml::Field], field: &dml::Field) -> Field {
+    let a_relation_field_is_based_on_this_field: bool = fields.iter().any(|f| match &f.field_type {
         dml::FieldType::Relation(rel_info) => rel_info.fields.contains(&field.name),
         _ => false,
     });
@@ -110,6 +132,7 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         relation_from_fields: get_relation_from_fields(field),
         relation_to_fields: get_relation_to_fields(field),
         relation_on_delete: get_relation_delete_strategy(field),
+        relation_on_update: get_relation_update_strategy(field),
         field_type: get_field_type(field),
         is_generated: Some(field.is_generated),
         is_updated_at: Some(field.is_updated_at),
@@ -119,9 +142,9 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
 
 fn get_field_kind(field: &dml::Field) -> String {
     match field.field_type {
-        dml::FieldType::Relation(_) => String::from("object"),
+        dml::FieldType::Relation(_) | dml::FieldType::CompositeType(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
-        dml::FieldType::Base(_, _) => String::from("scalar"),
+        dml::FieldType::Base(_, _) | dml::FieldType::ConnectorSpecific(_) => String::from("scalar"),
         _ => unimplemented!("DMMF does not support field type {:?}", field.field_type),
     }
 }
@@ -133,7 +156,7 @@ fn default_value_to_serde(dv_opt: &Option<dml::DefaultValue>) -> Option<serde_js
     })
 }
 
-fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
+pub(crate) fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
     match value {
         PrismaValue::Boolean(val) => serde_json::Value::Bool(*val),
         PrismaValue::String(val) => serde_json::Value::String(val.clone()),
@@ -146,6 +169,9 @@ fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
         PrismaValue::Null => serde_json::Value::Null,
         PrismaValue::Uuid(val) => serde_json::Value::String(val.to_string()),
         PrismaValue::Json(val) => serde_json::Value::String(val.to_string()),
+        PrismaValue::Decimal(val) => serde_json::Value::String(val.to_string()),
+        PrismaValue::BigInt(val) => serde_json::Value::String(val.to_string()),
+        PrismaValue::Bytes(val) => serde_json::Value::String(prisma_value::encode_bytes(val)),
         PrismaValue::List(value_vec) => {
             serde_json::Value::Array(value_vec.iter().map(|pv| prisma_value_to_serde(pv)).collect())
         }
@@ -164,6 +190,7 @@ fn function_to_serde(name: &str, args: &Vec<PrismaValue>) -> serde_json::Value {
 fn get_field_type(field: &dml::Field) -> String {
     match &field.field_type {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
+        dml::FieldType::CompositeType(t) => t.clone(),
         dml::FieldType::Enum(t) => t.clone(),
         dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::Base(t, _) => type_to_string(t),
@@ -196,9 +223,16 @@ fn get_relation_to_fields(field: &dml::Field) -> Option<Vec<String>> {
     }
 }
 
+fn get_relation_update_strategy(field: &dml::Field) -> Option<String> {
+    match &field.field_type {
+        dml::FieldType::Relation(relation_info) => relation_info.on_update.map(|action| action.to_string()),
+        _ => None,
+    }
+}
+
 fn get_relation_delete_strategy(field: &dml::Field) -> Option<String> {
     match &field.field_type {
-        dml::FieldType::Relation(relation_info) => Some(relation_info.on_delete.to_string()),
+        dml::FieldType::Relation(relation_info) => relation_info.on_delete.map(|action| action.to_string()),
         _ => None,
     }
 }
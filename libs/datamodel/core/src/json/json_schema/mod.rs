@@ -0,0 +1,83 @@
+mod to_json_schema;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+pub use to_json_schema::render_to_json_schema;
+pub use to_json_schema::render_to_json_schema_value;
+pub use to_json_schema::render_to_openapi_components;
+pub use to_json_schema::render_to_openapi_components_value;
+
+/// The JSON Schema version of exported documents.
+pub const JSON_SCHEMA_VERSION: &str = "http://json-schema.org/draft-07/schema#";
+
+// A subset of JSON Schema, with the OpenAPI `nullable` extension.
+// There is one definition per model, enum and composite type, relations are `$ref`s to other definitions.
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Default, serde::Serialize)]
+pub struct Schema {
+    #[serde(rename = "$ref", skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub schema_type: Option<SchemaType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nullable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub enum_values: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<Schema>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub any_of: Option<Vec<Schema>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_of: Option<Vec<Schema>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Definitions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+}
+
+/// A single type, or a type that may also be `null`.
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum SchemaType {
+    Single(&'static str),
+    Nullable([&'static str; 2]),
+}
+
+/// Named schemas, serialized as an object in declaration order.
+#[derive(Debug, Default)]
+pub struct Definitions(pub Vec<(String, Schema)>);
+
+impl Serialize for Definitions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+
+        for (name, schema) in &self.0 {
+            map.serialize_entry(name, schema)?;
+        }
+
+        map.end()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct JsonSchemaDocument {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    pub definitions: Definitions,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct OpenApiComponents {
+    pub components: Components,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct Components {
+    pub schemas: Definitions,
+}
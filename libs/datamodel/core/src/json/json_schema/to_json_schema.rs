@@ -0,0 +1,231 @@
+use super::*;
+use crate::common::ScalarType;
+use crate::json::dmmf::prisma_value_to_serde;
+use crate::{dml, ValueGeneratorFn};
+
+/// The two flavours of exported schemas differ in how they refer to definitions and express nullability.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Dialect {
+    /// `type: ["string", "null"]`, references into `#/definitions`.
+    JsonSchema,
+    /// `nullable: true`, references into `#/components/schemas`.
+    OpenApi,
+}
+
+impl Dialect {
+    fn reference(self, name: &str) -> String {
+        match self {
+            Dialect::JsonSchema => format!("#/definitions/{}", name),
+            Dialect::OpenApi => format!("#/components/schemas/{}", name),
+        }
+    }
+}
+
+pub fn render_to_json_schema(datamodel: &dml::Datamodel) -> String {
+    serde_json::to_string_pretty(&json_schema_document(datamodel)).expect("Failed to render JSON")
+}
+
+pub fn render_to_json_schema_value(datamodel: &dml::Datamodel) -> serde_json::Value {
+    serde_json::to_value(&json_schema_document(datamodel)).expect("Failed to render JSON")
+}
+
+pub fn render_to_openapi_components(datamodel: &dml::Datamodel) -> String {
+    serde_json::to_string_pretty(&openapi_components(datamodel)).expect("Failed to render JSON")
+}
+
+pub fn render_to_openapi_components_value(datamodel: &dml::Datamodel) -> serde_json::Value {
+    serde_json::to_value(&openapi_components(datamodel)).expect("Failed to render JSON")
+}
+
+fn json_schema_document(datamodel: &dml::Datamodel) -> JsonSchemaDocument {
+    JsonSchemaDocument {
+        schema: JSON_SCHEMA_VERSION,
+        definitions: definitions(datamodel, Dialect::JsonSchema),
+    }
+}
+
+fn openapi_components(datamodel: &dml::Datamodel) -> OpenApiComponents {
+    OpenApiComponents {
+        components: Components {
+            schemas: definitions(datamodel, Dialect::OpenApi),
+        },
+    }
+}
+
+fn definitions(datamodel: &dml::Datamodel, dialect: Dialect) -> Definitions {
+    let mut definitions = Vec::new();
+
+    // Ignored models are not exposed to the client, like in the DMMF.
+    for model in datamodel.models().filter(|model| !model.is_ignored) {
+        let schema = object_schema(datamodel, &model.fields, model.documentation.clone(), dialect);
+        definitions.push((model.name.clone(), schema));
+    }
+
+    for composite_type in datamodel.composite_types() {
+        let schema = object_schema(
+            datamodel,
+            &composite_type.fields,
+            composite_type.documentation.clone(),
+            dialect,
+        );
+        definitions.push((composite_type.name.clone(), schema));
+    }
+
+    for enm in datamodel.enums() {
+        definitions.push((enm.name.clone(), enum_schema(enm)));
+    }
+
+    Definitions(definitions)
+}
+
+fn enum_schema(enm: &dml::Enum) -> Schema {
+    Schema {
+        schema_type: Some(SchemaType::Single("string")),
+        enum_values: Some(enm.values.iter().map(|value| value.name.clone()).collect()),
+        description: enm.documentation.clone(),
+        ..Default::default()
+    }
+}
+
+fn object_schema(
+    datamodel: &dml::Datamodel,
+    fields: &[dml::Field],
+    documentation: Option<String>,
+    dialect: Dialect,
+) -> Schema {
+    let mut properties = Vec::new();
+    let mut required = Vec::new();
+
+    for field in fields.iter().filter(|field| is_exported(datamodel, field)) {
+        // Relations are only present when they are included, so they are never required.
+        if field.arity != dml::FieldArity::Optional && !field.field_type.is_relation() {
+            required.push(field.name.clone());
+        }
+
+        properties.push((field.name.clone(), field_schema(field, dialect)));
+    }
+
+    Schema {
+        schema_type: Some(SchemaType::Single("object")),
+        description: documentation,
+        properties: Some(Definitions(properties)),
+        required: Some(required).filter(|required| !required.is_empty()),
+        ..Default::default()
+    }
+}
+
+/// Ignored fields, fields of unsupported types and relations to ignored models are not exposed to the client.
+fn is_exported(datamodel: &dml::Datamodel, field: &dml::Field) -> bool {
+    if field.is_ignored || field.is_commented_out {
+        return false;
+    }
+
+    match &field.field_type {
+        dml::FieldType::Unsupported(_) => false,
+        dml::FieldType::Relation(relation_info) => datamodel
+            .find_model(&relation_info.to)
+            .map(|model| !model.is_ignored)
+            .unwrap_or(false),
+        _ => true,
+    }
+}
+
+fn field_schema(field: &dml::Field, dialect: Dialect) -> Schema {
+    let value_schema = match &field.field_type {
+        dml::FieldType::Enum(name) | dml::FieldType::CompositeType(name) => Schema {
+            reference: Some(dialect.reference(name)),
+            ..Default::default()
+        },
+        dml::FieldType::Relation(relation_info) => Schema {
+            reference: Some(dialect.reference(&relation_info.to)),
+            ..Default::default()
+        },
+        dml::FieldType::Base(scalar_type, _) => scalar_schema(*scalar_type, field),
+        dml::FieldType::ConnectorSpecific(scalar_field_type) => scalar_schema(scalar_field_type.prisma_type(), field),
+        dml::FieldType::Unsupported(_) => Schema::default(),
+    };
+
+    let mut schema = match field.arity {
+        dml::FieldArity::Required => value_schema,
+        dml::FieldArity::Optional => nullable(value_schema, dialect),
+        dml::FieldArity::List => Schema {
+            schema_type: Some(SchemaType::Single("array")),
+            items: Some(Box::new(value_schema)),
+            ..Default::default()
+        },
+    };
+
+    schema.description = field.documentation.clone();
+    schema.default = match &field.default_value {
+        Some(dml::DefaultValue::Single(value)) => Some(prisma_value_to_serde(value)),
+        _ => None,
+    };
+
+    schema
+}
+
+fn scalar_schema(scalar_type: ScalarType, field: &dml::Field) -> Schema {
+    let (schema_type, format) = match scalar_type {
+        ScalarType::Int => (Some("integer"), Some("int32")),
+        ScalarType::BigInt => (Some("integer"), Some("int64")),
+        ScalarType::Float => (Some("number"), Some("double")),
+        ScalarType::Decimal => (Some("number"), None),
+        ScalarType::Boolean => (Some("boolean"), None),
+        ScalarType::String if has_uuid_default(field) => (Some("string"), Some("uuid")),
+        ScalarType::String => (Some("string"), None),
+        ScalarType::DateTime => (Some("string"), Some("date-time")),
+        ScalarType::Bytes => (Some("string"), Some("byte")),
+        // Json values can be of any type.
+        ScalarType::Json => (None, None),
+    };
+
+    Schema {
+        schema_type: schema_type.map(SchemaType::Single),
+        format: format.map(String::from),
+        ..Default::default()
+    }
+}
+
+fn has_uuid_default(field: &dml::Field) -> bool {
+    match &field.default_value {
+        Some(dml::DefaultValue::Expression(generator)) => {
+            generator.generator == ValueGeneratorFn::UUID || generator.generator == ValueGeneratorFn::UUIDv7
+        }
+        _ => false,
+    }
+}
+
+fn nullable(mut schema: Schema, dialect: Dialect) -> Schema {
+    // Keywords next to `$ref` are ignored, so references are wrapped.
+    if schema.reference.is_some() {
+        return match dialect {
+            Dialect::JsonSchema => Schema {
+                any_of: Some(vec![
+                    schema,
+                    Schema {
+                        schema_type: Some(SchemaType::Single("null")),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            },
+            Dialect::OpenApi => Schema {
+                all_of: Some(vec![schema]),
+                nullable: Some(true),
+                ..Default::default()
+            },
+        };
+    }
+
+    match dialect {
+        // A schema without a type already allows `null`.
+        Dialect::JsonSchema => {
+            if let Some(SchemaType::Single(schema_type)) = schema.schema_type {
+                schema.schema_type = Some(SchemaType::Nullable([schema_type, "null"]));
+            }
+        }
+        Dialect::OpenApi => schema.nullable = Some(true),
+    }
+
+    schema
+}
@@ -52,24 +52,32 @@ extern crate failure; // Failure enum display derivation
 pub mod ast;
 pub mod common;
 pub mod configuration;
+pub mod diagram;
+pub mod diff;
 pub mod dml;
 pub mod error;
 pub mod json;
+pub mod schema_files;
 pub mod validator;
 
 pub use configuration::*;
 pub use dml::*;
+pub use schema_files::{SchemaFile, SchemaFiles};
+pub use validator::{Validated, ValidatedDatamodel};
 
 use crate::ast::SchemaAst;
 use std::io::Write;
 use validator::ValidationPipeline;
 
 /// Parses and validates a datamodel string, using core attributes only.
-pub fn parse_datamodel(datamodel_string: &str) -> Result<Datamodel, error::ErrorCollection> {
+/// The datamodel is returned together with the warnings found during validation.
+pub fn parse_datamodel(datamodel_string: &str) -> Result<ValidatedDatamodel, error::ErrorCollection> {
     parse_datamodel_internal(datamodel_string, false)
 }
 
-pub fn parse_datamodel_and_ignore_env_errors(datamodel_string: &str) -> Result<Datamodel, error::ErrorCollection> {
+pub fn parse_datamodel_and_ignore_env_errors(
+    datamodel_string: &str,
+) -> Result<ValidatedDatamodel, error::ErrorCollection> {
     parse_datamodel_internal(datamodel_string, true)
 }
 
@@ -77,7 +85,7 @@ pub fn parse_datamodel_and_ignore_env_errors(datamodel_string: &str) -> Result<D
 /// In case of an error, a pretty, colorful string is returned.
 pub fn parse_datamodel_or_pretty_error(datamodel_string: &str, file_name: &str) -> Result<Datamodel, String> {
     match parse_datamodel_internal(datamodel_string, false) {
-        Ok(dml) => Ok(dml),
+        Ok(validated) => Ok(validated.subject),
         Err(errs) => {
             let mut buffer = std::io::Cursor::new(Vec::<u8>::new());
 
@@ -93,10 +101,16 @@ pub fn parse_datamodel_or_pretty_error(datamodel_string: &str, file_name: &str)
     }
 }
 
+/// Parses and validates a schema split across multiple files.
+/// All spans, e.g. of errors and warnings, point into the merged source of the files.
+pub fn parse_datamodel_files(files: &SchemaFiles) -> Result<ValidatedDatamodel, error::ErrorCollection> {
+    parse_datamodel_internal(files.source(), false)
+}
+
 fn parse_datamodel_internal(
     datamodel_string: &str,
     ignore_env_var_errors: bool,
-) -> Result<Datamodel, error::ErrorCollection> {
+) -> Result<ValidatedDatamodel, error::ErrorCollection> {
     let ast = ast::parser::parse(datamodel_string)?;
     let sources = load_sources(&ast, ignore_env_var_errors)?;
     let validator = ValidationPipeline::with_sources(&sources);
@@ -107,12 +121,21 @@ fn parse_datamodel_internal(
 /// Validates a [Schema AST](/ast/struct.SchemaAst.html) and returns its
 /// [Datamodel](/struct.Datamodel.html).
 pub fn lift_ast(ast: &ast::SchemaAst) -> Result<Datamodel, error::ErrorCollection> {
+    lift_ast_internal(ast, false)
+}
+
+/// Like `lift_ast`, ignoring errors about environment variables that are not set.
+pub fn lift_ast_and_ignore_env_errors(ast: &ast::SchemaAst) -> Result<Datamodel, error::ErrorCollection> {
+    lift_ast_internal(ast, true)
+}
+
+fn lift_ast_internal(ast: &ast::SchemaAst, ignore_env_var_errors: bool) -> Result<Datamodel, error::ErrorCollection> {
     let mut errors = error::ErrorCollection::new();
-    let sources = load_sources(ast, false)?;
+    let sources = load_sources(ast, ignore_env_var_errors)?;
     let validator = ValidationPipeline::with_sources(&sources);
 
     match validator.validate(&ast) {
-        Ok(src) => Ok(src),
+        Ok(validated) => Ok(validated.subject),
         Err(mut err) => {
             errors.append(&mut err);
             Err(errors)
@@ -126,9 +149,19 @@ pub fn parse_schema_ast(datamodel_string: &str) -> Result<SchemaAst, error::Erro
 
 /// Loads all configuration blocks from a datamodel using the built-in source definitions.
 pub fn parse_configuration(datamodel_string: &str) -> Result<Configuration, error::ErrorCollection> {
+    parse_configuration_with_generator_schemas(datamodel_string, Vec::new(), false)
+}
+
+/// Loads all configuration blocks from a datamodel, validating the options of generator blocks
+/// against the given option schemas in addition to the ones of the built-in generators.
+pub fn parse_configuration_with_generator_schemas(
+    datamodel_string: &str,
+    generator_schemas: Vec<GeneratorOptionSchema>,
+    ignore_env_var_errors: bool,
+) -> Result<Configuration, error::ErrorCollection> {
     let ast = ast::parser::parse(datamodel_string)?;
-    let datasources = load_sources(&ast, false)?;
-    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
+    let datasources = load_sources(&ast, ignore_env_var_errors)?;
+    let generators = GeneratorLoader::with_option_schemas(generator_schemas).load_generators_from_ast(&ast)?;
 
     Ok(Configuration {
         datasources,
@@ -136,17 +169,15 @@ pub fn parse_configuration(datamodel_string: &str) -> Result<Configuration, erro
     })
 }
 
+/// Loads all configuration blocks from a schema split across multiple files.
+pub fn parse_configuration_files(files: &SchemaFiles) -> Result<Configuration, error::ErrorCollection> {
+    parse_configuration(files.source())
+}
+
 pub fn parse_configuration_and_ignore_env_errors(
     datamodel_string: &str,
 ) -> Result<Configuration, error::ErrorCollection> {
-    let ast = ast::parser::parse(datamodel_string)?;
-    let datasources = load_sources(&ast, true)?;
-    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
-
-    Ok(Configuration {
-        datasources,
-        generators,
-    })
+    parse_configuration_with_generator_schemas(datamodel_string, Vec::new(), true)
 }
 
 fn load_sources(
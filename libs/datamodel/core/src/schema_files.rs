@@ -0,0 +1,137 @@
+//! Schemas split across multiple files.
+//!
+//! The files are merged into one source string, which is parsed and validated like a single
+//! schema. Spans of the resulting AST, errors and warnings are offsets into the merged source,
+//! `SchemaFiles::locate` maps them back to the file they come from.
+use crate::ast::Span;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The file extension of schema files, used when loading a directory.
+pub const SCHEMA_FILE_EXTENSION: &str = "prisma";
+
+/// A single file of a schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaFile {
+    /// The name of the file, as shown in error messages.
+    pub name: String,
+    /// The contents of the file.
+    pub contents: String,
+    /// The start of the file in the merged source.
+    offset: usize,
+}
+
+impl SchemaFile {
+    /// The span of the file in the merged source.
+    pub fn span(&self) -> Span {
+        Span::new(self.offset, self.offset + self.contents.len())
+    }
+}
+
+/// A set of schema files, merged into one source.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SchemaFiles {
+    files: Vec<SchemaFile>,
+    source: String,
+}
+
+impl SchemaFiles {
+    pub fn new() -> SchemaFiles {
+        SchemaFiles::default()
+    }
+
+    /// A schema consisting of one file only.
+    pub fn single(name: &str, contents: &str) -> SchemaFiles {
+        let mut files = SchemaFiles::new();
+        files.add(name, contents);
+        files
+    }
+
+    /// Loads the given paths. Files are loaded as they are, directories contribute all their
+    /// `.prisma` files, ordered by name. Subdirectories are not searched.
+    pub fn load<P: AsRef<Path>>(paths: &[P]) -> io::Result<SchemaFiles> {
+        let mut files = SchemaFiles::new();
+
+        for path in paths {
+            let path = path.as_ref();
+
+            if path.is_dir() {
+                for file_path in schema_files_in(path)? {
+                    files.add_file(&file_path)?;
+                }
+            } else {
+                files.add_file(path)?;
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Appends a file to the schema.
+    pub fn add(&mut self, name: &str, contents: &str) {
+        let offset = self.source.len();
+
+        self.source.push_str(contents);
+
+        // Every file starts on a new line, so that the last declaration of a file never runs into the next file.
+        if !contents.ends_with('\n') {
+            self.source.push('\n');
+        }
+
+        self.files.push(SchemaFile {
+            name: String::from(name),
+            contents: String::from(contents),
+            offset,
+        });
+    }
+
+    fn add_file(&mut self, path: &Path) -> io::Result<()> {
+        let contents = fs::read_to_string(path)?;
+        self.add(&path.display().to_string(), &contents);
+
+        Ok(())
+    }
+
+    pub fn files(&self) -> &[SchemaFile] {
+        &self.files
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// The merged source of all files.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Finds the file a span of the merged source points into, together with the span relative to that file.
+    pub fn locate(&self, span: Span) -> Option<(&SchemaFile, Span)> {
+        let file = self.files.iter().rev().find(|file| file.offset <= span.start)?;
+
+        // Spans never cross file boundaries, except for the line break added after a file.
+        let end = std::cmp::min(span.end, file.span().end).max(span.start);
+        let local_span = Span::new(span.start - file.offset, end - file.offset);
+
+        Some((file, local_span))
+    }
+}
+
+fn schema_files_in(directory: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        let is_schema_file = path.extension().map(|extension| extension == SCHEMA_FILE_EXTENSION);
+
+        if path.is_file() && is_schema_file.unwrap_or(false) {
+            paths.push(path);
+        }
+    }
+
+    paths.sort();
+
+    Ok(paths)
+}
@@ -0,0 +1,56 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml, CheckConstraint};
+
+/// Prismas builtin `@@check` directive.
+pub struct ModelLevelCheckDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for ModelLevelCheckDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        "check"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let expression = args.default_arg("expression")?.as_str()?;
+
+        if expression.trim().is_empty() {
+            return self
+                .new_directive_validation_error("The check constraint expression must not be empty.", args.span());
+        }
+
+        let name = match args.optional_arg("name") {
+            Some(name_arg) => Some(name_arg.as_str()?),
+            None => None,
+        };
+
+        obj.check_constraints.push(CheckConstraint { name, expression });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        let directives = model
+            .check_constraints
+            .iter()
+            .map(|check_constraint| {
+                let mut args = vec![ast::Argument::new_string("", &check_constraint.expression)];
+
+                if let Some(name) = &check_constraint.name {
+                    args.push(ast::Argument::new_string("name", &name));
+                }
+
+                ast::Directive::new(self.directive_name(), args)
+            })
+            .collect();
+
+        Ok(directives)
+    }
+}
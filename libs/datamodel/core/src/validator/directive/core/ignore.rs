@@ -0,0 +1,57 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@ignore` directive.
+pub struct IgnoreDirectiveValidator {}
+
+impl DirectiveValidator<dml::Field> for IgnoreDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        "ignore"
+    }
+
+    fn validate_and_apply(&self, _args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        obj.is_ignored = true;
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if field.is_ignored {
+            return Ok(vec![ast::Directive::new(self.directive_name(), Vec::new())]);
+        }
+
+        Ok(vec![])
+    }
+}
+
+/// Prismas builtin `@@ignore` directive.
+pub struct ModelLevelIgnoreDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for ModelLevelIgnoreDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        "ignore"
+    }
+
+    fn validate_and_apply(&self, _args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.is_ignored = true;
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if model.is_ignored {
+            return Ok(vec![ast::Directive::new(self.directive_name(), Vec::new())]);
+        }
+
+        Ok(vec![])
+    }
+}
@@ -1,11 +1,14 @@
 use crate::dml;
 use crate::validator::directive::DirectiveListValidator;
 
+mod check;
 mod default;
 mod embedded;
 mod id;
+mod ignore;
 mod map;
 mod relation;
+mod schema;
 mod unique_and_index;
 mod updated_at;
 mod utils;
@@ -20,6 +23,7 @@ pub fn new_builtin_field_directives() -> DirectiveListValidator<dml::Field> {
     validator.add(Box::new(default::DefaultDirectiveValidator {}));
     validator.add(Box::new(relation::RelationDirectiveValidator {}));
     validator.add(Box::new(updated_at::UpdatedAtDirectiveValidator {}));
+    validator.add(Box::new(ignore::IgnoreDirectiveValidator {}));
 
     validator
 }
@@ -33,6 +37,9 @@ pub fn new_builtin_model_directives() -> DirectiveListValidator<dml::Model> {
     validator.add(Box::new(unique_and_index::ModelLevelUniqueDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexDirectiveValidator {}));
     validator.add(Box::new(id::ModelLevelIdDirectiveValidator {}));
+    validator.add(Box::new(check::ModelLevelCheckDirectiveValidator {}));
+    validator.add(Box::new(schema::SchemaDirectiveValidator {}));
+    validator.add(Box::new(ignore::ModelLevelIgnoreDirectiveValidator {}));
 
     validator
 }
@@ -32,10 +32,13 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 relation_info.fields = base_fields.as_array().to_literal_vec()?;
             }
 
-            // TODO: bring `onDelete` back once `prisma migrate` is a thing
-            //            if let Ok(on_delete) = args.arg("onDelete") {
-            //                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
-            //            }
+            if let Ok(on_delete) = args.arg("onDelete") {
+                relation_info.on_delete = Some(on_delete.parse_literal::<dml::ReferentialAction>()?);
+            }
+
+            if let Ok(on_update) = args.arg("onUpdate") {
+                relation_info.on_update = Some(on_update.parse_literal::<dml::ReferentialAction>()?);
+            }
 
             Ok(())
         } else {
@@ -90,11 +93,12 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 args.push(ast::Argument::new_array("references", related_fields));
             }
 
-            if relation_info.on_delete != dml::OnDeleteStrategy::None {
-                args.push(ast::Argument::new_constant(
-                    "onDelete",
-                    &relation_info.on_delete.to_string(),
-                ));
+            if let Some(on_delete) = relation_info.on_delete {
+                args.push(ast::Argument::new_constant("onDelete", &on_delete.to_string()));
+            }
+
+            if let Some(on_update) = relation_info.on_update {
+                args.push(ast::Argument::new_constant("onUpdate", &on_update.to_string()));
             }
 
             if !args.is_empty() {
@@ -0,0 +1,39 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@schema` directive.
+pub struct SchemaDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for SchemaDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        "schema"
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let schema = args.default_arg("name")?.as_str()?;
+
+        if schema.trim().is_empty() {
+            return self.new_directive_validation_error("The schema name must not be empty.", args.span());
+        }
+
+        obj.schema = Some(schema);
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if let Some(schema) = &model.schema {
+            return Ok(vec![ast::Directive::new(
+                self.directive_name(),
+                vec![ast::Argument::new_string("", schema)],
+            )]);
+        }
+
+        Ok(vec![])
+    }
+}
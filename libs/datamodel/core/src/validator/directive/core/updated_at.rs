@@ -11,7 +11,7 @@ impl DirectiveValidator<dml::Field> for UpdatedAtDirectiveValidator {
     }
 
     fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
-        if let dml::FieldType::Base(dml::ScalarType::DateTime, _) = obj.field_type {
+        if obj.field_type.scalar_type() == Some(dml::ScalarType::DateTime) {
             // everything good
         } else {
             return self.new_directive_validation_error(
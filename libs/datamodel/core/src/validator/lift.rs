@@ -6,7 +6,7 @@ use crate::{
     configuration, dml,
     error::{DatamodelError, ErrorCollection},
 };
-use datamodel_connector::{Connector, ExampleConnector};
+use datamodel_connector::ScalarFieldType;
 
 /// Helper for lifting a datamodel.
 ///
@@ -18,7 +18,8 @@ pub struct LiftAstToDml<'a> {
     source: Option<&'a Box<dyn configuration::Source + Send + Sync>>,
 }
 
-const USE_CONNECTORS_FOR_CUSTOM_TYPES: bool = false; // FEATURE FLAG
+/// Field attributes with this prefix specify the native database type of the field, e.g. `@db.VarChar(255)`.
+pub const NATIVE_TYPE_PREFIX: &str = "db.";
 
 impl<'a> LiftAstToDml<'a> {
     /// Creates a new instance, with all builtin directives and
@@ -42,6 +43,10 @@ impl<'a> LiftAstToDml<'a> {
                     Ok(en) => schema.add_enum(en),
                     Err(mut err) => errors.append(&mut err),
                 },
+                ast::Top::Model(ty) if ty.is_composite_type => match self.lift_composite_type(&ty, ast_schema) {
+                    Ok(ct) => schema.add_composite_type(ct),
+                    Err(mut err) => errors.append(&mut err),
+                },
                 ast::Top::Model(ty) => match self.lift_model(&ty, ast_schema) {
                     Ok(md) => schema.add_model(md),
                     Err(mut err) => errors.append(&mut err),
@@ -64,6 +69,7 @@ impl<'a> LiftAstToDml<'a> {
     fn lift_model(&self, ast_model: &ast::Model, ast_schema: &ast::SchemaAst) -> Result<dml::Model, ErrorCollection> {
         let mut model = dml::Model::new(ast_model.name.name.clone(), None);
         model.documentation = ast_model.documentation.clone().map(|comment| comment.text);
+        model.is_view = ast_model.is_view;
 
         let mut errors = ErrorCollection::new();
 
@@ -85,6 +91,74 @@ impl<'a> LiftAstToDml<'a> {
         Ok(model)
     }
 
+    /// Internal: Validates a composite type AST node and lifts it to a DML composite type.
+    /// Values of composite types are stored as Json, hence their fields can't carry attributes or defaults.
+    fn lift_composite_type(
+        &self,
+        ast_type: &ast::Model,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::CompositeType, ErrorCollection> {
+        let mut composite_type = dml::CompositeType::new(&ast_type.name.name);
+        composite_type.documentation = ast_type.documentation.clone().map(|comment| comment.text);
+
+        let mut errors = ErrorCollection::new();
+
+        for directive in &ast_type.directives {
+            errors.push(DatamodelError::new_directive_validation_error(
+                "Composite types cannot have attributes.",
+                &directive.name.name,
+                directive.span,
+            ));
+        }
+
+        for ast_field in &ast_type.fields {
+            for directive in &ast_field.directives {
+                errors.push(DatamodelError::new_directive_validation_error(
+                    "Fields of composite types cannot have attributes.",
+                    &directive.name.name,
+                    directive.span,
+                ));
+            }
+
+            if let Some(value) = &ast_field.default_value {
+                errors.push(DatamodelError::new_validation_error(
+                    "Fields of composite types cannot have default values.",
+                    value.span(),
+                ));
+            }
+
+            match self.lift_field_type(ast_field, None, ast_schema, &mut Vec::new()) {
+                Ok((dml::FieldType::Relation(_), _)) => errors.push(DatamodelError::new_field_validation_error(
+                    "Fields of composite types must be of a scalar type, an enum or another composite type.",
+                    &ast_type.name.name,
+                    &ast_field.name.name,
+                    ast_field.field_type.span,
+                )),
+                Ok((_, extra_attributes)) if !extra_attributes.is_empty() => {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        "Fields of composite types cannot use type aliases with attributes.",
+                        &ast_type.name.name,
+                        &ast_field.name.name,
+                        ast_field.field_type.span,
+                    ))
+                }
+                Ok((field_type, _)) => {
+                    let mut field = dml::Field::new(&ast_field.name.name, field_type);
+                    field.arity = self.lift_field_arity(&ast_field.arity);
+                    field.documentation = ast_field.documentation.clone().map(|comment| comment.text);
+                    composite_type.add_field(field);
+                }
+                Err(err) => errors.push(err),
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(composite_type)
+        }
+    }
+
     /// Internal: Validates an enum AST node.
     fn lift_enum(&self, ast_enum: &ast::Enum) -> Result<dml::Enum, ErrorCollection> {
         let mut errors = ErrorCollection::new();
@@ -144,6 +218,31 @@ impl<'a> LiftAstToDml<'a> {
         // If we cannot parse the field type, we exit right away.
         let (field_type, extra_attributes) = self.lift_field_type(&ast_field, None, ast_schema, &mut Vec::new())?;
 
+        // Native types depend on the connector and are not handled by the directive validators.
+        let (native_type_directives, ast_directives): (Vec<&ast::Directive>, Vec<&ast::Directive>) = ast_field
+            .directives
+            .iter()
+            .partition(|directive| directive.name.name.starts_with(NATIVE_TYPE_PREFIX));
+
+        let field_type = match native_type_directives.as_slice() {
+            [] => field_type,
+            [directive] => match self.lift_native_type(directive, &field_type) {
+                Ok(native_type) => dml::FieldType::ConnectorSpecific(native_type),
+                Err(err) => {
+                    errors.push(err);
+                    field_type
+                }
+            },
+            [_, duplicate, ..] => {
+                errors.push(DatamodelError::new_directive_validation_error(
+                    "A field can only have one native type.",
+                    &duplicate.name.name,
+                    duplicate.span,
+                ));
+                field_type
+            }
+        };
+
         let mut field = dml::Field::new(&ast_field.name.name, field_type.clone());
         field.documentation = ast_field.documentation.clone().map(|comment| comment.text);
         field.arity = self.lift_field_arity(&ast_field.arity);
@@ -151,8 +250,8 @@ impl<'a> LiftAstToDml<'a> {
         if let Some(value) = &ast_field.default_value {
             let validator = ValueValidator::new(value);
 
-            if let dml::FieldType::Base(base_type, _) = &field_type {
-                match validator.as_default_value(*base_type) {
+            if let Some(scalar_type) = field_type.scalar_type() {
+                match validator.as_default_value(scalar_type) {
                     Ok(dv) => field.default_value = Some(dv),
                     Err(err) => errors.push(err),
                 };
@@ -165,7 +264,8 @@ impl<'a> LiftAstToDml<'a> {
         }
 
         // We merge arttributes so we can fail on duplicates.
-        let attributes = [&extra_attributes[..], &ast_field.directives[..]].concat();
+        let ast_directives: Vec<ast::Directive> = ast_directives.into_iter().cloned().collect();
+        let attributes = [&extra_attributes[..], &ast_directives[..]].concat();
 
         if let Err(mut err) = self.directives.field.validate_and_apply(&attributes, &mut field) {
             errors.append(&mut err);
@@ -199,24 +299,9 @@ impl<'a> LiftAstToDml<'a> {
         let type_name = &ast_field.field_type.name;
 
         if let Ok(scalar_type) = ScalarType::from_str(type_name) {
-            if USE_CONNECTORS_FOR_CUSTOM_TYPES {
-                let pg_connector = ExampleConnector::postgres();
-                let args = vec![]; // TODO: figure out args
-                let pg_type_specification = ast_field
-                    .directives
-                    .iter()
-                    .find(|dir| dir.name.name.starts_with("pg.")) // we use find because there should be at max 1.
-                    .map(|dir| dir.name.name.trim_start_matches("pg."));
-
-                if let Some(x) = pg_type_specification.and_then(|ts| pg_connector.calculate_type(&ts, args)) {
-                    let field_type = dml::FieldType::ConnectorSpecific(x);
-                    Ok((field_type, vec![]))
-                } else {
-                    Ok((dml::FieldType::Base(scalar_type, custom_type_name), vec![]))
-                }
-            } else {
-                Ok((dml::FieldType::Base(scalar_type, custom_type_name), vec![]))
-            }
+            Ok((dml::FieldType::Base(scalar_type, custom_type_name), vec![]))
+        } else if ast_schema.find_composite_type(type_name).is_some() {
+            Ok((dml::FieldType::CompositeType(type_name.clone()), vec![]))
         } else if ast_schema.find_model(type_name).is_some() {
             Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
@@ -226,6 +311,75 @@ impl<'a> LiftAstToDml<'a> {
         }
     }
 
+    /// Internal: Lifts a native type attribute like `@db.VarChar(255)` using the connector of the datasource.
+    fn lift_native_type(
+        &self,
+        directive: &ast::Directive,
+        field_type: &dml::FieldType,
+    ) -> Result<ScalarFieldType, DatamodelError> {
+        let type_name = directive.name.name.trim_start_matches(NATIVE_TYPE_PREFIX);
+        let error = |message: &str| {
+            DatamodelError::new_directive_validation_error(message, &directive.name.name, directive.span)
+        };
+
+        let source = self
+            .source
+            .ok_or_else(|| error("Native types can only be used if a datasource is defined."))?;
+        let connector = source.connector();
+
+        let constructor = connector.find_field_type_constructor(type_name).ok_or_else(|| {
+            error(&format!(
+                "Native type {} is not supported for {} connector.",
+                type_name,
+                source.connector_type()
+            ))
+        })?;
+
+        let scalar_type = match field_type {
+            dml::FieldType::Base(scalar_type, _) => *scalar_type,
+            _ => return Err(error("Native types can only be used on scalar fields.")),
+        };
+
+        if constructor.prisma_type() != scalar_type {
+            return Err(error(&format!(
+                "Native type {} is not compatible with declared field type {}, expected field type {}.",
+                type_name,
+                scalar_type.to_string(),
+                constructor.prisma_type().to_string()
+            )));
+        }
+
+        let mut args = Vec::with_capacity(directive.arguments.len());
+
+        for argument in &directive.arguments {
+            if !argument.is_unnamed() {
+                return Err(error("Native type arguments must be unnamed."));
+            }
+
+            let value = ValueValidator::new(&argument.value).as_int()?;
+            args.push(value as i32);
+        }
+
+        if !constructor.accepts_number_of_args(args.len()) {
+            let expected = if constructor.args_are_optional() {
+                format!("0 or {}", constructor.number_of_args())
+            } else {
+                constructor.number_of_args().to_string()
+            };
+
+            return Err(error(&format!(
+                "Native type {} takes {} arguments, but received {}.",
+                type_name,
+                expected,
+                args.len()
+            )));
+        }
+
+        Ok(connector
+            .calculate_type(type_name, args)
+            .expect("Invariant violation: the native type constructor accepts the arguments."))
+    }
+
     fn resolve_custom_type(
         &self,
         ast_field: &ast::Field,
@@ -260,19 +414,6 @@ impl<'a> LiftAstToDml<'a> {
 
             attrs.append(&mut custom_type.directives.clone());
             Ok((field_type, attrs))
-        } else if USE_CONNECTORS_FOR_CUSTOM_TYPES {
-            let pg_connector = ExampleConnector::postgres();
-            let args = vec![]; // TODO: figure out args
-
-            if let Some(x) = pg_connector.calculate_type(&ast_field.field_type.name, args) {
-                let field_type = dml::FieldType::ConnectorSpecific(x);
-                Ok((field_type, vec![]))
-            } else {
-                Err(DatamodelError::new_type_not_found_error(
-                    type_name,
-                    ast_field.field_type.span,
-                ))
-            }
         } else {
             Err(DatamodelError::new_type_not_found_error(
                 type_name,
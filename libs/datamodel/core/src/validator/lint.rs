@@ -0,0 +1,151 @@
+use crate::{ast, configuration, dml, error::DatamodelWarning, ValueGenerator};
+
+/// Field names that suggest that a field holds an amount of money.
+const MONEY_FIELD_NAMES: &[&str] = &["amount", "balance", "cost", "fee", "money", "price", "salary", "total"];
+
+/// Helper for finding questionable, but valid parts of a datamodel.
+///
+/// Runs after validation and standardisation, so it can rely on a valid and complete datamodel.
+pub struct Linter<'a> {
+    source: Option<&'a Box<dyn configuration::Source + Send + Sync>>,
+}
+
+impl<'a> Linter<'a> {
+    pub fn new(source: Option<&'a Box<dyn configuration::Source + Send + Sync>>) -> Linter {
+        Self { source }
+    }
+
+    pub fn lint(&self, ast_schema: &ast::SchemaAst, schema: &dml::Datamodel) -> Vec<DatamodelWarning> {
+        let mut warnings = Vec::new();
+
+        for model in schema.models().filter(|model| !model.is_ignored) {
+            let ast_model = match ast_schema.find_model(&model.name) {
+                Some(ast_model) => ast_model,
+                None => continue,
+            };
+
+            for field in model.fields().filter(|field| !field.is_ignored) {
+                // Generated fields are not part of the AST, the model is the closest location.
+                let span = ast_model
+                    .fields
+                    .iter()
+                    .find(|ast_field| ast_field.name.name == field.name)
+                    .map(|ast_field| ast_field.name.span)
+                    .unwrap_or(ast_model.name.span);
+
+                warnings.extend(self.lint_relation_index(model, field, span));
+                warnings.extend(self.lint_float_for_money(model, field, span));
+                warnings.extend(self.lint_now_default(model, field, span));
+            }
+        }
+
+        warnings.extend(self.lint_unused_enums(ast_schema, schema));
+
+        warnings
+    }
+
+    fn lint_relation_index(&self, model: &dml::Model, field: &dml::Field, span: ast::Span) -> Option<DatamodelWarning> {
+        // MySQL creates an index for every foreign key on its own.
+        if self.source.map(|source| source.connector_type()) == Some(configuration::MYSQL_SOURCE_NAME) {
+            return None;
+        }
+
+        let relation_fields = match &field.field_type {
+            dml::FieldType::Relation(relation_info) if !relation_info.fields.is_empty() => &relation_info.fields,
+            _ => return None,
+        };
+
+        let is_covered_by = |indexed_fields: &[String]| indexed_fields.starts_with(relation_fields);
+
+        let single_field_covered = match relation_fields.as_slice() {
+            [single] => model
+                .find_field(single)
+                .map(|field| field.is_id || field.is_unique)
+                .unwrap_or(false),
+            _ => false,
+        };
+
+        if single_field_covered
+            || is_covered_by(&model.id_fields)
+            || model.indices.iter().any(|index| is_covered_by(&index.fields))
+        {
+            return None;
+        }
+
+        Some(DatamodelWarning::new_missing_relation_index_warning(
+            &model.name,
+            &field.name,
+            relation_fields,
+            span,
+        ))
+    }
+
+    fn lint_float_for_money(
+        &self,
+        model: &dml::Model,
+        field: &dml::Field,
+        span: ast::Span,
+    ) -> Option<DatamodelWarning> {
+        if field.field_type.scalar_type() != Some(dml::ScalarType::Float) {
+            return None;
+        }
+
+        let field_name = field.name.to_lowercase();
+
+        if MONEY_FIELD_NAMES
+            .iter()
+            .any(|money_name| field_name.contains(money_name))
+        {
+            Some(DatamodelWarning::new_float_for_money_warning(
+                &model.name,
+                &field.name,
+                span,
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn lint_now_default(&self, model: &dml::Model, field: &dml::Field, span: ast::Span) -> Option<DatamodelWarning> {
+        match &field.default_value {
+            Some(dml::DefaultValue::Expression(generator)) if generator == &ValueGenerator::new_now() => (),
+            _ => return None,
+        }
+
+        let type_name = match &field.field_type {
+            dml::FieldType::Base(dml::ScalarType::DateTime, _) => return None,
+            dml::FieldType::Base(_, Some(alias)) => alias.clone(),
+            dml::FieldType::Base(scalar_type, None) => scalar_type.to_string(),
+            _ => return None,
+        };
+
+        Some(DatamodelWarning::new_now_default_on_non_date_time_warning(
+            &model.name,
+            &field.name,
+            &type_name,
+            span,
+        ))
+    }
+
+    fn lint_unused_enums(&self, ast_schema: &ast::SchemaAst, schema: &dml::Datamodel) -> Vec<DatamodelWarning> {
+        let model_fields = schema.models().flat_map(|model| model.fields());
+        let composite_type_fields = schema
+            .composite_types()
+            .flat_map(|composite_type| composite_type.fields());
+
+        let used_enums: Vec<&str> = model_fields
+            .chain(composite_type_fields)
+            .filter_map(|field| match &field.field_type {
+                dml::FieldType::Enum(enum_name) => Some(enum_name.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        schema
+            .enums()
+            .filter(|r#enum| !used_enums.contains(&r#enum.name.as_str()))
+            .filter_map(|r#enum| ast_schema.find_enum(&r#enum.name))
+            .map(|ast_enum| DatamodelWarning::new_unused_enum_warning(&ast_enum.name.name, ast_enum.name.span))
+            .collect()
+    }
+}
@@ -1,10 +1,12 @@
 mod directive_box;
 mod invalid_model_names;
 mod lift;
+mod lint;
 mod lower;
 mod precheck;
 mod standardise;
 mod validate;
+mod validated;
 mod validation_pipeline;
 
 mod common;
@@ -13,7 +15,9 @@ pub mod directive;
 use directive_box::*;
 
 use lift::*;
+use lint::*;
 pub use lower::*;
 use standardise::*;
 use validate::*;
+pub use validated::*;
 pub use validation_pipeline::*;
@@ -1,6 +1,6 @@
 use super::common::*;
 use crate::error::DatamodelError;
-use crate::{ast, common::names::*, dml, error::ErrorCollection, Field, OnDeleteStrategy, UniqueCriteria};
+use crate::{ast, common::names::*, dml, error::ErrorCollection, Field, UniqueCriteria};
 
 /// Helper for standardsing a datamodel.
 ///
@@ -71,17 +71,19 @@ impl Standardiser {
                         },
                     };
 
-                    let underlying_fields =
-                        self.underlying_fields_for_unique_criteria(&unique_criteria, model.name.clone().as_ref());
+                    let underlying_fields = match &unique_criteria {
+                        Some(unique_criteria) => {
+                            self.underlying_fields_for_unique_criteria(unique_criteria, model.name.clone().as_ref())
+                        }
+                        None => Vec::new(),
+                    };
 
                     if embed_here {
                         // user input has precedence
                         if rel.to_fields.is_empty() && related_field_rel.to_fields.is_empty() {
-                            rel.to_fields = related_model
-                                .first_unique_criterion()
-                                .iter()
-                                .map(|f| f.name.to_owned())
-                                .collect();
+                            if let Some(unique_criteria) = related_model.strict_unique_criterias().first() {
+                                rel.to_fields = unique_criteria.fields.iter().map(|f| f.name.to_owned()).collect();
+                            }
                         }
 
                         // user input has precedence
@@ -184,11 +186,13 @@ impl Standardiser {
                             fields: vec![],
                             to_fields: vec![],
                             name: rel.name.clone(),
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: None,
+                            on_update: None,
                         };
                         let mut back_relation_field =
                             dml::Field::new_generated(&model.name, dml::FieldType::Relation(relation_info));
                         back_relation_field.arity = dml::FieldArity::List;
+                        back_relation_field.is_ignored = model.is_ignored || field.is_ignored;
 
                         result.push(AddMissingBackRelationField {
                             model: rel.to.clone(),
@@ -198,7 +202,18 @@ impl Standardiser {
                             underlying_fields: vec![],
                         });
                     } else {
-                        let unique_criteria = self.unique_criteria(&model);
+                        let unique_criteria = match self.unique_criteria(&model) {
+                            Some(unique_criteria) => unique_criteria,
+                            None => {
+                                errors.push(field_validation_error(
+                                    "Automatic related field generation requires a unique criteria on the model. Please add an explicit opposite relation field.",
+                                    &model,
+                                    &field,
+                                    &schema_ast,
+                                ));
+                                continue;
+                            }
+                        };
                         let unique_criteria_field_names =
                             unique_criteria.fields.iter().map(|f| f.name.to_owned()).collect();
 
@@ -256,12 +271,14 @@ impl Standardiser {
                             fields: underlying_field_names,
                             to_fields: unique_criteria_field_names,
                             name: rel.name.clone(),
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: None,
+                            on_update: None,
                         };
 
                         let mut back_relation_field =
                             dml::Field::new_generated(&model.name, dml::FieldType::Relation(relation_info));
                         back_relation_field.arity = dml::FieldArity::Optional;
+                        back_relation_field.is_ignored = model.is_ignored || field.is_ignored;
 
                         result.push(AddMissingBackRelationField {
                             model: rel.to.clone(),
@@ -282,8 +299,9 @@ impl Standardiser {
         }
     }
 
-    fn unique_criteria<'a>(&self, model: &'a dml::Model) -> UniqueCriteria<'a> {
-        model.loose_unique_criterias().into_iter().next().unwrap()
+    /// Ignored models are allowed to have no unique criteria at all.
+    fn unique_criteria<'a>(&self, model: &'a dml::Model) -> Option<UniqueCriteria<'a>> {
+        model.loose_unique_criterias().into_iter().next()
     }
 
     fn underlying_fields_for_unique_criteria(
@@ -29,6 +29,10 @@ impl<'a> Validator<'a> {
             all_errors.append(errs);
         }
 
+        if let Err(ref mut errs) = self.validate_composite_types_are_not_recursive(ast_schema, schema) {
+            all_errors.append(errs);
+        }
+
         // Model level validations.
         for model in schema.models() {
             // Having a separate error collection allows checking whether any error has occurred for a model.
@@ -41,6 +45,10 @@ impl<'a> Validator<'a> {
             if let Err(err) = self.validate_model_name(ast_schema.find_model(&model.name).expect(STATE_ERROR), model) {
                 errors_for_model.push(err);
             }
+            if let Err(err) = self.validate_model_schema(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push(err);
+            }
 
             if let Err(err) = self.validate_relations_not_ambiguous(ast_schema, model) {
                 errors_for_model.push(err);
@@ -61,6 +69,12 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_default_values(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) = self.validate_base_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -108,6 +122,10 @@ impl<'a> Validator<'a> {
             // Having a separate error collection allows checking whether any error has occurred for a model.
             let mut errors_for_model = ErrorCollection::new();
 
+            if let Err(ref mut the_errors) = self.validate_relations_to_ignored_models(ast_schema, schema, model) {
+                errors_for_model.append(the_errors);
+            }
+
             if !errors_for_model.has_errors() {
                 let mut new_errors = self.validate_relation_arguments_bla(
                     schema,
@@ -195,6 +213,37 @@ impl<'a> Validator<'a> {
                 .find(|ast_field| ast_field.name.name == field.name)
                 .unwrap();
 
+            if let dml::FieldType::CompositeType(composite_type) = &field.field_type {
+                let supports_json_type = match self.source {
+                    Some(source) => source.connector().supports_json(),
+                    None => false,
+                };
+                if !supports_json_type {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        &format!("Field `{}` in model `{}` can't be of composite type `{}`. Composite types are stored as Json, which the current connector does not support.", &field.name, &model.name, composite_type),
+                        &model.name,
+                        &field.name,
+                        ast_field.span,
+                    ));
+                }
+                if field.arity.is_list() {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        &format!("Field `{}` in model `{}` can't be a list of composite types. Use a list field inside of the composite type `{}` instead.", &field.name, &model.name, composite_type),
+                        &model.name,
+                        &field.name,
+                        ast_field.span,
+                    ));
+                }
+                if field.is_id || field.is_unique {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        &format!("Field `{}` in model `{}` can't be an id or unique field, because it is of composite type `{}`.", &field.name, &model.name, composite_type),
+                        &model.name,
+                        &field.name,
+                        ast_field.span,
+                    ));
+                }
+            }
+
             if let Some(dml::ScalarType::Json) = field.field_type.scalar_type() {
                 // TODO: this is really ugly
                 let supports_json_type = match self.source {
@@ -219,6 +268,119 @@ impl<'a> Validator<'a> {
         }
     }
 
+    /// Ensures that `sequence("name")` defaults are only used with connectors that support sequences.
+    fn validate_default_values(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        let sequences_are_supported = match self.source {
+            Some(source) => source.connector_type() == configuration::POSTGRES_SOURCE_NAME,
+            None => false,
+        };
+
+        for field in model.fields() {
+            let uses_sequence = match &field.default_value {
+                Some(dml::DefaultValue::Expression(generator)) => generator.sequence_name().is_some(),
+                _ => false,
+            };
+
+            if uses_sequence && !sequences_are_supported {
+                let ast_field = ast_model
+                    .fields
+                    .iter()
+                    .find(|ast_field| ast_field.name.name == field.name)
+                    .unwrap();
+                let span = ast_field
+                    .directives
+                    .iter()
+                    .find(|directive| directive.name.name == "default")
+                    .map(|directive| directive.span)
+                    .unwrap_or(ast_field.span);
+
+                errors.push(DatamodelError::new_field_validation_error(
+                    &format!("Field `{}` in model `{}` can't use a `sequence()` default. Sequences are only supported on Postgres.", &field.name, &model.name),
+                    &model.name,
+                    &field.name,
+                    span,
+                ));
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Values of composite types are embedded into each other, so a composite type must not contain itself.
+    fn validate_composite_types_are_not_recursive(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        schema: &dml::Datamodel,
+    ) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        for composite_type in schema.composite_types() {
+            let mut path = vec![composite_type.name.as_str()];
+
+            if let Some(field) = Self::find_recursive_composite_field(schema, composite_type, &mut path) {
+                let ast_type = ast_schema.find_composite_type(&composite_type.name).expect(STATE_ERROR);
+                let ast_field = ast_type
+                    .fields
+                    .iter()
+                    .find(|ast_field| ast_field.name.name == field.name)
+                    .expect(STATE_ERROR);
+
+                errors.push(DatamodelError::new_field_validation_error(
+                    &format!(
+                        "Recursive composite types are not allowed. Recursive path was: {}.",
+                        path.join(" -> ")
+                    ),
+                    &composite_type.name,
+                    &field.name,
+                    ast_field.field_type.span,
+                ));
+            }
+        }
+
+        errors.ok()
+    }
+
+    /// Returns the field of `composite_type` through which the first composite type in `path` is reachable again.
+    fn find_recursive_composite_field<'b>(
+        schema: &'b dml::Datamodel,
+        composite_type: &'b dml::CompositeType,
+        path: &mut Vec<&'b str>,
+    ) -> Option<&'b dml::Field> {
+        for field in composite_type.fields() {
+            let field_type_name = match &field.field_type {
+                dml::FieldType::CompositeType(name) => name.as_str(),
+                _ => continue,
+            };
+
+            if path.first() == Some(&field_type_name) {
+                path.push(field_type_name);
+                return Some(field);
+            }
+
+            if path.contains(&field_type_name) {
+                continue;
+            }
+
+            if let Some(field_type) = schema.find_composite_type(field_type_name) {
+                path.push(field_type_name);
+
+                if Self::find_recursive_composite_field(schema, field_type, path).is_some() {
+                    return Some(field);
+                }
+
+                path.pop();
+            }
+        }
+
+        None
+    }
+
     fn validate_model_has_id(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
         // TODO: replace with unique criteria function
         let multiple_single_field_id_error = Err(DatamodelError::new_model_validation_error(
@@ -252,7 +414,13 @@ impl<'a> Validator<'a> {
             return multiple_id_criteria_error;
         }
 
-        if has_single_field_id || has_multi_field_id || has_single_field_unique || has_multi_field_unique {
+        if has_single_field_id
+            || has_multi_field_id
+            || has_single_field_unique
+            || has_multi_field_unique
+            || model.is_ignored
+            || model.is_view
+        {
             Ok(())
         } else {
             missing_id_criteria_error
@@ -274,6 +442,35 @@ impl<'a> Validator<'a> {
         }
     }
 
+    /// Ensures that the schema of a model is one of the schemas listed on the datasource.
+    fn validate_model_schema(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let (schema, source) = match (&model.schema, self.source) {
+            (Some(schema), Some(source)) => (schema, source),
+            _ => return Ok(()),
+        };
+
+        if source.schemas().contains(schema) {
+            return Ok(());
+        }
+
+        let span = ast_model
+            .directives
+            .iter()
+            .find(|directive| directive.name.name == "schema")
+            .map(|directive| directive.span)
+            .unwrap_or(ast_model.span);
+
+        Err(DatamodelError::new_directive_validation_error(
+            &format!(
+                "The schema `{}` is not defined in the `schemas` property of the datasource `{}`.",
+                schema,
+                source.name()
+            ),
+            "schema",
+            span,
+        ))
+    }
+
     /// Ensures that embedded types do not have back relations
     /// to their parent types.
     fn validate_embedded_types_have_no_back_relation(
@@ -710,6 +907,39 @@ impl<'a> Validator<'a> {
                     }
                 }
 
+                // REFERENTIAL ACTIONS
+                let has_referential_actions = rel_info.on_delete.is_some() || rel_info.on_update.is_some();
+                if has_referential_actions && rel_info.fields.is_empty() {
+                    errors.push(DatamodelError::new_directive_validation_error(
+                        &format!(
+                            "The relation field `{}` on Model `{}` must not specify the `onDelete` or `onUpdate` argument in the {} directive. Referential actions can only be specified on the side of the relation that provides the `fields` argument.",
+                            &field.name, &model.name, RELATION_DIRECTIVE_NAME
+                        ),
+                        RELATION_DIRECTIVE_NAME,
+                        field_span.clone(),
+                    ));
+                }
+
+                let sets_null = rel_info.on_delete == Some(dml::ReferentialAction::SetNull)
+                    || rel_info.on_update == Some(dml::ReferentialAction::SetNull);
+                let required_fields: Vec<&str> = rel_info
+                    .fields
+                    .iter()
+                    .filter(|name| model.find_field(name).map(|f| f.arity.is_required()).unwrap_or(false))
+                    .map(|name| name.as_str())
+                    .collect();
+
+                if sets_null && !required_fields.is_empty() {
+                    errors.push(DatamodelError::new_directive_validation_error(
+                        &format!(
+                            "The relation field `{}` on Model `{}` uses the referential action `SetNull`, but the fields {} are required. Make them optional or choose a different referential action.",
+                            &field.name, &model.name, required_fields.iter().map(|name| format!("`{}`", name)).collect::<Vec<_>>().join(", ")
+                        ),
+                        RELATION_DIRECTIVE_NAME,
+                        field_span.clone(),
+                    ));
+                }
+
                 // MANY TO MANY
                 if field.arity.is_list() && related_field.arity.is_list() {
                     if !related_model.has_single_id_field() {
@@ -725,6 +955,19 @@ impl<'a> Validator<'a> {
                             field_span.clone(),
                         ));
                     }
+
+                    if model.is_view || related_model.is_view {
+                        errors.push(DatamodelError::new_field_validation_error(
+                            &format!(
+                                "The relation field `{}` on Model `{}` is part of a many to many relation with a view. Views can not be part of many to many relations. Use an explicit intermediate Model to represent this relationship.",
+                                &field.name,
+                                &model.name,
+                            ),
+                            &model.name,
+                            &field.name,
+                            field_span.clone(),
+                        ));
+                    }
                 }
             }
         }
@@ -732,6 +975,61 @@ impl<'a> Validator<'a> {
         errors
     }
 
+    /// Ensures that relation fields are ignored whenever the model they point to or their opposite relation field is ignored.
+    fn validate_relations_to_ignored_models(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        if model.is_ignored {
+            return Ok(());
+        }
+
+        for field in model.fields().filter(|field| !field.is_ignored) {
+            let rel_info = match &field.field_type {
+                dml::FieldType::Relation(rel_info) => rel_info,
+                _ => continue,
+            };
+
+            let related_model = datamodel.find_model(&rel_info.to).expect(STATE_ERROR);
+            let related_field_is_ignored = related_model
+                .related_field(&model.name, &rel_info.name, &field.name)
+                .map(|related_field| related_field.is_ignored)
+                .unwrap_or(false);
+
+            let message = if related_model.is_ignored {
+                format!(
+                    "The relation field `{}` on Model `{}` must specify the `@ignore` attribute, because the model `{}` it is pointing to is marked ignored.",
+                    &field.name, &model.name, &related_model.name
+                )
+            } else if related_field_is_ignored {
+                format!(
+                    "The relation field `{}` on Model `{}` must specify the `@ignore` attribute, because its opposite relation field is marked ignored.",
+                    &field.name, &model.name
+                )
+            } else {
+                continue;
+            };
+
+            let span = ast_schema
+                .find_field(&model.name, &field.name)
+                .map(|ast_field| ast_field.span)
+                .unwrap_or_else(|| ast_schema.find_model(&model.name).expect(STATE_ERROR).span);
+
+            errors.push(DatamodelError::new_field_validation_error(
+                &message,
+                &model.name,
+                &field.name,
+                span,
+            ));
+        }
+
+        errors.ok()
+    }
+
     /// Elegantly checks if any relations in the model are ambigious.
     fn validate_relations_not_ambiguous(
         &self,
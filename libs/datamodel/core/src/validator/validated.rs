@@ -0,0 +1,11 @@
+use crate::{dml, error::DatamodelWarning};
+
+/// The result of a successful validation: the validated subject, together with
+/// the warnings about questionable, but valid parts of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Validated<T> {
+    pub subject: T,
+    pub warnings: Vec<DatamodelWarning>,
+}
+
+pub type ValidatedDatamodel = Validated<dml::Datamodel>;
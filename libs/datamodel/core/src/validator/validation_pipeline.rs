@@ -6,6 +6,7 @@ pub struct ValidationPipeline<'a> {
     lifter: LiftAstToDml<'a>,
     validator: Validator<'a>,
     standardiser: Standardiser,
+    linter: Linter<'a>,
 }
 
 impl<'a> ValidationPipeline<'a> {
@@ -19,6 +20,7 @@ impl<'a> ValidationPipeline<'a> {
             lifter: LiftAstToDml::new(source),
             validator: Validator::new(source),
             standardiser: Standardiser::new(),
+            linter: Linter::new(source),
         }
     }
 
@@ -30,7 +32,8 @@ impl<'a> ValidationPipeline<'a> {
     /// * Perform string interpolation
     /// * Resolve and check default values
     /// * Resolve and check all field types
-    pub fn validate(&self, ast_schema: &ast::SchemaAst) -> Result<dml::Datamodel, ErrorCollection> {
+    /// * Collect warnings about questionable, but valid parts of the schema
+    pub fn validate(&self, ast_schema: &ast::SchemaAst) -> Result<ValidatedDatamodel, ErrorCollection> {
         let mut all_errors = ErrorCollection::new();
 
         // Phase 0 is parsing.
@@ -78,9 +81,15 @@ impl<'a> ValidationPipeline<'a> {
         }
 
         if all_errors.has_errors() {
-            Err(all_errors)
-        } else {
-            Ok(schema)
+            return Err(all_errors);
         }
+
+        // Phase 7: Warnings. These never fail.
+        let warnings = self.linter.lint(ast_schema, &schema);
+
+        Ok(ValidatedDatamodel {
+            subject: schema,
+            warnings,
+        })
     }
 }
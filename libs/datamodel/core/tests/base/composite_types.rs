@@ -0,0 +1,214 @@
+use crate::common::*;
+use datamodel::ast::Span;
+use datamodel::common::ScalarType;
+use datamodel::dml;
+use datamodel::error::DatamodelError;
+
+const DATASOURCE: &str = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost/db"
+    }
+"#;
+
+#[test]
+fn parse_basic_composite_type() {
+    let dml = format!(
+        "{}{}",
+        DATASOURCE,
+        r#"
+    model User {
+        id      Int      @id
+        address Address?
+    }
+
+    type Address {
+        street String
+        city   String?
+        tags   String[]
+    }
+    "#
+    );
+
+    let schema = parse(&dml);
+    let address = schema.find_composite_type("Address").expect("Composite type not found");
+
+    assert_eq!(address.fields().count(), 3);
+    assert_eq!(schema.find_model("Address"), None);
+
+    let user = schema.assert_has_model("User");
+    let field = user
+        .assert_has_field("address")
+        .assert_arity(&dml::FieldArity::Optional);
+    assert_eq!(field.field_type, dml::FieldType::CompositeType("Address".to_owned()));
+
+    address
+        .find_field("city")
+        .unwrap()
+        .assert_base_type(&ScalarType::String)
+        .assert_arity(&dml::FieldArity::Optional);
+}
+
+#[test]
+fn composite_types_can_be_nested() {
+    let dml = format!(
+        "{}{}",
+        DATASOURCE,
+        r#"
+    model User {
+        id      Int     @id
+        address Address
+    }
+
+    type Address {
+        street String
+        geo    Geo
+    }
+
+    type Geo {
+        lat Float
+        lng Float
+    }
+    "#
+    );
+
+    let schema = parse(&dml);
+    let address = schema.find_composite_type("Address").unwrap();
+
+    let geo = address.find_field("geo").unwrap();
+
+    assert_eq!(geo.field_type, dml::FieldType::CompositeType("Geo".to_owned()));
+}
+
+#[test]
+fn composite_types_are_rendered_with_the_type_keyword() {
+    let input = format!(
+        "{}{}",
+        DATASOURCE,
+        r#"
+        model User {
+            id      Int      @id
+            address Address?
+        }
+
+        type Address {
+            street String
+            city   String?
+        }
+    "#
+    );
+
+    let expected = r#"model User {
+  id      Int      @id
+  address Address?
+}
+
+type Address {
+  street String
+  city   String?
+}"#;
+
+    let dml = parse(&input);
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    assert_eq!(rendered, expected);
+}
+
+#[test]
+fn composite_types_require_a_connector_with_json_support() {
+    let dml = r#"
+    model User {
+        id      Int     @id
+        address Address
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "Field `address` in model `User` can't be of composite type `Address`. Composite types are stored as Json, which the current connector does not support.",
+        "User",
+        "address",
+        Span::new(54, 70),
+    ));
+}
+
+#[test]
+fn model_fields_must_not_be_lists_of_composite_types() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost/db"
+    }
+
+    model User {
+        id        Int       @id
+        addresses Address[]
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "Field `addresses` in model `User` can't be a list of composite types. Use a list field inside of the composite type `Address` instead.",
+        "User",
+        "addresses",
+        Span::new(164, 184),
+    ));
+}
+
+#[test]
+fn composite_types_must_not_be_recursive() {
+    let dml = r#"
+    type Person {
+        name   String
+        friend Friend?
+    }
+
+    type Friend {
+        person Person?
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(2).assert_is_at(
+        0,
+        DatamodelError::new_field_validation_error(
+            "Recursive composite types are not allowed. Recursive path was: Person -> Friend -> Person.",
+            "Person",
+            "friend",
+            Span::new(56, 63),
+        ),
+    );
+}
+
+#[test]
+fn composite_type_fields_must_not_be_relations() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    type Address {
+        owner User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "Fields of composite types must be of a scalar type, an enum or another composite type.",
+        "Address",
+        "owner",
+        Span::new(77, 81),
+    ));
+}
@@ -2,4 +2,8 @@ pub mod array_sugar;
 pub mod base_types;
 pub mod basic;
 pub mod comments;
+pub mod composite_types;
 pub mod duplicates;
+pub mod schema_files;
+pub mod views;
+pub mod warnings;
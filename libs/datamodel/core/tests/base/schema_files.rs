@@ -0,0 +1,98 @@
+use crate::common::*;
+use datamodel::{ast::Span, error::DatamodelError, SchemaFiles};
+
+const USER_FILE: &str = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+"#;
+
+const POST_FILE: &str = r#"model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}"#;
+
+#[test]
+fn declarations_can_refer_to_other_files() {
+    let mut files = SchemaFiles::new();
+    files.add("user.prisma", USER_FILE);
+    files.add("post.prisma", POST_FILE);
+
+    let datamodel = datamodel::parse_datamodel_files(&files).unwrap().subject;
+
+    datamodel
+        .assert_has_model("User")
+        .assert_has_field("posts")
+        .assert_relation_to("Post");
+
+    datamodel
+        .assert_has_model("Post")
+        .assert_has_field("author")
+        .assert_relation_to("User");
+}
+
+#[test]
+fn errors_are_located_in_their_file() {
+    let mut files = SchemaFiles::new();
+    files.add("user.prisma", USER_FILE);
+    files.add("other_user.prisma", USER_FILE);
+    files.add("post.prisma", POST_FILE);
+
+    let errors = datamodel::parse_datamodel_files(&files).unwrap_err();
+    let offset = USER_FILE.len();
+
+    errors.assert_is(DatamodelError::new_duplicate_top_error(
+        "User",
+        "model",
+        "model",
+        Span::new(offset + 6, offset + 10),
+    ));
+
+    let (file, span) = files.locate(errors.errors[0].span()).unwrap();
+
+    assert_eq!(file.name, "other_user.prisma");
+    assert_eq!(span, Span::new(6, 10));
+}
+
+#[test]
+fn errors_are_pretty_printed_with_their_file() {
+    let mut files = SchemaFiles::new();
+    files.add("user.prisma", USER_FILE);
+    files.add("post.prisma", "model Post {\n  id Int @id\n  author Author\n}\n");
+
+    let errors = datamodel::parse_datamodel_files(&files).unwrap_err();
+    let output = errors.to_pretty_string_files(&files);
+
+    assert!(output.contains("post.prisma:3"), "{}", output);
+    assert!(!output.contains("user.prisma"), "{}", output);
+}
+
+#[test]
+fn files_without_a_trailing_line_break_do_not_run_into_the_next_file() {
+    let mut files = SchemaFiles::new();
+    files.add("post.prisma", POST_FILE);
+    files.add("user.prisma", USER_FILE);
+
+    assert!(!POST_FILE.ends_with('\n'));
+    assert!(datamodel::parse_datamodel_files(&files).is_ok());
+}
+
+#[test]
+fn directories_are_loaded_in_file_name_order() {
+    let directory = std::env::temp_dir().join(format!("prisma-schema-files-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(directory.join("b_user.prisma"), USER_FILE).unwrap();
+    std::fs::write(directory.join("a_post.prisma"), POST_FILE).unwrap();
+    std::fs::write(directory.join("notes.txt"), "not a schema").unwrap();
+
+    let files = SchemaFiles::load(&[&directory]).unwrap();
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    let names: Vec<&str> = files.files().iter().map(|file| file.name.as_str()).collect();
+
+    assert_eq!(names.len(), 2);
+    assert!(names[0].ends_with("a_post.prisma"));
+    assert!(names[1].ends_with("b_user.prisma"));
+    assert!(datamodel::parse_datamodel_files(&files).is_ok());
+}
@@ -0,0 +1,91 @@
+use crate::common::*;
+use datamodel::ast::Span;
+use datamodel::common::ScalarType;
+use datamodel::error::DatamodelError;
+
+#[test]
+fn parse_basic_view() {
+    let dml = r#"
+    view UserSummary {
+        id        Int @id
+        postCount Int
+    }
+    "#;
+
+    let schema = parse(dml);
+    let view = schema.assert_has_model("UserSummary");
+    view.assert_is_view(true);
+    view.assert_has_field("postCount").assert_base_type(&ScalarType::Int);
+}
+
+#[test]
+fn models_are_not_views() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema.assert_has_model("User").assert_is_view(false);
+}
+
+#[test]
+fn views_are_rendered_with_the_view_keyword() {
+    let input = r#"
+        view UserSummary {
+            id        Int @id
+            postCount Int
+        }
+    "#;
+
+    let expected = r#"view UserSummary {
+  id        Int @id
+  postCount Int
+}"#;
+
+    let dml = parse(input);
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    assert_eq!(rendered, expected);
+}
+
+#[test]
+fn views_must_not_be_part_of_many_to_many_relations() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        users UserSummary[]
+    }
+
+    view UserSummary {
+        id    Int    @id
+        posts Post[]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(2).assert_is_at(
+        0,
+        DatamodelError::new_field_validation_error(
+            "The relation field `users` on Model `Post` is part of a many to many relation with a view. Views can not be part of many to many relations. Use an explicit intermediate Model to represent this relationship.",
+            "Post",
+            "users",
+            Span::new(51, 71),
+        ),
+    );
+}
+
+#[test]
+fn views_do_not_need_a_unique_criteria() {
+    let dml = r#"
+    view UserSummary {
+        name      String
+        postCount Int
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema.assert_has_model("UserSummary").assert_is_view(true);
+}
@@ -0,0 +1,208 @@
+use crate::common::*;
+use datamodel::{ast::Span, error::DatamodelWarning};
+
+fn field_name_span(dml: &str, model: &str, field: &str) -> Span {
+    let schema = datamodel::parse_schema_ast(dml).unwrap();
+
+    schema.find_field(model, field).unwrap().name.span
+}
+
+#[test]
+fn a_valid_schema_has_no_warnings() {
+    let dml = r#"
+    model User {
+        id        Int      @id
+        createdAt DateTime @default(now())
+        role      Role
+        posts     Post[]
+    }
+
+    model Post {
+        id       Int     @id
+        price    Decimal
+        authorId Int
+        author   User    @relation(fields: [authorId], references: [id])
+
+        @@index([authorId])
+    }
+
+    enum Role {
+        USER
+        ADMIN
+    }
+    "#;
+
+    assert_eq!(parse_warnings(dml), Vec::new());
+}
+
+#[test]
+fn warn_on_relation_fields_without_index() {
+    let dml = format!(
+        r#"
+    {}
+
+    model User {{
+        id    Int    @id
+        posts Post[]
+    }}
+
+    model Post {{
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    assert_eq!(
+        parse_warnings(&dml),
+        vec![DatamodelWarning::new_missing_relation_index_warning(
+            "Post",
+            "author",
+            &[String::from("authorId")],
+            field_name_span(&dml, "Post", "author"),
+        )]
+    );
+}
+
+#[test]
+fn relation_fields_covered_by_an_index_or_a_unique_do_not_warn() {
+    let dml = r#"
+    model User {
+        id      Int      @id
+        posts   Post[]
+        profile Profile?
+    }
+
+    model Post {
+        id       Int    @id
+        authorId Int
+        title    String
+        author   User   @relation(fields: [authorId], references: [id])
+
+        @@index([authorId, title])
+    }
+
+    model Profile {
+        id     Int  @id
+        userId Int  @unique
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    assert_eq!(parse_warnings(dml), Vec::new());
+}
+
+#[test]
+fn relation_fields_without_index_do_not_warn_on_mysql() {
+    let dml = format!(
+        r#"
+    {}
+
+    model User {{
+        id    Int    @id
+        posts Post[]
+    }}
+
+    model Post {{
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }}
+    "#,
+        MYSQL_SOURCE
+    );
+
+    assert_eq!(parse_warnings(&dml), Vec::new());
+}
+
+#[test]
+fn warn_on_float_for_money() {
+    let dml = r#"
+    model Product {
+        id         Int   @id
+        unitPrice  Float
+        weight     Float
+    }
+    "#;
+
+    assert_eq!(
+        parse_warnings(dml),
+        vec![DatamodelWarning::new_float_for_money_warning(
+            "Product",
+            "unitPrice",
+            field_name_span(dml, "Product", "unitPrice"),
+        )]
+    );
+}
+
+#[test]
+fn warn_on_now_default_on_non_date_time_fields() {
+    let dml = r#"
+    type Timestamp = String
+
+    model Event {
+        id        Int       @id
+        createdAt Timestamp @default(now())
+    }
+    "#;
+
+    assert_eq!(
+        parse_warnings(dml),
+        vec![DatamodelWarning::new_now_default_on_non_date_time_warning(
+            "Event",
+            "createdAt",
+            "Timestamp",
+            field_name_span(dml, "Event", "createdAt"),
+        )]
+    );
+}
+
+#[test]
+fn warn_on_unused_enums() {
+    let dml = r#"
+    model User {
+        id   Int  @id
+        role Role
+    }
+
+    enum Role {
+        USER
+        ADMIN
+    }
+
+    enum Color {
+        RED
+        GREEN
+    }
+    "#;
+
+    let schema = datamodel::parse_schema_ast(dml).unwrap();
+    let span = schema.find_enum("Color").unwrap().name.span;
+
+    assert_eq!(
+        parse_warnings(dml),
+        vec![DatamodelWarning::new_unused_enum_warning("Color", span)]
+    );
+}
+
+#[test]
+fn warnings_are_pretty_printed_as_warnings() {
+    let dml = r#"
+    enum Color {
+        RED
+        GREEN
+    }
+    "#;
+
+    let warnings = parse_warnings(dml);
+    let mut output = Vec::new();
+
+    warnings[0].pretty_print(&mut output, "schema.prisma", dml).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+
+    assert!(output.contains("The enum `Color` is not used by any field."));
+    assert!(output.contains("warning"));
+}
@@ -11,7 +11,8 @@ pub trait FieldAsserts {
     fn assert_connector_type(&self, sft: &ScalarFieldType) -> &Self;
     fn assert_relation_name(&self, t: &str) -> &Self;
     fn assert_relation_to(&self, t: &str) -> &Self;
-    fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self;
+    fn assert_relation_on_delete(&self, t: Option<dml::ReferentialAction>) -> &Self;
+    fn assert_relation_on_update(&self, t: Option<dml::ReferentialAction>) -> &Self;
     fn assert_relation_to_fields(&self, t: &[&str]) -> &Self;
     fn assert_relation_base_fields(&self, t: &[&str]) -> &Self;
     fn assert_arity(&self, arity: &dml::FieldArity) -> &Self;
@@ -27,6 +28,7 @@ pub trait FieldAsserts {
 pub trait ModelAsserts {
     fn assert_has_field(&self, t: &str) -> &dml::Field;
     fn assert_is_embedded(&self, t: bool) -> &Self;
+    fn assert_is_view(&self, t: bool) -> &Self;
     fn assert_with_db_name(&self, t: &str) -> &Self;
     fn assert_with_documentation(&self, t: &str) -> &Self;
     fn assert_has_index(&self, def: IndexDefinition) -> &Self;
@@ -105,7 +107,7 @@ impl FieldAsserts for dml::Field {
         self
     }
 
-    fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self {
+    fn assert_relation_on_delete(&self, t: Option<dml::ReferentialAction>) -> &Self {
         if let dml::FieldType::Relation(info) = &self.field_type {
             assert_eq!(info.on_delete, t);
         } else {
@@ -115,6 +117,16 @@ impl FieldAsserts for dml::Field {
         self
     }
 
+    fn assert_relation_on_update(&self, t: Option<dml::ReferentialAction>) -> &Self {
+        if let dml::FieldType::Relation(info) = &self.field_type {
+            assert_eq!(info.on_update, t);
+        } else {
+            panic!("Relation expected, but found {:?}", self.field_type);
+        }
+
+        self
+    }
+
     fn assert_relation_base_fields(&self, t: &[&str]) -> &Self {
         if let dml::FieldType::Relation(info) = &self.field_type {
             assert_eq!(info.fields, t);
@@ -207,6 +219,12 @@ impl ModelAsserts for dml::Model {
         self
     }
 
+    fn assert_is_view(&self, t: bool) -> &Self {
+        assert_eq!(self.is_view, t);
+
+        self
+    }
+
     fn assert_with_db_name(&self, t: &str) -> &Self {
         assert_eq!(self.database_name, Some(t.to_owned()));
 
@@ -293,7 +311,7 @@ impl ErrorAsserts for ErrorCollection {
 #[allow(dead_code)] // Not sure why the compiler thinks this is never used.
 pub fn parse(datamodel_string: &str) -> datamodel::Datamodel {
     match datamodel::parse_datamodel(datamodel_string) {
-        Ok(s) => s,
+        Ok(s) => s.subject,
         Err(errs) => {
             for err in errs.to_iter() {
                 err.pretty_print(&mut std::io::stderr().lock(), "", datamodel_string)
@@ -312,6 +330,20 @@ pub fn parse_error(datamodel_string: &str) -> ErrorCollection {
     }
 }
 
+#[allow(dead_code)] // Not sure why the compiler thinks this is never used.
+pub fn parse_warnings(datamodel_string: &str) -> Vec<DatamodelWarning> {
+    match datamodel::parse_datamodel(datamodel_string) {
+        Ok(validated) => validated.warnings,
+        Err(errs) => {
+            for err in errs.to_iter() {
+                err.pretty_print(&mut std::io::stderr().lock(), "", datamodel_string)
+                    .unwrap();
+            }
+            panic!("Datamodel parsing failed. Please see error above.")
+        }
+    }
+}
+
 pub const SQLITE_SOURCE: &'static str = r#"
     datasource db {
         provider = "sqlite"
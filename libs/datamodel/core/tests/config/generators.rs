@@ -1,5 +1,7 @@
 use crate::common::ErrorAsserts;
+use datamodel::ast::Span;
 use datamodel::error::DatamodelError;
+use datamodel::{GeneratorLoader, GeneratorOption, GeneratorOptionSchema, GeneratorOptionType};
 
 const DATAMODEL: &str = r#"
 generator js1 {
@@ -116,9 +118,144 @@ fn fail_to_load_generator_with_options_missing() {
         error.assert_is(DatamodelError::GeneratorArgumentNotFound {
             argument_name: String::from("provider"),
             generator_name: String::from("js1"),
-            span: datamodel::ast::Span::new(1, 73),
+            span: Span::new(1, 73),
         });
     } else {
         panic!("Expected error.")
     }
 }
+
+#[test]
+fn non_string_generator_options_must_be_preserved() {
+    let schema = r#"
+        generator go {
+          provider = "go"
+          debug = true
+          packages = ["a", "b"]
+          retries = 3
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let rendered = datamodel::json::mcf::generators_to_json(&config.generators);
+
+    let expected = r#"[
+        {
+          "name": "go",
+          "provider": "go",
+          "output": null,
+          "binaryTargets": [],
+          "config": {
+            "debug": true,
+            "packages": ["a", "b"],
+            "retries": "3"
+          }
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn options_of_known_generators_must_be_validated() {
+    let schema = r#"
+        generator client {
+          provider = "prisma-client-js"
+          previewFeature = ["nativeTypes"]
+          experimentalFeatures = "transactions"
+        }
+    "#;
+
+    let errors = datamodel::parse_configuration(schema).unwrap_err();
+
+    errors.assert_length(2);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_generator_validation_error(
+            "The option `previewFeature` is not known to the `prisma-client-js` generator.",
+            "client",
+            Span::new(78, 92),
+        ),
+    );
+    errors.assert_is_at(
+        1,
+        DatamodelError::new_type_mismatch_error("list of strings", "string", "transactions", Span::new(144, 158)),
+    );
+}
+
+#[test]
+fn registered_option_schemas_must_be_validated() {
+    let schema = r#"
+        generator client {
+          provider = "my-generator"
+          target = "es3"
+          flags = ["strict", "legacy"]
+        }
+    "#;
+
+    let mut loader = GeneratorLoader::new();
+    loader.register_option_schema(
+        GeneratorOptionSchema::new("my-generator")
+            .option(GeneratorOption::new("target", GeneratorOptionType::String).allowed_values(&["es2017", "es2020"]))
+            .option(GeneratorOption::new("flags", GeneratorOptionType::StringList).allowed_values(&["strict"]))
+            .option(GeneratorOption::new("mode", GeneratorOptionType::String).required()),
+    );
+
+    let ast = datamodel::parse_schema_ast(schema).unwrap();
+    let errors = loader.load_generators_from_ast(&ast).unwrap_err();
+
+    errors.assert_length(3);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_generator_validation_error(
+            "`es3` is not a valid value for the option `target`. Expected one of: es2017, es2020.",
+            "client",
+            Span::new(83, 88),
+        ),
+    );
+    errors.assert_is_at(
+        1,
+        DatamodelError::new_generator_validation_error(
+            "`legacy` is not a valid value for the option `flags`. Expected one of: strict.",
+            "client",
+            Span::new(118, 126),
+        ),
+    );
+    errors.assert_is_at(
+        2,
+        DatamodelError::new_generator_argument_not_found_error("mode", "client", Span::new(9, 137)),
+    );
+}
+
+#[test]
+fn option_schemas_can_be_passed_when_parsing_the_configuration() {
+    let schema = r#"
+        generator client {
+          provider = "my-generator"
+          target = "es3"
+        }
+    "#;
+
+    let option_schemas: Vec<GeneratorOptionSchema> = serde_json::from_str(
+        r#"[{
+            "provider": "my-generator",
+            "options": [{ "name": "target", "type": "string", "allowedValues": ["es2017", "es2020"] }]
+        }]"#,
+    )
+    .unwrap();
+
+    let errors = datamodel::parse_configuration_with_generator_schemas(schema, option_schemas, false).unwrap_err();
+
+    errors.assert_length(1);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_generator_validation_error(
+            "`es3` is not a valid value for the option `target`. Expected one of: es2017, es2020.",
+            "client",
+            Span::new(83, 88),
+        ),
+    );
+
+    // Without the schema, any option is accepted.
+    assert!(datamodel::parse_configuration(schema).is_ok());
+}
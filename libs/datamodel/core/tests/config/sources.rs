@@ -191,6 +191,53 @@ fn must_error_if_wrong_protocol_is_used_for_sqlite() {
     ));
 }
 
+#[test]
+fn must_error_if_schemas_are_used_for_mysql() {
+    let schema = r#"
+        datasource myds {
+            provider = "mysql"
+            url = "mysql://localhost"
+            schemas = ["billing"]
+        }
+    "#;
+    let config = datamodel::parse_configuration(schema);
+    assert!(config.is_err());
+    let errors = config.err().expect("This must error");
+    errors.assert_is(DatamodelError::new_source_validation_error(
+        "The `schemas` property is not supported by the `mysql` provider.",
+        "myds",
+        Span::new(118, 129),
+    ));
+}
+
+#[test]
+fn serialize_postgres_schemas_to_dmmf() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+          schemas = ["auth", "billing"]
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "connectorType": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://localhost"
+          },
+          "schemas": ["auth", "billing"]
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
 #[test]
 fn new_lines_in_source_must_work() {
     let schema = r#"
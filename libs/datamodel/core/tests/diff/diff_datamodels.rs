@@ -0,0 +1,319 @@
+use crate::common::*;
+use datamodel::diff::{diff_datamodels, Change, ChangeKind, ChangeSubject, DatamodelDiff, DatamodelVersion};
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+const PREVIOUS: &str = r#"
+model User {
+  id    Int    @id @default(autoincrement())
+  email String
+  name  String
+  role  Role   @default(USER)
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+
+model Comment {
+  id Int @id
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+"#;
+
+const NEXT: &str = r#"
+model User {
+  id    Int     @id @default(autoincrement())
+  email String  @unique
+  name  String? @map("full_name")
+  role  Role
+  age   Int
+  bio   String?
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id], onDelete: Cascade)
+}
+
+model Tag {
+  id Int @id
+}
+
+enum Role {
+  USER
+  EDITOR
+}
+"#;
+
+#[test]
+fn identical_datamodels_have_no_changes() {
+    let diff = diff_schemas(PREVIOUS, PREVIOUS);
+
+    assert!(diff.is_empty());
+    assert!(!diff.has_breaking_changes());
+    assert_eq!(diff.to_string(), "No changes.\n");
+}
+
+#[test]
+fn changes_must_be_reported_as_breaking_or_non_breaking() {
+    let diff = diff_schemas(PREVIOUS, NEXT);
+
+    let expected = indoc!(
+        r#"
+        Breaking changes:
+          - Changed field `User.name` from `String` to `String?`.
+          - Removed `@default` from field `User.role`.
+          - Added field `User.age`.
+          - Removed model `Comment`.
+          - Removed value `Role.ADMIN`.
+
+        Non-breaking changes:
+          - Added `@unique` to field `User.email`.
+          - Added `@map` to field `User.name`.
+          - Added field `User.bio`.
+          - Changed `@relation` of field `Post.author`.
+          - Added model `Tag`.
+          - Added value `Role.EDITOR`.
+        "#
+    );
+
+    assert!(diff.has_breaking_changes());
+    assert_eq!(diff.to_string(), expected);
+}
+
+#[test]
+fn renaming_compound_unique_criteria_is_breaking() {
+    let previous = r#"
+    model User {
+      id        Int    @id
+      firstName String
+      lastName  String
+
+      @@unique([firstName, lastName])
+      @@index([lastName])
+    }
+    "#;
+
+    let next = r#"
+    model User {
+      id        Int    @id
+      firstName String
+      lastName  String
+
+      @@unique([firstName, lastName], name: "fullName")
+      @@index([firstName])
+      @@map("users")
+    }
+    "#;
+
+    let diff = diff_schemas(previous, next);
+
+    let model_attribute = |attribute: &str| ChangeSubject::ModelAttribute {
+        model: String::from("User"),
+        attribute: String::from(attribute),
+    };
+
+    let kinds: Vec<(ChangeKind, ChangeSubject, bool)> = diff
+        .changes
+        .into_iter()
+        .map(|change: Change| (change.kind, change.subject, change.breaking))
+        .collect();
+
+    assert_eq!(
+        kinds,
+        vec![
+            (
+                ChangeKind::Changed,
+                model_attribute("@@unique([firstName, lastName])"),
+                true
+            ),
+            (ChangeKind::Removed, model_attribute("@@index([lastName])"), false),
+            (ChangeKind::Added, model_attribute("@@index([firstName])"), false),
+            (ChangeKind::Added, model_attribute("@@map"), false),
+        ]
+    );
+}
+
+#[test]
+fn ignored_models_and_fields_are_treated_as_absent() {
+    let previous = r#"
+    model User {
+      id       Int    @id
+      nickname String @ignore
+    }
+
+    model Legacy {
+      name String
+
+      @@ignore
+    }
+    "#;
+
+    let next = r#"
+    model User {
+      id Int @id
+    }
+    "#;
+
+    let diff = diff_schemas(previous, next);
+
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn changes_must_serialize_to_json() {
+    let previous = r#"
+    model User {
+      id Int @id
+    }
+    "#;
+
+    let next = r#"
+    model User {
+      id   Int    @id
+      name String
+    }
+    "#;
+
+    let diff = diff_schemas(previous, next);
+
+    let expected = json!({
+        "changes": [
+            {
+                "kind": "added",
+                "subject": "field",
+                "model": "User",
+                "field": "name",
+                "breaking": true,
+                "description": "Added field `User.name`."
+            }
+        ]
+    });
+
+    assert_eq!(diff.to_json_value(), expected);
+}
+
+#[test]
+fn changes_to_composite_types_must_be_reported() {
+    let previous = r#"
+    datasource db {
+      provider = "postgres"
+      url      = "postgresql://localhost/test"
+    }
+
+    model User {
+      id      Int     @id
+      address Address
+    }
+
+    type Address {
+      street String
+      zip    Int
+    }
+
+    type Location {
+      lat Float
+    }
+    "#;
+
+    let next = r#"
+    datasource db {
+      provider = "postgres"
+      url      = "postgresql://localhost/test"
+    }
+
+    model User {
+      id      Int     @id
+      address Address
+    }
+
+    type Address {
+      street  String
+      zip     String
+      city    String
+      country String?
+    }
+
+    type Phone {
+      number String
+    }
+    "#;
+
+    let diff = diff_schemas(previous, next);
+
+    let expected = indoc!(
+        r#"
+        Breaking changes:
+          - Changed field `Address.zip` from `Int` to `String`.
+          - Added field `Address.city`.
+          - Removed composite type `Location`.
+
+        Non-breaking changes:
+          - Added field `Address.country`.
+          - Added composite type `Phone`.
+        "#
+    );
+
+    assert_eq!(diff.to_string(), expected);
+}
+
+#[test]
+fn back_relation_fields_added_during_validation_must_be_diffed() {
+    let previous = r#"
+    model User {
+      id Int @id
+    }
+
+    model Post {
+      id       Int  @id
+      authorId Int
+      author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    let next = r#"
+    model User {
+      id Int @id
+    }
+
+    model Post {
+      id Int @id
+    }
+    "#;
+
+    let diff = diff_schemas(previous, next);
+
+    let expected = indoc!(
+        r#"
+        Breaking changes:
+          - Removed field `User.Post`.
+          - Removed field `Post.authorId`.
+          - Removed field `Post.author`.
+        "#
+    );
+
+    assert_eq!(diff.to_string(), expected);
+}
+
+fn diff_schemas(previous: &str, next: &str) -> DatamodelDiff {
+    let previous_ast = datamodel::parse_schema_ast(previous).unwrap();
+    let next_ast = datamodel::parse_schema_ast(next).unwrap();
+    let previous_datamodel = parse(previous);
+    let next_datamodel = parse(next);
+
+    diff_datamodels(
+        DatamodelVersion::new(&previous_ast, &previous_datamodel),
+        DatamodelVersion::new(&next_ast, &next_datamodel),
+    )
+}
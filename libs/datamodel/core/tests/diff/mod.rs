@@ -0,0 +1 @@
+pub mod diff_datamodels;
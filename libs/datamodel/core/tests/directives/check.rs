@@ -0,0 +1,104 @@
+use datamodel::{ast::Span, error::*, render_datamodel_to_string, CheckConstraint};
+
+use crate::common::*;
+
+#[test]
+fn basic_check_must_work() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check("price > 0")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let product_model = schema.assert_has_model("Product");
+
+    assert_eq!(
+        product_model.check_constraints,
+        vec![CheckConstraint {
+            name: None,
+            expression: "price > 0".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn multiple_checks_and_the_name_argument_must_work() {
+    let dml = r#"
+    model Product {
+        id       Int @id
+        price    Int
+        discount Int
+
+        @@check("price > 0")
+        @@check(expression: "discount < price", name: "discount_below_price")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let product_model = schema.assert_has_model("Product");
+
+    assert_eq!(
+        product_model.check_constraints,
+        vec![
+            CheckConstraint {
+                name: None,
+                expression: "price > 0".to_string(),
+            },
+            CheckConstraint {
+                name: Some("discount_below_price".to_string()),
+                expression: "discount < price".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn empty_check_expressions_must_error() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check("")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The check constraint expression must not be empty.",
+        "check",
+        Span::new(72, 81),
+    ));
+}
+
+#[test]
+fn check_directives_must_roundtrip() {
+    let dml = r#"
+    model Product {
+        id    Int    @id
+        name  String
+        price Int
+
+        @@check("price > 0")
+        @@check("name <> \"\"", name: "name_not_empty")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+    let reparsed = parse(&rendered);
+
+    assert_eq!(
+        reparsed.assert_has_model("Product").check_constraints,
+        schema.assert_has_model("Product").check_constraints
+    );
+    assert_eq!(
+        reparsed.assert_has_model("Product").check_constraints[1].expression,
+        "name <> \"\""
+    );
+}
@@ -94,3 +94,141 @@ fn should_error_if_unknown_function_is_used() {
         Span::new(68, 86),
     ));
 }
+
+#[test]
+fn should_error_if_id_generator_function_is_used_on_non_string_field() {
+    let dml = r#"
+    model Model {
+        id Int @id @default(ulid())
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    let start = dml.find("ulid()").unwrap();
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The function `ulid()` cannot be used on fields of type `Int`.",
+        "default",
+        Span::new(start, start + "ulid()".len()),
+    ));
+}
+
+#[test]
+fn should_error_on_unsupported_uuid_version() {
+    let dml = r#"
+    model Model {
+        id String @id @default(uuid(5))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    let start = dml.find("uuid(5)").unwrap();
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The function `uuid` only supports the versions 4 and 7.",
+        "default",
+        Span::new(start, start + "uuid(5)".len()),
+    ));
+}
+
+#[test]
+fn should_error_on_invalid_nanoid_size() {
+    let dml = r#"
+    model Model {
+        id String @id @default(nanoid(0))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    let start = dml.find("nanoid(0)").unwrap();
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The size of `nanoid` must be between 1 and 255.",
+        "default",
+        Span::new(start, start + "nanoid(0)".len()),
+    ));
+}
+
+#[test]
+fn should_error_on_too_long_nanoid_size() {
+    let dml = r#"
+    model Model {
+        id String @id @default(nanoid(256))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    let start = dml.find("nanoid(256)").unwrap();
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The size of `nanoid` must be between 1 and 255.",
+        "default",
+        Span::new(start, start + "nanoid(256)".len()),
+    ));
+}
+
+#[test]
+fn should_error_on_arguments_to_functions_without_arguments() {
+    let dml = r#"
+    model Model {
+        id String @id @default(cuid(10))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    let start = dml.find("cuid(10)").unwrap();
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The function `cuid` does not take any arguments.",
+        "default",
+        Span::new(start, start + "cuid(10)".len()),
+    ));
+}
+
+#[test]
+fn should_error_on_sequence_without_name() {
+    let dml = format!(
+        r#"
+    {}
+
+    model Model {{
+        id Int @id @default(sequence())
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+    let start = dml.find("sequence()").unwrap();
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The function `sequence` expects the name of a sequence as its only argument.",
+        "default",
+        Span::new(start, start + "sequence()".len()),
+    ));
+}
+
+#[test]
+fn should_error_on_sequence_outside_of_postgres() {
+    let dml = format!(
+        r#"
+    {}
+
+    model Model {{
+        id Int @id @default(sequence("model_id_seq"))
+    }}
+    "#,
+        MYSQL_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+    let directive = r#"default(sequence("model_id_seq"))"#;
+    let start = dml.find(directive).unwrap();
+
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "Field `id` in model `Model` can't use a `sequence()` default. Sequences are only supported on Postgres.",
+        "Model",
+        "id",
+        Span::new(start, start + directive.len()),
+    ));
+}
@@ -118,3 +118,127 @@ fn db_generated_function_must_work_for_enum_fields() {
         .assert_enum_type("Role")
         .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated()));
 }
+
+#[test]
+fn should_set_default_on_id_generator_functions() {
+    let dml = r#"
+    model Model {
+        id        String @id @default(uuid(7))
+        legacyId  String @default(uuid(4))
+        ulid      String @default(ulid())
+        shortId   String @default(nanoid(10))
+        defaultId String @default(nanoid())
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let model = datamodel.assert_has_model("Model");
+
+    model
+        .assert_has_field("id")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(7)]).unwrap(),
+        ));
+    model
+        .assert_has_field("legacyId")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(4)]).unwrap(),
+        ));
+    model
+        .assert_has_field("ulid")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("ulid".to_owned(), vec![]).unwrap(),
+        ));
+    model
+        .assert_has_field("shortId")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("nanoid".to_owned(), vec![PrismaValue::Int(10)]).unwrap(),
+        ));
+    model
+        .assert_has_field("defaultId")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("nanoid".to_owned(), vec![]).unwrap(),
+        ));
+}
+
+#[test]
+fn id_generator_functions_must_generate_values() {
+    match ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(7)])
+        .unwrap()
+        .generate()
+    {
+        Some(PrismaValue::Uuid(uuid)) => assert_eq!(uuid.get_version_num(), 7),
+        value => panic!("Expected a uuid, but got {:?}", value),
+    }
+
+    match ValueGenerator::new("ulid".to_owned(), vec![]).unwrap().generate() {
+        Some(PrismaValue::String(ulid)) => {
+            assert_eq!(ulid.len(), 26);
+            assert!(ulid.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()));
+        }
+        value => panic!("Expected a string, but got {:?}", value),
+    }
+
+    match ValueGenerator::new("nanoid".to_owned(), vec![PrismaValue::Int(10)])
+        .unwrap()
+        .generate()
+    {
+        Some(PrismaValue::String(nanoid)) => assert_eq!(nanoid.len(), 10),
+        value => panic!("Expected a string, but got {:?}", value),
+    }
+
+    match ValueGenerator::new("nanoid".to_owned(), vec![]).unwrap().generate() {
+        Some(PrismaValue::String(nanoid)) => assert_eq!(nanoid.len(), 21),
+        value => panic!("Expected a string, but got {:?}", value),
+    }
+}
+
+#[test]
+fn value_generators_are_compared_by_what_they_generate() {
+    let generator = |name: &str, args: Vec<PrismaValue>| ValueGenerator::new(name.to_owned(), args).unwrap();
+
+    assert!(generator("uuid", vec![]) == generator("uuid", vec![PrismaValue::Int(4)]));
+    assert!(generator("uuid", vec![]) != generator("uuid", vec![PrismaValue::Int(7)]));
+    assert!(generator("nanoid", vec![]) == generator("nanoid", vec![PrismaValue::Int(21)]));
+    assert!(generator("nanoid", vec![PrismaValue::Int(10)]) != generator("nanoid", vec![PrismaValue::Int(11)]));
+    assert!(
+        generator("sequence", vec![PrismaValue::String("a".into())])
+            != generator("sequence", vec![PrismaValue::String("b".into())])
+    );
+}
+
+#[test]
+fn should_set_default_on_sequence_function_on_postgres() {
+    let dml = format!(
+        r#"
+    {}
+
+    model Model {{
+        id      Int    @id @default(sequence("model_id_seq"))
+        counter BigInt @default(sequence("counter_seq"))
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let datamodel = parse(&dml);
+    let model = datamodel.assert_has_model("Model");
+
+    model
+        .assert_has_field("id")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new(
+                "sequence".to_owned(),
+                vec![PrismaValue::String("model_id_seq".to_owned())],
+            )
+            .unwrap(),
+        ));
+
+    match &model.find_field("counter").unwrap().default_value {
+        Some(DefaultValue::Expression(generator)) => {
+            assert_eq!(generator.sequence_name(), Some("counter_seq"));
+            assert_eq!(generator.generate(), None);
+        }
+        value => panic!("Expected a sequence default, but got {:?}", value),
+    }
+}
@@ -0,0 +1,157 @@
+use datamodel::{ast::Span, error::*};
+
+use crate::common::*;
+
+#[test]
+fn ignore_directives_must_work() {
+    let dml = r#"
+    model User {
+        id       Int    @id
+        nickname String @ignore
+    }
+
+    model Legacy {
+        name String
+
+        @@ignore
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    let user = schema.assert_has_model("User");
+    assert!(!user.is_ignored);
+    assert!(user.assert_has_field("nickname").is_ignored);
+    assert!(!user.assert_has_field("id").is_ignored);
+
+    assert!(schema.assert_has_model("Legacy").is_ignored);
+}
+
+#[test]
+fn models_that_are_not_ignored_still_need_a_unique_criteria() {
+    let dml = r#"
+    model Legacy {
+        name String
+    }
+    "#;
+
+    parse_error(dml).assert_is(DatamodelError::new_model_validation_error(
+        "Each model must have at least one unique criteria. Either mark a single field with `@id`, `@unique` or add a multi field criterion with `@@id([])` or `@@unique([])` to the model.",
+        "Legacy",
+        Span::new(5, 45),
+    ));
+}
+
+#[test]
+fn relation_fields_pointing_to_ignored_models_must_be_ignored() {
+    let dml = r#"
+    model Post {
+        id     Int    @id
+        userId Int
+        user   Legacy @relation(fields: [userId], references: [id])
+    }
+
+    model Legacy {
+        id    Int    @id
+        posts Post[]
+
+        @@ignore
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "The relation field `user` on Model `Post` must specify the `@ignore` attribute, because the model `Legacy` it is pointing to is marked ignored.",
+        "Post",
+        "user",
+        Span::new(71, 131),
+    ));
+}
+
+#[test]
+fn generated_back_relation_fields_of_ignored_models_are_ignored() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    model Legacy {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+
+        @@ignore
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    let back_relation_field = schema
+        .assert_has_model("User")
+        .fields()
+        .find(|field| field.points_to_model("Legacy"))
+        .unwrap();
+
+    assert!(back_relation_field.is_generated);
+    assert!(back_relation_field.is_ignored);
+}
+
+#[test]
+fn ignore_directives_are_rendered() {
+    let input = r#"
+        model User {
+            id       Int    @id
+            nickname String @ignore
+        }
+
+        model Legacy {
+            name String
+
+            @@ignore
+        }
+    "#;
+
+    let expected = r#"model User {
+  id       Int    @id
+  nickname String @ignore
+}
+
+model Legacy {
+  name String
+
+  @@ignore
+}"#;
+
+    let dml = parse(input);
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    assert_eq!(rendered, expected);
+}
+
+#[test]
+fn ignored_models_and_fields_are_not_part_of_the_dmmf() {
+    let dml = r#"
+    model User {
+        id       Int    @id
+        nickname String @ignore
+    }
+
+    model Legacy {
+        name String
+
+        @@ignore
+    }
+    "#;
+
+    let dmmf = datamodel::json::dmmf::render_to_dmmf_value(&parse(dml));
+    let models = dmmf["models"].as_array().unwrap();
+
+    assert_eq!(models.len(), 1);
+    assert_eq!(models[0]["name"], "User");
+
+    let fields = models[0]["fields"].as_array().unwrap();
+
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0]["name"], "id");
+}
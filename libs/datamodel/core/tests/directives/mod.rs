@@ -1,16 +1,20 @@
 pub mod builtin_directives;
+pub mod check;
 pub mod default_negative;
 pub mod default_positive;
 pub mod id_negative;
 pub mod id_positive;
+pub mod ignore;
 pub mod index;
 pub mod map;
+pub mod referential_actions;
 pub mod relations_basic;
 pub mod relations_consistency;
 pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_new;
 pub mod relations_positive;
+pub mod schema;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
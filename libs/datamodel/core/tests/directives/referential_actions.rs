@@ -0,0 +1,152 @@
+use crate::common::*;
+use datamodel::{ast::Span, dml, error::DatamodelError, render_datamodel_to_string};
+
+#[test]
+fn referential_actions_must_be_parsed() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: Restrict, onUpdate: Cascade)
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_field("user")
+        .assert_relation_on_delete(Some(dml::ReferentialAction::Restrict))
+        .assert_relation_on_update(Some(dml::ReferentialAction::Cascade));
+
+    schema
+        .assert_has_model("User")
+        .assert_has_field("posts")
+        .assert_relation_on_delete(None)
+        .assert_relation_on_update(None);
+}
+
+#[test]
+fn all_referential_actions_must_be_accepted() {
+    for action in &["Cascade", "Restrict", "NoAction", "SetNull", "SetDefault"] {
+        let dml = format!(
+            r#"
+            model User {{
+                id    Int    @id
+                posts Post[]
+            }}
+
+            model Post {{
+                id     Int   @id
+                userId Int?
+                user   User? @relation(fields: [userId], references: [id], onDelete: {action}, onUpdate: {action})
+            }}
+            "#,
+            action = action
+        );
+
+        let schema = parse(&dml);
+        let expected = <dml::ReferentialAction as dml::Parsable>::parse(action);
+
+        schema
+            .assert_has_model("Post")
+            .assert_has_field("user")
+            .assert_relation_on_delete(expected)
+            .assert_relation_on_update(expected);
+    }
+}
+
+#[test]
+fn referential_actions_must_be_rendered() {
+    let dml = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id     Int  @id
+  userId Int
+  user   User @relation(fields: [userId], references: [id], onDelete: Cascade, onUpdate: NoAction)
+}"#;
+
+    let rendered = render_datamodel_to_string(&parse(dml)).unwrap();
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn referential_actions_must_be_on_the_side_providing_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(onDelete: Cascade)
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The relation field `posts` on Model `User` must not specify the `onDelete` or `onUpdate` argument in the @relation directive. Referential actions can only be specified on the side of the relation that provides the `fields` argument.",
+        "@relation",
+        Span::new(51, 93),
+    ));
+}
+
+#[test]
+fn set_null_must_not_be_used_on_required_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: SetNull)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The relation field `user` on Model `Post` uses the referential action `SetNull`, but the fields `userId` are required. Make them optional or choose a different referential action.",
+        "@relation",
+        Span::new(139, 216),
+    ));
+}
+
+#[test]
+fn unknown_referential_actions_must_error() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: Explode)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_literal_parser_error(
+        "referential action",
+        "Explode",
+        Span::new(207, 214),
+    ));
+}
@@ -82,9 +82,8 @@ fn settings_must_be_deteced() {
         .assert_has_field("parent_todo")
         .assert_relation_to("Todo")
         .assert_relation_to_fields(&["id"])
-        .assert_arity(&dml::FieldArity::Optional);
-    // TODO: bring `onDelete` back once `prisma migrate` is a thing
-    //        .assert_relation_delete_strategy(dml::OnDeleteStrategy::Cascade);
+        .assert_arity(&dml::FieldArity::Optional)
+        .assert_relation_on_delete(None);
 }
 
 #[test]
@@ -0,0 +1,137 @@
+use datamodel::{ast::Span, error::*, render_datamodel_and_config_to_string};
+
+use crate::common::*;
+
+#[test]
+fn schema_directive_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432"
+        schemas  = ["auth", "billing"]
+    }
+
+    model User {
+        id Int @id
+
+        @@schema("auth")
+    }
+
+    model Invoice {
+        id Int @id
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    assert_eq!(schema.assert_has_model("User").schema, Some("auth".to_string()));
+    assert_eq!(schema.assert_has_model("Invoice").schema, None);
+}
+
+#[test]
+fn relations_across_schemas_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432"
+        schemas  = ["auth", "billing"]
+    }
+
+    model User {
+        id       Int       @id
+        invoices Invoice[]
+
+        @@schema("auth")
+    }
+
+    model Invoice {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+
+        @@schema("billing")
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("Invoice")
+        .assert_has_field("user")
+        .assert_relation_to("User");
+    assert_eq!(schema.assert_has_model("Invoice").schema, Some("billing".to_string()));
+}
+
+#[test]
+fn schemas_that_are_not_listed_on_the_datasource_must_error() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432"
+        schemas  = ["auth"]
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema("billing")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The schema `billing` is not defined in the `schemas` property of the datasource `db`.",
+        "schema",
+        Span::new(187, 204),
+    ));
+}
+
+#[test]
+fn empty_schema_names_must_error() {
+    let dml = r#"
+    model Invoice {
+        id Int @id
+
+        @@schema("")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The schema name must not be empty.",
+        "schema",
+        Span::new(51, 61),
+    ));
+}
+
+#[test]
+fn schema_directives_and_datasource_schemas_must_roundtrip() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432"
+        schemas  = ["auth", "billing"]
+    }
+
+    model User {
+        id Int @id
+
+        @@schema("auth")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let config = datamodel::parse_configuration(dml).unwrap();
+    let rendered = render_datamodel_and_config_to_string(&schema, &config).unwrap();
+
+    let reparsed = parse(&rendered);
+    let reparsed_config = datamodel::parse_configuration(&rendered).unwrap();
+
+    assert_eq!(reparsed.assert_has_model("User").schema, Some("auth".to_string()));
+    assert_eq!(
+        reparsed_config.datasources[0].schemas(),
+        &["auth".to_string(), "billing".to_string()]
+    );
+}
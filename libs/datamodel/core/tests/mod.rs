@@ -2,6 +2,7 @@ pub mod base;
 pub mod capabilities;
 pub mod common;
 pub mod config;
+pub mod diff;
 pub mod directives;
 pub mod functions;
 pub mod parsing;
@@ -159,7 +159,7 @@ fn nice_error_broken_field_type_legacy_required() {
 }
 
 #[test]
-fn nice_error_legacy_model_decl() {
+fn nice_error_attributes_on_composite_type_fields() {
     let dml = r#"
     type User {
         id Int @id
@@ -167,9 +167,10 @@ fn nice_error_legacy_model_decl() {
 
     let error = parse_error(dml);
 
-    error.assert_is(DatamodelError::new_legacy_parser_error(
-        "Model declarations have to be indicated with the `model` keyword.",
-        Span::new(5, 9),
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Fields of composite types cannot have attributes.",
+        "id",
+        Span::new(33, 35),
     ));
 }
 
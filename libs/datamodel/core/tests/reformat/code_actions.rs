@@ -0,0 +1,252 @@
+use datamodel::ast::{
+    code_actions::{code_actions, CodeAction},
+    text_edit::apply_edits,
+    Span,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn adds_the_missing_opposite_relation_field() {
+    let input = r#"
+model Blog {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id Int @id
+}
+"#;
+
+    let expected = r#"
+model Blog {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id Int @id
+  Blog Blog? @relation(fields: [blogId], references: [id])
+  blogId Int?
+}
+"#;
+
+    assert_code_action(
+        input,
+        "posts Post[]",
+        "Add the opposite relation field `Blog` to model `Post`",
+        expected,
+    );
+}
+
+#[test]
+fn adds_relation_arguments_and_foreign_key_fields() {
+    let input = r#"
+model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id     Int  @id
+  author User
+}
+"#;
+
+    let expected = r#"
+model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id     Int  @id
+  author User @relation(fields: [authorId], references: [id])
+  authorId Int
+}
+"#;
+
+    assert_code_action(
+        input,
+        "author User",
+        "Add `fields: [authorId], references: [id]` to the relation field `author`",
+        expected,
+    );
+}
+
+#[test]
+fn adds_unique_to_the_referenced_field() {
+    let input = r#"
+model User {
+  id    Int    @id
+  email String
+  posts Post[]
+}
+
+model Post {
+  id          Int    @id
+  authorEmail String
+  author      User   @relation(fields: [authorEmail], references: [email])
+}
+"#;
+
+    let expected = r#"
+model User {
+  id    Int    @id
+  email String @unique
+  posts Post[]
+}
+
+model Post {
+  id          Int    @id
+  authorEmail String
+  author      User   @relation(fields: [authorEmail], references: [email])
+}
+"#;
+
+    assert_code_action(
+        input,
+        "author      User",
+        "Add `@unique` to the field `email` of model `User`",
+        expected,
+    );
+}
+
+#[test]
+fn adds_an_id_field() {
+    let input = r#"
+model User {
+  name String
+}
+"#;
+
+    let expected = r#"
+model User {
+  id Int @id @default(autoincrement())
+  name String
+}
+"#;
+
+    assert_code_action(input, "model User", "Add an `id` field to model `User`", expected);
+}
+
+#[test]
+fn adds_an_id_field_to_a_model_without_fields() {
+    let input = r#"
+model User {}
+
+model Post {
+}
+"#;
+
+    let expected = r#"
+model User {
+  id Int @id @default(autoincrement())
+}
+
+model Post {
+}
+"#;
+
+    assert_code_action(input, "model User", "Add an `id` field to model `User`", expected);
+
+    let expected = r#"
+model User {}
+
+model Post {
+  id Int @id @default(autoincrement())
+}
+"#;
+
+    assert_code_action(input, "model Post", "Add an `id` field to model `Post`", expected);
+}
+
+#[test]
+fn marks_an_existing_id_field_as_id() {
+    let input = r#"
+model User {
+  id   Int
+  name String
+}
+"#;
+
+    let expected = r#"
+model User {
+  id   Int @id
+  name String
+}
+"#;
+
+    assert_code_action(
+        input,
+        "model User",
+        "Add `@id` to the field `id` of model `User`",
+        expected,
+    );
+}
+
+#[test]
+fn renames_duplicate_relations() {
+    let input = r#"
+model User {
+  id Int @id
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  editorId Int
+  author   User @relation(fields: [authorId], references: [id])
+  editor   User @relation(fields: [editorId], references: [id])
+}
+"#;
+
+    let expected = r#"
+model User {
+  id Int @id
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  editorId Int
+  author   User @relation(fields: [authorId], references: [id])
+  editor   User @relation("PostEditor", fields: [editorId], references: [id])
+}
+"#;
+
+    assert_code_action(
+        input,
+        "editor   User",
+        "Rename the relation of field `editor` to `PostEditor`",
+        expected,
+    );
+}
+
+#[test]
+fn scalar_fields_of_valid_models_have_no_code_actions() {
+    let input = r#"
+model User {
+  id   Int    @id
+  name String
+}
+"#;
+
+    assert_eq!(code_actions_at(input, "name String"), Vec::new());
+}
+
+fn code_actions_at(schema: &str, needle: &str) -> Vec<CodeAction> {
+    let position = schema.find(needle).expect("The needle is not part of the schema.");
+
+    code_actions(schema, Span::new(position, position))
+}
+
+fn assert_code_action(schema: &str, needle: &str, title: &str, expected: &str) {
+    let actions = code_actions_at(schema, needle);
+    let action = actions
+        .iter()
+        .find(|action| action.title == title)
+        .unwrap_or_else(|| panic!("There is no code action `{}` in {:?}", title, actions));
+
+    assert_eq!(apply_edits(schema, &action.edits), expected);
+}
@@ -1,2 +1,4 @@
+pub mod code_actions;
 pub mod reformat;
 pub mod reformat_implicit_relations;
+pub mod rename;
@@ -28,6 +28,24 @@ fn test_reformat_model_simple() {
     assert_reformat(input, expected);
 }
 
+#[test]
+fn test_reformat_view() {
+    let input = r#"
+        view UserSummary { 
+            id     Int  @id 
+            postCount   Int
+        }
+    "#;
+
+    let expected = r#"view UserSummary {
+  id        Int @id
+  postCount Int
+}
+"#;
+
+    assert_reformat(input, expected);
+}
+
 #[test]
 fn test_reformat_model_complex() {
     let input = r#"
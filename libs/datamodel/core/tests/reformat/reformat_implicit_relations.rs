@@ -111,6 +111,35 @@ model Post {
     assert_reformat(input, expected);
 }
 
+#[test]
+fn back_relation_fields_must_be_added_across_files() {
+    let blog = r#"model Blog {
+  id    Int    @id
+  posts Post[]
+}
+"#;
+
+    let post = r#"model Post {
+  id Int @id
+}
+"#;
+
+    let mut files = datamodel::SchemaFiles::new();
+    files.add("blog.prisma", blog);
+    files.add("post.prisma", post);
+
+    let expected_post = r#"model Post {
+  id     Int   @id
+  Blog   Blog? @relation(fields: [blogId], references: [id])
+  blogId Int?
+}
+"#;
+
+    let result = datamodel::ast::reformat::Reformatter::reformat_files(&files, 2);
+
+    assert_eq!(result, vec![blog.to_owned(), expected_post.to_owned()]);
+}
+
 fn assert_reformat(schema: &str, expected_result: &str) {
     println!("schema: {:?}", schema);
     let result = datamodel::ast::reformat::Reformatter::new(&schema).reformat_to_string();
@@ -0,0 +1,336 @@
+extern crate datamodel;
+use datamodel::ast::rename::{apply_edits, rename, RenameTarget};
+use datamodel::ast::WithSpan;
+use datamodel::error::DatamodelError;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn renaming_a_model_must_update_all_field_types() {
+    let input = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id       Int   @id
+  authorId Int
+  author   User? @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    let expected = r#"model Account {
+  id    Int    @id
+  posts Post[]
+  @@map("User")
+}
+
+model Post {
+  id       Int   @id
+  authorId Int
+  author   Account? @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    assert_rename(input, RenameTarget::Model("User".to_owned()), "Account", true, expected);
+}
+
+#[test]
+fn renaming_a_model_must_not_add_a_map_if_not_requested_or_already_present() {
+    let input = r#"model User {
+  id Int @id
+
+  @@map("users")
+}
+
+model Post {
+  id     Int  @id
+  author User
+}
+"#;
+
+    let expected = r#"model Account {
+  id Int @id
+
+  @@map("users")
+}
+
+model Post {
+  id     Int  @id
+  author Account
+}
+"#;
+
+    assert_rename(input, RenameTarget::Model("User".to_owned()), "Account", true, expected);
+    assert_rename(
+        input,
+        RenameTarget::Model("User".to_owned()),
+        "Account",
+        false,
+        expected,
+    );
+}
+
+#[test]
+fn renaming_a_field_must_update_indexes_and_relation_references() {
+    let input = r#"model User {
+  id    Int    @id
+  email String @unique
+  posts Post[]
+
+  @@index([email])
+}
+
+model Post {
+  id          Int    @id
+  authorEmail String
+  author      User   @relation(fields: [authorEmail], references: [email])
+}
+"#;
+
+    let expected = r#"model User {
+  id    Int    @id
+  mail String @unique @map("email")
+  posts Post[]
+
+  @@index([mail])
+}
+
+model Post {
+  id          Int    @id
+  authorEmail String
+  author      User   @relation(fields: [authorEmail], references: [mail])
+}
+"#;
+
+    assert_rename(
+        input,
+        RenameTarget::Field("User".to_owned(), "email".to_owned()),
+        "mail",
+        true,
+        expected,
+    );
+}
+
+#[test]
+fn renaming_a_field_must_update_relation_fields() {
+    let input = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+
+  @@unique([id, authorId])
+}
+"#;
+
+    let expected = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  writerId Int
+  author   User @relation(fields: [writerId], references: [id])
+
+  @@unique([id, writerId])
+}
+"#;
+
+    assert_rename(
+        input,
+        RenameTarget::Field("Post".to_owned(), "authorId".to_owned()),
+        "writerId",
+        false,
+        expected,
+    );
+}
+
+#[test]
+fn renaming_a_relation_field_must_not_add_a_map() {
+    let input = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    let expected = r#"model User {
+  id    Int    @id
+  articles Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    assert_rename(
+        input,
+        RenameTarget::Field("User".to_owned(), "posts".to_owned()),
+        "articles",
+        true,
+        expected,
+    );
+}
+
+#[test]
+fn renaming_an_enum_must_update_all_field_types() {
+    let input = r#"model User {
+  id   Int   @id
+  role Role  @default(USER)
+  old  Role?
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+"#;
+
+    let expected = r#"model User {
+  id   Int   @id
+  role UserRole  @default(USER)
+  old  UserRole?
+}
+
+enum UserRole {
+  USER
+  ADMIN
+  @@map("Role")
+}
+"#;
+
+    assert_rename(input, RenameTarget::Enum("Role".to_owned()), "UserRole", true, expected);
+}
+
+#[test]
+fn renaming_an_enum_value_must_update_default_values() {
+    let input = r#"model User {
+  id   Int  @id
+  role Role @default(USER)
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+"#;
+
+    let expected = r#"model User {
+  id   Int  @id
+  role Role @default(MEMBER)
+}
+
+enum Role {
+  MEMBER @map("USER")
+  ADMIN
+}
+"#;
+
+    assert_rename(
+        input,
+        RenameTarget::EnumValue("Role".to_owned(), "USER".to_owned()),
+        "MEMBER",
+        true,
+        expected,
+    );
+}
+
+#[test]
+fn renaming_must_fail_if_the_new_name_is_taken() {
+    let input = r#"model User {
+  id   Int  @id
+  name String
+}
+
+model Post {
+  id Int @id
+}
+"#;
+
+    let schema = datamodel::parse_schema_ast(input).unwrap();
+
+    let error = rename(&schema, &RenameTarget::Model("User".to_owned()), "Post", false).unwrap_err();
+    assert_eq!(
+        error,
+        DatamodelError::new_validation_error(
+            "The name `Post` is already used by another declaration.",
+            *schema.find_model("Post").unwrap().span()
+        )
+    );
+
+    let error = rename(
+        &schema,
+        &RenameTarget::Field("User".to_owned(), "id".to_owned()),
+        "name",
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error,
+        DatamodelError::new_duplicate_field_error("User", "name", schema.find_field("User", "name").unwrap().name.span)
+    );
+}
+
+#[test]
+fn renaming_a_model_must_keep_the_names_of_its_many_to_many_relations() {
+    let input = r#"model Post {
+  id         Int        @id
+  categories Category[]
+  tags       Tag[]      @relation("PostTags")
+  related    Post[]     @relation()
+  relatedBy  Post[]
+}
+
+model Category {
+  id    Int    @id
+  posts Post[]
+}
+
+model Tag {
+  id    Int    @id
+  posts Post[] @relation("PostTags")
+}
+"#;
+
+    let expected = r#"model Article {
+  id         Int        @id
+  categories Category[] @relation("CategoryToPost")
+  tags       Tag[]      @relation("PostTags")
+  related    Article[]     @relation("PostToPost")
+  relatedBy  Article[] @relation("PostToPost")
+  @@map("Post")
+}
+
+model Category {
+  id    Int    @id
+  posts Article[] @relation("CategoryToPost")
+}
+
+model Tag {
+  id    Int    @id
+  posts Article[] @relation("PostTags")
+}
+"#;
+
+    assert_rename(input, RenameTarget::Model("Post".to_owned()), "Article", true, expected);
+}
+
+fn assert_rename(input: &str, target: RenameTarget, new_name: &str, keep_database_name: bool, expected: &str) {
+    let schema = datamodel::parse_schema_ast(input).unwrap();
+    let edits = rename(&schema, &target, new_name, keep_database_name).unwrap();
+
+    assert_eq!(apply_edits(input, &edits), expected);
+}
@@ -1,3 +1,4 @@
 pub mod parser_renderer_ast;
 pub mod parser_renderer_dml;
 pub mod render_to_dmmf;
+pub mod render_to_json_schema;
@@ -0,0 +1,124 @@
+use crate::common::*;
+use datamodel::json::json_schema::{render_to_json_schema_value, render_to_openapi_components_value};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+const DATAMODEL: &str = r#"
+/// A user of the blog.
+model User {
+  id        String   @id @default(uuid())
+  email     String   @unique
+  name      String?
+  createdAt DateTime @default(now())
+  role      Role     @default(USER)
+  tags      String[]
+  posts     Post[]
+}
+
+model Post {
+  id       Int     @id @default(autoincrement())
+  authorId String?
+  author   User?   @relation(fields: [authorId], references: [id])
+  status   Role?
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+"#;
+
+#[test]
+fn models_and_enums_are_rendered_as_json_schema_definitions() {
+    let dml = parse(DATAMODEL);
+
+    let expected = json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "definitions": {
+            "User": {
+                "type": "object",
+                "description": "A user of the blog.",
+                "properties": {
+                    "id": { "type": "string", "format": "uuid" },
+                    "email": { "type": "string" },
+                    "name": { "type": ["string", "null"] },
+                    "createdAt": { "type": "string", "format": "date-time" },
+                    "role": { "$ref": "#/definitions/Role", "default": "USER" },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "posts": { "type": "array", "items": { "$ref": "#/definitions/Post" } }
+                },
+                "required": ["id", "email", "createdAt", "role", "tags"]
+            },
+            "Post": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer", "format": "int32" },
+                    "authorId": { "type": ["string", "null"] },
+                    "author": { "anyOf": [{ "$ref": "#/definitions/User" }, { "type": "null" }] },
+                    "status": { "anyOf": [{ "$ref": "#/definitions/Role" }, { "type": "null" }] }
+                },
+                "required": ["id"]
+            },
+            "Role": {
+                "type": "string",
+                "enum": ["USER", "ADMIN"]
+            }
+        }
+    });
+
+    assert_eq!(render_to_json_schema_value(&dml), expected);
+}
+
+#[test]
+fn models_and_enums_are_rendered_as_openapi_components() {
+    let dml = parse(DATAMODEL);
+    let rendered = render_to_openapi_components_value(&dml);
+    let schemas = &rendered["components"]["schemas"];
+
+    assert_eq!(
+        schemas["User"]["properties"]["name"],
+        json!({ "type": "string", "nullable": true })
+    );
+    assert_eq!(
+        schemas["User"]["properties"]["posts"],
+        json!({ "type": "array", "items": { "$ref": "#/components/schemas/Post" } })
+    );
+    assert_eq!(
+        schemas["Post"]["properties"]["author"],
+        json!({ "allOf": [{ "$ref": "#/components/schemas/User" }], "nullable": true })
+    );
+    assert_eq!(schemas["Role"], json!({ "type": "string", "enum": ["USER", "ADMIN"] }));
+}
+
+#[test]
+fn ignored_models_and_fields_are_not_rendered() {
+    let dml = parse(
+        r#"
+        model User {
+          id     Int    @id
+          secret String @ignore
+        }
+
+        model Legacy {
+          id     Int  @id
+          userId Int
+          user   User @relation(fields: [userId], references: [id])
+
+          @@ignore
+        }
+        "#,
+    );
+
+    let rendered = render_to_json_schema_value(&dml);
+
+    assert_eq!(
+        rendered["definitions"],
+        json!({
+            "User": {
+                "type": "object",
+                "properties": { "id": { "type": "integer", "format": "int32" } },
+                "required": ["id"]
+            }
+        })
+    );
+}
@@ -0,0 +1,112 @@
+use crate::common::*;
+use datamodel::diagram::{render_dot, render_mermaid};
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+const DATAMODEL: &str = r#"
+model User {
+  id      Int      @id
+  email   String   @unique @map("email_address")
+  name    String?
+  posts   Post[]
+  profile Profile?
+
+  @@map("users")
+}
+
+model Profile {
+  id     Int  @id
+  userId Int  @unique
+  user   User @relation(fields: [userId], references: [id])
+}
+
+model Post {
+  id         Int        @id
+  authorId   Int
+  author     User       @relation(fields: [authorId], references: [id])
+  categories Category[]
+}
+
+model Category {
+  id    Int    @id
+  posts Post[]
+}
+"#;
+
+#[test]
+fn datamodels_render_as_mermaid_er_diagrams() {
+    let expected = indoc!(
+        r#"
+        erDiagram
+          User["User (users)"] {
+            Int id PK
+            String email UK "db: email_address"
+            String name "nullable"
+          }
+          Profile {
+            Int id PK
+            Int userId FK, UK
+          }
+          Post {
+            Int id PK
+            Int authorId FK
+          }
+          Category {
+            Int id PK
+          }
+          User ||--o| Profile : "ProfileToUser"
+          User ||--o{ Post : "PostToUser"
+          Post }o--o{ Category : "CategoryToPost"
+        "#
+    );
+
+    assert_eq!(render_mermaid(&parse(DATAMODEL)), expected);
+}
+
+#[test]
+fn datamodels_render_as_dot_graphs() {
+    let expected = indoc!(
+        r#"
+        digraph datamodel {
+          rankdir=LR;
+          node [shape=record];
+          "User" [label="{User\n(users)|id: Int (PK)\lemail: String (UK) (db: email_address)\lname: String?\l}"];
+          "Profile" [label="{Profile|id: Int (PK)\luserId: Int (FK, UK)\l}"];
+          "Post" [label="{Post|id: Int (PK)\lauthorId: Int (FK)\l}"];
+          "Category" [label="{Category|id: Int (PK)\l}"];
+          "Profile" -> "User" [label="ProfileToUser", taillabel="0..1", headlabel="1"];
+          "Post" -> "User" [label="PostToUser", taillabel="*", headlabel="1"];
+          "Category" -> "Post" [label="CategoryToPost", taillabel="*", headlabel="*"];
+        }
+        "#
+    );
+
+    assert_eq!(render_dot(&parse(DATAMODEL)), expected);
+}
+
+#[test]
+fn compound_ids_mark_all_their_fields_as_primary_key() {
+    let dml = r#"
+    model Membership {
+      userId  Int
+      groupId Int
+      role    String
+
+      @@id([userId, groupId])
+      @@unique([userId, role])
+    }
+    "#;
+
+    let expected = indoc!(
+        r#"
+        erDiagram
+          Membership {
+            Int userId PK
+            Int groupId PK
+            String role
+          }
+        "#
+    );
+
+    assert_eq!(render_mermaid(&parse(dml)), expected);
+}
@@ -21,7 +21,7 @@ fn strings_with_quotes_render_as_escaped_literals() {
         }"#
     );
 
-    let mut dml = datamodel::parse_datamodel(input).unwrap();
+    let mut dml = datamodel::parse_datamodel(input).unwrap().subject;
     let cat = dml.models_mut().find(|m| m.name == "Category").unwrap();
     let name = cat.fields.iter_mut().find(|f| f.name == "name").unwrap();
     name.default_value = Some(DefaultValue::Single(PrismaValue::String("a \" b\"c d".into())));
@@ -41,7 +41,7 @@ fn strings_with_quotes_roundtrip() {
         }"#
     );
 
-    let dml = datamodel::parse_datamodel(input).unwrap();
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
     let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
 
     assert_eq!(input, rendered);
@@ -65,7 +65,7 @@ fn strings_with_newlines_render_as_escaped_literals() {
         }"#
     );
 
-    let mut dml = datamodel::parse_datamodel(input).unwrap();
+    let mut dml = datamodel::parse_datamodel(input).unwrap().subject;
     let cat = dml.models_mut().find(|m| m.name == "Category").unwrap();
     let name = cat.fields.iter_mut().find(|f| f.name == "name").unwrap();
     name.default_value = Some(DefaultValue::Single(PrismaValue::String(
@@ -87,7 +87,7 @@ fn strings_with_newlines_roundtrip() {
         }"#
     );
 
-    let dml = datamodel::parse_datamodel(input).unwrap();
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
     let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
 
     assert_eq!(input, rendered);
@@ -103,7 +103,7 @@ fn strings_with_backslashes_roundtrip() {
         }"#
     );
 
-    let dml = datamodel::parse_datamodel(input).unwrap();
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
     let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
 
     assert_eq!(input, rendered);
@@ -119,7 +119,7 @@ fn strings_with_multiple_escaped_characters_roundtrip() {
         }"#
     );
 
-    let dml = datamodel::parse_datamodel(dm).unwrap();
+    let dml = datamodel::parse_datamodel(dm).unwrap().subject;
     let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
 
     assert_eq!(dm, rendered);
@@ -141,7 +141,7 @@ fn internal_escaped_values_are_rendered_correctly() {
         }"#
     );
 
-    let mut dml = datamodel::parse_datamodel(dm).unwrap();
+    let mut dml = datamodel::parse_datamodel(dm).unwrap().subject;
 
     dml.models[0].fields[0].default_value = Some(DefaultValue::Single(PrismaValue::String(
         "xyz\\Datasource\\Model".to_string(),
@@ -1,2 +1,4 @@
+pub mod diagram;
 pub mod literals;
+pub mod native_types;
 pub mod simplification;
@@ -0,0 +1,33 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn native_types_render_as_db_attributes() {
+    let input = indoc!(
+        r#"
+        datasource pg {
+          provider = "postgresql"
+          url      = "postgresql://"
+        }
+
+        model Blog {
+          id    Int     @id @db.Integer
+          title String  @db.VarChar(255)
+          price Decimal @db.Numeric(10, 2)
+        }"#
+    );
+
+    let expected = indoc!(
+        r#"
+        model Blog {
+          id    Int     @id @db.Integer
+          title String  @db.VarChar(255)
+          price Decimal @db.Numeric(10, 2)
+        }"#
+    );
+
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    assert_eq!(rendered, expected);
+}
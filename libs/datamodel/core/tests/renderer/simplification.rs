@@ -45,7 +45,7 @@ fn test_render_relation_name_on_self_relations() {
   Category_B Category[] @relation("CategoryToCategory", references: [id])
 }"#;
 
-    let dml = datamodel::parse_datamodel(input).unwrap();
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
     let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
 
     print!("{}", rendered);
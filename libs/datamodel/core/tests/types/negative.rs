@@ -108,3 +108,88 @@ fn should_fail_on_custom_related_types() {
         ast::Span::new(25, 29),
     ));
 }
+
+#[test]
+fn should_fail_on_native_types_not_supported_by_the_connector() {
+    let dml = r#"
+    datasource pg {
+      provider = "postgresql"
+      url = "postgresql://"
+    }
+
+    model Blog {
+        id Int @id @db.MediumInt
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type MediumInt is not supported for postgresql connector.",
+        "db.MediumInt",
+        ast::Span::new(123, 135),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_types_incompatible_with_the_field_type() {
+    let dml = r#"
+    datasource pg {
+      provider = "postgresql"
+      url = "postgresql://"
+    }
+
+    model Blog {
+        id   Int    @id
+        name String @db.BigInt
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type BigInt is not compatible with declared field type String, expected field type BigInt.",
+        "db.BigInt",
+        ast::Span::new(148, 157),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_types_with_the_wrong_number_of_arguments() {
+    let dml = r#"
+    datasource pg {
+      provider = "postgresql"
+      url = "postgresql://"
+    }
+
+    model Blog {
+        id   Int    @id
+        name String @db.VarChar
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type VarChar takes 1 arguments, but received 0.",
+        "db.VarChar",
+        ast::Span::new(148, 158),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_types_without_a_datasource() {
+    let dml = r#"
+    model Blog {
+        id Int @id @db.BigInt
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native types can only be used if a datasource is defined.",
+        "db.BigInt",
+        ast::Span::new(38, 47),
+    ));
+}
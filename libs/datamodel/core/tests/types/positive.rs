@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::{common::ScalarType, DefaultValue, ValueGenerator};
+use datamodel::{common::ScalarType, dml, DefaultValue, ValueGenerator};
 use datamodel_connector::ScalarFieldType;
 use prisma_value::PrismaValue;
 
@@ -104,12 +104,13 @@ fn should_be_able_to_define_custom_enum_types() {
 }
 
 #[test]
-#[ignore]
 fn should_handle_type_mappings() {
     let dml = r#"
         model Blog {
-            id     Int    @id
-            bigInt BigInt
+            id      Int     @id
+            bigInt  BigInt  @default(9007199254740993)
+            decimal Decimal @default(1.10)
+            bytes   Bytes   @default("AQID")
         }
     "#;
 
@@ -119,21 +120,29 @@ fn should_handle_type_mappings() {
 
     user_model
         .assert_has_field("bigInt")
-        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint"));
+        .assert_base_type(&ScalarType::BigInt)
+        .assert_default_value(DefaultValue::Single(PrismaValue::BigInt(9007199254740993)));
+    user_model
+        .assert_has_field("decimal")
+        .assert_base_type(&ScalarType::Decimal)
+        .assert_default_value(DefaultValue::Single(PrismaValue::Decimal("1.10".parse().unwrap())));
+    user_model
+        .assert_has_field("bytes")
+        .assert_base_type(&ScalarType::Bytes)
+        .assert_default_value(DefaultValue::Single(PrismaValue::Bytes(vec![1, 2, 3])));
 }
 
 #[test]
-#[ignore]
 fn should_handle_type_specifications() {
     let dml = r#"
         datasource pg {
-          provider = "postgres"
+          provider = "postgresql"
           url = "postgresql://"
         }
 
         model Blog {
             id     Int @id
-            bigInt Int @pg.BigInt
+            bigInt BigInt @db.BigInt
         }
     "#;
 
@@ -143,5 +152,82 @@ fn should_handle_type_specifications() {
 
     user_model
         .assert_has_field("bigInt")
-        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint"));
+        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::BigInt, "bigint"));
+}
+
+#[test]
+fn should_handle_native_types_with_arguments_and_aliases() {
+    let dml = r#"
+        datasource pg {
+          provider = "postgresql"
+          url = "postgresql://"
+        }
+
+        model Blog {
+            id        Int      @id
+            title     String   @db.VarChar(255)
+            price     Decimal  @db.Decimal(10, 2)
+            createdAt DateTime @db.Timestamptz
+        }
+    "#;
+
+    let datamodel = parse(dml);
+    let blog = datamodel.assert_has_model("Blog");
+
+    let native_type = |name: &str| match &blog.find_field(name).unwrap().field_type {
+        dml::FieldType::ConnectorSpecific(native_type) => native_type.clone(),
+        other => panic!("Connector Specific Type expected, but found {:?}", other),
+    };
+
+    let title = native_type("title");
+    assert_eq!(title.name(), "VarChar");
+    assert_eq!(title.args(), &[255]);
+    assert_eq!(title.datasource_type(), "varchar(255)");
+    assert_eq!(title.prisma_type(), ScalarType::String);
+
+    let price = native_type("price");
+    assert_eq!(price.name(), "Numeric");
+    assert_eq!(price.args(), &[10, 2]);
+    assert_eq!(price.datasource_type(), "numeric(10,2)");
+
+    let created_at = native_type("createdAt");
+    assert_eq!(created_at.name(), "Timestamptz");
+    assert!(created_at.args().is_empty());
+    assert_eq!(created_at.datasource_type(), "timestamptz");
+}
+
+#[test]
+fn should_handle_native_types_on_mysql_and_sqlite() {
+    let mysql = r#"
+        datasource db {
+          provider = "mysql"
+          url = "mysql://"
+        }
+
+        model Blog {
+            id Int @id @db.MediumInt
+        }
+    "#;
+
+    parse(mysql)
+        .assert_has_model("Blog")
+        .assert_has_field("id")
+        .assert_connector_type(&ScalarFieldType::new("MediumInt", ScalarType::Int, "mediumint"));
+
+    let sqlite = r#"
+        datasource db {
+          provider = "sqlite"
+          url = "file:dev.db"
+        }
+
+        model Blog {
+            id    Int   @id
+            price Decimal @db.Numeric
+        }
+    "#;
+
+    parse(sqlite)
+        .assert_has_model("Blog")
+        .assert_has_field("price")
+        .assert_connector_type(&ScalarFieldType::new("Numeric", ScalarType::Decimal, "NUMERIC"));
 }
@@ -9,7 +9,7 @@ pub struct DatamodelConverter<'a> {
 
 impl<'a> DatamodelConverter<'a> {
     pub fn convert_string(datamodel: String) -> InternalDataModelTemplate {
-        let datamodel = datamodel::parse_datamodel(&datamodel).unwrap();
+        let datamodel = datamodel::parse_datamodel(&datamodel).unwrap().subject;
         Self::convert(&datamodel)
     }
 
@@ -55,13 +55,17 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_models(&self) -> Vec<ModelTemplate> {
         self.datamodel
             .models()
+            .filter(|model| !model.is_ignored)
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_view: model.is_view,
                 fields: self.convert_fields(&model),
                 manifestation: model.database_name().map(|s| s.to_owned()),
                 id_field_names: model.id_fields.clone(),
                 indexes: self.convert_indexes(&model),
+                documentation: model.documentation.clone(),
+                schema: model.schema.clone(),
             })
             .collect()
     }
@@ -69,6 +73,7 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_fields(&self, model: &dml::Model) -> Vec<FieldTemplate> {
         model
             .fields()
+            .filter(|field| !field.is_ignored)
             .map(|field| match field.field_type {
                 dml::FieldType::Relation(ref ri) => {
                     let relation = self
@@ -92,6 +97,7 @@ impl<'a> DatamodelConverter<'a> {
                         relation_name: relation.name(),
                         relation_side: relation.relation_side(field),
                         relation_info: ri.clone(),
+                        documentation: field.documentation.clone(),
                     })
                 }
                 _ => FieldTemplate::Scalar(ScalarFieldTemplate {
@@ -104,9 +110,11 @@ impl<'a> DatamodelConverter<'a> {
                     is_auto_generated_int_id: field.is_auto_generated_int_id(),
                     behaviour: field.behaviour(),
                     internal_enum: field.internal_enum(self.datamodel),
+                    composite_type: field.internal_composite_type(self.datamodel),
                     db_name: field.database_name.clone(),
                     arity: field.arity,
                     default_value: field.default_value.clone(),
+                    documentation: field.documentation.clone(),
                 }),
             })
             .collect()
@@ -131,6 +139,11 @@ impl<'a> DatamodelConverter<'a> {
             .indices
             .iter()
             .filter(|i| i.fields.len() > 1) // @@unique for 1 field are transformed to is_unique instead
+            .filter(|i| {
+                i.fields
+                    .iter()
+                    .all(|name| model.find_field(name).map(|f| !f.is_ignored).unwrap_or(true))
+            })
             .map(|i| IndexTemplate {
                 name: i.name.clone(),
                 fields: i.fields.clone(),
@@ -144,8 +157,10 @@ impl<'a> DatamodelConverter<'a> {
 
     pub fn calculate_relations(datamodel: &dml::Datamodel) -> Vec<TempRelationHolder> {
         let mut result = Vec::new();
-        for model in datamodel.models() {
-            for field in model.fields() {
+        // Ignored models and fields are not part of any relation. The datamodel validation guarantees that
+        // relation fields pointing to them are ignored as well.
+        for model in datamodel.models().filter(|model| !model.is_ignored) {
+            for field in model.fields().filter(|field| !field.is_ignored) {
                 if let dml::FieldType::Relation(relation_info) = &field.field_type {
                     let dml::RelationInfo {
                         to, to_fields, name, ..
@@ -368,6 +383,7 @@ trait DatamodelFieldExtensions {
     fn final_db_name(&self) -> String;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
     fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue;
+    fn internal_composite_type(&self, datamodel: &dml::Datamodel) -> Option<InternalCompositeType>;
     // fn default_value(&self) -> Option<dml::DefaultValue>; todo this is not applicable anymore
 }
 
@@ -376,18 +392,10 @@ impl DatamodelFieldExtensions for dml::Field {
         match &self.field_type {
             dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
             dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
-            dml::FieldType::Base(scalar, _) => match scalar {
-                dml::ScalarType::Boolean => TypeIdentifier::Boolean,
-                dml::ScalarType::DateTime => TypeIdentifier::DateTime,
-                dml::ScalarType::Float => TypeIdentifier::Float,
-                dml::ScalarType::Int => TypeIdentifier::Int,
-                dml::ScalarType::String => TypeIdentifier::String,
-                dml::ScalarType::Json => TypeIdentifier::Json,
-            },
+            dml::FieldType::CompositeType(_) => TypeIdentifier::Json,
+            dml::FieldType::Base(scalar, _) => scalar_type_identifier(scalar),
+            dml::FieldType::ConnectorSpecific(sft) => scalar_type_identifier(&sft.prisma_type()),
             dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
-            dml::FieldType::ConnectorSpecific { .. } => {
-                unimplemented!("Connector Specific types are not supported here yet")
-            }
         }
     }
 
@@ -460,7 +468,45 @@ impl DatamodelFieldExtensions for dml::Field {
         }
     }
 
+    fn internal_composite_type(&self, datamodel: &dml::Datamodel) -> Option<InternalCompositeType> {
+        match self.field_type {
+            dml::FieldType::CompositeType(ref name) => {
+                datamodel
+                    .find_composite_type(name)
+                    .map(|composite_type| InternalCompositeType {
+                        name: composite_type.name.clone(),
+                        fields: composite_type
+                            .fields()
+                            .map(|field| InternalCompositeField {
+                                name: field.name.clone(),
+                                type_identifier: field.type_identifier(),
+                                is_required: field.is_required(),
+                                is_list: field.is_list(),
+                                internal_enum: field.internal_enum(datamodel),
+                                composite_type: field.internal_composite_type(datamodel),
+                            })
+                            .collect(),
+                    })
+            }
+            _ => None,
+        }
+    }
+
     // fn default_value(&self) -> Option<dml::DefaultValue> {
     //     self.default_value.clone()
     // }
 }
+
+fn scalar_type_identifier(scalar: &dml::ScalarType) -> TypeIdentifier {
+    match scalar {
+        dml::ScalarType::Boolean => TypeIdentifier::Boolean,
+        dml::ScalarType::DateTime => TypeIdentifier::DateTime,
+        dml::ScalarType::Float => TypeIdentifier::Float,
+        dml::ScalarType::Int => TypeIdentifier::Int,
+        dml::ScalarType::String => TypeIdentifier::String,
+        dml::ScalarType::Json => TypeIdentifier::Json,
+        dml::ScalarType::Decimal => TypeIdentifier::Decimal,
+        dml::ScalarType::BigInt => TypeIdentifier::BigInt,
+        dml::ScalarType::Bytes => TypeIdentifier::Bytes,
+    }
+}
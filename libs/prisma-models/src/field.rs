@@ -66,6 +66,9 @@ pub enum TypeIdentifier {
     DateTime,
     UUID,
     Int,
+    Decimal,
+    BigInt,
+    Bytes,
 }
 
 impl Field {
@@ -118,6 +121,13 @@ impl Field {
         }
     }
 
+    pub fn documentation(&self) -> Option<&str> {
+        match self {
+            Field::Scalar(ref sf) => sf.documentation.as_deref(),
+            Field::Relation(ref rf) => rf.documentation.as_deref(),
+        }
+    }
+
     pub fn model(&self) -> ModelRef {
         match self {
             Self::Scalar(sf) => sf.model(),
@@ -170,6 +180,9 @@ impl From<ScalarType> for TypeIdentifier {
             ScalarType::Boolean => Self::Boolean,
             ScalarType::DateTime => Self::DateTime,
             ScalarType::Json => Self::Json,
+            ScalarType::Decimal => Self::Decimal,
+            ScalarType::BigInt => Self::BigInt,
+            ScalarType::Bytes => Self::Bytes,
         }
     }
 }
@@ -23,6 +23,7 @@ pub struct RelationFieldTemplate {
     pub relation_name: String,
     pub relation_side: RelationSide,
     pub relation_info: RelationInfo,
+    pub documentation: Option<String>,
 }
 
 #[derive(DebugStub, Clone)]
@@ -36,6 +37,7 @@ pub struct RelationField {
     pub relation_side: RelationSide,
     pub relation: OnceCell<RelationWeakRef>,
     pub relation_info: RelationInfo,
+    pub documentation: Option<String>,
 
     #[debug_stub = "#ModelWeakRef#"]
     pub model: ModelWeakRef,
@@ -109,6 +111,7 @@ impl RelationFieldTemplate {
             model,
             relation: OnceCell::new(),
             relation_info: self.relation_info,
+            documentation: self.documentation,
             fields: OnceCell::new(),
         })
     }
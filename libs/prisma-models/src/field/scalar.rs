@@ -25,9 +25,11 @@ pub struct ScalarFieldTemplate {
     pub is_auto_generated_int_id: bool,
     pub behaviour: Option<FieldBehaviour>,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<InternalCompositeType>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    pub documentation: Option<String>,
 }
 
 #[derive(DebugStub)]
@@ -39,10 +41,12 @@ pub struct ScalarField {
     pub is_id: bool,
     pub is_auto_generated_int_id: bool,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<InternalCompositeType>,
     pub behaviour: Option<FieldBehaviour>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    pub documentation: Option<String>,
 
     #[debug_stub = "#ModelWeakRef#"]
     pub model: ModelWeakRef,
@@ -61,6 +65,7 @@ impl Hash for ScalarField {
         self.is_id.hash(state);
         self.is_auto_generated_int_id.hash(state);
         self.internal_enum.hash(state);
+        self.composite_type.hash(state);
         self.behaviour.hash(state);
         self.is_unique.hash(state);
         self.model().hash(state);
@@ -78,6 +83,7 @@ impl PartialEq for ScalarField {
             && self.is_id == other.is_id
             && self.is_auto_generated_int_id == other.is_auto_generated_int_id
             && self.internal_enum == other.internal_enum
+            && self.composite_type == other.composite_type
             && self.behaviour == other.behaviour
             && self.default_value == other.default_value
             && self.is_unique == other.is_unique
@@ -112,10 +118,12 @@ impl ScalarFieldTemplate {
             read_only: OnceCell::new(),
             is_unique: self.is_unique,
             internal_enum: self.internal_enum,
+            composite_type: self.composite_type,
             behaviour: self.behaviour,
             arity: self.arity,
             db_name: self.db_name,
             default_value: self.default_value,
+            documentation: self.documentation,
             model,
         };
 
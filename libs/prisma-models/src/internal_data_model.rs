@@ -1,6 +1,12 @@
 use crate::prelude::*;
+use chrono::{DateTime, Utc};
 use once_cell::sync::OnceCell;
-use std::sync::{Arc, Weak};
+use rust_decimal::Decimal;
+use std::{
+    str::FromStr,
+    sync::{Arc, Weak},
+};
+use uuid::Uuid;
 
 pub type InternalDataModelRef = Arc<InternalDataModel>;
 pub type InternalDataModelWeakRef = Weak<InternalDataModel>;
@@ -90,6 +96,74 @@ impl InternalEnumValue {
     }
 }
 
+/// A composite type, the values of which are stored as Json documents.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeType {
+    pub name: String,
+    pub fields: Vec<InternalCompositeField>,
+}
+
+impl InternalCompositeType {
+    pub fn find_field(&self, name: &str) -> Option<&InternalCompositeField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeField {
+    pub name: String,
+    pub type_identifier: TypeIdentifier,
+    pub is_required: bool,
+    pub is_list: bool,
+    pub internal_enum: Option<InternalEnum>,
+
+    /// Set if the field holds a nested composite type.
+    pub composite_type: Option<InternalCompositeType>,
+}
+
+impl InternalCompositeField {
+    /// Converts a single stored Json value of this field into a `PrismaValue`.
+    /// Values that do not match the type of the field are returned as Json.
+    pub fn scalar_from_json(&self, json: &serde_json::Value) -> PrismaValue {
+        use serde_json::Value;
+
+        let value = match (&self.type_identifier, json) {
+            (_, Value::Null) => Some(PrismaValue::Null),
+            (TypeIdentifier::Json, json) => Some(PrismaValue::Json(json.to_string())),
+            (TypeIdentifier::String, Value::String(s)) => Some(PrismaValue::String(s.clone())),
+            (TypeIdentifier::Enum(_), Value::String(s)) => Some(PrismaValue::Enum(s.clone())),
+            (TypeIdentifier::UUID, Value::String(s)) => Uuid::parse_str(s).ok().map(PrismaValue::Uuid),
+            (TypeIdentifier::DateTime, Value::String(s)) => DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|dt| PrismaValue::DateTime(dt.with_timezone(&Utc))),
+            (TypeIdentifier::Boolean, Value::Bool(b)) => Some(PrismaValue::Boolean(*b)),
+            (TypeIdentifier::Int, Value::Number(n)) => n.as_i64().map(PrismaValue::Int),
+            (TypeIdentifier::Float, Value::Number(n)) => Decimal::from_str(&n.to_string()).ok().map(PrismaValue::Float),
+            (TypeIdentifier::Decimal, Value::Number(n)) => {
+                Decimal::from_str(&n.to_string()).ok().map(PrismaValue::Decimal)
+            }
+            (TypeIdentifier::Decimal, Value::String(s)) => Decimal::from_str(s).ok().map(PrismaValue::Decimal),
+            (TypeIdentifier::BigInt, Value::Number(n)) => n.as_i64().map(PrismaValue::BigInt),
+            (TypeIdentifier::BigInt, Value::String(s)) => s.parse().ok().map(PrismaValue::BigInt),
+            (TypeIdentifier::Bytes, Value::String(s)) => decode_bytes(s).ok().map(PrismaValue::Bytes),
+            _ => None,
+        };
+
+        value.unwrap_or_else(|| PrismaValue::Json(json.to_string()))
+    }
+
+    /// Converts a value of this field into its stored Json representation.
+    pub fn scalar_to_json(&self, value: PrismaValue) -> serde_json::Value {
+        match value {
+            PrismaValue::Json(s) => serde_json::from_str(&s).unwrap_or_else(|_| serde_json::Value::String(s)),
+            PrismaValue::List(values) => {
+                serde_json::Value::Array(values.into_iter().map(|value| self.scalar_to_json(value)).collect())
+            }
+            value => serde_json::to_value(&value).unwrap_or(serde_json::Value::Null),
+        }
+    }
+}
+
 impl InternalDataModelTemplate {
     pub fn build(self, db_name: String) -> InternalDataModelRef {
         let internal_data_model = Arc::new(InternalDataModel {
@@ -163,6 +237,16 @@ impl InternalDataModel {
             .collect()
     }
 
+    /// All non-list relation fields on other models (or self relations) that point to `model`.
+    pub fn fields_referencing_model(&self, model: &ModelRef) -> Vec<RelationFieldRef> {
+        self.relation_fields()
+            .iter()
+            .filter(|rf| &rf.related_model() == model)
+            .filter(|f| !f.is_list)
+            .map(|f| Arc::clone(f))
+            .collect()
+    }
+
     pub fn relation_fields(&self) -> &[RelationFieldRef] {
         self.relation_fields
             .get_or_init(|| {
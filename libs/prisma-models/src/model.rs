@@ -12,16 +12,22 @@ pub type ModelWeakRef = Weak<Model>;
 pub struct ModelTemplate {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
     pub id_field_names: Vec<String>,
     pub indexes: Vec<IndexTemplate>,
+    pub documentation: Option<String>,
+    pub schema: Option<String>,
 }
 
 #[derive(DebugStub)]
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
+    pub documentation: Option<String>,
+    pub schema: Option<String>,
 
     manifestation: Option<String>,
     fields: OnceCell<Fields>,
@@ -36,6 +42,9 @@ impl ModelTemplate {
         let model = Arc::new(Model {
             name: self.name,
             is_embedded: self.is_embedded,
+            is_view: self.is_view,
+            documentation: self.documentation,
+            schema: self.schema,
             fields: OnceCell::new(),
             indexes: OnceCell::new(),
             manifestation: self.manifestation,
@@ -89,7 +98,9 @@ impl Model {
     /// 1. If an ID definition (single or multi-part doesn't matter) is present, take that one.
     /// 2. If no ID definition is found, take the first scalar unique found that is required.
     /// 3. If no scalar unique is found, take the first compound unique found. All fields must be required.
-    /// 4. If all of the above fails, we panic. Models with no unique / ID are not supported (yet).
+    /// 4. If the model is a view, take all of its scalar, non-list fields. Nullable fields are included as well,
+    ///    as a view might not have any required field. Rows that only differ in NULL values can't be told apart.
+    /// 5. If all of the above fails, we panic. Models with no unique / ID are not supported (yet).
     pub fn primary_identifier(&self) -> ModelProjection {
         let fields: Vec<_> = self
             .fields()
@@ -107,6 +118,14 @@ impl Model {
                     .find(|index| index.fields().into_iter().all(|f| f.is_required))
                     .map(|index| index.fields().into_iter().map(|f| f.into()).collect())
             })
+            .or_else(|| {
+                // Views are only read, so the combination of all their columns is good enough to tell their rows apart.
+                if self.is_view {
+                    Some(self.fields().scalar().into_iter().filter(|sf| !sf.is_list).collect())
+                } else {
+                    None
+                }
+            })
             .expect(&format!(
                 "Unable to resolve a primary identifier for model {}.",
                 self.name
@@ -148,6 +167,14 @@ impl Model {
         self.manifestation.as_ref().map(|m| m.as_ref())
     }
 
+    /// The database schema of the model, falling back to the one of the internal data model.
+    pub fn schema_name(&self) -> String {
+        match &self.schema {
+            Some(schema) => schema.clone(),
+            None => self.internal_data_model().db_name.clone(),
+        }
+    }
+
     pub fn internal_data_model(&self) -> InternalDataModelRef {
         self.internal_data_model
             .upgrade()
@@ -21,6 +21,9 @@ impl PrismaValueExtensions for PrismaValue {
             (val @ PrismaValue::DateTime(_), TypeIdentifier::DateTime) => val,
             (val @ PrismaValue::Enum(_), TypeIdentifier::Enum(_)) => val,
             (val @ PrismaValue::Uuid(_), TypeIdentifier::UUID) => val,
+            (val @ PrismaValue::Decimal(_), TypeIdentifier::Decimal) => val,
+            (val @ PrismaValue::BigInt(_), TypeIdentifier::BigInt) => val,
+            (val @ PrismaValue::Bytes(_), TypeIdentifier::Bytes) => val,
 
             // Valid String coercions
             (PrismaValue::Int(i), TypeIdentifier::String) => PrismaValue::String(format!("{}", i)),
@@ -44,6 +47,11 @@ impl PrismaValueExtensions for PrismaValue {
             },
             (PrismaValue::Float(f), TypeIdentifier::Int) => PrismaValue::Int(f.trunc().to_i64().unwrap()),
 
+            // Valid Decimal and BigInt coercions
+            (PrismaValue::Float(f), TypeIdentifier::Decimal) => PrismaValue::Decimal(f),
+            (PrismaValue::Int(i), TypeIdentifier::Decimal) => PrismaValue::Decimal(i.into()),
+            (PrismaValue::Int(i), TypeIdentifier::BigInt) => PrismaValue::BigInt(i),
+
             // Todo other coercions here
 
             // Lists
@@ -1,5 +1,5 @@
 use crate::{DomainError, ModelProjection, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone)]
 pub struct SingleRecord {
@@ -62,6 +62,24 @@ impl ManyRecords {
         })
     }
 
+    /// Removes all records that hold the same values for the given projection as a previous record
+    /// with the same parent. The first occurrence is retained, so the result depends on the current order.
+    pub fn distinct(&mut self, projection: &ModelProjection) -> crate::Result<()> {
+        let mut seen = HashSet::new();
+        let mut records = Vec::with_capacity(self.records.len());
+
+        for record in self.records.drain(..) {
+            let values = record.projection(&self.field_names, projection)?;
+
+            if seen.insert((record.parent_id.clone(), values)) {
+                records.push(record);
+            }
+        }
+
+        self.records = records;
+        Ok(())
+    }
+
     pub fn push(&mut self, record: Record) {
         self.records.push(record);
     }
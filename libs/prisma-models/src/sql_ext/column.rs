@@ -91,24 +91,21 @@ impl AsColumns for RelationField {
         let internal_data_model = model.internal_data_model();
 
         let relation = self.relation();
-        let table_name = if relation.is_many_to_many() {
+        let (schema_name, table_name) = if relation.is_many_to_many() {
             if let RelationLinkManifestation::RelationTable(ref rt) = relation.manifestation {
-                rt.table.clone()
+                (internal_data_model.db_name.clone(), rt.table.clone())
             } else {
                 unreachable!()
             }
         } else {
-            model.db_name().to_string()
+            (model.schema_name(), model.db_name().to_string())
         };
 
         let inner: Vec<_> = self
             .scalar_fields()
             .iter()
             .map(|f| {
-                let parts = (
-                    (internal_data_model.db_name.clone(), table_name.clone()),
-                    f.db_name().to_owned(),
-                );
+                let parts = ((schema_name.clone(), table_name.clone()), f.db_name().to_owned());
 
                 Column::from(parts)
             })
@@ -144,7 +141,7 @@ where
 {
     fn as_column(&self) -> Column<'static> {
         let sf = self.as_ref();
-        let db = sf.internal_data_model().db_name.clone();
+        let db = sf.model().schema_name();
         let table = sf.model().db_name().to_string();
         let col = sf.db_name().to_string();
 
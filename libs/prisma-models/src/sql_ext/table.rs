@@ -7,6 +7,6 @@ pub trait AsTable {
 
 impl AsTable for Model {
     fn as_table(&self) -> Table<'static> {
-        (self.internal_data_model().db_name.clone(), self.db_name().to_string()).into()
+        (self.schema_name(), self.db_name().to_string()).into()
     }
 }
@@ -473,7 +473,7 @@ fn ambiguous_relations() {
 }
 
 fn convert(datamodel: &str) -> Arc<InternalDataModel> {
-    let datamodel = datamodel::parse_datamodel(datamodel).unwrap();
+    let datamodel = datamodel::parse_datamodel(datamodel).unwrap().subject;
     let template = DatamodelConverter::convert(&datamodel);
     template.build("not_important".to_string())
 }
@@ -33,6 +33,18 @@ pub enum PrismaValue {
 
     #[serde(serialize_with = "serialize_decimal")]
     Float(Decimal),
+
+    /// An exact decimal, serialized as a string to not lose precision.
+    #[serde(serialize_with = "serialize_to_string")]
+    Decimal(Decimal),
+
+    /// A 64 bit integer, serialized as a string as it exceeds the safe integer range of JavaScript.
+    #[serde(serialize_with = "serialize_to_string")]
+    BigInt(i64),
+
+    /// Binary data, serialized as base64.
+    #[serde(serialize_with = "serialize_bytes")]
+    Bytes(Vec<u8>),
 }
 
 pub fn stringify_date(date: &DateTime<Utc>) -> String {
@@ -83,6 +95,31 @@ where
     decimal.to_f64().expect("Decimal is not a f64.").serialize(serializer)
 }
 
+fn serialize_to_string<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: fmt::Display,
+    S: Serializer,
+{
+    value.to_string().serialize(serializer)
+}
+
+fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    encode_bytes(bytes).serialize(serializer)
+}
+
+/// Encodes binary data as base64, the representation of `Bytes` values in requests and responses.
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    base64::encode(bytes)
+}
+
+/// Decodes base64 encoded binary data.
+pub fn decode_bytes(s: &str) -> PrismaValueResult<Vec<u8>> {
+    base64::decode(s).map_err(|_| ConversionFailure::new("base64 encoded bytes", "PrismaValue::Bytes"))
+}
+
 impl PrismaValue {
     pub fn is_null(&self) -> bool {
         match self {
@@ -126,6 +163,9 @@ impl fmt::Display for PrismaValue {
             PrismaValue::Null => "null".fmt(f),
             PrismaValue::Uuid(x) => x.fmt(f),
             PrismaValue::Json(x) => x.fmt(f),
+            PrismaValue::Decimal(x) => x.fmt(f),
+            PrismaValue::BigInt(x) => x.fmt(f),
+            PrismaValue::Bytes(x) => encode_bytes(x).fmt(f),
             PrismaValue::List(x) => {
                 let as_string = format!("{:?}", x);
                 as_string.fmt(f)
@@ -37,6 +37,9 @@ impl<'a> From<PrismaValue> for Value<'a> {
             PrismaValue::Uuid(u) => u.to_string().into(),
             PrismaValue::List(l) => Value::Array(l.into_iter().map(|x| x.into()).collect()),
             PrismaValue::Json(s) => Value::Json(serde_json::from_str(&s).unwrap()),
+            PrismaValue::Decimal(d) => d.into(),
+            PrismaValue::BigInt(i) => i.into(),
+            PrismaValue::Bytes(b) => Value::Bytes(b.into()),
         }
     }
 }
@@ -19,6 +19,13 @@ pub enum SqlSchemaDescriberError {
     /// An unknown error occurred.
     #[error("unknown")]
     UnknownError,
+    /// A table name exists in more than one of the described schemas.
+    #[error("The table `{table}` exists in both the `{first_schema}` and `{second_schema}` schemas.")]
+    DuplicateTableName {
+        table: String,
+        first_schema: String,
+        second_schema: String,
+    },
 }
 
 /// The result type.
@@ -35,6 +42,58 @@ pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
     async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema>;
 }
 
+/// Describe the default schema together with additional schemas, as one `SqlSchema`.
+///
+/// Tables and views from the additional schemas are tagged with the schema they live in. Tables
+/// are looked up by name only, so a table name that appears in more than one of the described
+/// schemas is an error.
+pub async fn describe_schemas(
+    describer: &dyn SqlSchemaDescriberBackend,
+    default_schema: &str,
+    additional_schemas: &[String],
+) -> SqlSchemaDescriberResult<SqlSchema> {
+    let mut sql_schema = describer.describe(default_schema).await?;
+
+    for schema in additional_schemas.iter().filter(|schema| *schema != default_schema) {
+        debug!("describing additional schema '{}'", schema);
+        let additional = describer.describe(schema).await?;
+
+        for table in &additional.tables {
+            if let Some(existing) = sql_schema.get_table(&table.name) {
+                return Err(SqlSchemaDescriberError::DuplicateTableName {
+                    table: table.name.clone(),
+                    first_schema: existing.schema.clone().unwrap_or_else(|| default_schema.to_owned()),
+                    second_schema: schema.clone(),
+                });
+            }
+        }
+
+        sql_schema.tables.extend(additional.tables.into_iter().map(|mut table| {
+            table.schema = Some(schema.clone());
+            table
+        }));
+
+        sql_schema.views.extend(additional.views.into_iter().map(|mut view| {
+            view.schema = Some(schema.clone());
+            view
+        }));
+
+        for r#enum in additional.enums {
+            if sql_schema.get_enum(&r#enum.name).is_none() {
+                sql_schema.enums.push(r#enum);
+            }
+        }
+
+        for sequence in additional.sequences {
+            if !sql_schema.sequences.iter().any(|s| s.name == sequence.name) {
+                sql_schema.sequences.push(sequence);
+            }
+        }
+    }
+
+    Ok(sql_schema)
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct SQLMetadata {
     pub table_count: usize,
@@ -51,6 +110,9 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -79,6 +141,11 @@ impl SqlSchema {
         self.table(&name).unwrap()
     }
 
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
     /// Get a sequence.
     pub fn get_sequence(&self, name: &str) -> Option<&Sequence> {
         self.sequences.iter().find(|x| x.name == name)
@@ -89,10 +156,32 @@ impl SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 }
 
+/// A view found in a schema.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// The view's name.
+    pub name: String,
+    /// The query defining the view, if the database exposes it.
+    pub definition: Option<String>,
+    /// The view's columns.
+    pub columns: Vec<Column>,
+    /// The schema the view lives in, if it is not the default schema that was described.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+}
+
+impl View {
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
 /// A table found in a schema.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -107,6 +196,12 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's check constraints.
+    #[serde(default)]
+    pub check_constraints: Vec<CheckConstraint>,
+    /// The schema the table lives in, if it is not the default schema that was described.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
 }
 
 impl Table {
@@ -238,6 +333,13 @@ pub struct ColumnType {
     pub full_data_type: String,
     /// The maximum length for character or string bit types if specified.
     pub character_maximum_length: Option<i64>,
+    /// The precision of numeric types, or the fractional seconds precision of time and timestamp types, if
+    /// specified. Only described on Postgres, the other databases include it in the full data type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<i64>,
+    /// The scale of numeric types if specified. Only described on Postgres.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<i64>,
     /// The family of the raw type.
     pub family: ColumnTypeFamily,
     /// The arity of the column.
@@ -250,6 +352,8 @@ impl ColumnType {
             data_type: "".to_string(),
             full_data_type: "".to_string(),
             character_maximum_length: None,
+            precision: None,
+            scale: None,
             family,
             arity,
         }
@@ -263,8 +367,12 @@ impl ColumnType {
 pub enum ColumnTypeFamily {
     /// Integer types.
     Int,
+    /// 64 bit integer types.
+    BigInt,
     /// Floating point types.
     Float,
+    /// Exact numeric types.
+    Decimal,
     /// Boolean types.
     Boolean,
     /// String types.
@@ -295,7 +403,9 @@ impl fmt::Display for ColumnTypeFamily {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
             Self::Int => "int".to_string(),
+            Self::BigInt => "bigInt".to_string(),
             Self::Float => "float".to_string(),
+            Self::Decimal => "decimal".to_string(),
             Self::Boolean => "boolean".to_string(),
             Self::String => "string".to_string(),
             Self::DateTime => "dateTime".to_string(),
@@ -357,6 +467,12 @@ pub enum ForeignKeyAction {
     SetDefault,
 }
 
+impl Default for ForeignKeyAction {
+    fn default() -> Self {
+        ForeignKeyAction::NoAction
+    }
+}
+
 /// A foreign key.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -371,6 +487,9 @@ pub struct ForeignKey {
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
     pub on_delete_action: ForeignKeyAction,
+    /// Action on update of the referenced columns.
+    #[serde(default)]
+    pub on_update_action: ForeignKeyAction,
 }
 
 impl PartialEq for ForeignKey {
@@ -381,6 +500,16 @@ impl PartialEq for ForeignKey {
     }
 }
 
+/// A check constraint.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckConstraint {
+    /// The database name of the check constraint, when available.
+    pub name: Option<String>,
+    /// The boolean SQL expression every row of the table has to satisfy.
+    pub expression: String,
+}
+
 /// A SQL enum.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
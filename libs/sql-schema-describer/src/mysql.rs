@@ -56,18 +56,24 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = get_all_columns(self.conn.as_ref(), schema, &flavour).await;
         let mut indexes = get_all_indexes(self.conn.as_ref(), schema).await;
         let mut fks = get_foreign_keys(self.conn.as_ref(), schema).await;
+        let mut check_constraints = get_check_constraints(self.conn.as_ref(), schema).await;
 
         let mut enums = vec![];
         for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            let (table, enms) =
+                self.get_table(table_name, &mut columns, &mut indexes, &mut fks, &mut check_constraints);
             tables.push(table);
             enums.extend(enms.iter().cloned());
         }
 
+        let (views, view_enums) = self.get_views(schema, &mut columns).await;
+        enums.extend(view_enums);
+
         Ok(SqlSchema {
             tables,
             enums,
             sequences: vec![],
+            views,
         })
     }
 }
@@ -120,6 +126,41 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(
+        &self,
+        schema: &str,
+        columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
+    ) -> (Vec<View>, Vec<Enum>) {
+        debug!("Getting views");
+        let sql = "SELECT table_name view_name, view_definition view_definition
+            FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+        let mut views = vec![];
+        let mut enums = vec![];
+
+        for row in rows {
+            let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+            let definition = row.get("view_definition").and_then(|x| x.to_string());
+            let (view_columns, view_enums) = columns.remove(&name).unwrap_or_default();
+
+            enums.extend(view_enums);
+            views.push(View {
+                name,
+                definition,
+                columns: view_columns,
+                schema: None,
+            });
+        }
+
+        debug!(
+            "Found views: {:?}",
+            views.iter().map(|view| &view.name).collect::<Vec<_>>()
+        );
+        (views, enums)
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         use rust_decimal::prelude::*;
 
@@ -150,12 +191,14 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> (Table, Vec<Enum>) {
         debug!("Getting table '{}'", name);
         let (columns, enums) = columns.remove(name).expect("table columns not found");
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
         (
             Table {
                 name: name.to_string(),
@@ -163,6 +206,8 @@ impl SqlSchemaDescriber {
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                check_constraints,
+                schema: None,
             },
             enums,
         )
@@ -264,11 +309,11 @@ async fn get_all_columns(
                 Some(x) if x == "NULL" => None,
                 Some(default_string) => {
                     Some(match &tpe.family {
-                        ColumnTypeFamily::Int => match parse_int(&default_string) {
+                        ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => match parse_int(&default_string) {
                             Some(int_value) => DefaultValue::VALUE(int_value),
                             None => DefaultValue::DBGENERATED(default_string),
                         },
-                        ColumnTypeFamily::Float => match parse_float(&default_string) {
+                        ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => match parse_float(&default_string) {
                             Some(float_value) => DefaultValue::VALUE(float_value),
                             None => DefaultValue::DBGENERATED(default_string),
                         },
@@ -427,7 +472,8 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
             kcu.referenced_column_name referenced_column_name,
             kcu.ordinal_position ordinal_position,
             kcu.table_name table_name,
-            rc.delete_rule delete_rule
+            rc.delete_rule delete_rule,
+            rc.update_rule update_rule
         FROM information_schema.key_column_usage AS kcu
         INNER JOIN information_schema.referential_constraints AS rc ON
         kcu.constraint_name = rc.constraint_name
@@ -471,20 +517,16 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
             .get("ordinal_position")
             .and_then(|x| x.as_i64())
             .expect("get ordinal_position");
-        let on_delete_action = match row
-            .get("delete_rule")
-            .and_then(|x| x.to_string())
-            .expect("get delete_rule")
-            .to_lowercase()
-            .as_str()
-        {
-            "cascade" => ForeignKeyAction::Cascade,
-            "set null" => ForeignKeyAction::SetNull,
-            "set default" => ForeignKeyAction::SetDefault,
-            "restrict" => ForeignKeyAction::Restrict,
-            "no action" => ForeignKeyAction::NoAction,
-            s @ _ => panic!(format!("Unrecognized on delete action '{}'", s)),
-        };
+        let on_delete_action = foreign_key_action(
+            &row.get("delete_rule")
+                .and_then(|x| x.to_string())
+                .expect("get delete_rule"),
+        );
+        let on_update_action = foreign_key_action(
+            &row.get("update_rule")
+                .and_then(|x| x.to_string())
+                .expect("get update_rule"),
+        );
 
         let intermediate_fks = map.entry(table_name).or_default();
 
@@ -507,6 +549,7 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
                     referenced_table,
                     referenced_columns: vec![referenced_column],
                     on_delete_action,
+                    on_update_action,
                 };
                 intermediate_fks.insert(constraint_name, fk);
             }
@@ -524,12 +567,83 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
         .collect()
 }
 
+/// `ER_UNKNOWN_TABLE` is returned for unknown tables of `information_schema`, `ER_NO_SUCH_TABLE` for others.
+fn is_unknown_table_error(err: &quaint::error::Error) -> bool {
+    matches!(err.original_code(), Some("1109") | Some("1146"))
+}
+
+async fn get_check_constraints(conn: &dyn Queryable, schema_name: &str) -> HashMap<String, Vec<CheckConstraint>> {
+    let sql = "
+        SELECT
+            tc.table_name table_name,
+            cc.constraint_name constraint_name,
+            cc.check_clause check_clause
+        FROM information_schema.check_constraints cc
+        JOIN information_schema.table_constraints tc
+            ON tc.constraint_schema = cc.constraint_schema
+            AND tc.constraint_name = cc.constraint_name
+        WHERE cc.constraint_schema = ? AND tc.constraint_type = 'CHECK'
+        ORDER BY tc.table_name, cc.constraint_name
+    ";
+
+    debug!("describing check constraints, SQL: '{}'", sql);
+
+    let rows = match conn.query_raw(sql, &[schema_name.into()]).await {
+        Ok(rows) => rows,
+        // MySQL before 8.0.16 does not know about check constraints and has no table to describe them.
+        Err(err) if is_unknown_table_error(&err) => {
+            debug!("Could not describe check constraints: {}", err);
+            return HashMap::new();
+        }
+        Err(err) => panic!("querying for check constraints: {:?}", err),
+    };
+
+    let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+    for row in rows {
+        let table_name = row
+            .get("table_name")
+            .and_then(|x| x.to_string())
+            .expect("get table_name");
+        let name = row.get("constraint_name").and_then(|x| x.to_string());
+        let expression = row
+            .get("check_clause")
+            .and_then(|x| x.to_string())
+            .expect("get check_clause");
+
+        // MariaDB implements the JSON type as LONGTEXT with an implicit `json_valid` check.
+        if expression.starts_with("json_valid(") {
+            continue;
+        }
+
+        check_constraints
+            .entry(table_name)
+            .or_default()
+            .push(CheckConstraint { name, expression });
+    }
+
+    check_constraints
+}
+
+fn foreign_key_action(action: &str) -> ForeignKeyAction {
+    match action.to_lowercase().as_str() {
+        "no action" => ForeignKeyAction::NoAction,
+        "restrict" => ForeignKeyAction::Restrict,
+        "set null" => ForeignKeyAction::SetNull,
+        "set default" => ForeignKeyAction::SetDefault,
+        "cascade" => ForeignKeyAction::Cascade,
+        s @ _ => panic!(format!("Unrecognized foreign key action '{}'", s)),
+    }
+}
+
 fn get_column_type_and_enum(
     table: &str,
     column_name: &str,
     data_type: &str,
     full_data_type: &str,
     character_maximum_length: Option<i64>,
+    precision: None,
+    scale: None,
     arity: ColumnArity,
 ) -> (ColumnType, Option<Enum>) {
     let family = match (data_type, full_data_type) {
@@ -538,9 +652,9 @@ fn get_column_type_and_enum(
         ("tinyint", "tinyint(1)") => ColumnTypeFamily::Boolean,
         ("tinyint", _) => ColumnTypeFamily::Int,
         ("mediumint", _) => ColumnTypeFamily::Int,
-        ("bigint", _) => ColumnTypeFamily::Int,
-        ("decimal", _) => ColumnTypeFamily::Float,
-        ("numeric", _) => ColumnTypeFamily::Float,
+        ("bigint", _) => ColumnTypeFamily::BigInt,
+        ("decimal", _) => ColumnTypeFamily::Decimal,
+        ("numeric", _) => ColumnTypeFamily::Decimal,
         ("float", _) => ColumnTypeFamily::Float,
         ("double", _) => ColumnTypeFamily::Float,
         ("bit", _) => ColumnTypeFamily::Int,
@@ -580,6 +694,8 @@ fn get_column_type_and_enum(
         data_type: data_type.to_owned(),
         full_data_type: full_data_type.to_owned(),
         character_maximum_length,
+        precision: None,
+        scale: None,
         family: family.clone(),
         arity,
     };
@@ -32,18 +32,28 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_columns(schema, &enums).await;
         let mut foreign_keys = self.get_foreign_keys(schema).await;
         let mut indexes = self.get_indices(schema, &sequences).await;
+        let mut check_constraints = self.get_check_constraints(schema).await;
 
         let table_names = self.get_table_names(schema).await;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+            tables.push(self.get_table(
+                &table_name,
+                &mut columns,
+                &mut foreign_keys,
+                &mut indexes,
+                &mut check_constraints,
+            ));
         }
 
+        let views = self.get_views(schema, &mut columns).await;
+
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
 }
@@ -96,6 +106,36 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "SELECT table_name as view_name, view_definition as view_definition
+            FROM information_schema.views
+            WHERE table_schema = $1
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+        let views: Vec<View> = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+                let definition = row.get("view_definition").and_then(|x| x.to_string());
+                let columns = columns.remove(&name).unwrap_or_default();
+
+                View {
+                    name,
+                    definition,
+                    columns,
+                    schema: None,
+                }
+            })
+            .collect();
+
+        debug!(
+            "Found views: {:?}",
+            views.iter().map(|view| &view.name).collect::<Vec<_>>()
+        );
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
         let sql =
@@ -118,10 +158,12 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, Vec<Column>>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
         indices: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> Table {
         debug!("Getting table '{}'", name);
         let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
         let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
+        let check_constraints = check_constraints.remove(name).unwrap_or_else(Vec::new);
         let columns = columns.remove(name).expect("could not get columns");
         Table {
             name: name.to_string(),
@@ -129,6 +171,8 @@ impl SqlSchemaDescriber {
             foreign_keys,
             indices,
             primary_key,
+            check_constraints,
+            schema: None,
         }
     }
 
@@ -142,6 +186,11 @@ impl SqlSchemaDescriber {
                 data_type,
                 udt_name as full_data_type,
                 character_maximum_length,
+                CASE
+                    WHEN udt_name = 'numeric' THEN numeric_precision
+                    WHEN udt_name IN ('time', 'timetz', 'timestamp', 'timestamptz') THEN datetime_precision
+                END as precision,
+                CASE WHEN udt_name = 'numeric' THEN numeric_scale END as scale,
                 column_default,
                 is_nullable,
                 is_identity,
@@ -174,6 +223,8 @@ impl SqlSchemaDescriber {
                 .and_then(|x| x.to_string())
                 .expect("get full_data_type aka udt_name");
             let character_maximum_length = col.get("character_maximum_length").and_then(|x| x.as_i64());
+            let precision = col.get("precision").and_then(|x| x.as_i64());
+            let scale = col.get("scale").and_then(|x| x.as_i64());
             let is_identity_str = col
                 .get("is_identity")
                 .and_then(|x| x.to_string())
@@ -203,13 +254,17 @@ impl SqlSchemaDescriber {
                 ColumnArity::Nullable
             };
 
-            let tpe = get_column_type(
-                data_type.as_ref(),
-                &full_data_type,
-                character_maximum_length,
-                arity,
-                enums,
-            );
+            let tpe = ColumnType {
+                precision,
+                scale,
+                ..get_column_type(
+                    data_type.as_ref(),
+                    &full_data_type,
+                    character_maximum_length,
+                    arity,
+                    enums,
+                )
+            };
 
             let default = match col.get("column_default") {
                 None => None,
@@ -217,14 +272,14 @@ impl SqlSchemaDescriber {
                     None => None,
                     Some(default_string) => {
                         Some(match &tpe.family {
-                            ColumnTypeFamily::Int => match parse_int(&default_string) {
+                            ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => match parse_int(&default_string) {
                                 Some(int_value) => DefaultValue::VALUE(int_value),
                                 None => match is_autoincrement(&default_string, schema, &table_name, &col_name) {
                                     true => DefaultValue::SEQUENCE(default_string),
                                     false => DefaultValue::DBGENERATED(default_string),
                                 },
                             },
-                            ColumnTypeFamily::Float => match parse_float(&default_string) {
+                            ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => match parse_float(&default_string) {
                                 Some(float_value) => DefaultValue::VALUE(float_value),
                                 None => DefaultValue::DBGENERATED(default_string),
                             },
@@ -301,6 +356,7 @@ impl SqlSchemaDescriber {
                 cl.relname as "parent_table",
                 att.attname as "parent_column",
                 con.confdeltype,
+                con.confupdtype,
                 conname as constraint_name,
                 child,
                 parent,
@@ -315,7 +371,8 @@ impl SqlSchemaDescriber {
                     con1.confrelid,
                     con1.conrelid,
                     con1.conname,
-                    con1.confdeltype
+                    con1.confdeltype,
+                    con1.confupdtype
                 FROM
                     pg_class cl
                     join pg_namespace ns on cl.relnamespace = ns.oid
@@ -370,14 +427,12 @@ impl SqlSchemaDescriber {
                 .get("constraint_name")
                 .and_then(|x| x.to_string())
                 .expect("get constraint_name");
-            let on_delete_action = match confdeltype {
-                'a' => ForeignKeyAction::NoAction,
-                'r' => ForeignKeyAction::Restrict,
-                'c' => ForeignKeyAction::Cascade,
-                'n' => ForeignKeyAction::SetNull,
-                'd' => ForeignKeyAction::SetDefault,
-                _ => panic!(format!("unrecognized foreign key action '{}'", confdeltype)),
-            };
+            let confupdtype = row
+                .get("confupdtype")
+                .and_then(|x| x.as_char())
+                .expect("get confupdtype");
+            let on_delete_action = foreign_key_action(confdeltype);
+            let on_update_action = foreign_key_action(confupdtype);
             match intermediate_fks.get_mut(&id) {
                 Some((_, fk)) => {
                     fk.columns.push(column);
@@ -390,6 +445,7 @@ impl SqlSchemaDescriber {
                         referenced_table,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(id, (table_name, fk));
                 }
@@ -416,6 +472,48 @@ impl SqlSchemaDescriber {
         fks
     }
 
+    async fn get_check_constraints(&self, schema: &str) -> HashMap<String, Vec<CheckConstraint>> {
+        let sql = r#"
+            SELECT
+                cl.relname AS table_name,
+                con.conname AS constraint_name,
+                pg_get_expr(con.conbin, con.conrelid) AS expression
+            FROM pg_constraint con
+            JOIN pg_class cl ON cl.oid = con.conrelid
+            JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1 AND con.contype = 'c'
+            ORDER BY cl.relname, con.conname"#;
+        debug!("describing table check constraints, SQL: '{}'", sql);
+
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into()])
+            .await
+            .expect("querying for check constraints");
+        let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows {
+            let table_name = row
+                .get("table_name")
+                .and_then(|x| x.to_string())
+                .expect("get table_name");
+            let name = row.get("constraint_name").and_then(|x| x.to_string());
+            let expression = row
+                .get("expression")
+                .and_then(|x| x.to_string())
+                .expect("get expression");
+
+            debug!("Found check constraint on table '{}': {}", table_name, expression);
+
+            check_constraints
+                .entry(table_name)
+                .or_default()
+                .push(CheckConstraint { name, expression });
+        }
+
+        check_constraints
+    }
+
     /// Returns a map from table name to indexes and (optional) primary key.
     async fn get_indices(
         &self,
@@ -614,6 +712,8 @@ fn get_column_type<'a>(
     data_type: &str,
     full_data_type: &'a str,
     character_maximum_length: Option<i64>,
+    precision: None,
+    scale: None,
     arity: ColumnArity,
     enums: &Vec<Enum>,
 ) -> ColumnType {
@@ -626,7 +726,7 @@ fn get_column_type<'a>(
         x if data_type == "ARRAY" && x.starts_with("_") && enum_exists(trim(x)) => Enum(trim(x).to_owned()),
         "int2" | "_int2" => Int,
         "int4" | "_int4" => Int,
-        "int8" | "_int8" => Int,
+        "int8" | "_int8" => BigInt,
         "oid" | "_oid" => Int,
         "float4" | "_float4" => Float,
         "float8" | "_float8" => Float,
@@ -650,7 +750,7 @@ fn get_column_type<'a>(
         "polygon" | "_polygon" => Geometric,
         "bpchar" | "_bpchar" => String,
         "interval" | "_interval" => String,
-        "numeric" | "_numeric" => Float,
+        "numeric" | "_numeric" => Decimal,
         "money" | "_money" => Float,
         "pg_lsn" | "_pg_lsn" => LogSequenceNumber,
         "time" | "_time" => DateTime,
@@ -667,6 +767,8 @@ fn get_column_type<'a>(
         data_type: data_type.to_owned(),
         full_data_type: full_data_type.to_owned(),
         character_maximum_length,
+        precision: None,
+        scale: None,
         family,
         arity,
     }
@@ -821,3 +923,14 @@ mod tests {
         ));
     }
 }
+
+fn foreign_key_action(action: char) -> ForeignKeyAction {
+    match action {
+        'a' => ForeignKeyAction::NoAction,
+        'r' => ForeignKeyAction::Restrict,
+        'c' => ForeignKeyAction::Cascade,
+        'n' => ForeignKeyAction::SetNull,
+        'd' => ForeignKeyAction::SetDefault,
+        _ => panic!(format!("unrecognized foreign key action '{}'", action)),
+    }
+}
@@ -50,12 +50,15 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables[table_index].foreign_keys[fk_index].referenced_columns = columns
         }
 
+        let views = self.get_views(schema).await;
+
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
             tables: tables,
+            views,
         })
     }
 }
@@ -97,6 +100,32 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str) -> Vec<View> {
+        let sql = format!(r#"SELECT name, sql FROM "{}".sqlite_master WHERE type='view'"#, schema);
+        debug!("describing views with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).await.expect("get views");
+        let mut views = vec![];
+
+        for row in result_set {
+            let name = row.get("name").and_then(|x| x.to_string()).expect("view name");
+            let definition = row.get("sql").and_then(|x| x.to_string());
+            let (columns, _) = self.get_columns(schema, &name).await;
+
+            views.push(View {
+                name,
+                definition,
+                columns,
+                schema: None,
+            });
+        }
+
+        debug!(
+            "Found views: {:?}",
+            views.iter().map(|view| &view.name).collect::<Vec<_>>()
+        );
+        views
+    }
+
     async fn get_size(&self, _schema: &str) -> usize {
         debug!("Getting db size");
         let sql = format!(r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#);
@@ -114,15 +143,39 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(schema, name).await;
         let foreign_keys = self.get_foreign_keys(schema, name).await;
         let indices = self.get_indices(schema, name).await;
+        let check_constraints = self.get_check_constraints(schema, name).await;
         Table {
             name: name.to_string(),
             columns,
             indices,
             primary_key,
             foreign_keys,
+            check_constraints,
+            schema: None,
         }
     }
 
+    /// SQLite does not expose check constraints through any pragma, so they are parsed from the
+    /// `CREATE TABLE` statement stored in `sqlite_master`.
+    async fn get_check_constraints(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        let sql = format!(
+            r#"SELECT sql FROM "{}".sqlite_master WHERE type='table' AND name = ?"#,
+            schema
+        );
+        debug!("describing table check constraints, SQL: '{}'", sql);
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[table.into()])
+            .await
+            .expect("querying for check constraints");
+
+        result_set
+            .first()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .map(|create_table| parse_check_constraints(&create_table))
+            .unwrap_or_default()
+    }
+
     async fn get_columns(&self, schema: &str, table: &str) -> (Vec<Column>, Option<PrimaryKey>) {
         let sql = format!(r#"PRAGMA "{}".table_info ("{}")"#, schema, table);
         debug!("describing table columns, query: '{}'", sql);
@@ -151,14 +204,16 @@ impl SqlSchemaDescriber {
                             None
                         } else {
                             Some(match &tpe.family {
-                                ColumnTypeFamily::Int => match parse_int(&default_string) {
+                                ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => match parse_int(&default_string) {
                                     Some(int_value) => DefaultValue::VALUE(int_value),
                                     None => DefaultValue::DBGENERATED(default_string),
                                 },
-                                ColumnTypeFamily::Float => match parse_float(&default_string) {
-                                    Some(float_value) => DefaultValue::VALUE(float_value),
-                                    None => DefaultValue::DBGENERATED(default_string),
-                                },
+                                ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => {
+                                    match parse_float(&default_string) {
+                                        Some(float_value) => DefaultValue::VALUE(float_value),
+                                        None => DefaultValue::DBGENERATED(default_string),
+                                    }
+                                }
                                 ColumnTypeFamily::Boolean => match parse_int(&default_string) {
                                     Some(PrismaValue::Int(1)) => DefaultValue::VALUE(PrismaValue::Boolean(true)),
                                     Some(PrismaValue::Int(0)) => DefaultValue::VALUE(PrismaValue::Boolean(false)),
@@ -261,6 +316,7 @@ impl SqlSchemaDescriber {
             pub referenced_table: String,
             pub referenced_columns: HashMap<i64, String>,
             pub on_delete_action: ForeignKeyAction,
+            pub on_update_action: ForeignKeyAction,
         }
 
         let sql = format!(r#"PRAGMA "{}".foreign_key_list("{}");"#, schema, table);
@@ -294,25 +350,16 @@ impl SqlSchemaDescriber {
                     if let Some(column) = referenced_column {
                         referenced_columns.insert(seq, column);
                     };
-                    let on_delete_action = match row
-                        .get("on_delete")
-                        .and_then(|x| x.to_string())
-                        .expect("on_delete")
-                        .to_lowercase()
-                        .as_str()
-                    {
-                        "no action" => ForeignKeyAction::NoAction,
-                        "restrict" => ForeignKeyAction::Restrict,
-                        "set null" => ForeignKeyAction::SetNull,
-                        "set default" => ForeignKeyAction::SetDefault,
-                        "cascade" => ForeignKeyAction::Cascade,
-                        s @ _ => panic!(format!("Unrecognized on delete action '{}'", s)),
-                    };
+                    let on_delete_action =
+                        foreign_key_action(&row.get("on_delete").and_then(|x| x.to_string()).expect("on_delete"));
+                    let on_update_action =
+                        foreign_key_action(&row.get("on_update").and_then(|x| x.to_string()).expect("on_update"));
                     let fk = IntermediateForeignKey {
                         columns,
                         referenced_table,
                         referenced_columns,
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(id, fk);
                 }
@@ -344,6 +391,7 @@ impl SqlSchemaDescriber {
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
+                    on_update_action: intermediate_fk.on_update_action.to_owned(),
 
                     // Not relevant in SQLite since we cannot ALTER or DROP foreign keys by
                     // constraint name.
@@ -423,6 +471,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "datetime" => ColumnTypeFamily::DateTime,
         "timestamp" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
+        "blob" => ColumnTypeFamily::Binary,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
         "boolean[]" => ColumnTypeFamily::Boolean,
@@ -440,6 +489,8 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         data_type: tpe.to_string(),
         full_data_type: tpe.to_string(),
         character_maximum_length: None,
+        precision: None,
+        scale: None,
         family,
         arity,
     }
@@ -450,6 +501,17 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
 // using the backslash character are not supported because they are not standard SQL."
 //
 // - https://www.sqlite.org/lang_expr.html
+fn foreign_key_action(action: &str) -> ForeignKeyAction {
+    match action.to_lowercase().as_str() {
+        "no action" => ForeignKeyAction::NoAction,
+        "restrict" => ForeignKeyAction::Restrict,
+        "set null" => ForeignKeyAction::SetNull,
+        "set default" => ForeignKeyAction::SetDefault,
+        "cascade" => ForeignKeyAction::Cascade,
+        s @ _ => panic!(format!("Unrecognized foreign key action '{}'", s)),
+    }
+}
+
 fn unquote_sqlite_string_default(s: &str) -> Cow<'_, str> {
     const SQLITE_STRING_DEFAULT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?ms)^'(.*)'$|^"(.*)"$"#).unwrap());
     const SQLITE_ESCAPED_CHARACTER_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"''"#).unwrap());
@@ -475,3 +537,155 @@ const SQLITE_SYSTEM_TABLES: &[&str] = &[
     "sqlite_stat3",
     "sqlite_stat4",
 ];
+
+/// Extracts the `CHECK (...)` clauses, and the names given to them with `CONSTRAINT <name>`, from a
+/// `CREATE TABLE` statement.
+fn parse_check_constraints(create_table: &str) -> Vec<CheckConstraint> {
+    let chars: Vec<char> = create_table.chars().collect();
+    let mut check_constraints = Vec::new();
+    let mut previous_word: Option<String> = None;
+    let mut constraint_name: Option<String> = None;
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let (word, end) = match chars[pos] {
+            '\'' | '"' | '`' | '[' => {
+                let end = skip_quoted(&chars, pos);
+                let word: String = chars[pos + 1..end.saturating_sub(1).max(pos + 1)].iter().collect();
+
+                (word, end)
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let end = (pos..chars.len())
+                    .find(|idx| !(chars[*idx].is_alphanumeric() || chars[*idx] == '_'))
+                    .unwrap_or_else(|| chars.len());
+
+                (chars[pos..end].iter().collect(), end)
+            }
+            ',' => {
+                previous_word = None;
+                constraint_name = None;
+                pos += 1;
+                continue;
+            }
+            _ => {
+                pos += 1;
+                continue;
+            }
+        };
+
+        pos = end;
+
+        if word.eq_ignore_ascii_case("check") {
+            let open = (pos..chars.len()).find(|idx| !chars[*idx].is_whitespace());
+
+            if let Some(open) = open.filter(|open| chars[*open] == '(') {
+                let close = skip_parenthesized(&chars, open);
+                let expression: String = chars[open + 1..close.saturating_sub(1).max(open + 1)].iter().collect();
+
+                check_constraints.push(CheckConstraint {
+                    name: constraint_name.take(),
+                    expression: expression.trim().to_owned(),
+                });
+
+                previous_word = None;
+                pos = close;
+                continue;
+            }
+        }
+
+        constraint_name = match previous_word.as_deref() {
+            Some(previous) if previous.eq_ignore_ascii_case("constraint") => Some(word.clone()),
+            _ => None,
+        };
+
+        previous_word = Some(word);
+    }
+
+    check_constraints
+}
+
+/// Returns the position right after the quoted identifier or string literal starting at `start`.
+fn skip_quoted(chars: &[char], start: usize) -> usize {
+    let closing = match chars[start] {
+        '[' => ']',
+        c => c,
+    };
+    let mut pos = start + 1;
+
+    while pos < chars.len() {
+        if chars[pos] == closing {
+            // Quotes are escaped by doubling them.
+            if closing != ']' && chars.get(pos + 1) == Some(&closing) {
+                pos += 2;
+                continue;
+            }
+
+            return pos + 1;
+        }
+
+        pos += 1;
+    }
+
+    chars.len()
+}
+
+/// Returns the position right after the parenthesis closing the one at `start`.
+fn skip_parenthesized(chars: &[char], start: usize) -> usize {
+    let mut depth = 0;
+    let mut pos = start;
+
+    while pos < chars.len() {
+        match chars[pos] {
+            '\'' | '"' | '`' | '[' => {
+                pos = skip_quoted(chars, pos);
+                continue;
+            }
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return pos + 1;
+                }
+            }
+            _ => (),
+        }
+
+        pos += 1;
+    }
+
+    chars.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_check_constraints_works() {
+        let create_table = r#"CREATE TABLE "Product" (
+            id INTEGER PRIMARY KEY,
+            price INTEGER NOT NULL CHECK (price > 0),
+            name TEXT DEFAULT 'check (this)' CONSTRAINT "name_not_empty" CHECK(length("name") > 0),
+            CONSTRAINT price_range CHECK ((price < 1000) AND price <> 42)
+        )"#;
+
+        let expected = vec![
+            CheckConstraint {
+                name: None,
+                expression: "price > 0".to_owned(),
+            },
+            CheckConstraint {
+                name: Some("name_not_empty".to_owned()),
+                expression: r#"length("name") > 0"#.to_owned(),
+            },
+            CheckConstraint {
+                name: Some("price_range".to_owned()),
+                expression: "(price < 1000) AND price <> 42".to_owned(),
+            },
+        ];
+
+        assert_eq!(parse_check_constraints(create_table), expected);
+    }
+}
@@ -70,6 +70,8 @@ async fn is_required_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -82,6 +84,8 @@ async fn is_required_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Nullable,
@@ -124,6 +128,8 @@ async fn foreign_keys_must_work(api: &TestApi) {
             data_type: int_data_type(api),
             full_data_type: int_full_data_type(api),
             character_maximum_length: None,
+            precision: None,
+            scale: None,
 
             family: ColumnTypeFamily::Int,
             arity: ColumnArity::Required,
@@ -162,8 +168,11 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city".to_string()],
                 referenced_columns: vec!["id".to_string()],
                 referenced_table: "City".to_string(),
+                on_update_action: on_delete_action.clone(),
                 on_delete_action,
             }],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
@@ -209,6 +218,8 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -266,8 +277,11 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city_name".to_string(), "city".to_string()],
                 referenced_columns: vec!["name".to_string(), "id".to_string(),],
                 referenced_table: "City".to_string(),
+                on_update_action: on_delete_action.clone(),
                 on_delete_action,
             },],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
@@ -289,6 +303,8 @@ async fn names_with_hyphens_must_work(api: &TestApi) {
             data_type: int_data_type(api),
             full_data_type: int_full_data_type(api),
             character_maximum_length: None,
+            precision: None,
+            scale: None,
 
             family: ColumnTypeFamily::Int,
             arity: ColumnArity::Required,
@@ -331,6 +347,8 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -368,6 +386,8 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
@@ -396,6 +416,8 @@ async fn indices_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -409,6 +431,8 @@ async fn indices_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -441,6 +465,8 @@ async fn indices_must_work(api: &TestApi) {
                 sequence: pk_sequence,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
@@ -466,6 +492,8 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -479,6 +507,8 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -521,6 +551,8 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
             indices: expected_indices,
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }
     );
     assert!(
@@ -552,6 +584,8 @@ async fn defaults_must_work(api: &TestApi) {
             data_type: int_data_type(api),
             full_data_type: int_full_data_type(api),
             character_maximum_length: None,
+            precision: None,
+            scale: None,
 
             family: ColumnTypeFamily::Int,
             arity: ColumnArity::Nullable,
@@ -568,6 +602,8 @@ async fn defaults_must_work(api: &TestApi) {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
@@ -68,6 +68,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "int".to_string(),
                 full_data_type: "int(11)".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -81,6 +83,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "int".to_string(),
                 full_data_type: "int(11)".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -94,6 +98,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "smallint".to_string(),
                 full_data_type: "smallint(6)".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -107,6 +113,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinyint".to_string(),
                 full_data_type: "tinyint(4)".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -119,6 +127,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinyint".to_string(),
                 full_data_type: "tinyint(1)".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Boolean,
                 arity: ColumnArity::Required,
             },
@@ -132,6 +142,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "mediumint".to_string(),
                 full_data_type: "mediumint(9)".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -145,7 +157,9 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "bigint".to_string(),
                 full_data_type: "bigint(20)".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Int,
+                precision: None,
+                scale: None,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
@@ -158,7 +172,9 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "decimal".to_string(),
                 full_data_type: "decimal(10,0)".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Float,
+                precision: None,
+                scale: None,
+                family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
             },
 
@@ -171,7 +187,9 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "decimal".to_string(),
                 full_data_type: "decimal(10,0)".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Float,
+                precision: None,
+                scale: None,
+                family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
             },
 
@@ -184,6 +202,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "float".to_string(),
                 full_data_type: "float".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -197,6 +217,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "double".to_string(),
                 full_data_type: "double".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -210,6 +232,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "date".to_string(),
                 full_data_type: "date".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -223,6 +247,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "time".to_string(),
                 full_data_type: "time".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -236,6 +262,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "datetime".to_string(),
                 full_data_type: "datetime".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -249,6 +277,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "timestamp".to_string(),
                 full_data_type: "timestamp".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -262,6 +292,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "year".to_string(),
                 full_data_type: "year(4)".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -275,6 +307,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "char".to_string(),
                 full_data_type: "char(1)".to_string(),
                 character_maximum_length: Some(1),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -288,6 +322,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "varchar".to_string(),
                 full_data_type: "varchar(255)".to_string(),
                 character_maximum_length: Some(255),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -301,6 +337,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "text".to_string(),
                 full_data_type: "text".to_string(),
                 character_maximum_length: Some(65535),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -314,6 +352,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinytext".to_string(),
                 full_data_type: "tinytext".to_string(),
                 character_maximum_length: Some(255),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -327,6 +367,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "mediumtext".to_string(),
                 full_data_type: "mediumtext".to_string(),
                 character_maximum_length: Some(16777215),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -340,6 +382,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "longtext".to_string(),
                 full_data_type: "longtext".to_string(),
                 character_maximum_length: Some(4294967295),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -353,6 +397,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "enum".to_string(),
                 full_data_type: "enum(\'a\',\'b\')".to_string(),
                 character_maximum_length: Some(1),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Enum("User_enum_col".into()),
                 arity: ColumnArity::Required,
             },
@@ -366,6 +412,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "set".to_string(),
                 full_data_type: "set(\'a\',\'b\')".to_string(),
                 character_maximum_length: Some(3),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -379,6 +427,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "binary".to_string(),
                 full_data_type: "binary(1)".to_string(),
                 character_maximum_length: Some(1),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -392,6 +442,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "varbinary".to_string(),
                 full_data_type: "varbinary(255)".to_string(),
                 character_maximum_length: Some(255),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -405,6 +457,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "blob".to_string(),
                 full_data_type: "blob".to_string(),
                 character_maximum_length: Some(65535),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -418,6 +472,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinyblob".to_string(),
                 full_data_type: "tinyblob".to_string(),
                 character_maximum_length: Some(255),
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
@@ -432,6 +488,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "mediumblob".to_string(),
                 full_data_type: "mediumblob".to_string(),
                 character_maximum_length: Some(16777215),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -445,6 +503,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "longblob".to_string(),
                 full_data_type: "longblob".to_string(),
                 character_maximum_length: Some(4294967295),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -458,6 +518,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "geometry".to_string(),
                 full_data_type: "geometry".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -471,6 +533,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "point".to_string(),
                 full_data_type: "point".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -484,6 +548,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "linestring".to_string(),
                 full_data_type: "linestring".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -497,6 +563,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "polygon".to_string(),
                 full_data_type: "polygon".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -510,6 +578,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "multipoint".to_string(),
                 full_data_type: "multipoint".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -523,6 +593,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "multilinestring".to_string(),
                 full_data_type: "multilinestring".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -536,6 +608,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "multipolygon".to_string(),
                 full_data_type: "multipolygon".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -549,6 +623,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "geometrycollection".to_string(),
                 full_data_type: "geometrycollection".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -562,6 +638,8 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "json".to_string(),
                 full_data_type: "json".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Json,
                 arity: ColumnArity::Required,
             },
@@ -583,6 +661,8 @@ async fn all_mysql_column_types_must_work() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
@@ -621,6 +701,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -633,6 +715,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -645,6 +729,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -657,6 +743,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -669,6 +757,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -710,6 +800,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_2".to_owned()),
@@ -717,6 +808,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_3".to_owned()),
@@ -724,6 +816,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_4".to_owned()),
@@ -731,8 +824,11 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
@@ -837,6 +933,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             referenced_table: "User".into(),
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Cascade,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 
@@ -868,6 +965,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             referenced_table: "User".into(),
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Restrict,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 }
@@ -73,6 +73,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_bytea".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::List,
@@ -86,6 +88,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_bool".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Boolean,
                 arity: ColumnArity::List,
@@ -99,6 +103,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_date".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::List,
@@ -112,6 +118,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_float8".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::List,
@@ -125,6 +133,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_float8".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::List,
@@ -138,6 +148,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_int4".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::List,
@@ -151,6 +163,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_text".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::List,
@@ -164,6 +178,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_varchar".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::List,
@@ -177,6 +193,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bytea".into(),
                 full_data_type: "bytea".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
@@ -190,6 +208,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "boolean".into(),
                 full_data_type: "bool".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Boolean,
                 arity: ColumnArity::Required,
@@ -203,6 +223,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "date".into(),
                 full_data_type: "date".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -217,6 +239,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "double precision".into(),
                 full_data_type: "float8".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
@@ -231,6 +255,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "double precision".into(),
                 full_data_type: "float8".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
@@ -245,6 +271,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "integer".into(),
                 full_data_type: "int4".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -259,6 +287,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "integer".into(),
                 full_data_type: "int4".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -276,6 +306,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "text".into(),
                 full_data_type: "text".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
@@ -290,6 +322,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "character varying".into(),
                 full_data_type: "varchar".into(),
                 character_maximum_length: Some(1),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -303,8 +337,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bigint".into(),
                 full_data_type: "int8".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
@@ -317,7 +353,9 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bigint".into(),
                 full_data_type: "int8".into(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Int,
+                precision: None,
+                scale: None,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
@@ -333,6 +371,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bit".into(),
                 full_data_type: "bit".into(),
                 character_maximum_length: Some(1),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -346,6 +386,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bit varying".into(),
                 full_data_type: "varbit".into(),
                 character_maximum_length: Some(1),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -359,6 +401,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "box".into(),
                 full_data_type: "box".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -372,6 +416,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "character".into(),
                 full_data_type: "bpchar".into(),
                 character_maximum_length: Some(1),
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -385,6 +431,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "circle".into(),
                 full_data_type: "circle".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -398,6 +446,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "interval".into(),
                 full_data_type: "interval".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -411,6 +461,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "line".into(),
                 full_data_type: "line".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -424,6 +476,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "lseg".into(),
                 full_data_type: "lseg".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -437,7 +491,9 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "numeric".into(),
                 full_data_type: "numeric".into(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Float,
+                precision: None,
+                scale: None,
+                family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
             },
 
@@ -450,6 +506,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "path".into(),
                 full_data_type: "path".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -463,6 +521,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "pg_lsn".into(),
                 full_data_type: "pg_lsn".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::LogSequenceNumber,
                 arity: ColumnArity::Required,
             },
@@ -476,6 +536,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "polygon".into(),
                 full_data_type: "polygon".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -489,6 +551,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "smallint".into(),
                 full_data_type: "int2".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -502,6 +566,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "smallint".into(),
                 full_data_type: "int2".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -518,6 +584,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "integer".into(),
                 full_data_type: "int4".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -534,6 +602,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "time without time zone".into(),
                 full_data_type: "time".into(),
                 character_maximum_length: None,
+                precision: Some(6),
+                scale: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -547,6 +617,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "time with time zone".into(),
                 full_data_type: "timetz".into(),
                 character_maximum_length: None,
+                precision: Some(6),
+                scale: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -561,6 +633,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "timestamp without time zone".into(),
                 full_data_type: "timestamp".into(),
                 character_maximum_length: None,
+                precision: Some(6),
+                scale: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -575,6 +649,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "timestamp with time zone".into(),
                 full_data_type: "timestamptz".into(),
                 character_maximum_length: None,
+                precision: Some(6),
+                scale: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -589,6 +665,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "tsquery".into(),
                 full_data_type: "tsquery".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::TextSearch,
                 arity: ColumnArity::Required,
@@ -603,6 +681,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "tsvector".into(),
                 full_data_type: "tsvector".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::TextSearch,
                 arity: ColumnArity::Required,
@@ -617,6 +697,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "txid_snapshot".into(),
                 full_data_type: "txid_snapshot".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::TransactionId,
                 arity: ColumnArity::Required,
@@ -631,6 +713,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "json".into(),
                 full_data_type: "json".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Json,
                 arity: ColumnArity::Required,
@@ -645,6 +729,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "jsonb".into(),
                 full_data_type: "jsonb".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Json,
                 arity: ColumnArity::Required,
@@ -659,6 +745,8 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "uuid".into(),
                 full_data_type: "uuid".into(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Uuid,
                 arity: ColumnArity::Required,
@@ -689,6 +777,8 @@ async fn all_postgres_column_types_must_work() {
                 },),
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
@@ -725,6 +815,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -738,6 +830,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -751,6 +845,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -764,6 +860,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -777,6 +875,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -790,6 +890,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -811,6 +913,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_cascade_fkey".to_owned()),
@@ -818,6 +921,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_restrict_fkey".to_owned()),
@@ -825,6 +929,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_default_fkey".to_owned()),
@@ -832,6 +937,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_null_fkey".to_owned()),
@@ -839,8 +945,11 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
@@ -995,3 +1104,101 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_views_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"CREATE TABLE "{0}"."User" (id INTEGER PRIMARY KEY, name TEXT NOT NULL)"#,
+        api.schema_name()
+    );
+    let create_view = format!(
+        r#"CREATE VIEW "{0}"."UserNames" AS SELECT id, name FROM "{0}"."User""#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+    api.database().query_raw(&create_view, &[]).await?;
+
+    let schema = api.describe().await?;
+
+    assert!(schema.get_table("UserNames").is_none());
+
+    let view = schema.get_view("UserNames").expect("get view");
+    let column_names: Vec<&str> = view.columns.iter().map(|column| column.name.as_str()).collect();
+
+    assert_eq!(column_names, &["id", "name"]);
+    assert!(view.definition.as_ref().unwrap().contains("SELECT"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_check_constraints_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."Product" (
+                id INTEGER PRIMARY KEY,
+                price INTEGER NOT NULL CHECK (price > 0),
+                CONSTRAINT "price_range" CHECK (price < 1000)
+            )
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Product");
+
+    assert_eq!(
+        table.check_constraints,
+        &[
+            CheckConstraint {
+                name: Some("Product_price_check".to_string()),
+                expression: "(price > 0)".to_string(),
+            },
+            CheckConstraint {
+                name: Some("price_range".to_string()),
+                expression: "(price < 1000)".to_string(),
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_additional_schemas_must_be_described(api: &TestApi) -> TestResult {
+    let billing_schema = format!("{}_billing", api.schema_name());
+
+    let setup = [
+        format!(r#"DROP SCHEMA IF EXISTS "{}" CASCADE"#, billing_schema),
+        format!(r#"CREATE SCHEMA "{}""#, billing_schema),
+        format!(
+            r#"CREATE TABLE "{}"."User" (id INTEGER PRIMARY KEY)"#,
+            api.schema_name()
+        ),
+        format!(
+            r#"CREATE TABLE "{}"."Invoice" (id INTEGER PRIMARY KEY, user_id INTEGER NOT NULL REFERENCES "{}"."User"(id))"#,
+            billing_schema,
+            api.schema_name()
+        ),
+    ];
+
+    for statement in &setup {
+        api.database().query_raw(statement, &[]).await?;
+    }
+
+    let schema = api.describe_schemas(&[billing_schema.clone()]).await?;
+
+    let user = schema.table_bang("User");
+    assert_eq!(user.schema, None);
+
+    let invoice = schema.table_bang("Invoice");
+    assert_eq!(invoice.schema.as_ref(), Some(&billing_schema));
+    assert_eq!(invoice.foreign_keys.len(), 1);
+    assert_eq!(invoice.foreign_keys[0].referenced_table, "User");
+    assert_eq!(invoice.foreign_keys[0].columns, &["user_id"]);
+
+    Ok(())
+}
@@ -30,6 +30,8 @@ fn database_schema_is_serializable() {
                             data_type: "integer".to_string(),
                             full_data_type: "int".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -43,6 +45,8 @@ fn database_schema_is_serializable() {
                             data_type: "varchar(255)".to_string(),
                             full_data_type: "varchar(255)".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Nullable,
@@ -56,6 +60,8 @@ fn database_schema_is_serializable() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            precision: None,
+                            scale: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -79,7 +85,10 @@ fn database_schema_is_serializable() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 }],
+                check_constraints: vec![],
+                schema: None,
             },
             Table {
                 name: "table2".to_string(),
@@ -89,6 +98,8 @@ fn database_schema_is_serializable() {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -102,6 +113,8 @@ fn database_schema_is_serializable() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                schema: None,
             },
         ],
         enums: vec![Enum {
@@ -113,6 +126,7 @@ fn database_schema_is_serializable() {
             initial_value: 1,
             allocation_size: 32,
         }],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -137,6 +151,8 @@ fn database_schema_without_primary_key_is_serializable() {
                     data_type: "integer".to_string(),
                     full_data_type: "int".to_string(),
                     character_maximum_length: None,
+                    precision: None,
+                    scale: None,
 
                     family: ColumnTypeFamily::Int,
                     arity: ColumnArity::Nullable,
@@ -147,9 +163,12 @@ fn database_schema_without_primary_key_is_serializable() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -188,6 +207,8 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             data_type: "raw type".to_string(),
             full_data_type: "full raw type".to_string(),
             character_maximum_length: None,
+            precision: None,
+            scale: None,
 
             family: family.to_owned(),
             arity: ColumnArity::Nullable,
@@ -203,9 +224,12 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -231,6 +255,8 @@ fn database_schema_is_serializable_for_every_column_arity() {
                 data_type: "integer".to_string(),
                 full_data_type: "int".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: arity.to_owned(),
@@ -246,9 +272,12 @@ fn database_schema_is_serializable_for_every_column_arity() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -275,6 +304,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -288,6 +319,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -301,6 +334,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -314,6 +349,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -327,6 +364,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -344,6 +383,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -351,6 +391,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -358,6 +399,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -365,6 +407,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -372,11 +415,15 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
+            schema: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
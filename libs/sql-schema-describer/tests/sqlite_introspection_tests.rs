@@ -33,6 +33,8 @@ async fn sqlite_column_types_must_work() {
                 data_type: "int".to_string(),
                 full_data_type: "int".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -45,6 +47,8 @@ async fn sqlite_column_types_must_work() {
                 data_type: "INTEGER".to_string(),
                 full_data_type: "INTEGER".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -57,6 +61,8 @@ async fn sqlite_column_types_must_work() {
                 data_type: "TEXT".to_string(),
                 full_data_type: "TEXT".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -69,6 +75,8 @@ async fn sqlite_column_types_must_work() {
                 data_type: "REAL".to_string(),
                 full_data_type: "REAL".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -81,6 +89,8 @@ async fn sqlite_column_types_must_work() {
                 data_type: "INTEGER".to_string(),
                 full_data_type: "INTEGER".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -93,6 +103,8 @@ async fn sqlite_column_types_must_work() {
                 data_type: "decimal (5, 3)".to_string(),
                 full_data_type: "decimal (5, 3)".to_string(),
                 character_maximum_length: None,
+                precision: None,
+                scale: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -113,6 +125,8 @@ async fn sqlite_column_types_must_work() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
@@ -148,6 +162,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -160,6 +176,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -172,6 +190,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -184,6 +204,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -196,6 +218,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -209,6 +233,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        precision: None,
+                        scale: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -228,6 +254,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -235,6 +262,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -242,6 +270,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -249,6 +278,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -256,8 +286,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
+            schema: None,
         }
     );
 }
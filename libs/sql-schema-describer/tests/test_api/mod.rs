@@ -22,14 +22,23 @@ pub struct TestApi {
 
 impl TestApi {
     pub(crate) async fn describe(&self) -> Result<SqlSchema, anyhow::Error> {
+        Ok(self.describer().describe(self.schema_name()).await?)
+    }
+
+    pub(crate) async fn describe_schemas(&self, additional_schemas: &[String]) -> Result<SqlSchema, anyhow::Error> {
+        let describer = self.describer();
+
+        Ok(sql_schema_describer::describe_schemas(describer.as_ref(), self.schema_name(), additional_schemas).await?)
+    }
+
+    fn describer(&self) -> Box<dyn sql_schema_describer::SqlSchemaDescriberBackend> {
         let db = Arc::clone(&self.database);
-        let describer: Box<dyn sql_schema_describer::SqlSchemaDescriberBackend> = match self.sql_family() {
+
+        match self.sql_family() {
             SqlFamily::Postgres => Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(db)),
             SqlFamily::Sqlite => Box::new(sql_schema_describer::sqlite::SqlSchemaDescriber::new(db)),
             SqlFamily::Mysql => Box::new(sql_schema_describer::mysql::SqlSchemaDescriber::new(db)),
-        };
-
-        Ok(describer.describe(self.schema_name()).await?)
+        }
     }
 
     pub(crate) fn db_name(&self) -> &'static str {
@@ -64,7 +64,7 @@ async fn main() -> anyhow::Result<()> {
                 unreachable!()
             };
 
-            let introspected = introspection_core::RpcImpl::introspect_internal(schema)
+            let introspected = introspection_core::RpcImpl::introspect_internal(schema, false)
                 .await
                 .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
 
@@ -176,7 +176,7 @@ async fn generate_dmmf(cmd: &DmmfCommand) -> anyhow::Result<()> {
         if let Some(url) = cmd.url.as_ref() {
             let skeleton = minimal_schema_from_url(url)?;
 
-            let introspected = introspection_core::RpcImpl::introspect_internal(skeleton)
+            let introspected = introspection_core::RpcImpl::introspect_internal(skeleton, false)
                 .await
                 .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
 
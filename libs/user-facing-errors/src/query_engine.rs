@@ -226,3 +226,29 @@ pub struct InputError {
 pub struct ValueOutOfRange {
     pub details: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2021",
+    message = "The query did not complete within the timeout of ${timeout_ms}ms and was cancelled."
+)]
+pub struct QueryTimedOut {
+    pub timeout_ms: u64,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(code = "P2022", message = "The query exceeds a configured query limit: ${details}")]
+pub struct QueryLimitExceeded {
+    pub details: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2023",
+    message = "The check constraint `${constraint}` failed on the database: `${database_error}`"
+)]
+pub struct CheckConstraintViolation {
+    /// The name of the violated check constraint, when the database reports it.
+    pub constraint: String,
+    pub database_error: String,
+}
@@ -140,7 +140,9 @@ impl Migration {
     }
 
     pub fn parse_datamodel(&self) -> Result<Datamodel, (ErrorCollection, String)> {
-        datamodel::parse_datamodel(&self.datamodel_string).map_err(|err| (err, self.datamodel_string.clone()))
+        datamodel::parse_datamodel(&self.datamodel_string)
+            .map(|validated| validated.subject)
+            .map_err(|err| (err, self.datamodel_string.clone()))
     }
 
     pub fn parse_schema_ast(&self) -> Result<SchemaAst, (ErrorCollection, String)> {
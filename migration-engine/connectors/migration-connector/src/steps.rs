@@ -32,6 +32,12 @@ pub enum MigrationStep {
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CreateModel {
     pub model: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_view: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_composite_type: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq)]
@@ -41,11 +47,17 @@ pub struct UpdateModel {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub new_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_view: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_composite_type: Option<bool>,
 }
 
 impl UpdateModel {
     pub fn is_any_option_set(&self) -> bool {
-        self.new_name.is_some()
+        self.new_name.is_some() || self.is_view.is_some() || self.is_composite_type.is_some()
     }
 }
 
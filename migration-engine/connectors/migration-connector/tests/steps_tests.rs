@@ -7,6 +7,30 @@ fn full_CreateModel_must_work() {
     let json = r#"{"tag":"CreateModel","model":"Blog"}"#;
     let expected_struct = MigrationStep::CreateModel(CreateModel {
         model: "Blog".to_string(),
+        is_view: None,
+        is_composite_type: None,
+    });
+    assert_symmetric_serde(json, expected_struct);
+}
+
+#[test]
+fn CreateModel_for_a_view_must_work() {
+    let json = r#"{"tag":"CreateModel","model":"BlogSummary","isView":true}"#;
+    let expected_struct = MigrationStep::CreateModel(CreateModel {
+        model: "BlogSummary".to_string(),
+        is_view: Some(true),
+        is_composite_type: None,
+    });
+    assert_symmetric_serde(json, expected_struct);
+}
+
+#[test]
+fn CreateModel_for_a_composite_type_must_work() {
+    let json = r#"{"tag":"CreateModel","model":"Address","isCompositeType":true}"#;
+    let expected_struct = MigrationStep::CreateModel(CreateModel {
+        model: "Address".to_string(),
+        is_view: None,
+        is_composite_type: Some(true),
     });
     assert_symmetric_serde(json, expected_struct);
 }
@@ -17,6 +41,8 @@ fn minimal_UpdateModel_must_work() {
     let expected_struct = MigrationStep::UpdateModel(UpdateModel {
         model: "Blog".to_string(),
         new_name: None,
+        is_view: None,
+        is_composite_type: None,
     });
     assert_symmetric_serde(json, expected_struct);
 }
@@ -27,6 +53,8 @@ fn full_UpdateModel_must_work() {
     let expected_struct = MigrationStep::UpdateModel(UpdateModel {
         model: "Blog".to_string(),
         new_name: Some("MyBlog".to_string()),
+        is_view: None,
+        is_composite_type: None,
     });
     assert_symmetric_serde(json, expected_struct);
 }
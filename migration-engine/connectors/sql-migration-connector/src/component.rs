@@ -22,11 +22,12 @@ pub(crate) trait Component {
     }
 
     async fn describe(&self) -> SqlResult<sql_schema_describer::SqlSchema> {
-        Ok(self
-            .connector()
-            .database_describer
-            .describe(&self.schema_name())
-            .await?)
+        Ok(sql_schema_describer::describe_schemas(
+            self.connector().database_describer.as_ref(),
+            &self.schema_name(),
+            &self.connector().additional_schemas,
+        )
+        .await?)
     }
 
     fn sql_family(&self) -> SqlFamily {
@@ -40,6 +40,26 @@ impl DatabaseInfo {
                 .unwrap_or(false)
     }
 
+    /// MySQL accepts `CHECK` constraints in all versions, but only enforces and stores them since
+    /// 8.0.16. MariaDB does so since 10.2.1. Unknown versions are assumed to support them.
+    pub(crate) fn supports_check_constraints(&self) -> bool {
+        if self.sql_family() != SqlFamily::Mysql {
+            return true;
+        }
+
+        match self.database_version.as_deref().and_then(parse_version) {
+            Some(version) if self.is_mariadb() => version >= (10, 2, 1),
+            Some(version) => version >= (8, 0, 16),
+            None => true,
+        }
+    }
+
+    /// The schema of a model's table, as the describer reports it: tables in the schema of the
+    /// connection have no schema.
+    pub(crate) fn table_schema<'a>(&self, model_schema: Option<&'a str>) -> Option<&'a str> {
+        model_schema.filter(|schema| *schema != self.connection_info.schema_name())
+    }
+
     pub(crate) fn sql_family(&self) -> SqlFamily {
         self.connection_info.sql_family()
     }
@@ -76,6 +96,15 @@ async fn get_database_version(connection: &Quaint, connection_info: &ConnectionI
     }
 }
 
+/// Parses the major, minor and patch version from a version string like `8.0.21` or `10.4.13-MariaDB-1:10.4.13+maria~focal`.
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version
+        .split(|c: char| !c.is_ascii_digit())
+        .map(|part| part.parse().ok());
+
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
 fn check_datamodel_for_mysql_5_6(datamodel: &Datamodel, errors: &mut Vec<MigrationError>) {
     crate::datamodel_helpers::walk_fields(datamodel).for_each(|field| {
         if field.field_type().is_json() {
@@ -1,7 +1,7 @@
 use datamodel::{
     dml::{
-        Datamodel, DefaultValue, Enum, Field, FieldArity, FieldType, IndexDefinition, Model, ScalarType,
-        WithDatabaseName,
+        CheckConstraint, Datamodel, DefaultValue, Enum, Field, FieldArity, FieldType, IndexDefinition, Model,
+        ReferentialAction, ScalarFieldType, ScalarType, WithDatabaseName,
     },
     RelationInfo,
 };
@@ -10,15 +10,27 @@ pub(crate) fn walk_models<'a>(datamodel: &'a Datamodel) -> impl Iterator<Item =
     datamodel.models.iter().map(move |model| ModelRef { datamodel, model })
 }
 
-/// Iterator to walk all the fields in the schema, associating them with their parent model.
+/// Iterator to walk the models backed by tables, skipping views and ignored models.
+pub(crate) fn walk_table_models<'a>(datamodel: &'a Datamodel) -> impl Iterator<Item = ModelRef<'a>> + 'a {
+    walk_models(datamodel).filter(|model| !model.is_view() && !model.is_ignored())
+}
+
+/// Iterator to walk all the fields in the schema, associating them with their parent model. Ignored
+/// models and fields are skipped.
 pub(super) fn walk_fields<'a>(datamodel: &'a Datamodel) -> impl Iterator<Item = FieldRef<'a>> + 'a {
-    datamodel.models().flat_map(move |model| {
-        model.fields().map(move |field| FieldRef {
-            datamodel,
-            model,
-            field,
+    datamodel
+        .models()
+        .filter(|model| !model.is_ignored)
+        .flat_map(move |model| {
+            model
+                .fields()
+                .filter(|field| !field.is_ignored)
+                .map(move |field| FieldRef {
+                    datamodel,
+                    model,
+                    field,
+                })
         })
-    })
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -40,12 +52,16 @@ impl<'a> ModelRef<'a> {
         self.model.final_database_name()
     }
 
+    /// The fields of the model, skipping ignored fields.
     pub(super) fn fields<'b>(&'b self) -> impl Iterator<Item = FieldRef<'a>> + 'b {
-        self.model.fields().map(move |field| FieldRef {
-            datamodel: self.datamodel,
-            model: self.model,
-            field,
-        })
+        self.model
+            .fields()
+            .filter(|field| !field.is_ignored)
+            .map(move |field| FieldRef {
+                datamodel: self.datamodel,
+                model: self.model,
+                field,
+            })
     }
 
     pub(super) fn find_field(&self, name: &str) -> Option<FieldRef<'a>> {
@@ -60,8 +76,25 @@ impl<'a> ModelRef<'a> {
             })
     }
 
+    /// The indexes of the model, skipping indexes on ignored fields.
     pub(super) fn indexes<'b>(&'b self) -> impl Iterator<Item = &'a IndexDefinition> + 'b {
-        self.model.indices.iter()
+        let model = self.model;
+
+        model.indices.iter().filter(move |index| {
+            index
+                .fields
+                .iter()
+                .all(|name| model.find_field(name).map(|field| !field.is_ignored).unwrap_or(true))
+        })
+    }
+
+    pub(super) fn check_constraints<'b>(&'b self) -> impl Iterator<Item = &'a CheckConstraint> + 'b {
+        self.model.check_constraints.iter()
+    }
+
+    /// The database schema of the model, if it does not live in the default schema.
+    pub(super) fn schema(&self) -> Option<&'a str> {
+        self.model.schema.as_ref().map(String::as_str)
     }
 
     pub(super) fn model(&self) -> &'a Model {
@@ -72,6 +105,16 @@ impl<'a> ModelRef<'a> {
         &self.model.name
     }
 
+    /// Views are managed by the user, migrations never create or alter them.
+    pub(super) fn is_view(&self) -> bool {
+        self.model.is_view
+    }
+
+    /// Ignored models are left alone by migrations.
+    pub(super) fn is_ignored(&self) -> bool {
+        self.model.is_ignored
+    }
+
     pub(super) fn id_fields<'b>(&'b self) -> impl Iterator<Item = FieldRef<'a>> + 'b {
         // Single-id models
         self.model
@@ -131,6 +174,9 @@ impl<'a> FieldRef<'a> {
                 r#enum: self.datamodel.find_enum(name).unwrap(),
             }),
             FieldType::Base(scalar_type, _) => TypeRef::Base(*scalar_type),
+            FieldType::ConnectorSpecific(native_type) => TypeRef::Native(native_type),
+            // Values of composite types are stored in Json columns.
+            FieldType::CompositeType(_) => TypeRef::Base(ScalarType::Json),
             _ => TypeRef::Other,
         }
     }
@@ -176,6 +222,8 @@ impl<'a> FieldRef<'a> {
 pub(super) enum TypeRef<'a> {
     Enum(EnumRef<'a>),
     Base(ScalarType),
+    /// A scalar with a native database type, declared with a `@db.` attribute.
+    Native(&'a ScalarFieldType),
     Other,
 }
 
@@ -188,7 +236,11 @@ impl<'a> TypeRef<'a> {
     }
 
     pub(super) fn is_json(&self) -> bool {
-        matches!(self, TypeRef::Base(ScalarType::Json))
+        match self {
+            TypeRef::Base(ScalarType::Json) => true,
+            TypeRef::Native(native_type) => native_type.prisma_type() == ScalarType::Json,
+            _ => false,
+        }
     }
 }
 
@@ -260,6 +312,18 @@ impl<'a> RelationFieldRef<'a> {
         self.relation_info.name.as_ref()
     }
 
+    pub(crate) fn on_delete(&self) -> Option<ReferentialAction> {
+        self.relation_info.on_delete
+    }
+
+    pub(crate) fn on_update(&self) -> Option<ReferentialAction> {
+        self.relation_info.on_update
+    }
+
+    pub(crate) fn references_view(&self) -> bool {
+        self.referenced_model().is_view
+    }
+
     pub(crate) fn referenced_table_name(&self) -> &'a str {
         self.referenced_model().final_database_name()
     }
@@ -44,6 +44,8 @@ const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub struct SqlMigrationConnector {
     pub schema_name: String,
+    /// The schemas, next to `schema_name`, that the datasource spans. Only used on Postgres.
+    pub additional_schemas: Vec<String>,
     pub database: Arc<dyn Queryable + Send + Sync + 'static>,
     pub database_info: DatabaseInfo,
     pub database_describer: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
@@ -66,11 +68,21 @@ impl SqlMigrationConnector {
         Ok(Self {
             database_info,
             schema_name,
+            additional_schemas: Vec::new(),
             database: conn,
             database_describer: Arc::clone(&describer),
         })
     }
 
+    pub fn with_additional_schemas(mut self, additional_schemas: &[String]) -> Self {
+        self.additional_schemas = additional_schemas
+            .iter()
+            .filter(|schema| **schema != self.schema_name)
+            .cloned()
+            .collect();
+        self
+    }
+
     pub async fn create_database(database_str: &str) -> ConnectorResult<String> {
         use anyhow::Context;
         use futures::future::TryFutureExt;
@@ -146,11 +158,13 @@ impl SqlMigrationConnector {
                 }
             }
             ConnectionInfo::Postgres(_) => {
-                let schema_sql = format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", &self.schema_name);
+                for schema_name in std::iter::once(&self.schema_name).chain(self.additional_schemas.iter()) {
+                    let schema_sql = format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", schema_name);
 
-                debug!("{}", schema_sql);
+                    debug!("{}", schema_sql);
 
-                self.database.query_raw(&schema_sql, &[]).await?;
+                    self.database.query_raw(&schema_sql, &[]).await?;
+                }
             }
             ConnectionInfo::Mysql(_) => {
                 let schema_sql = format!(
@@ -177,10 +191,14 @@ impl SqlMigrationConnector {
         catch(self.connection_info(), async {
             match &self.connection_info() {
                 ConnectionInfo::Postgres(_) => {
-                    let sql_str = format!(r#"DROP SCHEMA "{}" CASCADE;"#, self.schema_name());
-                    debug!("{}", sql_str);
+                    for schema_name in
+                        std::iter::once(self.schema_name()).chain(self.additional_schemas.iter().map(String::as_str))
+                    {
+                        let sql_str = format!(r#"DROP SCHEMA "{}" CASCADE;"#, schema_name);
+                        debug!("{}", sql_str);
 
-                    self.conn().query_raw(&sql_str, &[]).await.ok();
+                        self.conn().query_raw(&sql_str, &[]).await.ok();
+                    }
                 }
                 ConnectionInfo::Sqlite { file_path, .. } => {
                     self.conn()
@@ -1,3 +1,4 @@
+mod check_constraints;
 mod sqlite;
 
 use crate::sql_schema_calculator::SqlSchemaCalculator;
@@ -29,7 +30,21 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
     ) -> ConnectorResult<SqlMigration> {
         let fut = async {
             let current_database_schema: SqlSchema = self.describe().await?;
-            let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info())?;
+            let mut expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info())?;
+            keep_ignored_tables_and_columns(
+                &current_database_schema,
+                &mut expected_database_schema,
+                next,
+                self.database_info(),
+            );
+            check_constraints::normalize_check_constraints(
+                self.conn(),
+                &current_database_schema,
+                &mut expected_database_schema,
+                self.schema_name(),
+                self.sql_family(),
+            )
+            .await?;
             infer(
                 &current_database_schema,
                 &expected_database_schema,
@@ -50,7 +65,13 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
     ) -> ConnectorResult<SqlMigration> {
         let result: SqlResult<SqlMigration> = (|| {
             let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous, self.database_info())?;
-            let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info())?;
+            let mut expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info())?;
+            keep_ignored_tables_and_columns(
+                &current_database_schema,
+                &mut expected_database_schema,
+                next,
+                self.database_info(),
+            );
             infer(
                 &current_database_schema,
                 &expected_database_schema,
@@ -94,6 +115,108 @@ fn infer(
     })
 }
 
+/// Copies the tables of ignored models and the columns of ignored fields from the current database
+/// schema into the calculated one, which does not contain them. Migrations then leave them alone, and
+/// tables that have to be redefined, like on SQLite, keep them.
+fn keep_ignored_tables_and_columns(
+    current: &SqlSchema,
+    expected: &mut SqlSchema,
+    datamodel: &Datamodel,
+    database_info: &DatabaseInfo,
+) {
+    for model in datamodel.models() {
+        let table_name = model.final_database_name();
+        let table_schema = database_info.table_schema(model.schema.as_deref());
+        let is_model_table = |table: &Table| table.name == table_name && table.schema.as_deref() == table_schema;
+
+        if model.is_ignored {
+            let ignored_table = match current.tables.iter().find(|table| is_model_table(table)) {
+                Some(table) => table,
+                None => continue,
+            };
+
+            if !expected.tables.iter().any(|table| is_model_table(table)) {
+                expected.tables.push(ignored_table.clone());
+            }
+
+            // Foreign keys pointing to the ignored table. They only have the name of the referenced table,
+            // which is resolved to a schema the same way as when the foreign keys are rendered.
+            let references_ignored_table = |fk: &&ForeignKey| {
+                current
+                    .get_table(&fk.referenced_table)
+                    .map(|table| is_model_table(table))
+                    .unwrap_or(false)
+            };
+
+            for current_table in current.tables.iter() {
+                let expected_table = match expected
+                    .tables
+                    .iter_mut()
+                    .find(|table| table.name == current_table.name && table.schema == current_table.schema)
+                {
+                    Some(table) => table,
+                    None => continue,
+                };
+
+                for fk in current_table.foreign_keys.iter().filter(references_ignored_table) {
+                    if !expected_table.foreign_keys.contains(fk) {
+                        expected_table.foreign_keys.push(fk.clone());
+                    }
+                }
+            }
+
+            continue;
+        }
+
+        let ignored_columns: Vec<&str> = model
+            .fields()
+            .filter(|field| field.is_ignored)
+            .map(|field| field.final_database_name())
+            .collect();
+
+        if ignored_columns.is_empty() {
+            continue;
+        }
+
+        let uses_ignored_column = |columns: &[String]| columns.iter().any(|c| ignored_columns.contains(&c.as_str()));
+
+        let current_table = current.tables.iter().find(|table| is_model_table(table));
+        let expected_table = expected.tables.iter_mut().find(|table| is_model_table(table));
+
+        if let (Some(current_table), Some(expected_table)) = (current_table, expected_table) {
+            for column in current_table
+                .columns
+                .iter()
+                .filter(|column| ignored_columns.contains(&column.name.as_str()))
+            {
+                if !expected_table.columns.iter().any(|c| c.name == column.name) {
+                    expected_table.columns.push(column.clone());
+                }
+            }
+
+            for index in current_table
+                .indices
+                .iter()
+                .filter(|index| uses_ignored_column(&index.columns))
+            {
+                if !expected_table.indices.iter().any(|i| i.name == index.name) {
+                    expected_table.indices.push(index.clone());
+                }
+            }
+
+            for fk in current_table
+                .foreign_keys
+                .iter()
+                .filter(|fk| uses_ignored_column(&fk.columns))
+            {
+                if !expected_table.foreign_keys.contains(fk) {
+                    expected_table.foreign_keys.push(fk.clone());
+                }
+            }
+        }
+    }
+}
+
 fn infer_database_migration_steps_and_fix(
     from: &SqlSchema,
     to: &SqlSchema,
@@ -0,0 +1,261 @@
+//! Databases store check constraint expressions in their own form, e.g. Postgres turns `price IN (1, 2)`
+//! into `price = ANY (ARRAY[1, 2])` and MySQL quotes all identifiers. The expressions of the calculated
+//! check constraints that already exist in the database are brought into that form by the database
+//! itself before diffing, so unchanged expressions compare equal and changed ones don't.
+
+use crate::{sql_renderer::Quoted, SqlFamily, SqlResult};
+use quaint::prelude::Queryable;
+use sql_schema_describer::SqlSchema;
+use tracing::debug;
+
+/// The temporary table the expressions are normalized on. It only exists during the normalization.
+const NORMALIZATION_TABLE: &str = "_prisma_check_normalization";
+
+/// The name of the normalized check constraint on `NORMALIZATION_TABLE`.
+const NORMALIZATION_CONSTRAINT: &str = "_prisma_check_normalization_check";
+
+pub(super) async fn normalize_check_constraints(
+    conn: &dyn Queryable,
+    current: &SqlSchema,
+    expected: &mut SqlSchema,
+    schema_name: &str,
+    sql_family: SqlFamily,
+) -> SqlResult<()> {
+    // SQLite stores the expressions as they are written in the CREATE TABLE statement.
+    if sql_family == SqlFamily::Sqlite {
+        return Ok(());
+    }
+
+    for expected_table in expected.tables.iter_mut() {
+        let current_table = match current
+            .tables
+            .iter()
+            .find(|table| table.name == expected_table.name && table.schema == expected_table.schema)
+        {
+            Some(table) => table,
+            None => continue,
+        };
+
+        let table_schema = current_table.schema.as_deref().unwrap_or(schema_name);
+
+        for check_constraint in expected_table.check_constraints.iter_mut() {
+            let current_expression = current_table
+                .check_constraints
+                .iter()
+                .find(|current| current.name.is_some() && current.name == check_constraint.name)
+                .map(|current| current.expression.trim());
+
+            // New check constraints are created anyway.
+            match current_expression {
+                Some(current_expression) if current_expression != check_constraint.expression.trim() => (),
+                _ => continue,
+            }
+
+            let normalized_expression = match sql_family {
+                SqlFamily::Postgres => {
+                    normalize_on_postgres(conn, table_schema, &current_table.name, &check_constraint.expression).await?
+                }
+                SqlFamily::Mysql => {
+                    normalize_on_mysql(conn, table_schema, &current_table.name, &check_constraint.expression).await?
+                }
+                SqlFamily::Sqlite => unreachable!("Check constraints are not normalized on SQLite."),
+            };
+
+            if let Some(normalized_expression) = normalized_expression {
+                check_constraint.expression = normalized_expression;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds the check constraint to an empty copy of the table inside of a transaction that is rolled back.
+async fn normalize_on_postgres(
+    conn: &dyn Queryable,
+    schema_name: &str,
+    table_name: &str,
+    expression: &str,
+) -> SqlResult<Option<String>> {
+    conn.query_raw("BEGIN", &[]).await?;
+
+    let normalized_expression = postgres_normalized_expression(conn, schema_name, table_name, expression).await;
+
+    conn.query_raw("ROLLBACK", &[]).await?;
+
+    Ok(expression_or_log(normalized_expression, expression))
+}
+
+async fn postgres_normalized_expression(
+    conn: &dyn Queryable,
+    schema_name: &str,
+    table_name: &str,
+    expression: &str,
+) -> SqlResult<Option<String>> {
+    let create_table = format!(
+        "CREATE TEMPORARY TABLE {} (LIKE {}.{})",
+        Quoted::postgres_ident(NORMALIZATION_TABLE),
+        Quoted::postgres_ident(schema_name),
+        Quoted::postgres_ident(table_name),
+    );
+
+    let add_check_constraint = format!(
+        "ALTER TABLE {} ADD CONSTRAINT {} CHECK ({})",
+        Quoted::postgres_ident(NORMALIZATION_TABLE),
+        Quoted::postgres_ident(NORMALIZATION_CONSTRAINT),
+        expression,
+    );
+
+    // The same expression the describer reads.
+    let sql = r#"
+        SELECT pg_get_expr(con.conbin, con.conrelid) AS expression
+        FROM pg_constraint con
+        WHERE con.conrelid = $1::text::regclass AND con.conname = $2"#;
+
+    conn.query_raw(&create_table, &[]).await?;
+    conn.query_raw(&add_check_constraint, &[]).await?;
+
+    let table = Quoted::postgres_ident(NORMALIZATION_TABLE).to_string();
+    let rows = conn
+        .query_raw(sql, &[table.as_str().into(), NORMALIZATION_CONSTRAINT.into()])
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .next()
+        .and_then(|row| row.get("expression").and_then(|x| x.to_string())))
+}
+
+/// Adds the check constraint to an empty temporary copy of the table. MySQL commits DDL statements
+/// implicitly, so the temporary table is dropped afterwards instead.
+async fn normalize_on_mysql(
+    conn: &dyn Queryable,
+    schema_name: &str,
+    table_name: &str,
+    expression: &str,
+) -> SqlResult<Option<String>> {
+    let normalized_expression = mysql_normalized_expression(conn, schema_name, table_name, expression).await;
+
+    let drop_table = format!(
+        "DROP TEMPORARY TABLE IF EXISTS {}",
+        Quoted::mysql_ident(NORMALIZATION_TABLE)
+    );
+
+    conn.query_raw(&drop_table, &[]).await?;
+
+    Ok(expression_or_log(normalized_expression, expression))
+}
+
+async fn mysql_normalized_expression(
+    conn: &dyn Queryable,
+    schema_name: &str,
+    table_name: &str,
+    expression: &str,
+) -> SqlResult<Option<String>> {
+    // Unlike `LIKE`, this does not copy the check constraints of the table, whose names would clash.
+    let create_table = format!(
+        "CREATE TEMPORARY TABLE {} SELECT * FROM {}.{} LIMIT 0",
+        Quoted::mysql_ident(NORMALIZATION_TABLE),
+        Quoted::mysql_ident(schema_name),
+        Quoted::mysql_ident(table_name),
+    );
+
+    let add_check_constraint = format!(
+        "ALTER TABLE {} ADD CONSTRAINT {} CHECK ({})",
+        Quoted::mysql_ident(NORMALIZATION_TABLE),
+        Quoted::mysql_ident(NORMALIZATION_CONSTRAINT),
+        expression,
+    );
+
+    // Temporary tables are not part of `information_schema`.
+    let show_create_table = format!("SHOW CREATE TABLE {}", Quoted::mysql_ident(NORMALIZATION_TABLE));
+
+    conn.query_raw(&create_table, &[]).await?;
+    conn.query_raw(&add_check_constraint, &[]).await?;
+
+    let rows = conn.query_raw(&show_create_table, &[]).await?;
+
+    Ok(rows
+        .into_iter()
+        .next()
+        .and_then(|row| row.get("Create Table").and_then(|x| x.to_string()))
+        .and_then(|create_table| mysql_check_clause(&create_table)))
+}
+
+/// Extracts the expression of `NORMALIZATION_CONSTRAINT` from a `SHOW CREATE TABLE` statement, where
+/// it is rendered like ``CONSTRAINT `name` CHECK (<expression>)``, the same way `information_schema`
+/// has it. MySQL escapes the quotes of string literals there, e.g. `_utf8mb4\'a\'`.
+fn mysql_check_clause(create_table: &str) -> Option<String> {
+    const QUOTES: &[char] = &['\'', '"', '`'];
+
+    let prefix = format!("CONSTRAINT {} CHECK (", Quoted::mysql_ident(NORMALIZATION_CONSTRAINT));
+    let start = create_table.find(&prefix)? + prefix.len();
+    let mut chars = create_table[start..].char_indices();
+    let mut depth = 1;
+    // The quote character of the current literal, and whether it is escaped.
+    let mut quote: Option<(char, bool)> = None;
+
+    while let Some((idx, c)) = chars.next() {
+        match (quote, c) {
+            (_, '\\') => match (quote, chars.next()) {
+                (None, Some((_, next))) if QUOTES.contains(&next) => quote = Some((next, true)),
+                (Some((open, true)), Some((_, next))) if next == open => quote = None,
+                _ => (),
+            },
+            (Some((open, false)), c) if c == open => quote = None,
+            (Some(_), _) => (),
+            (None, c) if QUOTES.contains(&c) => quote = Some((c, false)),
+            (None, '(') => depth += 1,
+            (None, ')') if depth == 1 => return Some(create_table[start..start + idx].to_owned()),
+            (None, ')') => depth -= 1,
+            _ => (),
+        }
+    }
+
+    None
+}
+
+/// Expressions the database does not accept are kept as they are, and fail when the migration is applied.
+fn expression_or_log(normalized_expression: SqlResult<Option<String>>, expression: &str) -> Option<String> {
+    match normalized_expression {
+        Ok(normalized_expression) => normalized_expression,
+        Err(err) => {
+            debug!(
+                "Could not normalize the check constraint expression `{}`: {}",
+                expression, err
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mysql_check_clauses_are_extracted() {
+        let create_table = "CREATE TEMPORARY TABLE `_prisma_check_normalization` (\n  \
+            `price` int NOT NULL,\n  \
+            `name` varchar(191) NOT NULL,\n  \
+            CONSTRAINT `_prisma_check_normalization_check` CHECK (((`price` > 0) and (`name` <> _utf8mb4')')))\n\
+            ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4";
+
+        assert_eq!(
+            mysql_check_clause(create_table).as_deref(),
+            Some("((`price` > 0) and (`name` <> _utf8mb4')'))")
+        );
+
+        let create_table = "CREATE TEMPORARY TABLE `_prisma_check_normalization` (\n  \
+            `name` varchar(191) NOT NULL,\n  \
+            CONSTRAINT `_prisma_check_normalization_check` CHECK ((`name` <> _utf8mb4\\'(\\'))\n\
+            ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4";
+
+        assert_eq!(
+            mysql_check_clause(create_table).as_deref(),
+            Some("(`name` <> _utf8mb4\\'(\\')")
+        );
+
+        assert_eq!(mysql_check_clause("CREATE TABLE `Product` (`price` int)"), None);
+    }
+}
@@ -84,6 +84,9 @@ fn needs_fix(alter_table: &AlterTable) -> bool {
         TableChange::DropColumn(_) => true,
         TableChange::AlterColumn(_) => true,
         TableChange::DropForeignKey(_) => true,
+        // SQLite has no way to add or drop check constraints on existing tables.
+        TableChange::AddCheckConstraint(_) => true,
+        TableChange::DropCheckConstraint(_) => true,
     });
 
     change_that_does_not_work_on_sqlite.is_some()
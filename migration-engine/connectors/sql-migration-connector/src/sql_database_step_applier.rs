@@ -138,6 +138,14 @@ fn render_raw_sql(
 ) -> Result<Vec<String>, anyhow::Error> {
     let sql_family = renderer.sql_family();
     let schema_name = database_info.connection_info().schema_name().to_string();
+    // Tables can live in another schema than the one from the connection string.
+    let table_schema = |table_name: &str| -> String {
+        next_schema
+            .get_table(table_name)
+            .or_else(|| current_schema.get_table(table_name))
+            .and_then(|table| table.schema.clone())
+            .unwrap_or_else(|| schema_name.clone())
+    };
 
     match step {
         SqlMigrationStep::CreateEnum(create_enum) => render_create_enum(renderer, create_enum),
@@ -163,7 +171,7 @@ fn render_raw_sql(
 
             let mut create_table = format!(
                 "CREATE TABLE {} (\n{}",
-                renderer.quote_with_schema(&schema_name, &table.name),
+                renderer.quote_with_schema(table.schema.as_ref().unwrap_or(&schema_name), &table.name),
                 columns,
             );
 
@@ -175,6 +183,14 @@ fn render_raw_sql(
                 write!(create_table, ",\n    PRIMARY KEY ({})", column_names)?;
             }
 
+            for check_constraint in &table.check_constraints {
+                write!(
+                    create_table,
+                    ",\n    {}",
+                    render_check_constraint(renderer, check_constraint)
+                )?;
+            }
+
             if sql_family == SqlFamily::Sqlite && !table.foreign_keys.is_empty() {
                 write!(create_table, ",")?;
 
@@ -185,7 +201,7 @@ fn render_raw_sql(
                         create_table,
                         "FOREIGN KEY ({constrained_columns}) {references}{comma}",
                         constrained_columns = fk.columns.iter().map(|col| format!(r#""{}""#, col)).join(","),
-                        references = renderer.render_references(&schema_name, fk),
+                        references = renderer.render_references(&table_schema(&fk.referenced_table), fk),
                         comma = if fks.peek().is_some() { ",\n" } else { "" },
                     )?;
                 }
@@ -198,7 +214,7 @@ fn render_raw_sql(
         SqlMigrationStep::DropTable(DropTable { name }) => match sql_family {
             SqlFamily::Mysql | SqlFamily::Postgres => Ok(vec![format!(
                 "DROP TABLE {};",
-                renderer.quote_with_schema(&schema_name, &name)
+                renderer.quote_with_schema(&table_schema(name), &name)
             )]),
             // Turning off the pragma is safe, because schema validation would forbid foreign keys
             // to a non-existent model. There appears to be no other way to deal with cyclic
@@ -211,17 +227,21 @@ fn render_raw_sql(
             ]),
         },
         SqlMigrationStep::DropTables(DropTables { names }) => {
-            let fully_qualified_names = names.iter().map(|name| renderer.quote_with_schema(&schema_name, &name));
+            let fully_qualified_names = names
+                .iter()
+                .map(|name| renderer.quote_with_schema(&table_schema(name), &name).to_string());
             Ok(vec![format!("DROP TABLE {};", fully_qualified_names.join(","))])
         }
         SqlMigrationStep::RenameTable { name, new_name } => {
             let new_name = match sql_family {
                 SqlFamily::Sqlite => renderer.quote(new_name).to_string(),
-                _ => renderer.quote_with_schema(&schema_name, &new_name).to_string(),
+                _ => renderer
+                    .quote_with_schema(&table_schema(new_name), &new_name)
+                    .to_string(),
             };
             Ok(vec![format!(
                 "ALTER TABLE {} RENAME TO {};",
-                renderer.quote_with_schema(&schema_name, &name),
+                renderer.quote_with_schema(&table_schema(name), &name),
                 new_name
             )])
         }
@@ -233,7 +253,7 @@ fn render_raw_sql(
                 write!(
                     add_constraint,
                     "ALTER TABLE {table} ADD ",
-                    table = renderer.quote_with_schema(&schema_name, table)
+                    table = renderer.quote_with_schema(&table_schema(table), table)
                 )?;
 
                 if let Some(constraint_name) = foreign_key.constraint_name.as_ref() {
@@ -246,7 +266,8 @@ fn render_raw_sql(
                     foreign_key.columns.iter().map(|col| renderer.quote(col)).join(", ")
                 )?;
 
-                add_constraint.push_str(&renderer.render_references(&schema_name, &foreign_key));
+                add_constraint
+                    .push_str(&renderer.render_references(&table_schema(&foreign_key.referenced_table), &foreign_key));
 
                 Ok(vec![add_constraint])
             }
@@ -304,6 +325,22 @@ fn render_raw_sql(
                         }
                         SqlFamily::Sqlite => (),
                     },
+                    TableChange::AddCheckConstraint(AddCheckConstraint { check_constraint }) => match sql_family {
+                        SqlFamily::Mysql | SqlFamily::Postgres => {
+                            lines.push(format!("ADD {}", render_check_constraint(renderer, check_constraint)));
+                        }
+                        SqlFamily::Sqlite => (),
+                    },
+                    TableChange::DropCheckConstraint(DropCheckConstraint { constraint_name }) => match sql_family {
+                        // MariaDB does not know about `DROP CHECK`.
+                        SqlFamily::Mysql if !database_info.is_mariadb() => {
+                            lines.push(format!("DROP CHECK {}", renderer.quote(&constraint_name)));
+                        }
+                        SqlFamily::Mysql | SqlFamily::Postgres => {
+                            lines.push(format!("DROP CONSTRAINT {}", renderer.quote(&constraint_name)));
+                        }
+                        SqlFamily::Sqlite => (),
+                    },
                 };
             }
 
@@ -313,22 +350,26 @@ fn render_raw_sql(
 
             Ok(vec![format!(
                 "ALTER TABLE {} {};",
-                renderer.quote_with_schema(&schema_name, &table.name),
+                renderer.quote_with_schema(table.schema.as_ref().unwrap_or(&schema_name), &table.name),
                 lines.join(",\n")
             )])
         }
-        SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
-            Ok(vec![render_create_index(renderer, database_info, table, index)])
-        }
+        SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => Ok(vec![render_create_index(
+            renderer,
+            database_info,
+            &table_schema(table),
+            table,
+            index,
+        )]),
         SqlMigrationStep::DropIndex(DropIndex { table, name }) => match sql_family {
             SqlFamily::Mysql => Ok(vec![format!(
                 "DROP INDEX {} ON {}",
                 renderer.quote(&name),
-                renderer.quote_with_schema(&schema_name, &table),
+                renderer.quote_with_schema(&table_schema(table), &table),
             )]),
             SqlFamily::Postgres | SqlFamily::Sqlite => Ok(vec![format!(
                 "DROP INDEX {}",
-                renderer.quote_with_schema(&schema_name, &name)
+                renderer.quote_with_schema(&table_schema(table), &name)
             )]),
         },
         SqlMigrationStep::AlterIndex(AlterIndex {
@@ -362,13 +403,13 @@ fn render_raw_sql(
 
                     // Order matters: dropping the old index first wouldn't work when foreign key constraints are still relying on it.
                     Ok(vec![
-                        render_create_index(renderer, database_info, table, &new_index),
-                        mysql_drop_index(renderer, &schema_name, table, index_name)?,
+                        render_create_index(renderer, database_info, &table_schema(table), table, &new_index),
+                        mysql_drop_index(renderer, &table_schema(table), table, index_name)?,
                     ])
                 } else {
                     Ok(vec![format!(
                         "ALTER TABLE {table_name} RENAME INDEX {index_name} TO {index_new_name}",
-                        table_name = renderer.quote_with_schema(&schema_name, &table),
+                        table_name = renderer.quote_with_schema(&table_schema(table), &table),
                         index_name = renderer.quote(index_name),
                         index_new_name = renderer.quote(index_new_name)
                     )])
@@ -376,18 +417,57 @@ fn render_raw_sql(
             }
             SqlFamily::Postgres => Ok(vec![format!(
                 "ALTER INDEX {} RENAME TO {}",
-                renderer.quote_with_schema(&schema_name, index_name),
+                renderer.quote_with_schema(&table_schema(table), index_name),
                 renderer.quote(index_new_name)
             )]),
             SqlFamily::Sqlite => unimplemented!("Index renaming on SQLite."),
         },
         SqlMigrationStep::RawSql { raw } => Ok(vec![raw.to_owned()]),
+        SqlMigrationStep::AlterTableSchema(AlterTableSchema {
+            table,
+            previous_schema,
+            next_schema,
+        }) => {
+            let previous_schema = previous_schema.as_ref().unwrap_or(&schema_name);
+            let next_schema = next_schema.as_ref().unwrap_or(&schema_name);
+
+            match sql_family {
+                SqlFamily::Postgres => Ok(vec![format!(
+                    "ALTER TABLE {} SET SCHEMA {};",
+                    renderer.quote_with_schema(previous_schema, table),
+                    renderer.quote(next_schema)
+                )]),
+                SqlFamily::Mysql => Ok(vec![format!(
+                    "ALTER TABLE {} RENAME TO {};",
+                    renderer.quote_with_schema(previous_schema, table),
+                    renderer.quote_with_schema(next_schema, table)
+                )]),
+                SqlFamily::Sqlite => Ok(Vec::new()),
+            }
+        }
+        SqlMigrationStep::CreateSequence(CreateSequence { sequence }) => match sql_family {
+            SqlFamily::Postgres => Ok(vec![format!(
+                "CREATE SEQUENCE IF NOT EXISTS {} START WITH {} INCREMENT BY {};",
+                renderer.quote_with_schema(&schema_name, &sequence.name),
+                sequence.initial_value,
+                sequence.allocation_size
+            )]),
+            _ => Ok(Vec::new()),
+        },
+        SqlMigrationStep::DropSequence(DropSequence { name }) => match sql_family {
+            SqlFamily::Postgres => Ok(vec![format!(
+                "DROP SEQUENCE IF EXISTS {};",
+                renderer.quote_with_schema(&schema_name, &name)
+            )]),
+            _ => Ok(Vec::new()),
+        },
     }
 }
 
 fn render_create_index(
     renderer: &dyn SqlRenderer,
     database_info: &DatabaseInfo,
+    schema_name: &str,
     table_name: &str,
     index: &Index,
 ) -> String {
@@ -398,16 +478,12 @@ fn render_create_index(
     };
     let sql_family = database_info.sql_family();
     let index_name = match sql_family {
-        SqlFamily::Sqlite => renderer
-            .quote_with_schema(database_info.connection_info().schema_name(), &name)
-            .to_string(),
+        SqlFamily::Sqlite => renderer.quote_with_schema(schema_name, &name).to_string(),
         _ => renderer.quote(&name).to_string(),
     };
     let table_reference = match sql_family {
         SqlFamily::Sqlite => renderer.quote(table_name).to_string(),
-        _ => renderer
-            .quote_with_schema(database_info.connection_info().schema_name(), table_name)
-            .to_string(),
+        _ => renderer.quote_with_schema(schema_name, table_name).to_string(),
     };
     let columns = columns.iter().map(|c| renderer.quote(c));
 
@@ -420,6 +496,17 @@ fn render_create_index(
     )
 }
 
+fn render_check_constraint(renderer: &dyn SqlRenderer, check_constraint: &CheckConstraint) -> String {
+    match &check_constraint.name {
+        Some(name) => format!(
+            "CONSTRAINT {} CHECK ({})",
+            renderer.quote(name),
+            check_constraint.expression
+        ),
+        None => format!("CHECK ({})", check_constraint.expression),
+    }
+}
+
 fn mysql_drop_index(
     renderer: &dyn SqlRenderer,
     schema_name: &str,
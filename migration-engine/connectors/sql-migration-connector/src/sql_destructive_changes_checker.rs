@@ -21,18 +21,23 @@ impl Component for SqlDestructiveChangesChecker<'_> {
 }
 
 impl SqlDestructiveChangesChecker<'_> {
+    /// Tables from additional schemas carry their schema, all others live in the default one.
+    fn table_schema_name<'a>(&'a self, table: &'a sql_schema_describer::Table) -> &'a str {
+        table.schema.as_ref().map(String::as_str).unwrap_or(self.schema_name())
+    }
+
     async fn check_table_drop(
         &self,
-        table_name: &str,
+        table: &sql_schema_describer::Table,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
-        let rows_count = self.count_rows_in_table(table_name).await?;
+        let rows_count = self.count_rows_in_table(table).await?;
 
         if rows_count > 0 {
             diagnostics.add_warning(MigrationWarning {
                 description: format!(
                     "You are about to drop the table `{table_name}`, which is not empty ({rows_count} rows).",
-                    table_name = table_name,
+                    table_name = table.name,
                     rows_count = rows_count
                 ),
             });
@@ -42,7 +47,7 @@ impl SqlDestructiveChangesChecker<'_> {
     }
 
     async fn count_values_in_column(&self, column_name: &str, table: &sql_schema_describer::Table) -> SqlResult<i64> {
-        let query = Select::from_table((self.schema_name(), table.name.as_str()))
+        let query = Select::from_table((self.table_schema_name(table), table.name.as_str()))
             .value(count(quaint::ast::Column::new(column_name)))
             .so_that(column_name.is_not_null());
 
@@ -67,15 +72,15 @@ impl SqlDestructiveChangesChecker<'_> {
         Ok(values_count)
     }
 
-    async fn count_rows_in_table(&self, table_name: &str) -> SqlResult<i64> {
-        let query = Select::from_table((self.schema_name(), table_name)).value(count(asterisk()));
+    async fn count_rows_in_table(&self, table: &sql_schema_describer::Table) -> SqlResult<i64> {
+        let query = Select::from_table((self.table_schema_name(table), table.name.as_str())).value(count(asterisk()));
         let result_set = self.conn().query(query.into()).await?;
         let rows_count = result_set
             .first()
             .ok_or_else(|| {
                 SqlError::Generic(anyhow::anyhow!(
                     "No row was returned when checking for existing rows in the `{}` table.",
-                    table_name
+                    table.name
                 ))
             })?
             .at(0)
@@ -83,7 +88,7 @@ impl SqlDestructiveChangesChecker<'_> {
             .ok_or_else(|| {
                 SqlError::Generic(anyhow::anyhow!(
                     "No count was returned when checking for existing rows in the `{}` table.",
-                    table_name
+                    table.name
                 ))
             })?;
 
@@ -132,7 +137,7 @@ impl SqlDestructiveChangesChecker<'_> {
             return Ok(());
         }
 
-        let rows_count = self.count_rows_in_table(&table.name).await?;
+        let rows_count = self.count_rows_in_table(table).await?;
 
         // Empty tables can be safely migrated.
         if rows_count == 0 {
@@ -183,7 +188,7 @@ impl SqlDestructiveChangesChecker<'_> {
             return Ok(());
         }
 
-        self.check_for_column_arity_change(&previous_table.name, &differ, diagnostics)
+        self.check_for_column_arity_change(previous_table, &differ, diagnostics)
             .await?;
 
         let values_count = self.count_values_in_column(&alter_column.name, previous_table).await?;
@@ -276,11 +281,11 @@ impl SqlDestructiveChangesChecker<'_> {
 
     async fn check_for_column_arity_change(
         &self,
-        table_name: &str,
+        table: &sql_schema_describer::Table,
         differ: &crate::sql_schema_differ::ColumnDiffer<'_>,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
-        let rows_count = self.count_rows_in_table(table_name).await?;
+        let rows_count = self.count_rows_in_table(table).await?;
 
         if !differ.all_changes().arity_changed()
             || !differ.next.tpe.arity.is_required()
@@ -291,7 +296,7 @@ impl SqlDestructiveChangesChecker<'_> {
         }
 
         let typed_unexecutable = sql_unexecutable_migration::SqlUnexecutableMigration::MadeOptionalFieldRequired {
-            table: table_name.to_owned(),
+            table: table.name.clone(),
             column: differ.previous.name.clone(),
         };
 
@@ -339,11 +344,13 @@ impl SqlDestructiveChangesChecker<'_> {
                 // Here, check for each table we are going to delete if it is empty. If
                 // not, return a warning.
                 SqlMigrationStep::DropTable(DropTable { name }) => {
-                    self.check_table_drop(name, &mut diagnostics).await?;
+                    if let Some(table) = before.get_table(name) {
+                        self.check_table_drop(table, &mut diagnostics).await?;
+                    }
                 }
                 SqlMigrationStep::DropTables(DropTables { names }) => {
-                    for name in names {
-                        self.check_table_drop(name, &mut diagnostics).await?;
+                    for table in names.iter().filter_map(|name| before.get_table(name)) {
+                        self.check_table_drop(table, &mut diagnostics).await?;
                     }
                 }
                 // SqlMigrationStep::CreateIndex(CreateIndex { table, index }) if index.is_unique() => todo!(),
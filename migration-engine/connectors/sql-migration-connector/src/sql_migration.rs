@@ -2,7 +2,7 @@ pub(crate) mod expanded_alter_column;
 
 use migration_connector::DatabaseMigrationMarker;
 use serde::{Deserialize, Serialize};
-use sql_schema_describer::{Column, ForeignKey, Index, SqlSchema, Table};
+use sql_schema_describer::{CheckConstraint, Column, ForeignKey, Index, Sequence, SqlSchema, Table};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SqlMigration {
@@ -50,6 +50,9 @@ pub enum SqlMigrationStep {
     CreateEnum(CreateEnum),
     DropEnum(DropEnum),
     AlterEnum(AlterEnum),
+    AlterTableSchema(AlterTableSchema),
+    CreateSequence(CreateSequence),
+    DropSequence(DropSequence),
 }
 
 /// A helper struct to serialize an [SqlMigrationStep](/sql-migration/enum.SqlMigrationStep.html)
@@ -82,12 +85,32 @@ pub struct AlterTable {
     pub changes: Vec<TableChange>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateSequence {
+    pub sequence: Sequence,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropSequence {
+    pub name: String,
+}
+
+/// Move a table to another schema. `None` stands for the schema from the connection string.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AlterTableSchema {
+    pub table: String,
+    pub previous_schema: Option<String>,
+    pub next_schema: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum TableChange {
     AddColumn(AddColumn),
     AlterColumn(AlterColumn),
     DropColumn(DropColumn),
     DropForeignKey(DropForeignKey),
+    AddCheckConstraint(AddCheckConstraint),
+    DropCheckConstraint(DropCheckConstraint),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -117,6 +140,16 @@ pub struct DropForeignKey {
     pub constraint_name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AddCheckConstraint {
+    pub check_constraint: CheckConstraint,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropCheckConstraint {
+    pub constraint_name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateIndex {
     pub table: String,
@@ -59,6 +59,14 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer) -> Option<Vec
                 (ColumnTypeFamily::Int, ColumnTypeFamily::String) => {
                     changes.push(PostgresAlterColumn::SetType(columns.next.tpe.clone()))
                 }
+                // Ints can be widened.
+                (ColumnTypeFamily::Int, ColumnTypeFamily::BigInt) => {
+                    changes.push(PostgresAlterColumn::SetType(columns.next.tpe.clone()))
+                }
+                // Native types of the same family can be cast, like `varchar(10)` to `varchar(20)`.
+                (previous, next) if previous == next => {
+                    changes.push(PostgresAlterColumn::SetType(columns.next.tpe.clone()))
+                }
                 _ => return None,
             },
             ColumnChange::Renaming => unreachable!("column renaming"),
@@ -87,6 +87,21 @@ pub(crate) fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
     }
 }
 
+pub(crate) fn render_on_update(on_update: &ForeignKeyAction) -> &'static str {
+    match on_update {
+        ForeignKeyAction::NoAction => "",
+        ForeignKeyAction::SetNull => "ON UPDATE SET NULL",
+        ForeignKeyAction::Cascade => "ON UPDATE CASCADE",
+        ForeignKeyAction::SetDefault => "ON UPDATE SET DEFAULT",
+        ForeignKeyAction::Restrict => "ON UPDATE RESTRICT",
+    }
+}
+
+/// Renders binary data as hexadecimal digits, for use in byte string literals.
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
 pub(crate) trait IteratorJoin {
     fn join(self, sep: &str) -> String;
 }
@@ -47,11 +47,12 @@ impl SqlRenderer for MySqlRenderer {
             .join(",");
 
         format!(
-            " REFERENCES `{}`.`{}`({}) {} ON UPDATE CASCADE",
+            " REFERENCES `{}`.`{}`({}) {} {}",
             schema_name,
             foreign_key.referenced_table,
             referenced_columns,
-            render_on_delete(&foreign_key.on_delete_action)
+            render_on_delete(&foreign_key.on_delete_action),
+            render_on_update(&foreign_key.on_update_action)
         )
     }
 
@@ -65,6 +66,7 @@ impl SqlRenderer for MySqlRenderer {
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultValue::VALUE(PrismaValue::Bytes(bytes)), _) => format!("X'{}'", hex(bytes)).into(),
             (DefaultValue::VALUE(val), _) => format!("{}", val).into(),
             (DefaultValue::SEQUENCE(_), _) => todo!("rendering of sequence defaults"),
         }
@@ -73,6 +75,11 @@ impl SqlRenderer for MySqlRenderer {
 
 impl MySqlRenderer {
     fn render_column_type(&self, column: &ColumnRef<'_>) -> anyhow::Result<Cow<'static, str>> {
+        // Native types from `@db.` attributes, and the column types of described columns, are rendered verbatim.
+        if !column.column_type().full_data_type.is_empty() {
+            return Ok(column.column_type().full_data_type.clone().into());
+        }
+
         match &column.column_type().family {
             ColumnTypeFamily::Boolean => Ok("boolean".into()),
             ColumnTypeFamily::DateTime => {
@@ -84,7 +91,9 @@ impl MySqlRenderer {
                 }
             }
             ColumnTypeFamily::Float => Ok("Decimal(65,30)".into()),
+            ColumnTypeFamily::Decimal => Ok("Decimal(65,30)".into()),
             ColumnTypeFamily::Int => Ok("int".into()),
+            ColumnTypeFamily::BigInt => Ok("bigint".into()),
             // we use varchar right now as mediumtext doesn't allow default values
             // a bigger length would not allow to use such a column as primary key
             ColumnTypeFamily::String => Ok(format!("varchar{}", VARCHAR_LENGTH_PREFIX).into()),
@@ -99,6 +108,7 @@ impl MySqlRenderer {
                 Ok(format!("ENUM({})", variants).into())
             }
             ColumnTypeFamily::Json => Ok("json".into()),
+            ColumnTypeFamily::Binary => Ok("longblob".into()),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
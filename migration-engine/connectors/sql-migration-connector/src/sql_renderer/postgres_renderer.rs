@@ -1,5 +1,5 @@
 use super::common::*;
-use crate::{sql_schema_helpers::*, SqlFamily};
+use crate::{sql_schema_differ::postgres_native_type, sql_schema_helpers::*, SqlFamily};
 use once_cell::sync::Lazy;
 use prisma_models::PrismaValue;
 use regex::Regex;
@@ -28,7 +28,14 @@ impl super::SqlRenderer for PostgresRenderer {
         let is_serial = column.auto_increment();
 
         if is_serial {
-            format!("{} SERIAL", column_name)
+            // Columns without a native type have the default type of their family.
+            let serial = match postgres_native_type(column.column_type()).as_deref() {
+                Some("smallint") => "SMALLSERIAL",
+                Some("bigint") => "BIGSERIAL",
+                _ => "SERIAL",
+            };
+
+            format!("{} {}", column_name, serial)
         } else {
             format!("{} {} {} {}", column_name, tpe_str, nullability_str, default_str)
         }
@@ -42,10 +49,11 @@ impl super::SqlRenderer for PostgresRenderer {
             .join(",");
 
         format!(
-            "REFERENCES {}({}) {}  ON UPDATE CASCADE",
+            "REFERENCES {}({}) {} {}",
             self.quote_with_schema(schema_name, &foreign_key.referenced_table),
             referenced_columns,
-            render_on_delete(&foreign_key.on_delete_action)
+            render_on_delete(&foreign_key.on_delete_action),
+            render_on_update(&foreign_key.on_update_action)
         )
     }
 
@@ -59,8 +67,9 @@ impl super::SqlRenderer for PostgresRenderer {
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultValue::VALUE(PrismaValue::Bytes(bytes)), _) => format!("'\\x{}'", hex(bytes)).into(),
             (DefaultValue::VALUE(val), _) => val.to_string().into(),
-            (DefaultValue::SEQUENCE(_), _) => todo!("rendering of sequence defaults"),
+            (DefaultValue::SEQUENCE(val), _) => val.as_str().into(),
         }
     }
 }
@@ -71,14 +80,31 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
         _ => "",
     };
 
+    if let ColumnTypeFamily::Enum(name) = &t.family {
+        return format!("{}{}", Quoted::postgres_ident(name), array);
+    }
+
+    // Native types from `@db.` attributes, and the types of described columns. The latter are udt names,
+    // like `_int4` for arrays or `varchar` without the length, so both are normalized.
+    if !t.full_data_type.is_empty() {
+        let native_type = postgres_native_type(t).unwrap_or_else(|| match t.arity {
+            ColumnArity::List => t.full_data_type.trim_start_matches('_').to_owned(),
+            _ => t.full_data_type.clone(),
+        });
+
+        return format!("{}{}", native_type, array);
+    }
+
     match &t.family {
         ColumnTypeFamily::Boolean => format!("boolean {}", array),
         ColumnTypeFamily::DateTime => format!("timestamp(3) {}", array),
         ColumnTypeFamily::Float => format!("Decimal(65,30) {}", array),
+        ColumnTypeFamily::Decimal => format!("Decimal(65,30) {}", array),
         ColumnTypeFamily::Int => format!("integer {}", array),
+        ColumnTypeFamily::BigInt => format!("bigint {}", array),
         ColumnTypeFamily::String => format!("text {}", array),
-        ColumnTypeFamily::Enum(name) => format!("{}{}", Quoted::postgres_ident(name), array),
         ColumnTypeFamily::Json => format!("jsonb {}", array),
+        ColumnTypeFamily::Binary => format!("bytea {}", array),
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
@@ -45,10 +45,11 @@ impl super::SqlRenderer for SqliteRenderer {
             .join(",");
 
         format!(
-            "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} ON UPDATE CASCADE",
+            "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} {on_update_action}",
             referenced_table = self.quote(&foreign_key.referenced_table),
             referenced_fields = referenced_fields,
-            on_delete_action = render_on_delete(&foreign_key.on_delete_action)
+            on_delete_action = render_on_delete(&foreign_key.on_delete_action),
+            on_update_action = render_on_update(&foreign_key.on_update_action)
         )
     }
 
@@ -62,6 +63,7 @@ impl super::SqlRenderer for SqliteRenderer {
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultValue::VALUE(PrismaValue::Bytes(bytes)), _) => format!("X'{}'", hex(bytes)).into(),
             (DefaultValue::VALUE(val), _) => format!("{}", val).into(),
             (DefaultValue::SEQUENCE(_), _) => unreachable!("rendering of sequence defaults"),
         }
@@ -70,12 +72,18 @@ impl super::SqlRenderer for SqliteRenderer {
 
 impl SqliteRenderer {
     fn render_column_type(&self, t: &ColumnType) -> String {
+        // Native types from `@db.` attributes, and the declared types of described columns, are rendered verbatim.
+        if !t.full_data_type.is_empty() {
+            return t.full_data_type.clone();
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("BOOLEAN"),
             ColumnTypeFamily::DateTime => format!("DATE"),
             ColumnTypeFamily::Float => format!("REAL"),
             ColumnTypeFamily::Int => format!("INTEGER"),
             ColumnTypeFamily::String => format!("TEXT"),
+            ColumnTypeFamily::Binary => format!("BLOB"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
@@ -1,7 +1,7 @@
 use crate::{
     datamodel_helpers::{self, FieldRef, ModelRef, TypeRef},
     error::SqlError,
-    sql_renderer::IteratorJoin,
+    sql_renderer::{IteratorJoin, Quoted},
     DatabaseInfo, SqlResult,
 };
 use datamodel::common::*;
@@ -47,15 +47,46 @@ impl<'a> SqlSchemaCalculator<'a> {
         }
 
         let enums = self.calculate_enums();
-        let sequences = Vec::new();
+        let sequences = self.calculate_sequences();
 
         Ok(sql::SqlSchema {
             tables,
             enums,
             sequences,
+            // Views are managed by the user, migrations never touch them.
+            views: Vec::new(),
         })
     }
 
+    /// The sequences of `sequence("name")` defaults. They are created by the migration, so the
+    /// defaults can refer to them.
+    fn calculate_sequences(&self) -> Vec<sql::Sequence> {
+        if self.database_info.sql_family() != SqlFamily::Postgres {
+            return Vec::new();
+        }
+
+        let mut sequences: Vec<sql::Sequence> = Vec::new();
+
+        let sequence_names = datamodel_helpers::walk_fields(&self.data_model)
+            .filter(|field| !field.model().is_view())
+            .filter_map(|field| match field.default_value() {
+                Some(dml::DefaultValue::Expression(expression)) => expression.sequence_name().map(String::from),
+                _ => None,
+            });
+
+        for name in sequence_names {
+            if !sequences.iter().any(|sequence| sequence.name == name) {
+                sequences.push(sql::Sequence {
+                    name,
+                    initial_value: 1,
+                    allocation_size: 1,
+                });
+            }
+        }
+
+        sequences
+    }
+
     fn calculate_enums(&self) -> Vec<sql::Enum> {
         match self.database_info.sql_family() {
             SqlFamily::Postgres => self
@@ -72,6 +103,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                 let mut enums = Vec::with_capacity(self.data_model.enums.len());
 
                 let enum_fields = datamodel_helpers::walk_fields(&self.data_model)
+                    .filter(|field| !field.model().is_view())
                     .filter_map(|field| field.field_type().as_enum().map(|enum_ref| (field, enum_ref)));
 
                 for (field, enum_tpe) in enum_fields {
@@ -96,13 +128,13 @@ impl<'a> SqlSchemaCalculator<'a> {
     fn calculate_model_tables<'iter>(
         &'iter self,
     ) -> impl Iterator<Item = SqlResult<(ModelRef<'a>, sql::Table)>> + 'iter {
-        datamodel_helpers::walk_models(self.data_model).map(move |model| {
+        datamodel_helpers::walk_table_models(self.data_model).map(move |model| {
             let columns = model
                 .fields()
                 .flat_map(|f| match f.field_type() {
-                    TypeRef::Base(_) => Some(sql::Column {
+                    TypeRef::Base(_) | TypeRef::Native(_) => Some(sql::Column {
                         name: f.db_name().to_owned(),
-                        tpe: column_type(&f),
+                        tpe: column_type(&f, self.database_info.sql_family()),
                         default: migration_value_new(&f),
                         auto_increment: matches!(f.default_value(), Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Autoincrement, .. }))),
                     }),
@@ -168,12 +200,26 @@ impl<'a> SqlSchemaCalculator<'a> {
                 }
             });
 
+            // Check constraints the database doesn't store would be created again by every migration.
+            let check_constraints = model
+                .check_constraints()
+                .filter(|_| self.database_info.supports_check_constraints())
+                .map(|check_constraint| sql::CheckConstraint {
+                    name: Some(check_constraint.name.clone().unwrap_or_else(|| {
+                        default_check_constraint_name(model.db_name(), &check_constraint.expression)
+                    })),
+                    expression: check_constraint.expression.clone(),
+                })
+                .collect();
+
             let table = sql::Table {
                 name: model.database_name().to_owned(),
                 columns,
                 indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                 primary_key: Some(primary_key),
                 foreign_keys: Vec::new(),
+                check_constraints,
+                schema: self.database_info.table_schema(model.schema()).map(String::from),
             };
 
             Ok((model, table))
@@ -184,7 +230,9 @@ impl<'a> SqlSchemaCalculator<'a> {
         let relation_fields = model
             .fields()
             .filter_map(|field| field.as_relation_field())
-            .filter(|relation_field| !relation_field.is_virtual());
+            .filter(|relation_field| !relation_field.is_virtual())
+            // Views cannot be the target of a foreign key.
+            .filter(|relation_field| !relation_field.references_view());
 
         for relation_field in relation_fields {
             let fk_columns: Vec<String> = relation_field.referencing_columns().map(String::from).collect();
@@ -201,10 +249,17 @@ impl<'a> SqlSchemaCalculator<'a> {
                     columns: fk_columns,
                     referenced_table: relation_field.referenced_table_name().to_owned(),
                     referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
-                    on_delete_action: match column_arity(relation_field.arity()) {
-                        ColumnArity::Required => sql::ForeignKeyAction::Cascade,
-                        _ => sql::ForeignKeyAction::SetNull,
+                    on_delete_action: match relation_field.on_delete() {
+                        Some(action) => foreign_key_action(action),
+                        None => match column_arity(relation_field.arity()) {
+                            ColumnArity::Required => sql::ForeignKeyAction::Cascade,
+                            _ => sql::ForeignKeyAction::SetNull,
+                        },
                     },
+                    on_update_action: relation_field
+                        .on_update()
+                        .map(foreign_key_action)
+                        .unwrap_or(sql::ForeignKeyAction::Cascade),
                 };
 
                 table.foreign_keys.push(fk);
@@ -220,8 +275,9 @@ impl<'a> SqlSchemaCalculator<'a> {
                     let model_a = ModelRef::new(&relation.model_a, self.data_model);
                     let model_b = ModelRef::new(&relation.model_b, self.data_model);
 
-                    let a_columns = relation_table_columns(&model_a, relation.model_a_column());
-                    let b_columns = relation_table_columns(&model_b, relation.model_b_column());
+                    let sql_family = self.database_info.sql_family();
+                    let a_columns = relation_table_columns(&model_a, relation.model_a_column(), sql_family);
+                    let b_columns = relation_table_columns(&model_b, relation.model_b_column(), sql_family);
 
                     let foreign_keys = vec![
                         sql::ForeignKey {
@@ -234,6 +290,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                                 .map(|field| field.db_name().to_owned())
                                 .collect(),
                             on_delete_action: sql::ForeignKeyAction::Cascade,
+                            on_update_action: sql::ForeignKeyAction::Cascade,
                         },
                         sql::ForeignKey {
                             constraint_name: None,
@@ -245,6 +302,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                                 .map(|field| field.db_name().to_owned())
                                 .collect(),
                             on_delete_action: sql::ForeignKeyAction::Cascade,
+                            on_update_action: sql::ForeignKeyAction::Cascade,
                         },
                     ];
 
@@ -270,6 +328,8 @@ impl<'a> SqlSchemaCalculator<'a> {
                         indices: indexes,
                         primary_key: None,
                         foreign_keys,
+                        check_constraints: Vec::new(),
+                        schema: None,
                     };
                     result.push(table);
                 }
@@ -284,7 +344,11 @@ impl<'a> SqlSchemaCalculator<'a> {
     }
 }
 
-fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name: String) -> Vec<sql::Column> {
+fn relation_table_columns(
+    referenced_model: &ModelRef<'_>,
+    reference_field_name: String,
+    sql_family: SqlFamily,
+) -> Vec<sql::Column> {
     if referenced_model.model().id_fields.is_empty() {
         let unique_field = referenced_model.fields().find(|f| f.is_unique());
         let id_field = referenced_model.fields().find(|f| f.is_id());
@@ -296,7 +360,7 @@ fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name:
 
         vec![sql::Column {
             name: reference_field_name,
-            tpe: column_type(&unique_field),
+            tpe: column_type(&unique_field, sql_family),
             default: None,
             auto_increment: false,
         }]
@@ -309,7 +373,7 @@ fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name:
                     reference_field_name = reference_field_name,
                     referenced_column_name = referenced_field.db_name()
                 ),
-                tpe: column_type(&referenced_field),
+                tpe: column_type(&referenced_field, sql_family),
                 default: None,
                 auto_increment: false,
             })
@@ -318,6 +382,16 @@ fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name:
 }
 
 fn migration_value_new(field: &FieldRef<'_>) -> Option<sql_schema_describer::DefaultValue> {
+    // Sequences are evaluated by the database, also for id fields.
+    if let Some(dml::DefaultValue::Expression(expression)) = field.default_value() {
+        if let Some(sequence_name) = expression.sequence_name() {
+            return Some(sql_schema_describer::DefaultValue::SEQUENCE(format!(
+                "nextval('{}'::regclass)",
+                Quoted::postgres_ident(sequence_name)
+            )));
+        }
+    }
+
     if field.is_id() {
         return None;
     }
@@ -352,17 +426,28 @@ fn enum_column_type(field: &FieldRef<'_>, database_info: &DatabaseInfo, db_name:
             sql::ColumnTypeFamily::Enum(format!("{}_{}", field.model().db_name(), field.db_name())),
             arity,
         ),
-        _ => column_type(field),
+        family => column_type(field, family),
     }
 }
 
-fn column_type(field: &FieldRef<'_>) -> sql::ColumnType {
-    column_type_for_scalar_type(&scalar_type_for_field(field), column_arity(field.arity()))
+fn column_type(field: &FieldRef<'_>, sql_family: SqlFamily) -> sql::ColumnType {
+    let column_type =
+        column_type_for_scalar_type(&scalar_type_for_field(field), column_arity(field.arity()), sql_family);
+
+    match field.field_type() {
+        TypeRef::Native(native_type) => sql::ColumnType {
+            data_type: native_type.datasource_type().to_owned(),
+            full_data_type: native_type.datasource_type().to_owned(),
+            ..column_type
+        },
+        _ => column_type,
+    }
 }
 
 fn scalar_type_for_field(field: &FieldRef<'_>) -> ScalarType {
     match field.field_type() {
         TypeRef::Base(ref scalar) => *scalar,
+        TypeRef::Native(native_type) => native_type.prisma_type(),
         TypeRef::Enum(_) => ScalarType::String,
         x => panic!(format!(
             "This field type is not suported here. Field type is {:?} on field {}",
@@ -372,6 +457,27 @@ fn scalar_type_for_field(field: &FieldRef<'_>) -> ScalarType {
     }
 }
 
+/// The name of a check constraint declared without one. It is derived from the expression, so adding
+/// or removing other check constraints of the model does not rename it.
+fn default_check_constraint_name(table_name: &str, expression: &str) -> String {
+    // FNV-1a, because unlike the hashers of the standard library its output is stable across releases.
+    let hash = expression.trim().bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+
+    format!("{}_check_{:08x}", table_name, hash)
+}
+
+fn foreign_key_action(action: ReferentialAction) -> sql::ForeignKeyAction {
+    match action {
+        ReferentialAction::Cascade => sql::ForeignKeyAction::Cascade,
+        ReferentialAction::Restrict => sql::ForeignKeyAction::Restrict,
+        ReferentialAction::NoAction => sql::ForeignKeyAction::NoAction,
+        ReferentialAction::SetNull => sql::ForeignKeyAction::SetNull,
+        ReferentialAction::SetDefault => sql::ForeignKeyAction::SetDefault,
+    }
+}
+
 fn column_arity(arity: FieldArity) -> sql::ColumnArity {
     match &arity {
         FieldArity::Required => sql::ColumnArity::Required,
@@ -380,7 +486,11 @@ fn column_arity(arity: FieldArity) -> sql::ColumnArity {
     }
 }
 
-fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnArity) -> sql::ColumnType {
+fn column_type_for_scalar_type(
+    scalar_type: &ScalarType,
+    column_arity: ColumnArity,
+    sql_family: SqlFamily,
+) -> sql::ColumnType {
     match scalar_type {
         ScalarType::Int => sql::ColumnType::pure(sql::ColumnTypeFamily::Int, column_arity),
         ScalarType::Float => sql::ColumnType::pure(sql::ColumnTypeFamily::Float, column_arity),
@@ -388,6 +498,17 @@ fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnAri
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String, column_arity),
         ScalarType::DateTime => sql::ColumnType::pure(sql::ColumnTypeFamily::DateTime, column_arity),
         ScalarType::Json => sql::ColumnType::pure(sql::ColumnTypeFamily::Json, column_arity),
+        // SQLite has no exact numeric type.
+        ScalarType::Decimal if matches!(sql_family, SqlFamily::Sqlite) => {
+            sql::ColumnType::pure(sql::ColumnTypeFamily::Float, column_arity)
+        }
+        ScalarType::Decimal => sql::ColumnType::pure(sql::ColumnTypeFamily::Decimal, column_arity),
+        // SQLite integers are always 64 bit wide.
+        ScalarType::BigInt if matches!(sql_family, SqlFamily::Sqlite) => {
+            sql::ColumnType::pure(sql::ColumnTypeFamily::Int, column_arity)
+        }
+        ScalarType::BigInt => sql::ColumnType::pure(sql::ColumnTypeFamily::BigInt, column_arity),
+        ScalarType::Bytes => sql::ColumnType::pure(sql::ColumnTypeFamily::Binary, column_arity),
     }
 }
 
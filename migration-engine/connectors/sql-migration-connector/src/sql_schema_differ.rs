@@ -3,19 +3,20 @@ mod enums;
 mod index;
 mod table;
 
-pub(crate) use column::{ColumnChange, ColumnDiffer};
+pub(crate) use column::{postgres_native_type, ColumnChange, ColumnDiffer};
 pub(crate) use table::TableDiffer;
 
 use crate::*;
 use enums::EnumDiffer;
 use once_cell::sync::Lazy;
-use regex::RegexSet;
+use regex::{Regex, RegexSet};
 use sql_schema_describer::*;
 use tracing::debug;
 
 #[derive(Debug)]
 pub(crate) struct DiffingOptions {
     is_mariadb: bool,
+    sql_family: SqlFamily,
     ignore_tables: Lazy<RegexSet>,
 }
 
@@ -23,6 +24,7 @@ impl DiffingOptions {
     pub(crate) fn from_database_info(database_info: &DatabaseInfo) -> Self {
         DiffingOptions {
             is_mariadb: database_info.is_mariadb(),
+            sql_family: database_info.sql_family(),
             ignore_tables: match database_info.sql_family() {
                 SqlFamily::Postgres => POSTGRES_IGNORED_TABLES,
                 _ => EMPTY_REGEXSET,
@@ -36,6 +38,7 @@ impl Default for DiffingOptions {
     fn default() -> Self {
         DiffingOptions {
             is_mariadb: false,
+            sql_family: SqlFamily::Postgres,
             ignore_tables: EMPTY_REGEXSET,
         }
     }
@@ -55,17 +58,25 @@ pub struct SqlSchemaDiff {
     pub drop_tables: Vec<DropTable>,
     pub create_tables: Vec<CreateTable>,
     pub alter_tables: Vec<AlterTable>,
+    pub alter_table_schemas: Vec<AlterTableSchema>,
     pub create_indexes: Vec<CreateIndex>,
     pub drop_indexes: Vec<DropIndex>,
     pub alter_indexes: Vec<AlterIndex>,
     pub create_enums: Vec<CreateEnum>,
     pub drop_enums: Vec<DropEnum>,
     pub alter_enums: Vec<AlterEnum>,
+    pub create_sequences: Vec<CreateSequence>,
+    pub drop_sequences: Vec<DropSequence>,
 }
 
 impl SqlSchemaDiff {
     pub fn into_steps(self) -> Vec<SqlMigrationStep> {
-        wrap_as_step(self.create_enums, SqlMigrationStep::CreateEnum)
+        // Order matters: tables are moved to their new schema first, because the other steps
+        // render table names qualified with the schema of the next state.
+        wrap_as_step(self.alter_table_schemas, SqlMigrationStep::AlterTableSchema)
+            .chain(wrap_as_step(self.create_enums, SqlMigrationStep::CreateEnum))
+            // Order matters: column defaults can refer to the created sequences.
+            .chain(wrap_as_step(self.create_sequences, SqlMigrationStep::CreateSequence))
             .chain(wrap_as_step(self.alter_enums, SqlMigrationStep::AlterEnum))
             .chain(wrap_as_step(self.drop_indexes, SqlMigrationStep::DropIndex))
             // Order matters: we must create tables before `alter_table`s because we could
@@ -82,6 +93,8 @@ impl SqlSchemaDiff {
             .chain(wrap_as_step(self.add_foreign_keys, SqlMigrationStep::AddForeignKey))
             .chain(wrap_as_step(self.drop_tables, SqlMigrationStep::DropTable))
             .chain(wrap_as_step(self.drop_enums, SqlMigrationStep::DropEnum))
+            // Order matters: sequences can only be dropped after the defaults using them.
+            .chain(wrap_as_step(self.drop_sequences, SqlMigrationStep::DropSequence))
             .chain(wrap_as_step(self.alter_indexes, SqlMigrationStep::AlterIndex))
             .collect()
     }
@@ -114,12 +127,15 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             drop_tables,
             create_tables: self.create_tables(),
             alter_tables,
+            alter_table_schemas: self.alter_table_schemas(),
             create_indexes: self.create_indexes(),
             drop_indexes: self.drop_indexes(),
             alter_indexes,
             create_enums: self.create_enums(),
             drop_enums: self.drop_enums(),
             alter_enums: self.alter_enums(),
+            create_sequences: self.create_sequences(),
+            drop_sequences: self.drop_sequences(),
         }
     }
 
@@ -186,10 +202,13 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     next: &next_table,
                 };
 
+                // Order matters: check constraints can reference the dropped and added columns.
                 let changes: Vec<TableChange> = Self::drop_foreign_keys(&differ)
+                    .chain(Self::drop_check_constraints(&differ))
                     .chain(Self::drop_columns(&differ))
                     .chain(Self::add_columns(&differ))
                     .chain(Self::alter_columns(&differ))
+                    .chain(Self::add_check_constraints(&differ))
                     .collect();
 
                 if !changes.is_empty() {
@@ -205,6 +224,17 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         result
     }
 
+    fn alter_table_schemas(&self) -> Vec<AlterTableSchema> {
+        self.table_pairs()
+            .filter(|tables| tables.previous.schema != tables.next.schema)
+            .map(|tables| AlterTableSchema {
+                table: tables.next.name.clone(),
+                previous_schema: tables.previous.schema.clone(),
+                next_schema: tables.next.schema.clone(),
+            })
+            .collect()
+    }
+
     fn drop_columns<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
         differ.dropped_columns().map(|column| {
             let change = DropColumn {
@@ -260,6 +290,25 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             })
     }
 
+    fn drop_check_constraints<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ
+            .dropped_check_constraints()
+            .filter_map(|check_constraint| check_constraint.name.as_ref())
+            .map(|constraint_name| {
+                TableChange::DropCheckConstraint(DropCheckConstraint {
+                    constraint_name: constraint_name.clone(),
+                })
+            })
+    }
+
+    fn add_check_constraints<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ.created_check_constraints().map(|check_constraint| {
+            TableChange::AddCheckConstraint(AddCheckConstraint {
+                check_constraint: check_constraint.clone(),
+            })
+        })
+    }
+
     fn create_indexes(&self) -> Vec<CreateIndex> {
         let mut steps = Vec::new();
 
@@ -343,6 +392,31 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .collect()
     }
 
+    fn create_sequences(&self) -> Vec<CreateSequence> {
+        self.next
+            .sequences
+            .iter()
+            .filter(|sequence| self.previous.get_sequence(&sequence.name).is_none())
+            .map(|sequence| CreateSequence {
+                sequence: sequence.clone(),
+            })
+            .collect()
+    }
+
+    /// Only sequences used by `sequence("name")` defaults are dropped. The sequences behind
+    /// autoincrementing columns belong to the columns and are dropped together with them.
+    fn drop_sequences(&self) -> Vec<DropSequence> {
+        self.previous
+            .sequences
+            .iter()
+            .filter(|sequence| self.next.get_sequence(&sequence.name).is_none())
+            .filter(|sequence| sequence_is_used_by_default(&self.previous, &sequence.name))
+            .map(|sequence| DropSequence {
+                name: sequence.name.clone(),
+            })
+            .collect()
+    }
+
     /// An iterator over the tables that are present in both schemas.
     fn table_pairs<'a>(&'a self) -> impl Iterator<Item = TableDiffer<'schema>> + 'a
     where
@@ -475,6 +549,46 @@ fn foreign_keys_match(previous: &ForeignKey, next: &ForeignKey) -> bool {
         && previous.referenced_columns == next.referenced_columns
         && previous.columns == next.columns
         && previous.on_delete_action == next.on_delete_action
+        && previous.on_update_action == next.on_update_action
+}
+
+/// Compare two [CheckConstraint](/sql-schema-describer/struct.CheckConstraint.html)s and return
+/// whether they should be considered equivalent for schema diffing purposes. The expressions are
+/// compared as they are, the expected ones must already be in the form the database stores them in.
+fn check_constraints_match(previous: &CheckConstraint, next: &CheckConstraint) -> bool {
+    let names_match = match (&previous.name, &next.name) {
+        (Some(previous_name), Some(next_name)) => previous_name == next_name,
+        _ => true,
+    };
+
+    names_match && previous.expression.trim() == next.expression.trim()
+}
+
+/// Whether a column that is not autoincrementing has a `nextval('sequence_name'::regclass)` default.
+fn sequence_is_used_by_default(schema: &SqlSchema, sequence_name: &str) -> bool {
+    schema
+        .tables
+        .iter()
+        .flat_map(|table| table.columns.iter())
+        .filter(|column| !column.auto_increment)
+        .filter_map(|column| column.default.as_ref())
+        .any(|default| default_sequence_name(default) == Some(sequence_name))
+}
+
+/// The unqualified name of the sequence a `nextval(...)` default draws from.
+pub(crate) fn default_sequence_name(default: &DefaultValue) -> Option<&str> {
+    static NEXTVAL: Lazy<Regex> = Lazy::new(|| Regex::new(r"^nextval\('(.+)'::regclass\)$").unwrap());
+
+    let default = match default {
+        DefaultValue::SEQUENCE(default) | DefaultValue::DBGENERATED(default) => default,
+        _ => return None,
+    };
+
+    NEXTVAL
+        .captures(default)
+        .and_then(|captures| captures.get(1))
+        .and_then(|name| name.as_str().rsplit('.').next())
+        .map(|name| name.trim_matches('"'))
 }
 
 fn tables_match(previous: &Table, next: &Table) -> bool {
@@ -1,4 +1,5 @@
-use sql_schema_describer::{Column, ColumnTypeFamily, DefaultValue};
+use crate::SqlFamily;
+use sql_schema_describer::{Column, ColumnType, ColumnTypeFamily, DefaultValue};
 
 #[derive(Debug)]
 pub(crate) struct ColumnDiffer<'a> {
@@ -10,6 +11,9 @@ pub(crate) struct ColumnDiffer<'a> {
 /// On MariaDB, JSON is an alias for LONGTEXT. https://mariadb.com/kb/en/json-data-type/
 const MARIADB_ALIASES: &[ColumnTypeFamily] = &[ColumnTypeFamily::String, ColumnTypeFamily::Json];
 
+/// Floats are stored in the same exact numeric type as decimals, so only their native types are compared.
+const NUMERIC_ALIASES: &[ColumnTypeFamily] = &[ColumnTypeFamily::Float, ColumnTypeFamily::Decimal];
+
 impl<'a> ColumnDiffer<'a> {
     pub(crate) fn name(&self) -> &'a str {
         debug_assert_eq!(self.previous.name, self.next.name);
@@ -59,7 +63,37 @@ impl<'a> ColumnDiffer<'a> {
             return false;
         }
 
-        self.previous.tpe.family != self.next.tpe.family
+        let families_match = self.previous.tpe.family == self.next.tpe.family
+            || (NUMERIC_ALIASES.contains(&self.previous.tpe.family) && NUMERIC_ALIASES.contains(&self.next.tpe.family));
+
+        !families_match || self.native_type_changed()
+    }
+
+    /// Compares the database types of columns of the same family, like `varchar(10)` and `varchar(20)`.
+    /// Described and calculated columns spell their types differently, so both are normalized first.
+    fn native_type_changed(&self) -> bool {
+        // Only fields with a native type attribute ask for a database type. Other columns keep the type
+        // they have, e.g. an introspected `varchar(255)` column of a `String` field.
+        if self.next.tpe.full_data_type.is_empty() {
+            return false;
+        }
+
+        // JSON columns are described as LONGTEXT on MariaDB.
+        if self.diffing_options.is_mariadb && self.previous.tpe.family == ColumnTypeFamily::Json {
+            return false;
+        }
+
+        let native_type = |column: &Column| match self.diffing_options.sql_family {
+            SqlFamily::Postgres => postgres_native_type(&column.tpe),
+            SqlFamily::Mysql => mysql_native_type(column),
+            // SQLite only knows type affinities.
+            SqlFamily::Sqlite => None,
+        };
+
+        match (native_type(self.previous), native_type(self.next)) {
+            (Some(previous), Some(next)) => previous != next,
+            _ => false,
+        }
     }
 
     /// There are workarounds to cope with current migration and introspection limitations.
@@ -70,6 +104,13 @@ impl<'a> ColumnDiffer<'a> {
     ///
     /// - We bail on a number of cases that are too complex to deal with right now or underspecified.
     fn defaults_match(&self) -> bool {
+        // Sequences from `sequence()` are compared by name, also when the column was a serial before.
+        if let (Some(next @ DefaultValue::SEQUENCE(_)), false) = (&self.next.default, self.next.auto_increment) {
+            let previous = self.previous.default.as_ref().and_then(super::default_sequence_name);
+
+            return previous == super::default_sequence_name(next);
+        }
+
         if self.previous.auto_increment {
             return true;
         }
@@ -107,6 +148,154 @@ impl<'a> ColumnDiffer<'a> {
     }
 }
 
+/// The type of a Postgres column, spelled like in the native type attributes, e.g. `varchar(10)`.
+pub(crate) fn postgres_native_type(tpe: &ColumnType) -> Option<String> {
+    // Columns without a native type are created with the default type of their family.
+    if tpe.full_data_type.is_empty() {
+        let default = match tpe.family {
+            ColumnTypeFamily::Int => "integer",
+            ColumnTypeFamily::BigInt => "bigint",
+            ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => "numeric(65,30)",
+            ColumnTypeFamily::Boolean => "boolean",
+            ColumnTypeFamily::String => "text",
+            ColumnTypeFamily::DateTime => "timestamp(3)",
+            ColumnTypeFamily::Json => "jsonb",
+            ColumnTypeFamily::Binary => "bytea",
+            _ => return None,
+        };
+
+        return Some(default.to_owned());
+    }
+
+    if let ColumnTypeFamily::Enum(_) = tpe.family {
+        return None;
+    }
+
+    let (name, mut args) = split_args(&tpe.full_data_type.to_lowercase());
+
+    let name = match name.trim_start_matches('_') {
+        "int2" => "smallint",
+        "int4" => "integer",
+        "int8" => "bigint",
+        "float4" => "real",
+        "float8" => "double precision",
+        "bool" => "boolean",
+        "bpchar" => "char",
+        name => name,
+    }
+    .to_owned();
+
+    // Described columns carry their arguments separately.
+    if args.is_empty() {
+        args = match name.as_str() {
+            "varchar" | "char" => tpe.character_maximum_length.into_iter().collect(),
+            "numeric" => tpe.precision.into_iter().chain(tpe.scale).collect(),
+            "time" | "timestamp" | "timestamptz" => tpe.precision.into_iter().collect(),
+            _ => Vec::new(),
+        };
+    }
+
+    // Types without a native type attribute can't be compared, like `varchar` without a length.
+    let is_known = match name.as_str() {
+        "varchar" | "char" => !args.is_empty(),
+        "smallint" | "integer" | "bigint" | "real" | "double precision" | "numeric" | "text" | "boolean" => true,
+        "timestamp" | "timestamptz" | "date" | "time" | "json" | "jsonb" | "bytea" => true,
+        _ => false,
+    };
+
+    if !is_known {
+        return None;
+    }
+
+    // The precision of time types defaults to 6.
+    if matches!(name.as_str(), "time" | "timestamp" | "timestamptz") && args == [6] {
+        args.clear();
+    }
+
+    Some(join_args(name, &args))
+}
+
+/// The type of a MySQL column as in `information_schema.columns.column_type`, e.g. `decimal(10,2)`.
+fn mysql_native_type(column: &Column) -> Option<String> {
+    // Columns without a native type are created with the default type of their family.
+    if column.tpe.full_data_type.is_empty() {
+        let default = match column.tpe.family {
+            ColumnTypeFamily::Int => "int",
+            ColumnTypeFamily::BigInt => "bigint",
+            ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => "decimal(65,30)",
+            ColumnTypeFamily::Boolean => "tinyint(1)",
+            ColumnTypeFamily::String => "varchar(191)",
+            // CURRENT_TIMESTAMP has up to second precision.
+            ColumnTypeFamily::DateTime if matches!(column.default, Some(DefaultValue::NOW)) => "datetime",
+            ColumnTypeFamily::DateTime => "datetime(3)",
+            ColumnTypeFamily::Json => "json",
+            ColumnTypeFamily::Binary => "longblob",
+            _ => return None,
+        };
+
+        return Some(default.to_owned());
+    }
+
+    if let ColumnTypeFamily::Enum(_) = column.tpe.family {
+        return None;
+    }
+
+    // Signedness can't be expressed in the datamodel.
+    let full_data_type = column
+        .tpe
+        .full_data_type
+        .to_lowercase()
+        .replace(" unsigned", "")
+        .replace(" zerofill", "");
+
+    let (name, args) = split_args(&full_data_type);
+
+    let args: &[i64] = match (name, args.as_slice()) {
+        ("boolean", _) | ("bool", _) => return Some("tinyint(1)".to_owned()),
+        ("tinyint", [1]) => &args,
+        // Display widths of integer types don't change the type.
+        ("tinyint", _) | ("smallint", _) | ("mediumint", _) | ("int", _) | ("bigint", _) => &[],
+        ("float", _) | ("double", _) | ("decimal", _) | ("varchar", _) | ("char", _) => &args,
+        ("tinytext", _) | ("text", _) | ("mediumtext", _) | ("longtext", _) | ("json", _) => &args,
+        ("datetime", _) | ("timestamp", _) | ("date", _) | ("time", _) => &args,
+        ("binary", _) | ("varbinary", _) | ("tinyblob", _) | ("blob", _) | ("mediumblob", _) | ("longblob", _) => &args,
+        // Types without a native type attribute can't be compared, like `year`.
+        _ => return None,
+    };
+
+    Some(join_args(name.to_owned(), args))
+}
+
+/// Splits a type like `decimal(10, 2)` into its name and arguments.
+fn split_args(full_data_type: &str) -> (&str, Vec<i64>) {
+    let start = match full_data_type.find('(') {
+        Some(start) => start,
+        None => return (full_data_type.trim(), Vec::new()),
+    };
+
+    let end = full_data_type[start..]
+        .find(')')
+        .map(|end| end + start)
+        .unwrap_or_else(|| full_data_type.len());
+
+    let args = full_data_type[start + 1..end]
+        .split(',')
+        .filter_map(|arg| arg.trim().parse().ok())
+        .collect();
+
+    (full_data_type[..start].trim(), args)
+}
+
+fn join_args(name: String, args: &[i64]) -> String {
+    if args.is_empty() {
+        return name;
+    }
+
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+
+    format!("{}({})", name, args.join(","))
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum ColumnChange {
     Renaming,
@@ -1,5 +1,5 @@
 use super::column::ColumnDiffer;
-use sql_schema_describer::{Column, ForeignKey, Index, Table};
+use sql_schema_describer::{CheckConstraint, Column, ForeignKey, Index, Table};
 
 pub(crate) struct TableDiffer<'a> {
     pub(crate) diffing_options: &'a super::DiffingOptions,
@@ -78,6 +78,22 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    pub(crate) fn created_check_constraints<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.next_check_constraints().filter(move |next| {
+            !self
+                .previous_check_constraints()
+                .any(|previous| super::check_constraints_match(previous, next))
+        })
+    }
+
+    pub(crate) fn dropped_check_constraints<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.previous_check_constraints().filter(move |previous| {
+            !self
+                .next_check_constraints()
+                .any(|next| super::check_constraints_match(previous, next))
+        })
+    }
+
     fn previous_columns(&self) -> impl Iterator<Item = &'schema Column> {
         self.previous.columns.iter()
     }
@@ -94,6 +110,14 @@ impl<'schema> TableDiffer<'schema> {
         self.next.foreign_keys.iter()
     }
 
+    fn previous_check_constraints(&self) -> impl Iterator<Item = &'schema CheckConstraint> {
+        self.previous.check_constraints.iter()
+    }
+
+    fn next_check_constraints(&self) -> impl Iterator<Item = &'schema CheckConstraint> {
+        self.next.check_constraints.iter()
+    }
+
     fn previous_indexes<'a>(&'a self) -> impl Iterator<Item = &'schema Index> + 'a {
         self.previous.indices.iter()
     }
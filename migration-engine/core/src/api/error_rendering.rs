@@ -55,18 +55,16 @@ fn fallback_jsonrpc_error(err: impl std::error::Error) -> JsonRpcError {
 
 pub fn pretty_print_datamodel_errors(
     errors: &datamodel::error::ErrorCollection,
-    datamodel: &str,
+    files: &datamodel::SchemaFiles,
 ) -> std::io::Result<String> {
     use std::io::Write as _;
 
-    let file_name = "schema.prisma";
-
     let mut message: Vec<u8> = Vec::new();
 
     for error in errors.to_iter() {
         writeln!(&mut message)?;
         error
-            .pretty_print(&mut message, file_name, datamodel)
+            .pretty_print_files(&mut message, files)
             .expect("Failed to write errors to stderr");
     }
 
@@ -174,6 +174,7 @@ impl<'a> ApplyMigrationCommand<'a> {
             errors,
             warnings,
             general_errors: Vec::new(),
+            datamodel_warnings: Vec::new(),
             unexecutable_migrations,
         })
     }
@@ -70,6 +70,7 @@ impl<'a> MigrationCommand for CalculateDatabaseStepsCommand<'a> {
             errors: Vec::new(),
             warnings,
             general_errors: Vec::new(),
+            datamodel_warnings: Vec::new(),
             unexecutable_migrations,
         })
     }
@@ -1,8 +1,11 @@
 //! The InferMigrationSteps RPC method.
 
-use super::MigrationStepsResultOutput;
+use super::{DatamodelWarningOutput, MigrationStepsResultOutput};
 use crate::{commands::command::*, migration_engine::MigrationEngine, *};
-use datamodel::ast::{parser::parse, SchemaAst};
+use datamodel::{
+    ast::{parser::parse, SchemaAst},
+    ValidatedDatamodel,
+};
 use migration_connector::*;
 use serde::Deserialize;
 use tracing::debug;
@@ -48,7 +51,10 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
         let assumed_datamodel =
             datamodel::lift_ast(&assumed_datamodel_ast).map_err(CommandError::ProducedBadDatamodel)?;
 
-        let next_datamodel = parse_datamodel(&cmd.input.datamodel)?;
+        let ValidatedDatamodel {
+            subject: next_datamodel,
+            warnings: datamodel_warnings,
+        } = parse_datamodel(&cmd.input.datamodel)?;
         let version_check_errors = connector.check_database_version_compatibility(&next_datamodel);
 
         let next_datamodel_ast = parse(&cmd.input.datamodel).map_err(|err| {
@@ -113,6 +119,7 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             errors: version_check_errors,
             warnings,
             general_errors: vec![],
+            datamodel_warnings: datamodel_warnings.iter().map(DatamodelWarningOutput::from).collect(),
             unexecutable_migrations,
         })
     }
@@ -18,6 +18,7 @@ pub use migration_progress::*;
 pub use reset::*;
 pub use unapply_migration::*;
 
+use datamodel::error::DatamodelWarning;
 use migration_connector::{MigrationError, MigrationStep, MigrationWarning, UnexecutableMigration};
 use serde::{Deserialize, Serialize};
 
@@ -30,6 +31,29 @@ pub struct MigrationStepsResultOutput {
     pub warnings: Vec<MigrationWarning>,
     pub errors: Vec<MigrationError>,
     pub general_errors: Vec<String>,
+    /// Warnings about questionable, but valid parts of the submitted datamodel.
+    #[serde(default)]
+    pub datamodel_warnings: Vec<DatamodelWarningOutput>,
     #[serde(skip)]
     pub unexecutable_migrations: Vec<UnexecutableMigration>,
 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatamodelWarningOutput {
+    pub code: String,
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl From<&DatamodelWarning> for DatamodelWarningOutput {
+    fn from(warning: &DatamodelWarning) -> Self {
+        DatamodelWarningOutput {
+            code: warning.code().to_owned(),
+            message: warning.description(),
+            start: warning.span().start,
+            end: warning.span().end,
+        }
+    }
+}
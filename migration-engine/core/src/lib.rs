@@ -14,6 +14,7 @@ use commands::{CommandError, CommandResult};
 use datamodel::{
     configuration::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     dml::Datamodel,
+    ValidatedDatamodel,
 };
 use error::Error;
 use sql_migration_connector::SqlMigrationConnector;
@@ -30,7 +31,9 @@ pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericAp
     let connector = match source.connector_type() {
         #[cfg(feature = "sql")]
         provider if [MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME].contains(&provider) => {
-            SqlMigrationConnector::new(&source.url().value).await?
+            SqlMigrationConnector::new(&source.url().value)
+                .await?
+                .with_additional_schemas(source.schemas())
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
@@ -56,7 +59,7 @@ pub async fn create_database(datamodel: &str) -> CoreResult<String> {
     }
 }
 
-pub(crate) fn parse_datamodel(datamodel: &str) -> CommandResult<Datamodel> {
+pub(crate) fn parse_datamodel(datamodel: &str) -> CommandResult<ValidatedDatamodel> {
     datamodel::parse_datamodel(&datamodel)
         .map_err(|err| CommandError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", datamodel)))
 }
@@ -197,6 +197,8 @@ fn apply_create_model(datamodel: &mut ast::SchemaAst, step: &steps::CreateModel)
         fields: vec![],
         directives: vec![],
         commented_out: false,
+        is_view: step.is_view.unwrap_or(false),
+        is_composite_type: step.is_composite_type.unwrap_or(false),
     };
 
     datamodel.tops.push(ast::Top::Model(model));
@@ -213,6 +215,8 @@ fn apply_update_model(datamodel: &mut ast::SchemaAst, step: &steps::UpdateModel)
     })?;
 
     apply_model_update(model, &step.new_name, update_model_name);
+    apply_model_update(model, &step.is_view, update_model_is_view);
+    apply_model_update(model, &step.is_composite_type, update_model_is_composite_type);
 
     Ok(())
 }
@@ -227,6 +231,14 @@ fn update_model_name(model: &mut ast::Model, new_name: &String) {
     model.name = new_ident(new_name.clone());
 }
 
+fn update_model_is_view(model: &mut ast::Model, is_view: &bool) {
+    model.is_view = *is_view;
+}
+
+fn update_model_is_composite_type(model: &mut ast::Model, is_composite_type: &bool) {
+    model.is_composite_type = *is_composite_type;
+}
+
 fn apply_delete_model(datamodel: &mut ast::SchemaAst, step: &steps::DeleteModel) -> Result<(), CalculatorError> {
     datamodel.find_model(&step.model).ok_or_else(|| {
         format_err!(
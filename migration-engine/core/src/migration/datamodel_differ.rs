@@ -1,22 +1,12 @@
 #![deny(rust_2018_idioms)]
 
-mod directives;
-mod enum_values;
-mod enums;
-mod fields;
-mod models;
-mod source;
-mod top_level;
-
-use directives::DirectiveDiffer;
-use enum_values::EnumValueDiffer;
-use enums::EnumDiffer;
-use fields::FieldDiffer;
-use models::ModelDiffer;
-use top_level::TopDiffer;
-
-use crate::migration::datamodel_differ::source::SourceArgumentsDiffer;
-use datamodel::ast;
+use datamodel::ast::{
+    self,
+    differ::{
+        directive_is_regular, directive_is_repeated, DirectiveDiffer, EnumDiffer, EnumValueDiffer, FieldDiffer,
+        ModelDiffer, SourceArgumentsDiffer, TopDiffer,
+    },
+};
 use migration_connector::steps::{
     self, ArgumentLocation, DirectiveLocation, DirectivePath, MigrationStep, SourceLocation,
 };
@@ -261,6 +251,8 @@ fn push_created_models<'a>(steps: &mut Steps, models: impl Iterator<Item = &'a a
 
         let create_model_step = steps::CreateModel {
             model: created_model.name.name.clone(),
+            is_view: Some(created_model.is_view).filter(|is_view| *is_view),
+            is_composite_type: Some(created_model.is_composite_type).filter(|is_composite_type| *is_composite_type),
         };
 
         steps.push(MigrationStep::CreateModel(create_model_step));
@@ -270,12 +262,12 @@ fn push_created_models<'a>(steps: &mut Steps, models: impl Iterator<Item = &'a a
         push_created_directives(
             steps,
             &directive_path,
-            created_model.directives.iter().filter(models::directive_is_regular),
+            created_model.directives.iter().filter(directive_is_regular),
         );
         push_created_directives_with_arguments(
             steps,
             &directive_path,
-            created_model.directives.iter().filter(models::directive_is_repeated),
+            created_model.directives.iter().filter(directive_is_repeated),
         );
     }
 }
@@ -294,6 +286,24 @@ fn push_updated_models<'a>(steps: &mut Steps, models: impl Iterator<Item = Model
     models.for_each(|model| {
         let model_name = &model.previous.name.name;
 
+        if model.previous.is_view != model.next.is_view {
+            steps.push(MigrationStep::UpdateModel(steps::UpdateModel {
+                model: model_name.clone(),
+                new_name: None,
+                is_view: Some(model.next.is_view),
+                is_composite_type: None,
+            }));
+        }
+
+        if model.previous.is_composite_type != model.next.is_composite_type {
+            steps.push(MigrationStep::UpdateModel(steps::UpdateModel {
+                model: model_name.clone(),
+                new_name: None,
+                is_view: None,
+                is_composite_type: Some(model.next.is_composite_type),
+            }));
+        }
+
         push_created_fields(steps, model_name, model.created_fields());
         push_deleted_fields(steps, model_name, model.deleted_fields());
         push_updated_fields(steps, model_name, model.field_pairs());
@@ -153,6 +153,48 @@ impl<'a> TableAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_in_schema(self, schema: Option<&str>) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.schema.as_deref() == schema,
+            anyhow::anyhow!(
+                "Expected table {} to be in schema {:?}, found {:?}.",
+                self.0.name,
+                schema,
+                self.0.schema
+            )
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_check_constraints_count(self, n: usize) -> AssertionResult<Self> {
+        let check_constraints_count = self.0.check_constraints.len();
+        anyhow::ensure!(
+            check_constraints_count == n,
+            anyhow::anyhow!("Expected {} check constraints, found {}.", n, check_constraints_count)
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_has_check_constraint(self, constraint_name: &str) -> AssertionResult<Self> {
+        let found = self
+            .0
+            .check_constraints
+            .iter()
+            .any(|check_constraint| check_constraint.name.as_deref() == Some(constraint_name));
+
+        anyhow::ensure!(
+            found,
+            "Assertion failed: check constraint {} not found on {}. Existing check constraints: {:?}",
+            constraint_name,
+            self.0.name,
+            self.0.check_constraints
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_does_not_have_column(self, column_name: &str) -> AssertionResult<Self> {
         if self.0.column(column_name).is_some() {
             anyhow::bail!("Assertion failed: found column `{}` on `{}`.", column_name, self.0.name);
@@ -384,6 +426,28 @@ impl<'a> ForeignKeyAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_on_delete(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_delete_action == action,
+            "Assertion failed: expected foreign key to have {:?} on delete, found {:?}.",
+            action,
+            self.0.on_delete_action,
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_on_update(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_update_action == action,
+            "Assertion failed: expected foreign key to have {:?} on update, found {:?}.",
+            action,
+            self.0.on_update_action,
+        );
+
+        Ok(self)
+    }
 }
 
 pub struct IndexAssertion<'a>(&'a Index);
@@ -31,5 +31,7 @@ pub fn create_id_directive_step(model: &str, field: &str) -> MigrationStep {
 pub fn create_model_step(model: &str) -> MigrationStep {
     MigrationStep::CreateModel(CreateModel {
         model: model.to_string(),
+        is_view: None,
+        is_composite_type: None,
     })
 }
@@ -35,6 +35,8 @@ pub struct TestApi {
     database: Arc<dyn Queryable + Send + Sync + 'static>,
     api: MigrationApi<sql_migration_connector::SqlMigrationConnector, sql_migration_connector::SqlMigration>,
     connection_info: ConnectionInfo,
+    /// Schemas the connector spans next to the one from the connection string.
+    additional_schemas: Vec<String>,
 }
 
 impl TestApi {
@@ -160,11 +162,13 @@ impl TestApi {
     }
 
     pub async fn describe_database(&self) -> Result<SqlSchema, anyhow::Error> {
-        let mut result = self
-            .describer()
-            .describe(self.schema_name())
-            .await
-            .expect("Description failed");
+        let mut result = sql_schema_describer::describe_schemas(
+            self.describer().as_ref(),
+            self.schema_name(),
+            &self.additional_schemas,
+        )
+        .await
+        .expect("Description failed");
 
         // the presence of the _Migration table makes assertions harder. Therefore remove it from the result.
         result.tables = result
@@ -259,6 +263,7 @@ pub async fn mysql_8_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        additional_schemas: Vec::new(),
     }
 }
 
@@ -272,6 +277,7 @@ pub async fn mysql_5_6_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        additional_schemas: Vec::new(),
     }
 }
 
@@ -285,6 +291,7 @@ pub async fn mysql_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        additional_schemas: Vec::new(),
     }
 }
 
@@ -298,6 +305,7 @@ pub async fn mysql_mariadb_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        additional_schemas: Vec::new(),
     }
 }
 
@@ -311,6 +319,7 @@ pub async fn postgres9_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        additional_schemas: Vec::new(),
     }
 }
 
@@ -324,6 +333,7 @@ pub async fn postgres_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        additional_schemas: Vec::new(),
     }
 }
 
@@ -337,6 +347,7 @@ pub async fn postgres11_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        additional_schemas: Vec::new(),
     }
 }
 
@@ -350,6 +361,25 @@ pub async fn postgres12_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        additional_schemas: Vec::new(),
+    }
+}
+
+/// A Postgres test API whose connector also spans the given schemas, as configured by the
+/// `schemas` property of the datasource.
+pub async fn postgres_test_api_with_schemas(db_name: &str, additional_schemas: &[String]) -> TestApi {
+    let url = postgres_10_url(db_name);
+    let connection_info = ConnectionInfo::from_url(&url).unwrap();
+    let connector = postgres_migration_connector(&url)
+        .await
+        .with_additional_schemas(additional_schemas);
+
+    TestApi {
+        connector_name: "postgres",
+        connection_info,
+        database: Arc::clone(&connector.database),
+        api: test_api(connector).await,
+        additional_schemas: additional_schemas.to_vec(),
     }
 }
 
@@ -362,5 +392,6 @@ pub async fn sqlite_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        additional_schemas: Vec::new(),
     }
 }
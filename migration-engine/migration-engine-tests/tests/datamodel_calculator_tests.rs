@@ -194,6 +194,8 @@ fn creating_a_model_that_already_exists_must_error() {
 
     let steps = &[MigrationStep::CreateModel(CreateModel {
         model: "Test".to_string(),
+        is_view: None,
+        is_composite_type: None,
     })];
 
     assert_eq!(
@@ -343,6 +345,8 @@ fn updating_a_model_that_does_not_exist_must_error() {
     let steps = &[MigrationStep::UpdateModel(UpdateModel {
         model: "Test".to_string(),
         new_name: None,
+        is_view: None,
+        is_composite_type: None,
     })];
 
     assert_eq!(
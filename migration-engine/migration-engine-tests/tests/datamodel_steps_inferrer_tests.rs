@@ -20,6 +20,8 @@ fn infer_CreateModel_if_it_does_not_exist_yet() {
     let expected = &[
         MigrationStep::CreateModel(CreateModel {
             model: "Test".to_string(),
+            is_view: None,
+            is_composite_type: None,
         }),
         MigrationStep::CreateField(CreateField {
             model: "Test".to_string(),
@@ -1460,6 +1462,8 @@ fn infer_CreateTypeAlias() {
         }),
         MigrationStep::CreateModel(CreateModel {
             model: "User".to_string(),
+            is_view: None,
+            is_composite_type: None,
         }),
         MigrationStep::CreateField(CreateField {
             model: "User".to_string(),
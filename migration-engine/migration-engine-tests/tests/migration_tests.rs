@@ -295,6 +295,7 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) {
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
+            on_update_action: ForeignKeyAction::Cascade,
         }]
     );
 
@@ -324,6 +325,7 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) {
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
+            on_update_action: ForeignKeyAction::Cascade,
         }]
     );
 }
@@ -379,6 +381,7 @@ async fn changing_a_relation_field_to_a_scalar_field_must_work(api: &TestApi) ->
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
+                on_update_action: ForeignKeyAction::Cascade,
             })
     })?;
 
@@ -450,6 +453,7 @@ async fn changing_a_scalar_field_to_a_relation_field_must_work(api: &TestApi) {
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
+            on_update_action: ForeignKeyAction::Cascade,
         }]
     );
 }
@@ -521,6 +525,7 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
                 referenced_table: "A".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
+                on_update_action: ForeignKeyAction::Cascade,
             },
             ForeignKey {
                 constraint_name: match api.sql_family() {
@@ -532,6 +537,7 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
+                on_update_action: ForeignKeyAction::Cascade,
             }
         ]
     );
@@ -581,6 +587,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade, // required relations can't set ON DELETE SET NULL
+                on_update_action: ForeignKeyAction::Cascade,
             },
             ForeignKey {
                 constraint_name: match api.sql_family() {
@@ -592,6 +599,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 referenced_table: "C".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
+                on_update_action: ForeignKeyAction::Cascade,
             }
         ]
     );
@@ -628,6 +636,7 @@ async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) {
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
+            on_update_action: ForeignKeyAction::Cascade,
         }]
     );
 }
@@ -661,6 +670,7 @@ async fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type(api: &TestA
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
+            on_update_action: ForeignKeyAction::Cascade,
         }]
     );
 }
@@ -733,6 +743,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
+            on_update_action: ForeignKeyAction::Cascade,
         }]
     );
 
@@ -761,6 +772,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             referenced_table: "A".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
+            on_update_action: ForeignKeyAction::Cascade,
         }]
     );
 
@@ -1233,6 +1245,7 @@ async fn reserved_sql_key_words_must_work(api: &TestApi) {
             referenced_table: "Group".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::SetNull,
+            on_update_action: ForeignKeyAction::Cascade,
         }]
     );
 }
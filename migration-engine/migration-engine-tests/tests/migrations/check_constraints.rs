@@ -0,0 +1,223 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn check_constraints_are_created(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+
+            @@check("price > 0")
+            @@check("discount < price", name: "discount_below_price")
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table
+            .assert_check_constraints_count(2)?
+            .assert_has_check_constraint("discount_below_price")
+    })?;
+
+    let schema = api.describe_database().await?;
+    let has_default_name = schema
+        .table_bang("Product")
+        .check_constraints
+        .iter()
+        .filter_map(|check_constraint| check_constraint.name.as_deref())
+        .any(|name| name.starts_with("Product_check_"));
+
+    assert!(has_default_name);
+
+    // The expressions as stored by the database must not be detected as changes.
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn check_constraints_can_be_added_and_dropped(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "positive_price")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table
+            .assert_check_constraints_count(1)?
+            .assert_has_check_constraint("positive_price")
+    })?;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Product", |table| table.assert_check_constraints_count(0))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn removing_a_check_constraint_does_not_rename_the_others(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+
+            @@check("price > 0")
+            @@check("discount >= 0")
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let check_constraint_names = |schema: &sql_schema_describer::SqlSchema| -> Vec<String> {
+        schema
+            .table_bang("Product")
+            .check_constraints
+            .iter()
+            .filter_map(|check_constraint| check_constraint.name.clone())
+            .collect()
+    };
+
+    let schema = api.describe_database().await?;
+    let discount_check_name = schema
+        .table_bang("Product")
+        .check_constraints
+        .iter()
+        .find(|check_constraint| check_constraint.expression.contains("discount"))
+        .and_then(|check_constraint| check_constraint.name.clone())
+        .unwrap();
+
+    let dm2 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+
+            @@check("discount >= 0")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+
+    assert_eq!(check_constraint_names(&schema), vec![discount_check_name]);
+
+    api.infer_apply(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn check_constraint_expression_changes_are_migrated(api: &TestApi) -> TestResult {
+    let dm = |expression: &str| {
+        format!(
+            r#"
+            model Product {{
+                id    Int @id
+                price Int
+
+                @@check("{}", name: "positive_price")
+            }}
+            "#,
+            expression
+        )
+    };
+
+    api.infer_apply(&dm("price > 0")).send().await?.assert_green()?;
+    api.infer_apply(&dm("price > 10")).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let check_constraint = &schema.table_bang("Product").check_constraints[0];
+
+    assert_eq!(check_constraint.name.as_deref(), Some("positive_price"));
+    assert!(
+        check_constraint.expression.contains("10"),
+        "{}",
+        check_constraint.expression
+    );
+
+    api.infer_apply(&dm("price > 10"))
+        .send()
+        .await?
+        .assert_green()?
+        .assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn check_constraints_rewritten_by_postgres_are_not_migrated_again(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id    Int    @id
+            price Int
+            name  String
+
+            @@check("price IN (1, 2, 3)")
+            @@check("price BETWEEN 1 AND 10", name: "price_range")
+            @@check("name <> '' AND (price > 0 OR name = 'free')")
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Product", |table| table.assert_check_constraints_count(3))?;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn check_constraints_are_only_created_where_mysql_enforces_them(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "positive_price")
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    // MySQL 5.6 and 5.7 accept check constraints, but neither enforce nor store them.
+    let is_enforced = api.connector_name() == "mysql_8" || api.is_mariadb();
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        if is_enforced {
+            table
+                .assert_check_constraints_count(1)?
+                .assert_has_check_constraint("positive_price")
+        } else {
+            table.assert_check_constraints_count(0)
+        }
+    })?;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector(tags("postgres"))]
+async fn ignored_models_and_fields_are_left_untouched(api: &TestApi) -> TestResult {
+    let create_cat = format!(
+        r#"CREATE TABLE "{}"."Cat" (id INTEGER PRIMARY KEY, name TEXT NOT NULL, secret TEXT)"#,
+        api.schema_name()
+    );
+    let create_legacy = format!(r#"CREATE TABLE "{}"."Legacy" (value TEXT)"#, api.schema_name());
+
+    api.database().query_raw(&create_cat, &[]).await?;
+    api.database().query_raw(&create_legacy, &[]).await?;
+
+    let dm = r#"
+        model Cat {
+            id     Int     @id
+            name   String
+            secret Int?    @ignore
+        }
+
+        model Legacy {
+            value Int
+
+            @@ignore
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(2)?
+        .assert_table("Cat", |table| {
+            table.assert_column("secret", |column| column.assert_type_is_string())
+        })?
+        .assert_table("Legacy", |table| {
+            table.assert_column("value", |column| column.assert_type_is_string())
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn ignored_models_are_not_created(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Legacy {
+            id Int @id
+
+            @@ignore
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_table("Cat", |table| Ok(table))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn ignored_fields_survive_table_redefinitions_on_sqlite(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id     Int     @id
+            name   String
+            secret String?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .value("secret", "lasagna")
+        .result_raw()
+        .await?;
+
+    // Making `name` optional requires redefining the table on SQLite.
+    let dm2 = r#"
+        model Cat {
+            id     Int     @id
+            name   String?
+            secret String? @ignore
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_column("name", |column| column.assert_is_nullable())?
+            .assert_column("secret", |column| column.assert_type_is_string())
+    })?;
+
+    let rows = api.select("Cat").column("id").column("secret").send_debug().await?;
+    assert_eq!(rows, &[&["Integer(1)", r#"Text("lasagna")"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn ignored_fields_are_not_dropped_when_transitioning_out_of_watch_mode(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id     Int     @id
+            secret String?
+        }
+    "#;
+
+    api.infer_apply(dm1)
+        .migration_id(Some("mig00"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id     Int     @id
+            secret String? @ignore
+        }
+    "#;
+
+    api.infer_apply(dm2)
+        .migration_id(Some("watch01"))
+        .send()
+        .await?
+        .assert_green()?
+        .assert_no_steps()?;
+
+    // The database steps since the last non-watch migration are calculated from the datamodels.
+    let output = api.infer(dm2).migration_id(Some("mig01")).send().await?;
+
+    assert_eq!(output.database_steps, serde_json::json!([]));
+
+    Ok(())
+}
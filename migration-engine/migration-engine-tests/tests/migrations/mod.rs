@@ -1,6 +1,11 @@
+mod check_constraints;
+mod ignore;
 mod indexes;
 mod mariadb;
+mod multi_schema;
 mod mysql;
 mod postgres;
+mod referential_actions;
 mod sql;
 mod sqlite;
+mod views;
@@ -0,0 +1,156 @@
+use migration_engine_tests::sql::*;
+
+#[tokio::test]
+async fn tables_are_created_and_altered_in_their_schema() -> TestResult {
+    let billing_schema = "multi_schema_tables_billing".to_string();
+    let api = postgres_test_api_with_schemas("multi_schema_tables", &[billing_schema.clone()]).await;
+
+    let dm1 = format!(
+        r#"
+        model User {{
+            id       Int       @id
+            invoices Invoice[]
+        }}
+
+        model Invoice {{
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id])
+
+            @@schema("{}")
+        }}
+    "#,
+        billing_schema
+    );
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("User", |table| table.assert_in_schema(None))?
+        .assert_table("Invoice", |table| {
+            table
+                .assert_in_schema(Some(billing_schema.as_str()))?
+                .assert_fk_on_columns(&["userId"], |fk| fk.assert_references("User", &["id"]))
+        })?;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = format!(
+        r#"
+        model User {{
+            id       Int       @id
+            invoices Invoice[]
+        }}
+
+        model Invoice {{
+            id     Int     @id
+            amount Int     @default(0)
+            userId Int
+            user   User    @relation(fields: [userId], references: [id])
+
+            @@schema("{}")
+            @@index([amount])
+        }}
+    "#,
+        billing_schema
+    );
+
+    api.infer_apply(&dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Invoice", |table| {
+        table
+            .assert_in_schema(Some(billing_schema.as_str()))?
+            .assert_has_column("amount")?
+            .assert_index_on_columns(&["amount"], |index| Ok(index))
+    })?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn changing_the_schema_of_a_model_moves_its_table() -> TestResult {
+    let archive_schema = "multi_schema_moves_archive".to_string();
+    let api = postgres_test_api_with_schemas("multi_schema_moves", &[archive_schema.clone()]).await;
+
+    let dm1 = r#"
+        model Invoice {
+            id     Int @id
+            amount Int
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+    api.insert("Invoice")
+        .value("id", 1)
+        .value("amount", 30)
+        .result_raw()
+        .await?;
+
+    let dm2 = format!(
+        r#"
+        model Invoice {{
+            id     Int @id
+            amount Int
+
+            @@schema("{}")
+        }}
+    "#,
+        archive_schema
+    );
+
+    api.infer_apply(&dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Invoice", |table| {
+        table
+            .assert_in_schema(Some(archive_schema.as_str()))?
+            .assert_has_column("amount")
+    })?;
+
+    api.infer_apply(&dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Invoice", |table| table.assert_in_schema(None))?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn models_in_the_schema_of_the_connection_are_not_moved() -> TestResult {
+    let api = postgres_test_api_with_schemas("multi_schema_connection_schema", &[]).await;
+
+    let create_legacy = format!(r#"CREATE TABLE "{}"."Legacy" (value TEXT)"#, api.schema_name());
+    api.database().query_raw(&create_legacy, &[]).await?;
+
+    let dm = format!(
+        r#"
+        model Invoice {{
+            id Int @id
+
+            @@schema("{schema}")
+        }}
+
+        model Legacy {{
+            value String
+
+            @@ignore
+            @@schema("{schema}")
+        }}
+    "#,
+        schema = api.schema_name()
+    );
+
+    api.infer_apply(&dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Invoice", |table| table.assert_in_schema(None))?
+        .assert_table("Legacy", |table| table.assert_in_schema(None))?;
+
+    api.infer_apply(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use migration_engine_tests::*;
-use sql_schema_describer::{ColumnArity, ColumnTypeFamily};
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, DefaultValue};
 
 #[test_each_connector(tags("postgres"))]
 async fn enums_can_be_dropped_on_postgres(api: &TestApi) -> TestResult {
@@ -96,3 +96,220 @@ async fn existing_postgis_tables_must_not_be_migrated(api: &TestApi) -> TestResu
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn sequence_defaults_are_migrated_on_postgres(api: &TestApi) -> TestResult {
+    let dm = format!(
+        r#"
+        {}
+
+        model Cat {{
+            id      Int @id
+            counter Int @default(sequence("counter_seq"))
+        }}
+
+        model Dog {{
+            id Int @id @default(autoincrement())
+        }}
+        "#,
+        api.datasource()
+    );
+
+    api.infer_apply(&dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let default = schema.table_bang("Cat").column_bang("counter").default.clone();
+
+    match default {
+        Some(DefaultValue::DBGENERATED(default)) | Some(DefaultValue::SEQUENCE(default)) => {
+            assert!(default.contains("nextval"), "{}", default);
+            assert!(default.contains("counter_seq"), "{}", default);
+        }
+        other => panic!("Expected a sequence default, but got {:?}", other),
+    }
+
+    assert!(schema.get_sequence("counter_seq").is_some());
+
+    api.infer(&dm).send_assert().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = format!(
+        r#"
+        {}
+
+        model Cat {{
+            id      Int @id
+            counter Int
+        }}
+
+        model Dog {{
+            id Int @id @default(autoincrement())
+        }}
+        "#,
+        api.datasource()
+    );
+
+    api.infer_apply(&dm2).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.get_sequence("counter_seq").is_none());
+    assert!(schema.get_sequence("Dog_id_seq").is_some());
+
+    api.infer(&dm2).send_assert().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn sequence_defaults_can_be_added_and_switched_on_postgres(api: &TestApi) -> TestResult {
+    let dm = |default: &str| {
+        format!(
+            r#"
+            {}
+
+            model Cat {{
+                id      Int @id
+                counter Int {}
+            }}
+            "#,
+            api.datasource(),
+            default
+        )
+    };
+
+    let assert_counter_sequence = |schema: &sql_schema_describer::SqlSchema, sequence_name: &str| {
+        let default = &schema.table_bang("Cat").column_bang("counter").default;
+
+        match default {
+            Some(DefaultValue::DBGENERATED(default)) | Some(DefaultValue::SEQUENCE(default)) => {
+                assert!(default.contains(sequence_name), "{}", default)
+            }
+            other => panic!("Expected a sequence default, but got {:?}", other),
+        }
+    };
+
+    api.infer_apply(&dm("")).send().await?.assert_green()?;
+
+    api.infer_apply(&dm(r#"@default(sequence("first_seq"))"#))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let schema = api.describe_database().await?;
+    assert_counter_sequence(&schema, "first_seq");
+
+    api.infer_apply(&dm(r#"@default(sequence("second_seq"))"#))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let schema = api.describe_database().await?;
+    assert_counter_sequence(&schema, "second_seq");
+    assert!(schema.get_sequence("first_seq").is_none());
+    assert!(schema.get_sequence("second_seq").is_some());
+
+    api.infer(&dm(r#"@default(sequence("second_seq"))"#))
+        .send_assert()
+        .await?
+        .assert_green()?
+        .assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn native_type_changes_are_migrated_on_postgres(api: &TestApi) -> TestResult {
+    let dm = |title_type: &str, price_type: &str| {
+        format!(
+            r#"
+            {}
+
+            model Product {{
+                id        Int      @id
+                title     String   @db.{}
+                price     Decimal  @db.{}
+                createdAt DateTime @db.Timestamptz(3)
+            }}
+            "#,
+            api.datasource(),
+            title_type,
+            price_type,
+        )
+    };
+
+    let dm1 = dm("VarChar(10)", "Numeric(10, 2)");
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+    api.infer(&dm1).send_assert().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = dm("VarChar(20)", "Numeric(12, 4)");
+
+    api.infer_apply(&dm2).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let table = schema.table_bang("Product");
+
+    assert_eq!(table.column_bang("title").tpe.character_maximum_length, Some(20));
+    assert_eq!(table.column_bang("price").tpe.precision, Some(12));
+    assert_eq!(table.column_bang("price").tpe.scale, Some(4));
+
+    api.infer(&dm2).send_assert().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn columns_of_fields_without_native_types_keep_their_type_on_postgres(api: &TestApi) -> TestResult {
+    let create_product = format!(
+        r#"CREATE TABLE "{}"."Product" (id INTEGER PRIMARY KEY, title VARCHAR(255) NOT NULL, stock SMALLINT NOT NULL)"#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_product, &[]).await?;
+
+    let dm = r#"
+        model Product {
+            id    Int    @id
+            title String
+            stock Int
+        }
+    "#;
+
+    let result = api.infer_apply(dm).send().await?.assert_green()?.into_inner();
+
+    assert!(result.database_steps.as_array().unwrap().is_empty());
+
+    let schema = api.describe_database().await?;
+    let table = schema.table_bang("Product");
+
+    assert_eq!(table.column_bang("title").tpe.character_maximum_length, Some(255));
+    assert_eq!(table.column_bang("stock").tpe.full_data_type, "int2");
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn autoincrement_on_small_ints_creates_a_smallserial_on_postgres(api: &TestApi) -> TestResult {
+    let dm = format!(
+        r#"
+        {}
+
+        model Counter {{
+            id Int @id @default(autoincrement()) @db.SmallInt
+        }}
+        "#,
+        api.datasource()
+    );
+
+    api.infer_apply(&dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let id = schema.table_bang("Counter").column_bang("id");
+
+    assert_eq!(id.tpe.full_data_type, "int2");
+    assert!(id.auto_increment);
+
+    api.infer(&dm).send_assert().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
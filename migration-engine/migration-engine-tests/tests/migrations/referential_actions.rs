@@ -0,0 +1,95 @@
+use migration_engine_tests::sql::*;
+use sql_schema_describer::ForeignKeyAction;
+
+#[test_each_connector(tags("sql"))]
+async fn referential_actions_are_rendered_into_foreign_keys(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int   @id
+            authorId Int?
+            author   User? @relation(fields: [authorId], references: [id], onDelete: Restrict, onUpdate: SetNull)
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["authorId"], |fk| {
+            fk.assert_on_delete(ForeignKeyAction::Restrict)?
+                .assert_on_update(ForeignKeyAction::SetNull)
+        })
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn relations_without_referential_actions_keep_the_default_actions(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["authorId"], |fk| {
+            fk.assert_on_delete(ForeignKeyAction::Cascade)?
+                .assert_on_update(ForeignKeyAction::Cascade)
+        })
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn changing_a_referential_action_updates_the_foreign_key(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id], onDelete: Restrict)
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["authorId"], |fk| fk.assert_on_delete(ForeignKeyAction::Restrict))
+    })?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use migration_engine_tests::sql::*;
+use sql_schema_describer::ColumnTypeFamily;
 use std::borrow::Cow;
 
 #[test_each_connector(tags("sql"))]
@@ -388,3 +389,40 @@ async fn dropping_mutually_referencing_tables_works(api: &TestApi) -> TestResult
 
     Ok(())
 }
+
+#[test_each_connector(tags("sql"))]
+async fn changing_a_field_from_int_to_bigint_must_work(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Post {
+            id    Int @id
+            views Int
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Post {
+            id    Int    @id
+            views BigInt
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let expected_family = if api.is_sqlite() {
+        ColumnTypeFamily::Int
+    } else {
+        ColumnTypeFamily::BigInt
+    };
+
+    assert_eq!(
+        schema.table_bang("Post").column_bang("views").tpe.family,
+        expected_family
+    );
+
+    api.infer(dm2).send_assert().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector(tags("sql"))]
+async fn views_are_not_created_as_tables(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id       Int    @id
+            authorId Int
+            author   Author @relation(fields: [authorId], references: [id])
+        }
+
+        view Author {
+            id    Int    @id
+            posts Post[]
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_table("Post", |table| {
+            table.assert_has_column("authorId")?.assert_foreign_keys_count(0)
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn existing_views_are_left_untouched(api: &TestApi) -> TestResult {
+    let create_view = format!(
+        r#"CREATE VIEW "{}"."Answers" AS SELECT 42 AS answer"#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_view, &[]).await?;
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.has_table("Cat"));
+    assert!(schema.get_view("Answers").is_some());
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::CodeActionsOpts;
+use datamodel::ast::{self, code_actions::CodeAction};
+use std::{
+    fs,
+    io::{self, Read},
+};
+
+#[derive(serde::Serialize)]
+struct CodeActionOutput {
+    title: String,
+    edits: Vec<TextEditOutput>,
+}
+
+#[derive(serde::Serialize)]
+struct TextEditOutput {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+impl From<CodeAction> for CodeActionOutput {
+    fn from(action: CodeAction) -> Self {
+        let edits = action
+            .edits
+            .into_iter()
+            .map(|edit| TextEditOutput {
+                start: edit.span.start,
+                end: edit.span.end,
+                text: edit.new_text,
+            })
+            .collect();
+
+        CodeActionOutput {
+            title: action.title,
+            edits,
+        }
+    }
+}
+
+pub fn run(opts: CodeActionsOpts) {
+    let datamodel_string = match opts.input {
+        Some(file_name) => {
+            fs::read_to_string(&file_name).expect(&format!("Unable to open file {}", file_name.display()))
+        }
+        None => {
+            let mut buf = String::new();
+
+            io::stdin()
+                .read_to_string(&mut buf)
+                .expect("Unable to read from stdin.");
+
+            buf
+        }
+    };
+
+    let span = ast::Span::new(opts.start, opts.end.unwrap_or(opts.start));
+    let actions: Vec<CodeActionOutput> = ast::code_actions::code_actions(&datamodel_string, span)
+        .into_iter()
+        .map(CodeActionOutput::from)
+        .collect();
+
+    let json = serde_json::to_string(&actions).expect("Failed to render JSON");
+
+    print!("{}", json)
+}
@@ -0,0 +1,48 @@
+use crate::DiagramOpts;
+use std::{
+    fs,
+    io::{self, Read, Write},
+    process,
+};
+
+pub fn run(opts: DiagramOpts) {
+    let datamodel_string = match opts.input {
+        Some(file_name) => {
+            fs::read_to_string(&file_name).expect(&format!("Unable to open file {}", file_name.display()))
+        }
+        None => {
+            let mut buf = String::new();
+
+            io::stdin()
+                .read_to_string(&mut buf)
+                .expect("Unable to read from stdin.");
+
+            buf
+        }
+    };
+
+    let datamodel = match datamodel::parse_datamodel_and_ignore_env_errors(&datamodel_string) {
+        Ok(validated) => validated.subject,
+        Err(errors) => {
+            eprintln!("{}", errors.to_pretty_string("schema.prisma", &datamodel_string));
+            process::exit(1)
+        }
+    };
+
+    let diagram = match opts.format.as_str() {
+        "dot" => datamodel::diagram::render_dot(&datamodel),
+        _ => datamodel::diagram::render_mermaid(&datamodel),
+    };
+
+    match opts.output {
+        Some(file_name) => {
+            fs::write(&file_name, diagram).expect(&format!("Unable to write file {}", file_name.display()));
+        }
+        None => {
+            io::stdout()
+                .lock()
+                .write_all(diagram.as_bytes())
+                .expect("Unable to write to stdout.");
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::DiffOpts;
+use datamodel::{ast::SchemaAst, diff::DatamodelVersion, dml::Datamodel, SchemaFiles};
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+    process,
+};
+
+pub fn run(opts: DiffOpts) {
+    let previous_files = load_files(&opts.previous);
+    let next_files = load_files(&opts.next);
+
+    let (previous_ast, previous_datamodel) = parse_files(&previous_files);
+    let (next_ast, next_datamodel) = parse_files(&next_files);
+
+    let diff = datamodel::diff::diff_datamodels(
+        DatamodelVersion::new(&previous_ast, &previous_datamodel),
+        DatamodelVersion::new(&next_ast, &next_datamodel),
+    );
+
+    let report = if opts.json { diff.to_json() } else { diff.to_string() };
+
+    io::stdout()
+        .lock()
+        .write_all(report.as_bytes())
+        .expect("Unable to write to stdout.");
+}
+
+fn load_files(paths: &[PathBuf]) -> SchemaFiles {
+    SchemaFiles::load(paths).expect("Unable to read the schema files.")
+}
+
+fn parse_files(files: &SchemaFiles) -> (SchemaAst, Datamodel) {
+    let parsed = datamodel::parse_schema_ast(files.source())
+        .and_then(|ast| datamodel::lift_ast_and_ignore_env_errors(&ast).map(|datamodel| (ast, datamodel)));
+
+    match parsed {
+        Ok(parsed) => parsed,
+        Err(errors) => {
+            eprintln!("{}", errors.to_pretty_string_files(files));
+            process::exit(1)
+        }
+    }
+}
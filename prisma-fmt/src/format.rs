@@ -1,12 +1,17 @@
-use datamodel::ast::reformat::Reformatter;
+use datamodel::{ast::reformat::Reformatter, SchemaFiles};
 use std::{
     fs::{self, File},
     io::{self, BufWriter, Read},
+    path::Path,
 };
 
 use crate::FormatOpts;
 
 pub fn run(opts: FormatOpts) {
+    if let Some(directory) = opts.input.as_ref().filter(|input| input.is_dir()) {
+        return format_directory(directory, opts.tabwidth);
+    }
+
     let datamodel_string = match opts.input {
         Some(file_name) => {
             fs::read_to_string(&file_name).expect(&format!("Unable to open file {}", file_name.display()))
@@ -34,3 +39,14 @@ pub fn run(opts: FormatOpts) {
         }
     }
 }
+
+/// Formats every schema file of the directory in place. The files are parsed together, so back
+/// relations are added to the file of the model they belong to.
+fn format_directory(directory: &Path, tabwidth: usize) {
+    let files = SchemaFiles::load(&[directory]).expect(&format!("Unable to read directory {}", directory.display()));
+    let formatted = Reformatter::reformat_files(&files, tabwidth);
+
+    for (file, contents) in files.files().iter().zip(formatted) {
+        fs::write(&file.name, contents).expect(&format!("Unable to write file {}", file.name));
+    }
+}
@@ -1,37 +1,57 @@
-use crate::{LintOpts, MiniError};
-use datamodel::error::DatamodelError;
+use crate::{LintOpts, MiniError, Severity};
+use datamodel::{ast::Span, SchemaFiles};
 use serde_json;
 use std::io::{self, Read};
 
 pub fn run(opts: LintOpts) {
-    let mut datamodel_string = String::new();
+    let files = if opts.input.is_empty() {
+        let mut datamodel_string = String::new();
 
-    io::stdin()
-        .read_to_string(&mut datamodel_string)
-        .expect("Unable to read from stdin.");
+        io::stdin()
+            .read_to_string(&mut datamodel_string)
+            .expect("Unable to read from stdin.");
+
+        SchemaFiles::single("", &datamodel_string)
+    } else {
+        SchemaFiles::load(&opts.input).expect("Unable to read the schema files.")
+    };
 
     let datamodel_result = if opts.no_env_errors {
-        datamodel::parse_datamodel_and_ignore_env_errors(&datamodel_string)
+        datamodel::parse_datamodel_and_ignore_env_errors(files.source())
     } else {
-        datamodel::parse_datamodel(&datamodel_string)
+        datamodel::parse_datamodel_files(&files)
+    };
+
+    let mini_errors: Vec<MiniError> = match datamodel_result {
+        Err(err) => err
+            .errors
+            .iter()
+            .map(|err| mini_error(&opts, &files, err.span(), format!("{}", err), Severity::Error))
+            .collect(),
+        Ok(validated) => validated
+            .warnings
+            .iter()
+            .map(|warning| mini_error(&opts, &files, warning.span(), format!("{}", warning), Severity::Warning))
+            .collect(),
+    };
+
+    let json = serde_json::to_string(&mini_errors).expect("Failed to render JSON");
+
+    print!("{}", json)
+}
+
+/// Errors of a schema read from STDIN keep their offsets, others are reported relative to their file.
+fn mini_error(opts: &LintOpts, files: &SchemaFiles, span: Span, text: String, severity: Severity) -> MiniError {
+    let (file, span) = match files.locate(span) {
+        Some((file, local_span)) if !opts.input.is_empty() => (Some(file.name.clone()), local_span),
+        _ => (None, span),
     };
 
-    match datamodel_result {
-        Err(err) => {
-            let mini_errors: Vec<MiniError> = err
-                .errors
-                .iter()
-                .map(|err: &DatamodelError| MiniError {
-                    start: err.span().start,
-                    end: err.span().end,
-                    text: format!("{}", err),
-                })
-                .collect();
-
-            let json = serde_json::to_string(&mini_errors).expect("Failed to render JSON");
-
-            print!("{}", json)
-        }
-        _ => print!("[]"),
+    MiniError {
+        file,
+        start: span.start,
+        end: span.end,
+        text,
+        severity,
     }
 }
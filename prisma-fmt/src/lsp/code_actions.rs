@@ -0,0 +1,56 @@
+use super::position::{position_to_offset, span_to_range};
+use datamodel::ast::{self, code_actions};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, Diagnostic, TextEdit, WorkspaceEdit,
+};
+use std::collections::HashMap;
+
+/// Offers the quick fixes for the diagnostics and the cursor position of the request.
+pub fn code_actions(text: &str, params: CodeActionParams) -> Vec<CodeActionOrCommand> {
+    let uri = params.text_document.uri;
+    let mut locations: Vec<(ast::Span, Option<Diagnostic>)> = params
+        .context
+        .diagnostics
+        .into_iter()
+        .map(|diagnostic| (range_start(text, &diagnostic), Some(diagnostic)))
+        .collect();
+
+    let cursor = position_to_offset(text, params.range.start);
+    locations.push((ast::Span::new(cursor, cursor), None));
+
+    let mut actions: Vec<CodeAction> = Vec::new();
+
+    for (span, diagnostic) in locations {
+        for action in code_actions::code_actions(text, span) {
+            // The same fix is usually found for the diagnostic and the cursor.
+            if actions.iter().any(|existing| existing.title == action.title) {
+                continue;
+            }
+
+            let edits = action
+                .edits
+                .into_iter()
+                .map(|edit| TextEdit::new(span_to_range(text, edit.span), edit.new_text))
+                .collect();
+
+            let mut changes = HashMap::new();
+            changes.insert(uri.clone(), edits);
+
+            actions.push(CodeAction {
+                title: action.title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: diagnostic.clone().map(|diagnostic| vec![diagnostic]),
+                edit: Some(WorkspaceEdit::new(changes)),
+                ..CodeAction::default()
+            });
+        }
+    }
+
+    actions.into_iter().map(CodeActionOrCommand::CodeAction).collect()
+}
+
+fn range_start(text: &str, diagnostic: &Diagnostic) -> ast::Span {
+    let start = position_to_offset(text, diagnostic.range.start);
+
+    ast::Span::new(start, start)
+}
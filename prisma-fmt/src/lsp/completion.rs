@@ -0,0 +1,308 @@
+use super::position::position_to_offset;
+use datamodel::validator::directive;
+use lsp_types::{CompletionItem, CompletionItemKind, Position};
+
+const SCALAR_TYPES: &[&str] = &[
+    "String", "Boolean", "Int", "Float", "DateTime", "Json", "Decimal", "BigInt", "Bytes",
+];
+
+const DEFAULT_FUNCTIONS: &[&str] = &[
+    "autoincrement",
+    "now",
+    "cuid",
+    "uuid",
+    "ulid",
+    "nanoid",
+    "sequence",
+    "dbgenerated",
+];
+
+/// The kind of block the cursor is in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Block {
+    Model,
+    Enum,
+    Other,
+    TopLevel,
+}
+
+/// Computes the completions at the given position. The text around the cursor is inspected
+/// directly, as the schema is usually incomplete while typing.
+pub fn complete(text: &str, position: Position) -> Vec<CompletionItem> {
+    let offset = position_to_offset(text, position);
+    let line_start = text[..offset].rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+    let line = &text[line_start..offset];
+    let block = find_block(&text[..line_start]);
+
+    let word_start = line
+        .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .map(|idx| idx + 1)
+        .unwrap_or(0);
+    let before_word = &line[..word_start];
+
+    if let Some((directive_name, is_block_directive)) = find_open_directive(before_word) {
+        return argument_completions(&directive_name, is_block_directive);
+    }
+
+    if before_word.ends_with("@@") {
+        return directive_completions(block, true);
+    }
+
+    if before_word.ends_with('@') {
+        return directive_completions(block, false);
+    }
+
+    let mut tokens = before_word.split_whitespace();
+    let is_type_position = tokens.next().is_some() && tokens.next().is_none() && before_word.ends_with(' ');
+
+    if block == Block::Model && is_type_position {
+        return type_completions(text);
+    }
+
+    Vec::new()
+}
+
+/// Finds the kind of the innermost block that is open at the end of the given text.
+fn find_block(text: &str) -> Block {
+    let opening_brace = match text.rfind(|c| c == '{' || c == '}') {
+        Some(idx) if &text[idx..idx + 1] == "{" => idx,
+        _ => return Block::TopLevel,
+    };
+
+    let header_start = text[..opening_brace].rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+
+    match text[header_start..opening_brace].split_whitespace().next() {
+        Some("model") | Some("view") | Some("type") => Block::Model,
+        Some("enum") => Block::Enum,
+        _ => Block::Other,
+    }
+}
+
+/// If the text ends inside the argument list of a directive, returns the name of that directive
+/// and whether it is a block directive (`@@`).
+fn find_open_directive(text: &str) -> Option<(String, bool)> {
+    let mut depth = 0;
+
+    for (idx, c) in text.char_indices().rev() {
+        match c {
+            ')' | ']' => depth += 1,
+            '[' if depth > 0 => depth -= 1,
+            '(' if depth > 0 => depth -= 1,
+            '(' => {
+                let before = &text[..idx];
+                let name_start = before.rfind('@')? + 1;
+                let name = &before[name_start..];
+
+                if !name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.') {
+                    return None;
+                }
+
+                return Some((name.to_owned(), before[..name_start].ends_with("@@")));
+            }
+            _ => (),
+        }
+    }
+
+    None
+}
+
+fn directive_completions(block: Block, is_block_directive: bool) -> Vec<CompletionItem> {
+    let names: Vec<String> = match (block, is_block_directive) {
+        (Block::Model, false) => collect_names(directive::new_builtin_field_directives().directive_names()),
+        (Block::Model, true) => collect_names(directive::new_builtin_model_directives().directive_names()),
+        (Block::Enum, false) => collect_names(directive::new_builtin_enum_value_directives().directive_names()),
+        (Block::Enum, true) => collect_names(directive::new_builtin_enum_directives().directive_names()),
+        _ => Vec::new(),
+    };
+
+    names
+        .into_iter()
+        .map(|name| completion_item(name, CompletionItemKind::Property))
+        .collect()
+}
+
+fn collect_names<'a>(names: impl Iterator<Item = &'a str>) -> Vec<String> {
+    names.map(String::from).collect()
+}
+
+fn argument_completions(directive_name: &str, is_block_directive: bool) -> Vec<CompletionItem> {
+    let arguments: &[&str] = match (directive_name, is_block_directive) {
+        ("relation", false) => &["name", "fields", "references", "onDelete", "onUpdate"],
+        ("map", _) | ("schema", true) => &["name"],
+        ("id", true) | ("unique", true) | ("index", true) => &["fields", "name"],
+        ("check", true) => &["expression", "name"],
+        ("default", false) => &["value"],
+        _ => &[],
+    };
+
+    let mut items: Vec<CompletionItem> = arguments
+        .iter()
+        .map(|argument| CompletionItem {
+            insert_text: Some(format!("{}: ", argument)),
+            ..completion_item(argument.to_string(), CompletionItemKind::Field)
+        })
+        .collect();
+
+    if directive_name == "default" {
+        items.extend(DEFAULT_FUNCTIONS.iter().map(|function| CompletionItem {
+            insert_text: Some(format!("{}()", function)),
+            ..completion_item(function.to_string(), CompletionItemKind::Function)
+        }));
+    }
+
+    items
+}
+
+fn type_completions(text: &str) -> Vec<CompletionItem> {
+    let mut items: Vec<CompletionItem> = SCALAR_TYPES
+        .iter()
+        .map(|name| completion_item(name.to_string(), CompletionItemKind::TypeParameter))
+        .collect();
+
+    items.extend(declared_types(text));
+
+    items
+}
+
+/// Returns the models, enums, composite types and type aliases declared in the schema.
+/// Falls back to scanning the declarations if the schema doesn't parse.
+fn declared_types(text: &str) -> Vec<CompletionItem> {
+    if let Ok(schema) = datamodel::parse_schema_ast(text) {
+        let models = schema.models().into_iter().map(|model| {
+            let kind = if model.is_composite_type {
+                CompletionItemKind::Struct
+            } else {
+                CompletionItemKind::Class
+            };
+
+            completion_item(model.name.name.clone(), kind)
+        });
+        let enums = schema
+            .enums()
+            .into_iter()
+            .map(|r#enum| completion_item(r#enum.name.name.clone(), CompletionItemKind::Enum));
+        let aliases = schema
+            .types()
+            .into_iter()
+            .map(|alias| completion_item(alias.name.name.clone(), CompletionItemKind::TypeParameter));
+
+        return models.chain(enums).chain(aliases).collect();
+    }
+
+    text.lines()
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace();
+
+            let kind = match tokens.next()? {
+                "model" | "view" => CompletionItemKind::Class,
+                "type" => CompletionItemKind::Struct,
+                "enum" => CompletionItemKind::Enum,
+                _ => return None,
+            };
+
+            let name = tokens.next()?.trim_end_matches('{');
+
+            if name.is_empty() {
+                None
+            } else {
+                Some(completion_item(name.to_owned(), kind))
+            }
+        })
+        .collect()
+}
+
+fn completion_item(label: String, kind: CompletionItemKind) -> CompletionItem {
+    CompletionItem {
+        label,
+        kind: Some(kind),
+        ..CompletionItem::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(items: Vec<CompletionItem>) -> Vec<String> {
+        items.into_iter().map(|item| item.label).collect()
+    }
+
+    #[test]
+    fn find_block_must_return_the_innermost_open_block() {
+        assert_eq!(find_block("model User {\n  id Int\n"), Block::Model);
+        assert_eq!(find_block("type Address {\n"), Block::Model);
+        assert_eq!(find_block("enum Role {\n"), Block::Enum);
+        assert_eq!(find_block("datasource db {\n"), Block::Other);
+        assert_eq!(find_block("model User {\n  id Int\n}\n"), Block::TopLevel);
+        assert_eq!(find_block(""), Block::TopLevel);
+    }
+
+    #[test]
+    fn find_open_directive_must_skip_closed_argument_lists() {
+        assert_eq!(
+            find_open_directive("  author User @relation(fields: [authorId], "),
+            Some((String::from("relation"), false))
+        );
+        assert_eq!(
+            find_open_directive("  @@index([a, "),
+            Some((String::from("index"), true))
+        );
+        assert_eq!(find_open_directive("  id Int @default(autoincrement()) "), None);
+        assert_eq!(find_open_directive("  id Int "), None);
+    }
+
+    #[test]
+    fn field_directives_must_be_completed_after_an_at_sign() {
+        let items = labels(complete("model User {\n  id Int @\n}", Position::new(1, 10)));
+
+        assert!(items.contains(&String::from("default")));
+        assert!(items.contains(&String::from("relation")));
+        assert!(!items.contains(&String::from("index")));
+    }
+
+    #[test]
+    fn block_directives_must_be_completed_after_two_at_signs() {
+        let items = labels(complete("model User {\n  @@\n}", Position::new(1, 4)));
+
+        assert!(items.contains(&String::from("index")));
+        assert!(items.contains(&String::from("unique")));
+        assert!(!items.contains(&String::from("default")));
+    }
+
+    #[test]
+    fn arguments_must_be_completed_inside_a_directive() {
+        let items = labels(complete(
+            "model Post {\n  author User @relation(\n}",
+            Position::new(1, 24),
+        ));
+        assert!(items.contains(&String::from("references")));
+
+        let items = labels(complete("model Post {\n  id Int @default(\n}", Position::new(1, 18)));
+        assert!(items.contains(&String::from("autoincrement")));
+    }
+
+    #[test]
+    fn types_must_be_completed_after_a_field_name() {
+        let text = "model User {\n  posts \n}\n\nmodel Post {\n  id Int @id\n}\n\nenum Role {\n  USER\n}";
+        let items = complete(text, Position::new(1, 8));
+
+        let post = items
+            .iter()
+            .find(|item| item.label == "Post")
+            .expect("Post not completed");
+        assert_eq!(post.kind, Some(CompletionItemKind::Class));
+
+        let role = items
+            .iter()
+            .find(|item| item.label == "Role")
+            .expect("Role not completed");
+        assert_eq!(role.kind, Some(CompletionItemKind::Enum));
+
+        assert!(labels(items).contains(&String::from("String")));
+    }
+
+    #[test]
+    fn nothing_must_be_completed_at_the_top_level() {
+        assert!(complete("mo", Position::new(0, 2)).is_empty());
+    }
+}
@@ -0,0 +1,82 @@
+use super::position::{position_to_offset, span_to_range};
+use datamodel::ast::{self, Span};
+use lsp_types::{Location, Position, Url};
+
+/// Resolves the type of the field under the cursor to the declaration of that model, enum or type.
+pub fn goto_definition(uri: &Url, text: &str, position: Position) -> Option<Location> {
+    let offset = position_to_offset(text, position);
+    let schema = datamodel::parse_schema_ast(text).ok()?;
+    let field_type = find_field_type_at(&schema, offset)?;
+    let span = find_declaration(&schema, field_type)?;
+
+    Some(Location::new(uri.clone(), span_to_range(text, span)))
+}
+
+fn find_field_type_at(schema: &ast::SchemaAst, offset: usize) -> Option<&str> {
+    schema
+        .models()
+        .into_iter()
+        .flat_map(|model| model.fields.iter())
+        .find(|field| field.field_type.span.contains(offset))
+        .map(|field| field.field_type.name.as_str())
+}
+
+/// Returns the span of the name of the model, enum or type alias with the given name.
+pub fn find_declaration(schema: &ast::SchemaAst, name: &str) -> Option<Span> {
+    if let Some(model) = schema.find_model(name) {
+        return Some(model.name.span);
+    }
+
+    if let Some(r#enum) = schema.find_enum(name) {
+        return Some(r#enum.name.span);
+    }
+
+    schema.find_type_alias(name).map(|alias| alias.name.span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lsp_types::Range;
+
+    const SCHEMA: &str = r#"model User {
+  id   Int  @id
+  role Role
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+
+enum Role {
+  USER
+}
+"#;
+
+    fn uri() -> Url {
+        Url::parse("file:///schema.prisma").unwrap()
+    }
+
+    #[test]
+    fn the_type_of_a_relation_field_must_resolve_to_the_model() {
+        let location = goto_definition(&uri(), SCHEMA, Position::new(8, 12)).expect("No definition");
+
+        assert_eq!(location.uri, uri());
+        assert_eq!(location.range, Range::new(Position::new(0, 6), Position::new(0, 10)));
+    }
+
+    #[test]
+    fn the_type_of_an_enum_field_must_resolve_to_the_enum() {
+        let location = goto_definition(&uri(), SCHEMA, Position::new(2, 8)).expect("No definition");
+
+        assert_eq!(location.range, Range::new(Position::new(11, 5), Position::new(11, 9)));
+    }
+
+    #[test]
+    fn scalar_types_and_field_names_must_not_resolve() {
+        assert!(goto_definition(&uri(), SCHEMA, Position::new(1, 8)).is_none());
+        assert!(goto_definition(&uri(), SCHEMA, Position::new(8, 3)).is_none());
+    }
+}
@@ -0,0 +1,34 @@
+use super::position::span_to_range;
+use datamodel::ast::Span;
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString};
+
+/// Validates the schema and returns all errors, or all warnings if it is valid, as diagnostics.
+/// Missing environment variables are not reported, as editors usually don't share the environment of the application.
+pub fn compute(text: &str) -> Vec<Diagnostic> {
+    match datamodel::parse_datamodel_and_ignore_env_errors(text) {
+        Ok(validated) => validated
+            .warnings
+            .iter()
+            .map(|warning| {
+                let mut diagnostic =
+                    to_diagnostic(text, warning.span(), warning.description(), DiagnosticSeverity::Warning);
+                diagnostic.code = Some(NumberOrString::String(warning.code().to_owned()));
+
+                diagnostic
+            })
+            .collect(),
+        Err(errors) => errors
+            .errors
+            .iter()
+            .map(|error| to_diagnostic(text, error.span(), error.description(), DiagnosticSeverity::Error))
+            .collect(),
+    }
+}
+
+fn to_diagnostic(text: &str, span: Span, message: String, severity: DiagnosticSeverity) -> Diagnostic {
+    let mut diagnostic = Diagnostic::new_simple(span_to_range(text, span), message);
+    diagnostic.severity = Some(severity);
+    diagnostic.source = Some(String::from("prisma"));
+
+    diagnostic
+}
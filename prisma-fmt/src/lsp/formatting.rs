@@ -0,0 +1,23 @@
+use super::position::offset_to_position;
+use datamodel::ast::reformat::Reformatter;
+use lsp_types::{Position, Range, TextEdit};
+
+/// Reformats the whole schema. Schemas with syntax errors are left untouched.
+pub fn format(text: &str, tab_size: usize) -> Vec<TextEdit> {
+    if datamodel::parse_schema_ast(text).is_err() {
+        return Vec::new();
+    }
+
+    let mut formatted = Vec::new();
+    Reformatter::new(text).reformat_to(&mut formatted, tab_size);
+
+    let formatted = String::from_utf8(formatted).expect("The reformatter produced invalid UTF-8.");
+
+    if formatted == text {
+        return Vec::new();
+    }
+
+    let range = Range::new(Position::new(0, 0), offset_to_position(text, text.len()));
+
+    vec![TextEdit::new(range, formatted)]
+}
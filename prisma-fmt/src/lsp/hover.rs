@@ -0,0 +1,210 @@
+use super::position::{position_to_offset, span_to_range};
+use datamodel::{ast, Datamodel, WithDatabaseName};
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position};
+
+/// Shows the documentation and database name of the model, enum or field under the cursor.
+/// Hovering the type of a field shows the declaration of that type.
+pub fn hover(text: &str, position: Position) -> Option<Hover> {
+    let offset = position_to_offset(text, position);
+    let schema = datamodel::parse_schema_ast(text).ok()?;
+    let datamodel = datamodel::parse_datamodel_and_ignore_env_errors(text)
+        .ok()
+        .map(|validated| validated.subject);
+    let datamodel = datamodel.as_ref();
+
+    for model in schema.models() {
+        if model.name.span.contains(offset) {
+            return Some(hover_result(text, &model.name, model_contents(model, datamodel)));
+        }
+
+        for field in &model.fields {
+            if field.name.span.contains(offset) {
+                return Some(hover_result(text, &field.name, field_contents(model, field, datamodel)));
+            }
+
+            if field.field_type.span.contains(offset) {
+                let contents = type_contents(&schema, &field.field_type.name, datamodel)?;
+                return Some(hover_result(text, &field.field_type, contents));
+            }
+        }
+    }
+
+    for r#enum in schema.enums() {
+        if r#enum.name.span.contains(offset) {
+            return Some(hover_result(text, &r#enum.name, enum_contents(r#enum, datamodel)));
+        }
+    }
+
+    None
+}
+
+fn hover_result(text: &str, identifier: &ast::Identifier, contents: String) -> Hover {
+    Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: contents,
+        }),
+        range: Some(span_to_range(text, identifier.span)),
+    }
+}
+
+fn type_contents(schema: &ast::SchemaAst, name: &str, datamodel: Option<&Datamodel>) -> Option<String> {
+    if let Some(model) = schema.find_model(name) {
+        return Some(model_contents(model, datamodel));
+    }
+
+    if let Some(r#enum) = schema.find_enum(name) {
+        return Some(enum_contents(r#enum, datamodel));
+    }
+
+    schema.find_type_alias(name).map(|alias| {
+        render(
+            &format!("type {} = {}", name, alias.field_type.name),
+            &alias.documentation,
+            None,
+        )
+    })
+}
+
+fn model_contents(model: &ast::Model, datamodel: Option<&Datamodel>) -> String {
+    let keyword = if model.is_composite_type {
+        "type"
+    } else if model.is_view {
+        "view"
+    } else {
+        "model"
+    };
+
+    let database_name = datamodel
+        .and_then(|datamodel| datamodel.find_model(&model.name.name))
+        .map(|model| model.final_database_name());
+
+    render(
+        &format!("{} {}", keyword, model.name.name),
+        &model.documentation,
+        database_name,
+    )
+}
+
+fn field_contents(model: &ast::Model, field: &ast::Field, datamodel: Option<&Datamodel>) -> String {
+    let type_suffix = match field.arity {
+        ast::FieldArity::Required => "",
+        ast::FieldArity::Optional => "?",
+        ast::FieldArity::List => "[]",
+    };
+
+    // Relation fields don't have a column of their own.
+    let database_name = datamodel
+        .and_then(|datamodel| datamodel.find_model(&model.name.name))
+        .and_then(|model| model.find_field(&field.name.name))
+        .filter(|field| !field.field_type.is_relation())
+        .map(|field| field.final_database_name());
+
+    render(
+        &format!("{} {}{}", field.name.name, field.field_type.name, type_suffix),
+        &field.documentation,
+        database_name,
+    )
+}
+
+fn enum_contents(r#enum: &ast::Enum, datamodel: Option<&Datamodel>) -> String {
+    let database_name = datamodel
+        .and_then(|datamodel| datamodel.find_enum(&r#enum.name.name))
+        .map(|r#enum| r#enum.final_database_name());
+
+    render(
+        &format!("enum {}", r#enum.name.name),
+        &r#enum.documentation,
+        database_name,
+    )
+}
+
+fn render(signature: &str, documentation: &Option<ast::Comment>, database_name: Option<&str>) -> String {
+    let mut contents = format!("```prisma\n{}\n```", signature);
+
+    if let Some(documentation) = documentation {
+        contents.push_str(&format!("\n\n{}", documentation.text));
+    }
+
+    if let Some(database_name) = database_name {
+        contents.push_str(&format!("\n\nDatabase name: `{}`", database_name));
+    }
+
+    contents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lsp_types::Range;
+
+    const SCHEMA: &str = r#"/// A user of the blog.
+model User {
+  id    Int    @id
+  /// The email address.
+  email String @map("email_address")
+  role  Role
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+
+enum Role {
+  USER
+
+  @@map("roles")
+}
+"#;
+
+    fn hover_contents(position: Position) -> String {
+        match hover(SCHEMA, position).expect("No hover").contents {
+            HoverContents::Markup(markup) => markup.value,
+            contents => panic!("Unexpected hover contents: {:?}", contents),
+        }
+    }
+
+    #[test]
+    fn hovering_a_model_must_show_its_documentation_and_database_name() {
+        let result = hover(SCHEMA, Position::new(1, 7)).expect("No hover");
+        assert_eq!(
+            result.range,
+            Some(Range::new(Position::new(1, 6), Position::new(1, 10)))
+        );
+
+        let contents = hover_contents(Position::new(1, 7));
+        assert_eq!(
+            contents,
+            "```prisma\nmodel User\n```\n\nA user of the blog.\n\nDatabase name: `User`"
+        );
+    }
+
+    #[test]
+    fn hovering_a_field_must_show_its_type_and_database_name() {
+        let contents = hover_contents(Position::new(4, 3));
+        assert_eq!(
+            contents,
+            "```prisma\nemail String\n```\n\nThe email address.\n\nDatabase name: `email_address`"
+        );
+    }
+
+    #[test]
+    fn hovering_a_relation_field_must_not_show_a_database_name() {
+        let contents = hover_contents(Position::new(6, 3));
+        assert_eq!(contents, "```prisma\nposts Post[]\n```");
+    }
+
+    #[test]
+    fn hovering_the_type_of_a_field_must_show_the_declaration_of_the_type() {
+        let contents = hover_contents(Position::new(5, 9));
+        assert_eq!(contents, "```prisma\nenum Role\n```\n\nDatabase name: `roles`");
+    }
+
+    #[test]
+    fn hovering_whitespace_must_show_nothing() {
+        assert!(hover(SCHEMA, Position::new(2, 0)).is_none());
+    }
+}
@@ -0,0 +1,217 @@
+//! A language server for Prisma schemas, speaking the Language Server Protocol over stdio.
+//! Documents are synchronized in full on every change.
+mod code_actions;
+mod completion;
+mod definition;
+mod diagnostics;
+mod formatting;
+mod hover;
+mod position;
+mod rename;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _, PublishDiagnostics,
+    },
+    request::{CodeActionRequest, Completion, Formatting, GotoDefinition, HoverRequest, Rename, Request as _},
+    CodeActionParams, CodeActionProviderCapability, CompletionOptions, CompletionParams, CompletionResponse,
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams, DocumentFormattingParams,
+    GotoDefinitionParams, GotoDefinitionResponse, HoverParams, HoverProviderCapability, PublishDiagnosticsParams,
+    RenameParams, RenameProviderCapability, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use std::collections::HashMap;
+
+/// The open documents, by URI.
+type Documents = HashMap<Url, String>;
+
+pub fn run() {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = serde_json::to_value(capabilities()).expect("Failed to render the server capabilities.");
+    connection
+        .initialize(capabilities)
+        .expect("Failed to initialize the language server.");
+
+    main_loop(&connection);
+
+    io_threads.join().expect("Failed to shut down the language server.");
+}
+
+fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::Full)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec![String::from("@"), String::from("(")]),
+            ..CompletionOptions::default()
+        }),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(true),
+        document_formatting_provider: Some(true),
+        rename_provider: Some(RenameProviderCapability::Simple(true)),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        ..ServerCapabilities::default()
+    }
+}
+
+fn main_loop(connection: &Connection) {
+    let mut documents = Documents::new();
+
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection
+                    .handle_shutdown(&request)
+                    .expect("Failed to handle the shutdown request.")
+                {
+                    return;
+                }
+
+                let response = handle_request(&documents, request);
+                send(connection, Message::Response(response));
+            }
+            Message::Notification(notification) => {
+                if let Some(params) = handle_notification(&mut documents, notification) {
+                    let notification = Notification::new(PublishDiagnostics::METHOD.to_owned(), params);
+                    send(connection, Message::Notification(notification));
+                }
+            }
+            Message::Response(_) => (),
+        }
+    }
+}
+
+fn send(connection: &Connection, message: Message) {
+    connection
+        .sender
+        .send(message)
+        .expect("Failed to send a message to the client.");
+}
+
+fn handle_request(documents: &Documents, request: Request) -> Response {
+    let request = match request.extract::<CompletionParams>(Completion::METHOD) {
+        Ok((id, params)) => {
+            let position = params.text_document_position;
+            let result = documents
+                .get(&position.text_document.uri)
+                .map(|text| CompletionResponse::Array(completion::complete(text, position.position)));
+
+            return Response::new_ok(id, result);
+        }
+        Err(request) => request,
+    };
+
+    let request = match request.extract::<HoverParams>(HoverRequest::METHOD) {
+        Ok((id, params)) => {
+            let position = params.text_document_position_params;
+            let result = documents
+                .get(&position.text_document.uri)
+                .and_then(|text| hover::hover(text, position.position));
+
+            return Response::new_ok(id, result);
+        }
+        Err(request) => request,
+    };
+
+    let request = match request.extract::<GotoDefinitionParams>(GotoDefinition::METHOD) {
+        Ok((id, params)) => {
+            let position = params.text_document_position_params;
+            let uri = &position.text_document.uri;
+            let result = documents
+                .get(uri)
+                .and_then(|text| definition::goto_definition(uri, text, position.position))
+                .map(GotoDefinitionResponse::Scalar);
+
+            return Response::new_ok(id, result);
+        }
+        Err(request) => request,
+    };
+
+    let request = match request.extract::<DocumentFormattingParams>(Formatting::METHOD) {
+        Ok((id, params)) => {
+            let tab_size = params.options.tab_size as usize;
+            let result = documents
+                .get(&params.text_document.uri)
+                .map(|text| formatting::format(text, tab_size));
+
+            return Response::new_ok(id, result);
+        }
+        Err(request) => request,
+    };
+
+    let request = match request.extract::<RenameParams>(Rename::METHOD) {
+        Ok((id, params)) => {
+            let position = params.text_document_position;
+            let uri = &position.text_document.uri;
+            let result = match documents.get(uri) {
+                Some(text) => rename::rename(uri, text, position.position, &params.new_name),
+                None => Ok(None),
+            };
+
+            return match result {
+                Ok(result) => Response::new_ok(id, result),
+                Err(message) => Response::new_err(id, ErrorCode::InvalidParams as i32, message),
+            };
+        }
+        Err(request) => request,
+    };
+
+    let request = match request.extract::<CodeActionParams>(CodeActionRequest::METHOD) {
+        Ok((id, params)) => {
+            let result = documents
+                .get(&params.text_document.uri)
+                .cloned()
+                .map(|text| code_actions::code_actions(&text, params));
+
+            return Response::new_ok(id, result);
+        }
+        Err(request) => request,
+    };
+
+    method_not_found(request.id, &request.method)
+}
+
+fn method_not_found(id: RequestId, method: &str) -> Response {
+    Response::new_err(
+        id,
+        ErrorCode::MethodNotFound as i32,
+        format!("Unsupported request: {}", method),
+    )
+}
+
+/// Updates the open documents. Returns the diagnostics to publish, if the notification changed a document.
+fn handle_notification(documents: &mut Documents, notification: Notification) -> Option<PublishDiagnosticsParams> {
+    let notification = match notification.extract::<DidOpenTextDocumentParams>(DidOpenTextDocument::METHOD) {
+        Ok(params) => {
+            let document = params.text_document;
+            let diagnostics = diagnostics::compute(&document.text);
+            documents.insert(document.uri.clone(), document.text);
+
+            return Some(PublishDiagnosticsParams::new(document.uri, diagnostics, None));
+        }
+        Err(notification) => notification,
+    };
+
+    let notification = match notification.extract::<DidChangeTextDocumentParams>(DidChangeTextDocument::METHOD) {
+        Ok(params) => {
+            let uri = params.text_document.uri;
+            let text = params.content_changes.into_iter().last()?.text;
+            let diagnostics = diagnostics::compute(&text);
+            documents.insert(uri.clone(), text);
+
+            return Some(PublishDiagnosticsParams::new(uri, diagnostics, None));
+        }
+        Err(notification) => notification,
+    };
+
+    match notification.extract::<DidCloseTextDocumentParams>(DidCloseTextDocument::METHOD) {
+        Ok(params) => {
+            let uri = params.text_document.uri;
+            documents.remove(&uri);
+
+            // Clears the diagnostics of the closed document.
+            Some(PublishDiagnosticsParams::new(uri, Vec::new(), None))
+        }
+        Err(_) => None,
+    }
+}
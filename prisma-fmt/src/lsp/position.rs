@@ -0,0 +1,100 @@
+use datamodel::ast::Span;
+use lsp_types::{Position, Range};
+
+/// Converts a byte offset into the schema to an LSP position.
+/// LSP counts the characters of a line in UTF-16 code units.
+pub fn offset_to_position(text: &str, offset: usize) -> Position {
+    let mut line = 0;
+    let mut character = 0;
+
+    for (idx, c) in text.char_indices() {
+        if idx >= offset {
+            break;
+        }
+
+        if c == '\n' {
+            line += 1;
+            character = 0;
+        } else {
+            character += c.len_utf16() as u64;
+        }
+    }
+
+    Position::new(line, character)
+}
+
+/// Converts an LSP position to a byte offset into the schema.
+/// Positions past the end of a line are clamped to the end of that line.
+pub fn position_to_offset(text: &str, position: Position) -> usize {
+    let mut line = 0;
+    let mut character = 0;
+
+    for (idx, c) in text.char_indices() {
+        if line == position.line && character >= position.character {
+            return idx;
+        }
+
+        if c == '\n' {
+            if line == position.line {
+                return idx;
+            }
+
+            line += 1;
+            character = 0;
+        } else {
+            character += c.len_utf16() as u64;
+        }
+    }
+
+    text.len()
+}
+
+/// Converts a span of the schema to an LSP range.
+pub fn span_to_range(text: &str, span: Span) -> Range {
+    Range::new(offset_to_position(text, span.start), offset_to_position(text, span.end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = "model User {\n  id Int\n}";
+
+    #[test]
+    fn offsets_must_convert_to_positions() {
+        assert_eq!(offset_to_position(SCHEMA, 0), Position::new(0, 0));
+        assert_eq!(offset_to_position(SCHEMA, 15), Position::new(1, 2));
+        assert_eq!(offset_to_position(SCHEMA, SCHEMA.len()), Position::new(2, 1));
+    }
+
+    #[test]
+    fn positions_must_convert_to_offsets() {
+        assert_eq!(position_to_offset(SCHEMA, Position::new(0, 0)), 0);
+        assert_eq!(position_to_offset(SCHEMA, Position::new(1, 2)), 15);
+        assert_eq!(position_to_offset(SCHEMA, Position::new(2, 1)), SCHEMA.len());
+    }
+
+    #[test]
+    fn positions_past_the_end_of_a_line_must_be_clamped() {
+        assert_eq!(position_to_offset(SCHEMA, Position::new(1, 100)), 21);
+        assert_eq!(position_to_offset(SCHEMA, Position::new(5, 0)), SCHEMA.len());
+    }
+
+    #[test]
+    fn characters_must_be_counted_in_utf16_code_units() {
+        // `é` takes two bytes and one UTF-16 code unit, `😀` four bytes and two UTF-16 code units.
+        let text = "// é😀x\nmodel";
+
+        assert_eq!(offset_to_position(text, 9), Position::new(0, 6));
+        assert_eq!(position_to_offset(text, Position::new(0, 6)), 9);
+        assert_eq!(offset_to_position(text, 11), Position::new(1, 0));
+        assert_eq!(position_to_offset(text, Position::new(1, 0)), 11);
+    }
+
+    #[test]
+    fn spans_must_convert_to_ranges() {
+        let range = span_to_range(SCHEMA, Span::new(18, 21));
+
+        assert_eq!(range, Range::new(Position::new(1, 5), Position::new(1, 8)));
+    }
+}
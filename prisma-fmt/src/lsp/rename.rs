@@ -0,0 +1,76 @@
+use super::position::{position_to_offset, span_to_range};
+use datamodel::ast::{
+    self,
+    rename::{self, RenameTarget},
+};
+use lsp_types::{Position, TextEdit, Url, WorkspaceEdit};
+use std::collections::HashMap;
+
+/// Renames the model, field, enum or enum value under the cursor.
+/// The database names are kept, so that renaming in the editor never drops a table or column.
+pub fn rename(uri: &Url, text: &str, position: Position, new_name: &str) -> Result<Option<WorkspaceEdit>, String> {
+    let offset = position_to_offset(text, position);
+    let schema = datamodel::parse_schema_ast(text).map_err(|errors| format!("{}", errors))?;
+
+    let target = match find_target(&schema, offset) {
+        Some(target) => target,
+        None => return Ok(None),
+    };
+
+    let edits = rename::rename(&schema, &target, new_name, true).map_err(|error| format!("{}", error))?;
+
+    let edits = edits
+        .into_iter()
+        .map(|edit| TextEdit::new(span_to_range(text, edit.span), edit.new_text))
+        .collect();
+
+    let mut changes = HashMap::new();
+    changes.insert(uri.clone(), edits);
+
+    Ok(Some(WorkspaceEdit::new(changes)))
+}
+
+fn find_target(schema: &ast::SchemaAst, offset: usize) -> Option<RenameTarget> {
+    for model in schema.models() {
+        if model.name.span.contains(offset) {
+            return Some(RenameTarget::Model(model.name.name.clone()));
+        }
+
+        for field in &model.fields {
+            if field.name.span.contains(offset) {
+                return Some(RenameTarget::Field(model.name.name.clone(), field.name.name.clone()));
+            }
+
+            if field.field_type.span.contains(offset) {
+                let type_name = &field.field_type.name;
+
+                if schema.find_model(type_name).is_some() {
+                    return Some(RenameTarget::Model(type_name.clone()));
+                }
+
+                if schema.find_enum(type_name).is_some() {
+                    return Some(RenameTarget::Enum(type_name.clone()));
+                }
+
+                return None;
+            }
+        }
+    }
+
+    for r#enum in schema.enums() {
+        if r#enum.name.span.contains(offset) {
+            return Some(RenameTarget::Enum(r#enum.name.name.clone()));
+        }
+
+        for value in &r#enum.values {
+            if value.name.span.contains(offset) {
+                return Some(RenameTarget::EnumValue(
+                    r#enum.name.name.clone(),
+                    value.name.name.clone(),
+                ));
+            }
+        }
+    }
+
+    None
+}
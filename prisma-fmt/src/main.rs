@@ -1,5 +1,10 @@
+mod code_actions;
+mod diagram;
+mod diff;
 mod format;
 mod lint;
+mod lsp;
+mod rename;
 
 use std::path::PathBuf;
 
@@ -11,12 +16,17 @@ pub struct LintOpts {
     /// If set, silences all `environment variable not found` errors
     #[structopt(long)]
     no_env_errors: bool,
+    /// Specifies the files or directories of a schema split across multiple
+    /// files. If none are given, the input is read from STDIN
+    #[structopt(short = "i", long)]
+    input: Vec<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
 pub struct FormatOpts {
     /// Specifies the input file to use. If none is given, the input is read
-    /// from STDIN
+    /// from STDIN. If a directory is given, all its schema files are
+    /// formatted in place
     #[structopt(short = "i", long)]
     input: Option<PathBuf>,
     /// Specifies the output file to use. If none is given, the output is
@@ -28,6 +38,78 @@ pub struct FormatOpts {
     tabwidth: usize,
 }
 
+#[derive(Debug, StructOpt, Clone)]
+pub struct RenameOpts {
+    /// Specifies the input file to use. If none is given, the input is read
+    /// from STDIN
+    #[structopt(short = "i", long)]
+    input: Option<PathBuf>,
+    /// Specifies the output file to use. If none is given, the output is
+    /// written to STDOUT
+    #[structopt(short = "o", long)]
+    output: Option<PathBuf>,
+    /// The model to rename, or the model of the field to rename
+    #[structopt(long)]
+    model: Option<String>,
+    /// The enum to rename, or the enum of the value to rename
+    #[structopt(long = "enum")]
+    enum_name: Option<String>,
+    /// The field of the model to rename
+    #[structopt(long)]
+    field: Option<String>,
+    /// The value of the enum to rename
+    #[structopt(long)]
+    value: Option<String>,
+    /// The new name
+    #[structopt(long)]
+    to: String,
+    /// Adds `@map` and `@@map` directives, so that the names in the database do not change
+    #[structopt(long)]
+    map: bool,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+pub struct CodeActionsOpts {
+    /// Specifies the input file to use. If none is given, the input is read
+    /// from STDIN
+    #[structopt(short = "i", long)]
+    input: Option<PathBuf>,
+    /// The start offset of the problem to fix, usually the start of an error reported by `lint`
+    #[structopt(long)]
+    start: usize,
+    /// The end offset of the problem to fix. Defaults to the start offset
+    #[structopt(long)]
+    end: Option<usize>,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+pub struct DiagramOpts {
+    /// Specifies the input file to use. If none is given, the input is read
+    /// from STDIN
+    #[structopt(short = "i", long)]
+    input: Option<PathBuf>,
+    /// Specifies the output file to use. If none is given, the output is
+    /// written to STDOUT
+    #[structopt(short = "o", long)]
+    output: Option<PathBuf>,
+    /// The diagram language, a Mermaid `erDiagram` or a Graphviz DOT graph
+    #[structopt(short = "f", long, default_value = "mermaid", possible_values = &["mermaid", "dot"])]
+    format: String,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+pub struct DiffOpts {
+    /// The files or directories of the previous version of the schema
+    #[structopt(long, required = true)]
+    previous: Vec<PathBuf>,
+    /// The files or directories of the next version of the schema
+    #[structopt(long, required = true)]
+    next: Vec<PathBuf>,
+    /// Prints the changes as JSON instead of a human readable report
+    #[structopt(long)]
+    json: bool,
+}
+
 #[derive(Debug, StructOpt, Clone)]
 #[structopt(version = env!("GIT_HASH"))]
 /// Prisma Datamodel v2 formatter
@@ -36,18 +118,44 @@ pub enum FmtOpts {
     Lint(LintOpts),
     /// Specifies format mode
     Format(FormatOpts),
+    /// Renames a model, field, enum or enum value and all references to it
+    Rename(RenameOpts),
+    /// Lists quick fixes for the problem at the given offsets, as JSON edits
+    CodeActions(CodeActionsOpts),
+    /// Renders an entity relationship diagram of the models and their relations
+    Diagram(DiagramOpts),
+    /// Lists the changes between two versions of a schema, and whether they break clients
+    Diff(DiffOpts),
+    /// Starts the language server, communicating over stdio
+    Lsp,
 }
 
 #[derive(serde::Serialize)]
 pub struct MiniError {
+    /// The file of a schema split across multiple files, `start` and `end` are relative to it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
     pub start: usize,
     pub end: usize,
     pub text: String,
+    pub severity: Severity,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
 }
 
 fn main() {
     match FmtOpts::from_args() {
         FmtOpts::Lint(opts) => lint::run(opts),
         FmtOpts::Format(opts) => format::run(opts),
+        FmtOpts::Rename(opts) => rename::run(opts),
+        FmtOpts::CodeActions(opts) => code_actions::run(opts),
+        FmtOpts::Diagram(opts) => diagram::run(opts),
+        FmtOpts::Diff(opts) => diff::run(opts),
+        FmtOpts::Lsp => lsp::run(),
     }
 }
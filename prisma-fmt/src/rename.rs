@@ -0,0 +1,62 @@
+use datamodel::ast::rename::{self, RenameTarget};
+use std::{
+    fs,
+    io::{self, Read, Write},
+    process,
+};
+
+use crate::RenameOpts;
+
+pub fn run(opts: RenameOpts) {
+    let target = match (opts.model, opts.enum_name, opts.field, opts.value) {
+        (Some(model), None, None, None) => RenameTarget::Model(model),
+        (Some(model), None, Some(field), None) => RenameTarget::Field(model, field),
+        (None, Some(r#enum), None, None) => RenameTarget::Enum(r#enum),
+        (None, Some(r#enum), None, Some(value)) => RenameTarget::EnumValue(r#enum, value),
+        _ => fail("Specify either `--model` with an optional `--field`, or `--enum` with an optional `--value`."),
+    };
+
+    let datamodel_string = match opts.input {
+        Some(file_name) => {
+            fs::read_to_string(&file_name).expect(&format!("Unable to open file {}", file_name.display()))
+        }
+        None => {
+            let mut buf = String::new();
+
+            io::stdin()
+                .read_to_string(&mut buf)
+                .expect("Unable to read from stdin.");
+
+            buf
+        }
+    };
+
+    let schema = match datamodel::parse_schema_ast(&datamodel_string) {
+        Ok(schema) => schema,
+        Err(errors) => fail(&format!("{}", errors)),
+    };
+
+    let edits = match rename::rename(&schema, &target, &opts.to, opts.map) {
+        Ok(edits) => edits,
+        Err(error) => fail(&format!("{}", error)),
+    };
+
+    let result = rename::apply_edits(&datamodel_string, &edits);
+
+    match opts.output {
+        Some(file_name) => {
+            fs::write(&file_name, result).expect(&format!("Unable to write file {}", file_name.display()));
+        }
+        None => {
+            io::stdout()
+                .lock()
+                .write_all(result.as_bytes())
+                .expect("Unable to write to stdout.");
+        }
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
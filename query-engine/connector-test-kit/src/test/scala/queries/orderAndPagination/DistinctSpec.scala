@@ -0,0 +1,139 @@
+package queries.orderAndPagination
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class DistinctSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Customer {
+      |  id      Int      @id
+      |  name    String
+      |  country String
+      |  orders  Order[]
+      |}
+      |
+      |model Order {
+      |  id          Int      @id
+      |  product     String
+      |  customer_id Int
+      |  customer    Customer @relation(fields: [customer_id], references: [id])
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createTestData()
+  }
+
+  private def createTestData(): Unit = {
+    createCustomer(1, "Alice", "DE", Seq((1, "Apple"), (2, "Apple"), (3, "Pear")))
+    createCustomer(2, "Bob", "CH", Seq((4, "Pear"), (5, "Pear")))
+    createCustomer(3, "Carol", "DE", Seq.empty)
+    createCustomer(4, "Dave", "US", Seq.empty)
+    createCustomer(5, "Eve", "CH", Seq.empty)
+  }
+
+  private def createCustomer(id: Int, name: String, country: String, orders: Seq[(Int, String)]): Unit = {
+    val nestedOrders = orders.map { case (orderId, product) => s"""{ id: $orderId, product: "$product" }""" }.mkString(", ")
+
+    server.query(
+      s"""mutation {
+         |  createOneCustomer(data: { id: $id, name: "$name", country: "$country", orders: { create: [$nestedOrders] } }) { id }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+  }
+
+  "A distinct query on a single field" should "return the first record for each distinct value" in {
+    val result = server.query(
+      """{
+        |  findManyCustomer(distinct: [country]) {
+        |    country
+        |  }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"findManyCustomer":[{"country":"DE"},{"country":"CH"},{"country":"US"}]}}""")
+  }
+
+  "A distinct query" should "honor the ordering" in {
+    val result = server.query(
+      """{
+        |  findManyCustomer(distinct: [country], orderBy: id_DESC) {
+        |    id
+        |    country
+        |  }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"findManyCustomer":[{"id":5,"country":"CH"},{"id":4,"country":"US"},{"id":3,"country":"DE"}]}}""")
+  }
+
+  "A distinct query with pagination" should "paginate over the distinct records" in {
+    val result = server.query(
+      """{
+        |  findManyCustomer(distinct: [country], skip: 1, take: 1) {
+        |    country
+        |  }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"findManyCustomer":[{"country":"CH"}]}}""")
+  }
+
+  "A distinct query with a negative take" should "return the last distinct records" in {
+    val result = server.query(
+      """{
+        |  findManyCustomer(distinct: [country], take: -2) {
+        |    country
+        |  }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"findManyCustomer":[{"country":"CH"},{"country":"US"}]}}""")
+  }
+
+  "A distinct query on a field that isn't selected" should "still be distinct on that field" in {
+    val result = server.query(
+      """{
+        |  findManyCustomer(distinct: [country]) {
+        |    id
+        |  }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"findManyCustomer":[{"id":1},{"id":2},{"id":4}]}}""")
+  }
+
+  "A nested distinct query" should "be distinct per parent record" in {
+    val result = server.query(
+      """{
+        |  findManyCustomer(where: { id_in: [1, 2] }) {
+        |    id
+        |    orders(distinct: [product], take: 1) {
+        |      product
+        |    }
+        |  }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be(
+      """{"data":{"findManyCustomer":[{"id":1,"orders":[{"product":"Apple"}]},{"id":2,"orders":[{"product":"Pear"}]}]}}""")
+  }
+}
@@ -0,0 +1,36 @@
+/// A feature of the underlying database that the query schema can rely on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConnectorCapability {
+    ScalarLists(ScalarListsCapability),
+    IdCapability(IdCapability),
+    EmbeddedTypes,
+    JoinRelationsFilter,
+    ImportExport,
+    TransactionalExecution,
+    SupportsExistingDatabases,
+    Migrations,
+    RawAccess,
+    Introspection,
+    JoinRelationLinks,
+    MongoJoinRelationLinks,
+    RelationLinkList,
+    RelationLinkTable,
+
+    /// Json values can be stored and filtered on.
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScalarListsCapability {
+    /// Any kind of scalar list support.
+    ScalarLists,
+    EmbeddedScalarLists,
+    NonEmbeddedScalarList,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IdCapability {
+    IntId,
+    UuidId,
+    IdSequence,
+}
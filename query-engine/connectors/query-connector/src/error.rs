@@ -21,6 +21,16 @@ impl ConnectorError {
                 })
                 .unwrap(),
             ),
+            ErrorKind::CheckConstraintViolation {
+                constraint,
+                database_error,
+            } => Some(
+                KnownError::new(user_facing_errors::query_engine::CheckConstraintViolation {
+                    constraint: constraint.to_owned(),
+                    database_error: database_error.to_owned(),
+                })
+                .unwrap(),
+            ),
             _ => None,
         };
 
@@ -42,6 +52,9 @@ pub enum ErrorKind {
     #[fail(display = "Foreign key constraint failed")]
     ForeignKeyConstraintViolation { constraint: DatabaseConstraint },
 
+    #[fail(display = "Check constraint failed: {}", constraint)]
+    CheckConstraintViolation { constraint: String, database_error: String },
+
     #[fail(display = "Record does not exist.")]
     RecordDoesNotExist,
 
@@ -116,3 +129,25 @@ impl From<DomainError> for ConnectorError {
         ConnectorError::from_kind(ErrorKind::DomainError(e))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_constraint_violations_are_user_facing() {
+        let error = ConnectorError::from_kind(ErrorKind::CheckConstraintViolation {
+            constraint: "positive_price".to_owned(),
+            database_error: "CHECK constraint failed: positive_price".to_owned(),
+        });
+
+        let user_facing_error = error.user_facing_error.expect("a user facing error");
+
+        assert_eq!(user_facing_error.error_code, "P2023");
+        assert_eq!(
+            user_facing_error.message,
+            "The check constraint `positive_price` failed on the database: `CHECK constraint failed: positive_price`"
+        );
+        assert_eq!(user_facing_error.meta["constraint"], "positive_price");
+    }
+}
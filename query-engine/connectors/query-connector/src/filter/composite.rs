@@ -0,0 +1,104 @@
+use super::{Filter, ScalarCondition};
+use prisma_models::{InternalCompositeField, PrismaValue, ScalarFieldRef};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Filtering on the value of a composite field. Composite values are stored as
+/// Json, which connectors can't filter on, so the filter is evaluated in memory:
+///
+/// ```graphql
+/// findManyUser(where: { address: { city: "Berlin" } })
+/// ```
+pub struct CompositeFilter {
+    pub field: ScalarFieldRef,
+    pub condition: CompositeCondition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositeCondition {
+    /// The composite value is null.
+    IsNull,
+
+    /// The composite value is set and all conditions on its fields hold.
+    Fields(Vec<CompositeFieldFilter>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompositeFieldFilter {
+    pub field: InternalCompositeField,
+    pub condition: CompositeFieldCondition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositeFieldCondition {
+    Scalar(ScalarCondition),
+    Composite(CompositeCondition),
+}
+
+impl CompositeFilter {
+    /// Checks the stored value of the composite field against the filter.
+    pub fn matches(&self, value: &PrismaValue) -> bool {
+        let json = match value {
+            PrismaValue::Json(s) | PrismaValue::String(s) => serde_json::from_str(s).unwrap_or(serde_json::Value::Null),
+            _ => serde_json::Value::Null,
+        };
+
+        self.condition.matches(&json)
+    }
+}
+
+impl CompositeCondition {
+    fn matches(&self, json: &serde_json::Value) -> bool {
+        match (self, json) {
+            (CompositeCondition::IsNull, json) => json.is_null(),
+            (CompositeCondition::Fields(filters), serde_json::Value::Object(object)) => filters.iter().all(|filter| {
+                let value = object.get(&filter.field.name).unwrap_or(&serde_json::Value::Null);
+                filter.matches(value)
+            }),
+            (CompositeCondition::Fields(_), _) => false,
+        }
+    }
+}
+
+impl CompositeFieldFilter {
+    fn matches(&self, json: &serde_json::Value) -> bool {
+        match self.condition {
+            CompositeFieldCondition::Composite(ref condition) => condition.matches(json),
+            CompositeFieldCondition::Scalar(ref condition) => {
+                let value = self.field.scalar_from_json(json);
+
+                match condition {
+                    ScalarCondition::Equals(other) => &value == other,
+                    ScalarCondition::NotEquals(other) => &value != other,
+                    ScalarCondition::Contains(other) => string_matches(&value, other, |s, o| s.contains(o)),
+                    ScalarCondition::NotContains(other) => !string_matches(&value, other, |s, o| s.contains(o)),
+                    ScalarCondition::StartsWith(other) => string_matches(&value, other, |s, o| s.starts_with(o)),
+                    ScalarCondition::NotStartsWith(other) => !string_matches(&value, other, |s, o| s.starts_with(o)),
+                    ScalarCondition::EndsWith(other) => string_matches(&value, other, |s, o| s.ends_with(o)),
+                    ScalarCondition::NotEndsWith(other) => !string_matches(&value, other, |s, o| s.ends_with(o)),
+                    ScalarCondition::LessThan(other) => !value.is_null() && &value < other,
+                    ScalarCondition::LessThanOrEquals(other) => !value.is_null() && &value <= other,
+                    ScalarCondition::GreaterThan(other) => !value.is_null() && &value > other,
+                    ScalarCondition::GreaterThanOrEquals(other) => !value.is_null() && &value >= other,
+                    ScalarCondition::In(values) => values.contains(&value),
+                    ScalarCondition::NotIn(values) => !values.contains(&value),
+                }
+            }
+        }
+    }
+}
+
+fn string_matches<F>(value: &PrismaValue, other: &PrismaValue, f: F) -> bool
+where
+    F: Fn(&str, &str) -> bool,
+{
+    match (value, other) {
+        (PrismaValue::String(s), PrismaValue::String(o)) => f(s, o),
+        _ => false,
+    }
+}
+
+impl From<CompositeFilter> for Filter {
+    fn from(cf: CompositeFilter) -> Self {
+        Filter::Composite(cf)
+    }
+}
@@ -4,6 +4,7 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod composite;
 mod id_filter;
 mod list;
 mod relation;
@@ -12,6 +13,7 @@ mod scalar;
 use prisma_models::dml;
 use prisma_models::prelude::*;
 
+pub use composite::*;
 pub use id_filter::*;
 pub use list::*;
 pub use relation::*;
@@ -26,6 +28,7 @@ pub enum Filter {
     ScalarList(ScalarListFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
+    Composite(CompositeFilter),
     NodeSubscription,
     BoolFilter(bool),
     Empty,
@@ -167,9 +170,11 @@ pub fn test_data_model() -> InternalDataModelRef {
             is_auto_generated_int_id: false,
             behaviour: None,
             internal_enum: None,
+            composite_type: None,
             arity: dml::FieldArity::Optional,
             default_value: None,
             db_name: None,
+            documentation: None,
         }),
         FieldTemplate::Scalar(ScalarFieldTemplate {
             name: "name".to_owned(),
@@ -181,9 +186,11 @@ pub fn test_data_model() -> InternalDataModelRef {
             is_auto_generated_int_id: false,
             behaviour: None,
             internal_enum: None,
+            composite_type: None,
             arity: dml::FieldArity::Optional,
             default_value: None,
             db_name: None,
+            documentation: None,
         }),
         FieldTemplate::Relation(RelationFieldTemplate {
             name: "sites".to_owned(),
@@ -195,6 +202,7 @@ pub fn test_data_model() -> InternalDataModelRef {
             relation_name: "bar".to_owned(),
             relation_side: RelationSide::A,
             relation_info: dml::RelationInfo::new(""),
+            documentation: None,
         }),
     ];
 
@@ -208,27 +216,35 @@ pub fn test_data_model() -> InternalDataModelRef {
         is_auto_generated_int_id: false,
         behaviour: None,
         internal_enum: None,
+        composite_type: None,
         arity: dml::FieldArity::Optional,
         default_value: None,
         db_name: None,
+        documentation: None,
     })];
 
     let model_templates = vec![
         ModelTemplate {
             name: "User".to_owned(),
             is_embedded: false,
+            is_view: false,
             fields: user_field_templates,
             manifestation: None,
             id_field_names: vec![],
             indexes: vec![],
+            documentation: None,
+            schema: None,
         },
         ModelTemplate {
             name: "Site".to_owned(),
             is_embedded: false,
+            is_view: false,
             fields: site_field_templates,
             manifestation: None,
             id_field_names: vec![],
             indexes: vec![],
+            documentation: None,
+            schema: None,
         },
     ];
 
@@ -2,21 +2,38 @@ mod dispatch;
 
 pub use dispatch::*;
 
-use crate::{Filter, QueryArguments, WriteArgs};
+use crate::{ConnectorCapability, Filter, QueryArguments, WriteArgs};
 use async_trait::async_trait;
 use prisma_models::*;
 use prisma_value::PrismaValue;
+use serde::Serialize;
 
 #[async_trait]
 pub trait Connector {
     async fn get_connection(&self) -> crate::Result<Box<dyn Connection>>;
+
+    /// Cancels the statement currently running in the given database session.
+    /// The cancellation is issued from a separate connection, as the session itself is busy.
+    async fn cancel_session(&self, session: SessionId) -> crate::Result<()>;
+
+    /// The capabilities of the database the connector is connected to.
+    /// Capabilities can depend on the database version, which is queried from the database.
+    async fn capabilities(&self) -> crate::Result<Vec<ConnectorCapability>>;
 }
 
 #[async_trait]
-pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
+pub trait Connection: ReadOperations + WriteOperations + ExplainOperations + Send + Sync {
     async fn start_transaction<'a>(&'a self) -> crate::Result<Box<dyn Transaction + 'a>>;
+
+    /// The database session of the connection, used to cancel running statements.
+    /// Returns `None` if the database does not support cancelling statements.
+    async fn session_id(&self) -> crate::Result<Option<SessionId>>;
 }
 
+/// Identifies a database session (e.g. the Postgres backend pid or the MySQL connection id).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionId(pub i64);
+
 #[async_trait]
 pub trait Transaction: ReadOperations + WriteOperations + Send + Sync {
     async fn commit(&self) -> crate::Result<()>;
@@ -125,6 +142,45 @@ pub trait ReadOperations {
     async fn count_by_model(&self, model: &ModelRef, query_arguments: QueryArguments) -> crate::Result<usize>;
 }
 
+/// Operations to inspect the statements a connector issues for reads, without reading any records.
+#[async_trait]
+pub trait ExplainOperations {
+    /// Explains the statements `get_many_records` would issue for the given arguments.
+    ///
+    /// - If `database_plan` is set, the execution plan of the database itself is
+    ///   included for every statement (e.g. the output of `EXPLAIN` on SQL databases).
+    async fn explain_many_records(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        database_plan: bool,
+    ) -> crate::Result<Vec<StatementExplanation>>;
+
+    /// Explains the statement `count_by_model` would issue for the given arguments.
+    async fn explain_count_by_model(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        database_plan: bool,
+    ) -> crate::Result<StatementExplanation>;
+}
+
+/// A statement as the connector would send it to the database.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementExplanation {
+    /// The statement in the query language of the database, e.g. SQL.
+    pub statement: String,
+
+    /// Parameters bound to the placeholders of the statement, in order.
+    pub parameters: Vec<serde_json::Value>,
+
+    /// Execution plan of the database for the statement, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_plan: Option<serde_json::Value>,
+}
+
 #[async_trait]
 pub trait WriteOperations {
     /// Insert a single record to the database.
@@ -1,5 +1,6 @@
 #![deny(warnings)]
 
+pub mod capabilities;
 pub mod error;
 pub mod filter;
 
@@ -8,6 +9,7 @@ mod interface;
 mod query_arguments;
 mod write_args;
 
+pub use capabilities::*;
 pub use compare::*;
 pub use filter::*;
 pub use interface::*;
@@ -14,6 +14,10 @@ pub struct QueryArguments {
     pub skip: Option<i64>,
     pub filter: Option<Filter>,
     pub order_by: Option<OrderBy>,
+
+    /// Fields the result should be made distinct on.
+    /// Distinct is processed in memory by the core, which also moves `skip` and `take` there.
+    pub distinct: Option<ModelProjection>,
 }
 
 impl QueryArguments {
@@ -23,7 +27,17 @@ impl QueryArguments {
     }
 
     fn needs_implicit_ordering(&self) -> bool {
-        self.skip.is_some() || self.cursor.is_some() || self.take.is_some() || self.order_by.is_some()
+        self.skip.is_some()
+            || self.cursor.is_some()
+            || self.take.is_some()
+            || self.order_by.is_some()
+            || self.distinct.is_some()
+    }
+
+    /// If distinct is requested, records have to be deduplicated before pagination can be applied,
+    /// hence connectors must not apply `skip` and `take` themselves.
+    pub fn requires_inmemory_pagination(&self) -> bool {
+        self.distinct.is_some()
     }
 
     pub fn ordering_directions(&self) -> OrderDirections {
@@ -49,6 +63,7 @@ impl QueryArguments {
                 let take = self.take;
                 let skip = self.skip;
                 let order_by = self.order_by;
+                let distinct = self.distinct;
 
                 filter
                     .batched()
@@ -59,6 +74,7 @@ impl QueryArguments {
                         skip: skip.clone(),
                         filter: Some(filter),
                         order_by: order_by.clone(),
+                        distinct: distinct.clone(),
                     })
                     .collect()
             }
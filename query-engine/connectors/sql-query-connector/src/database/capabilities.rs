@@ -0,0 +1,142 @@
+use crate::QueryExt;
+use connector_interface::{ConnectorCapability, IdCapability, ScalarListsCapability};
+use quaint::prelude::ConnectionInfo;
+
+/// MySQL added the `JSON` type in 5.7.8.
+const MYSQL_JSON_VERSION: (u32, u32, u32) = (5, 7, 8);
+
+/// MariaDB aliases `JSON` to `LONGTEXT` with a validity check starting with 10.2.7.
+const MARIADB_JSON_VERSION: (u32, u32, u32) = (10, 2, 7);
+
+/// Postgres added the `JSONB` type in 9.4.
+const POSTGRES_JSON_VERSION: (u32, u32, u32) = (9, 4, 0);
+
+/// Queries the server version and returns the capabilities of the database.
+pub(crate) async fn detect(
+    conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
+) -> crate::Result<Vec<ConnectorCapability>> {
+    let query = match connection_info {
+        ConnectionInfo::Postgres(..) => "SELECT current_setting('server_version') AS version",
+        ConnectionInfo::Mysql(..) => "SELECT @@version AS version",
+        ConnectionInfo::Sqlite { .. } => "SELECT sqlite_version() AS version",
+    };
+
+    let result_set = conn.query_raw(query, &[]).await?;
+
+    let version = result_set.first().and_then(|row| {
+        row.get("version")
+            .and_then(|version| version.as_str().map(ToOwned::to_owned))
+    });
+
+    let capabilities = match connection_info {
+        ConnectionInfo::Postgres(..) => postgres_capabilities(version.as_deref()),
+        ConnectionInfo::Mysql(..) => mysql_capabilities(version.as_deref()),
+        ConnectionInfo::Sqlite { .. } => sqlite_capabilities(version.as_deref()),
+    };
+
+    Ok(capabilities)
+}
+
+/// Capabilities of a Postgres server with the given version.
+/// Without a version, the latest Postgres version is assumed.
+pub fn postgres_capabilities(version: Option<&str>) -> Vec<ConnectorCapability> {
+    let mut capabilities = common_capabilities();
+    capabilities.push(ConnectorCapability::ScalarLists(
+        ScalarListsCapability::EmbeddedScalarLists,
+    ));
+
+    if at_least(version, POSTGRES_JSON_VERSION) {
+        capabilities.push(ConnectorCapability::Json);
+    }
+
+    capabilities
+}
+
+/// Capabilities of a MySQL or MariaDB server with the given version.
+/// Without a version, the latest MySQL version is assumed.
+pub fn mysql_capabilities(version: Option<&str>) -> Vec<ConnectorCapability> {
+    let mut capabilities = common_capabilities();
+
+    let json_version = match version {
+        Some(version) if version.contains("MariaDB") => MARIADB_JSON_VERSION,
+        _ => MYSQL_JSON_VERSION,
+    };
+
+    if at_least(version, json_version) {
+        capabilities.push(ConnectorCapability::Json);
+    }
+
+    capabilities
+}
+
+/// Capabilities of SQLite. SQLite has no scalar lists and stores Json values as text, which
+/// works with every version, so the capabilities don't depend on the version.
+pub fn sqlite_capabilities(_version: Option<&str>) -> Vec<ConnectorCapability> {
+    let mut capabilities = common_capabilities();
+    capabilities.push(ConnectorCapability::Json);
+
+    capabilities
+}
+
+fn common_capabilities() -> Vec<ConnectorCapability> {
+    vec![
+        ConnectorCapability::IdCapability(IdCapability::IntId),
+        ConnectorCapability::IdCapability(IdCapability::UuidId),
+        ConnectorCapability::IdCapability(IdCapability::IdSequence),
+        ConnectorCapability::TransactionalExecution,
+        ConnectorCapability::SupportsExistingDatabases,
+        ConnectorCapability::Migrations,
+        ConnectorCapability::RawAccess,
+        ConnectorCapability::Introspection,
+        ConnectorCapability::JoinRelationLinks,
+        ConnectorCapability::RelationLinkTable,
+    ]
+}
+
+/// Unknown or unparseable versions are treated as the latest version.
+fn at_least(version: Option<&str>, required: (u32, u32, u32)) -> bool {
+    version.and_then(parse_version).map(|v| v >= required).unwrap_or(true)
+}
+
+/// Parses the leading `major.minor.patch` part of version strings like
+/// `12.3 (Debian 12.3-1.pgdg100+1)`, `5.7.29-log` or `10.4.12-MariaDB`.
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let numeric = version.trim().split(|c: char| !c.is_ascii_digit() && c != '.').next()?;
+
+    let mut parts = numeric.split('.').map(|part| part.parse::<u32>().ok());
+
+    let major = parts.next()??;
+    let minor = parts.next().flatten().unwrap_or(0);
+    let patch = parts.next().flatten().unwrap_or(0);
+
+    Some((major, minor, patch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_are_parsed_from_server_version_strings() {
+        assert_eq!(Some((12, 3, 0)), parse_version("12.3 (Debian 12.3-1.pgdg100+1)"));
+        assert_eq!(Some((5, 7, 29)), parse_version("5.7.29-log"));
+        assert_eq!(
+            Some((10, 4, 12)),
+            parse_version("10.4.12-MariaDB-1:10.4.12+maria~bionic")
+        );
+        assert_eq!(Some((3, 31, 1)), parse_version("3.31.1"));
+        assert_eq!(None, parse_version("unknown"));
+    }
+
+    #[test]
+    fn json_depends_on_the_server_version() {
+        assert!(mysql_capabilities(Some("8.0.19")).contains(&ConnectorCapability::Json));
+        assert!(!mysql_capabilities(Some("5.6.47")).contains(&ConnectorCapability::Json));
+        assert!(!mysql_capabilities(Some("10.1.44-MariaDB")).contains(&ConnectorCapability::Json));
+        assert!(mysql_capabilities(Some("10.4.12-MariaDB")).contains(&ConnectorCapability::Json));
+        assert!(!postgres_capabilities(Some("9.3.25")).contains(&ConnectorCapability::Json));
+        assert!(postgres_capabilities(None).contains(&ConnectorCapability::Json));
+        assert!(sqlite_capabilities(Some("3.31.1")).contains(&ConnectorCapability::Json));
+    }
+}
@@ -2,8 +2,8 @@ use super::transaction::SqlConnectorTransaction;
 use crate::{database::operations::*, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, Connection, QueryArguments, ReadOperations, RecordFilter, Transaction,
-    WriteArgs, WriteOperations,
+    self as connector, filter::Filter, Connection, ExplainOperations, QueryArguments, ReadOperations, RecordFilter,
+    SessionId, StatementExplanation, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -48,6 +48,13 @@ where
         })
         .await
     }
+
+    async fn session_id(&self) -> connector::Result<Option<SessionId>> {
+        let connection_info = &self.connection_info;
+
+        self.catch(async move { cancel::session_id(&self.inner, connection_info).await })
+            .await
+    }
 }
 
 #[async_trait]
@@ -90,6 +97,49 @@ where
     }
 }
 
+#[async_trait]
+impl<C> ExplainOperations for SqlConnection<C>
+where
+    C: QueryExt + Send + Sync + 'static,
+{
+    async fn explain_many_records(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        database_plan: bool,
+    ) -> connector::Result<Vec<StatementExplanation>> {
+        let connection_info = &self.connection_info;
+
+        self.catch(async move {
+            explain::explain_many_records(
+                &self.inner,
+                connection_info,
+                model,
+                query_arguments,
+                selected_fields,
+                database_plan,
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn explain_count_by_model(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        database_plan: bool,
+    ) -> connector::Result<StatementExplanation> {
+        let connection_info = &self.connection_info;
+
+        self.catch(async move {
+            explain::explain_count_by_model(&self.inner, connection_info, model, query_arguments, database_plan).await
+        })
+        .await
+    }
+}
+
 #[async_trait]
 impl<C> WriteOperations for SqlConnection<C>
 where
@@ -1,3 +1,5 @@
+pub mod capabilities;
+
 mod connection;
 mod mysql;
 mod postgresql;
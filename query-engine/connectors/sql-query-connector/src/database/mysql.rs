@@ -1,10 +1,10 @@
-use super::connection::SqlConnection;
+use super::{capabilities, connection::SqlConnection, operations::cancel};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
     error::{ConnectorError, ErrorKind},
-    Connection, Connector,
+    Connection, Connector, ConnectorCapability, SessionId,
 };
 use datamodel::Source;
 use quaint::{pooled::Quaint, prelude::ConnectionInfo};
@@ -13,6 +13,9 @@ use std::time::Duration;
 pub struct Mysql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+
+    /// Statements are cancelled from connections of their own, opened with this url.
+    url: String,
 }
 
 #[async_trait]
@@ -32,7 +35,11 @@ impl FromSource for Mysql {
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
 
-        Ok(Mysql { pool, connection_info })
+        Ok(Mysql {
+            pool,
+            connection_info,
+            url: source.url().value.clone(),
+        })
     }
 }
 
@@ -47,4 +54,19 @@ impl Connector for Mysql {
         })
         .await
     }
+
+    async fn cancel_session(&self, session: SessionId) -> connector::Result<()> {
+        super::catch(&self.connection_info, async move {
+            cancel::cancel_session_from_url(&self.url, &self.connection_info, session).await
+        })
+        .await
+    }
+
+    async fn capabilities(&self) -> connector::Result<Vec<ConnectorCapability>> {
+        super::catch(&self.connection_info, async move {
+            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
+            capabilities::detect(&conn, &self.connection_info).await
+        })
+        .await
+    }
 }
@@ -0,0 +1,67 @@
+use crate::{QueryExt, SqlError};
+use connector_interface::SessionId;
+use quaint::{ast::Value, prelude::ConnectionInfo, single::Quaint};
+use std::time::Duration;
+
+/// How long cancelling a statement may take, so that an unresponsive database can't hold up the
+/// timeout that triggered the cancellation.
+const CANCEL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Reads the id of the database session the connection is bound to.
+/// SQLite runs in-process and has no sessions that could be cancelled.
+pub async fn session_id(conn: &dyn QueryExt, connection_info: &ConnectionInfo) -> crate::Result<Option<SessionId>> {
+    let query = match connection_info {
+        ConnectionInfo::Postgres(..) => "SELECT pg_backend_pid() AS session_id",
+        ConnectionInfo::Mysql(..) => "SELECT CONNECTION_ID() AS session_id",
+        ConnectionInfo::Sqlite { .. } => return Ok(None),
+    };
+
+    let result_set = conn.query_raw(query, &[]).await?;
+
+    let session_id = result_set
+        .first()
+        .and_then(|row| row.get("session_id").and_then(|id| id.as_i64()))
+        .map(SessionId);
+
+    Ok(session_id)
+}
+
+/// Cancels the statement currently running in the given session. The session itself stays open,
+/// so that an open transaction can still be rolled back on it.
+pub async fn cancel_session(
+    conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
+    session: SessionId,
+) -> crate::Result<()> {
+    match connection_info {
+        ConnectionInfo::Postgres(..) => {
+            conn.query_raw("SELECT pg_cancel_backend($1)", &[Value::from(session.0)])
+                .await?;
+        }
+        // `KILL` doesn't accept placeholders.
+        ConnectionInfo::Mysql(..) => {
+            conn.execute_raw(&format!("KILL QUERY {}", session.0), &[]).await?;
+        }
+        ConnectionInfo::Sqlite { .. } => (),
+    };
+
+    Ok(())
+}
+
+/// Cancels the statement running in the given session from a connection of its own. Connections of
+/// the pool can't be used, as the pool may have none left besides the one running the statement.
+pub async fn cancel_session_from_url(
+    url: &str,
+    connection_info: &ConnectionInfo,
+    session: SessionId,
+) -> crate::Result<()> {
+    let cancel = async {
+        let conn = Quaint::new(url).await?;
+        cancel_session(&conn, connection_info, session).await
+    };
+
+    match tokio::time::timeout(CANCEL_TIMEOUT, cancel).await {
+        Ok(result) => result,
+        Err(_) => Err(SqlError::QueryError("Cancelling the statement timed out.".into())),
+    }
+}
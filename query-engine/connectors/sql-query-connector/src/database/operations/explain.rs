@@ -0,0 +1,83 @@
+use crate::{query_builder::read, QueryExt};
+use connector_interface::*;
+use prisma_models::*;
+use quaint::{
+    ast::*,
+    prelude::ConnectionInfo,
+    visitor::{self, Visitor},
+};
+
+pub async fn explain_many_records(
+    conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
+    model: &ModelRef,
+    mut query_arguments: QueryArguments,
+    selected_fields: &ModelProjection,
+    database_plan: bool,
+) -> crate::Result<Vec<StatementExplanation>> {
+    // Mirrors the batching done in `read::get_many_records`.
+    let queries: Vec<Select<'static>> = if query_arguments.can_batch() {
+        query_arguments.order_by.take();
+
+        query_arguments
+            .batched()
+            .into_iter()
+            .map(|args| read::get_records(model, selected_fields.as_columns(), args))
+            .collect()
+    } else {
+        vec![read::get_records(model, selected_fields.as_columns(), query_arguments)]
+    };
+
+    let mut explanations = Vec::with_capacity(queries.len());
+
+    for query in queries {
+        explanations.push(explain(conn, connection_info, query.into(), database_plan).await?);
+    }
+
+    Ok(explanations)
+}
+
+pub async fn explain_count_by_model(
+    conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    database_plan: bool,
+) -> crate::Result<StatementExplanation> {
+    let query = read::count_by_model(model, query_arguments);
+
+    explain(conn, connection_info, query.into(), database_plan).await
+}
+
+/// Renders the query for the database of the connection and, if requested,
+/// runs the `EXPLAIN` variant of the database that returns the plan in a structured format.
+async fn explain(
+    conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
+    query: Query<'static>,
+    database_plan: bool,
+) -> crate::Result<StatementExplanation> {
+    let (statement, parameters) = match connection_info {
+        ConnectionInfo::Postgres(..) => visitor::Postgres::build(query),
+        ConnectionInfo::Mysql(..) => visitor::Mysql::build(query),
+        ConnectionInfo::Sqlite { .. } => visitor::Sqlite::build(query),
+    };
+
+    let database_plan = if database_plan {
+        let explain_statement = match connection_info {
+            ConnectionInfo::Postgres(..) => format!("EXPLAIN (FORMAT JSON) {}", statement),
+            ConnectionInfo::Mysql(..) => format!("EXPLAIN FORMAT=JSON {}", statement),
+            ConnectionInfo::Sqlite { .. } => format!("EXPLAIN QUERY PLAN {}", statement),
+        };
+
+        Some(conn.json(&explain_statement, &parameters).await?)
+    } else {
+        None
+    };
+
+    Ok(StatementExplanation {
+        statement,
+        parameters: parameters.into_iter().map(serde_json::Value::from).collect(),
+        database_plan,
+    })
+}
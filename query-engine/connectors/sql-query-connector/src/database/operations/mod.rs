@@ -1,2 +1,4 @@
+pub mod cancel;
+pub mod explain;
 pub mod read;
 pub mod write;
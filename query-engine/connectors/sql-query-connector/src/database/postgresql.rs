@@ -1,9 +1,9 @@
-use super::connection::SqlConnection;
+use super::{capabilities, connection::SqlConnection, operations::cancel};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     error::{ConnectorError, ErrorKind},
-    Connection, Connector,
+    Connection, Connector, ConnectorCapability, SessionId,
 };
 use datamodel::Source;
 use quaint::{pooled::Quaint, prelude::ConnectionInfo};
@@ -12,6 +12,9 @@ use std::time::Duration;
 pub struct PostgreSql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+
+    /// Statements are cancelled from connections of their own, opened with this url.
+    url: String,
 }
 
 #[async_trait]
@@ -30,7 +33,11 @@ impl FromSource for PostgreSql {
 
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
-        Ok(PostgreSql { pool, connection_info })
+        Ok(PostgreSql {
+            pool,
+            connection_info,
+            url: source.url().value.clone(),
+        })
     }
 }
 
@@ -44,4 +51,19 @@ impl Connector for PostgreSql {
         })
         .await
     }
+
+    async fn cancel_session(&self, session: SessionId) -> connector_interface::Result<()> {
+        super::catch(&self.connection_info, async move {
+            cancel::cancel_session_from_url(&self.url, &self.connection_info, session).await
+        })
+        .await
+    }
+
+    async fn capabilities(&self) -> connector_interface::Result<Vec<ConnectorCapability>> {
+        super::catch(&self.connection_info, async move {
+            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
+            capabilities::detect(&conn, &self.connection_info).await
+        })
+        .await
+    }
 }
@@ -1,10 +1,10 @@
-use super::connection::SqlConnection;
+use super::{capabilities, connection::SqlConnection};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
     error::{ConnectorError, ErrorKind},
-    Connection, Connector,
+    Connection, Connector, ConnectorCapability, SessionId,
 };
 use datamodel::Source;
 use quaint::{connector::SqliteParams, error::ErrorKind as QuaintKind, pooled::Quaint, prelude::ConnectionInfo};
@@ -92,4 +92,17 @@ impl Connector for Sqlite {
         })
         .await
     }
+
+    /// SQLite connections never report a session, so there is nothing to cancel.
+    async fn cancel_session(&self, _session: SessionId) -> connector::Result<()> {
+        Ok(())
+    }
+
+    async fn capabilities(&self) -> connector::Result<Vec<ConnectorCapability>> {
+        super::catch(&self.connection_info(), async move {
+            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
+            capabilities::detect(&conn, self.connection_info()).await
+        })
+        .await
+    }
 }
@@ -49,6 +49,9 @@ pub enum SqlError {
     #[fail(display = "Foreign key constraint failed")]
     ForeignKeyConstraintViolation { constraint: DatabaseConstraint },
 
+    #[fail(display = "Check constraint failed: {}", constraint)]
+    CheckConstraintViolation { constraint: String, database_error: String },
+
     #[fail(display = "Record does not exist.")]
     RecordDoesNotExist,
 
@@ -120,6 +123,13 @@ impl SqlError {
             SqlError::ForeignKeyConstraintViolation { constraint } => {
                 ConnectorError::from_kind(ErrorKind::ForeignKeyConstraintViolation { constraint })
             }
+            SqlError::CheckConstraintViolation {
+                constraint,
+                database_error,
+            } => ConnectorError::from_kind(ErrorKind::CheckConstraintViolation {
+                constraint,
+                database_error,
+            }),
             SqlError::RecordDoesNotExist => ConnectorError::from_kind(ErrorKind::RecordDoesNotExist),
             SqlError::ColumnDoesNotExist => ConnectorError::from_kind(ErrorKind::ColumnDoesNotExist),
             SqlError::ConnectionError(e) => ConnectorError {
@@ -180,6 +190,10 @@ impl SqlError {
 
 impl From<quaint::error::Error> for SqlError {
     fn from(e: quaint::error::Error) -> Self {
+        if let Some(error) = check_constraint_violation(&e) {
+            return error;
+        }
+
         match QuaintKind::from(e) {
             QuaintKind::FromRowError(_) => todo!("QuaintKind::FromRowError"),
             QuaintKind::QueryError(qe) => Self::QueryError(qe),
@@ -218,6 +232,46 @@ impl From<quaint::error::Error> for SqlError {
     }
 }
 
+/// Quaint has no dedicated error kind for check constraint violations, so we recognize them from
+/// the original error code (Postgres, MySQL and MariaDB) or message (SQLite) of the database.
+fn check_constraint_violation(e: &quaint::error::Error) -> Option<SqlError> {
+    let message = e.original_message()?;
+
+    if !is_check_constraint_violation(e.original_code(), message) {
+        return None;
+    }
+
+    Some(SqlError::CheckConstraintViolation {
+        constraint: check_constraint_name(message).unwrap_or_else(|| String::from("N/A")),
+        database_error: message.to_owned(),
+    })
+}
+
+fn is_check_constraint_violation(code: Option<&str>, message: &str) -> bool {
+    match code {
+        Some("23514") | Some("3819") | Some("4025") => true,
+        _ => message.starts_with("CHECK constraint failed"),
+    }
+}
+
+/// Extracts the constraint name from messages like `new row for relation "Product" violates check
+/// constraint "positive_price"`, `Check constraint 'positive_price' is violated.`,
+/// ``CONSTRAINT `positive_price` failed for ...`` or `CHECK constraint failed: positive_price`.
+fn check_constraint_name(message: &str) -> Option<String> {
+    const SQLITE_PREFIX: &str = "CHECK constraint failed: ";
+
+    if message.starts_with(SQLITE_PREFIX) {
+        return Some(message[SQLITE_PREFIX.len()..].trim().to_owned());
+    }
+
+    let start = message.to_ascii_lowercase().find("constraint ")? + "constraint ".len();
+    let rest = &message[start..];
+    let quote = rest.chars().next().filter(|c| ['"', '\'', '`'].contains(c))?;
+    let end = rest[1..].find(quote)? + 1;
+
+    Some(rest[1..end].to_owned())
+}
+
 impl From<DomainError> for SqlError {
     fn from(e: DomainError) -> SqlError {
         SqlError::DomainError(e)
@@ -241,3 +295,52 @@ impl From<FromUtf8Error> for SqlError {
         SqlError::ColumnReadFailure(e.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_constraint_violations_are_recognized() {
+        let violations = &[
+            (Some("23514"), "new row violates check constraint"),
+            (Some("3819"), "Check constraint is violated."),
+            (Some("4025"), "CONSTRAINT failed"),
+            (None, "CHECK constraint failed: positive_price"),
+            (Some("275"), "CHECK constraint failed: positive_price"),
+        ];
+
+        for (code, message) in violations {
+            assert!(is_check_constraint_violation(*code, message), "{}", message);
+        }
+
+        let other_errors = &[
+            (Some("23505"), "duplicate key value violates unique constraint"),
+            (Some("1062"), "Duplicate entry '1' for key 'PRIMARY'"),
+            (None, "UNIQUE constraint failed: Product.id"),
+        ];
+
+        for (code, message) in other_errors {
+            assert!(!is_check_constraint_violation(*code, message), "{}", message);
+        }
+    }
+
+    #[test]
+    fn check_constraint_names_are_extracted_from_the_database_errors() {
+        let messages = &[
+            r#"new row for relation "Product" violates check constraint "positive_price""#,
+            "Check constraint 'positive_price' is violated.",
+            "CONSTRAINT `positive_price` failed for `shop`.`Product`",
+            "CHECK constraint failed: positive_price",
+        ];
+
+        for message in messages {
+            let name = check_constraint_name(message);
+
+            assert_eq!(Some("positive_price"), name.as_deref(), "{}", message);
+        }
+
+        assert_eq!(None, check_constraint_name("CHECK constraint failed"));
+        assert_eq!(None, check_constraint_name("violates check constraint without quotes"));
+    }
+}
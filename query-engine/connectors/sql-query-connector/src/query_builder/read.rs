@@ -33,8 +33,11 @@ impl SelectDefinition for QueryArguments {
         let ordering_directions = self.ordering_directions();
         let ordering = Ordering::for_model(&model, ordering_directions);
 
-        let limit = self.take_abs();
-        let skip = self.skip.unwrap_or(0);
+        let (limit, skip) = if self.requires_inmemory_pagination() {
+            (None, 0)
+        } else {
+            (self.take_abs(), self.skip.unwrap_or(0))
+        };
 
         let filter: ConditionTree = self
             .filter
@@ -6,8 +6,9 @@ use futures::future::FutureExt;
 use prisma_models::*;
 use quaint::{
     ast::*,
-    connector::{self, Queryable},
+    connector::{self, Queryable, ResultSet},
     pooled::PooledConnection,
+    single,
 };
 
 use serde_json::{Map, Value};
@@ -15,6 +16,7 @@ use std::{convert::TryFrom, panic::AssertUnwindSafe};
 
 impl<'t> QueryExt for connector::Transaction<'t> {}
 impl QueryExt for PooledConnection {}
+impl QueryExt for single::Quaint {}
 
 /// An extension trait for Quaint's `Queryable`, offering certain Prisma-centric
 /// database operations on top of `Queryable`.
@@ -42,21 +44,15 @@ pub trait QueryExt: Queryable + Send + Sync {
         let params: Vec<_> = params.into_iter().map(quaint::ast::Value::from).collect();
         let result_set = AssertUnwindSafe(self.query_raw(&q, &params)).catch_unwind().await??;
 
-        let columns: Vec<String> = result_set.columns().into_iter().map(ToString::to_string).collect();
-        let mut result = Vec::new();
-
-        for row in result_set.into_iter() {
-            let mut object = Map::new();
+        Ok(result_set_to_json(result_set))
+    }
 
-            for (idx, p_value) in row.into_iter().enumerate() {
-                let column_name: String = columns[idx].clone();
-                object.insert(column_name, Value::from(p_value));
-            }
+    /// Execute a singular SQL query with already converted parameters in the
+    /// database, returning an arbitrary JSON `Value` as a result.
+    async fn json<'a>(&'a self, q: &'a str, params: &'a [quaint::ast::Value<'a>]) -> crate::Result<Value> {
+        let result_set = self.query_raw(q, params).await?;
 
-            result.push(Value::Object(object));
-        }
-
-        Ok(Value::Array(result))
+        Ok(result_set_to_json(result_set))
     }
 
     /// Execute a singular SQL query in the database, returning the number of
@@ -147,3 +143,22 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(result)
     }
 }
+
+/// Converts all rows of the result set into JSON objects, keyed by column name.
+fn result_set_to_json(result_set: ResultSet) -> Value {
+    let columns: Vec<String> = result_set.columns().into_iter().map(ToString::to_string).collect();
+    let mut result = Vec::new();
+
+    for row in result_set.into_iter() {
+        let mut object = Map::new();
+
+        for (idx, p_value) in row.into_iter().enumerate() {
+            let column_name: String = columns[idx].clone();
+            object.insert(column_name, Value::from(p_value));
+        }
+
+        result.push(Value::Object(object));
+    }
+
+    Value::Array(result)
+}
@@ -163,6 +163,46 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
             ),
             other => PrismaValue::from(other),
         },
+        TypeIdentifier::Decimal => match p_value {
+            Value::Null => PrismaValue::Null,
+            Value::Real(d) => PrismaValue::Decimal(d),
+            Value::Integer(i) => PrismaValue::Decimal(Decimal::from(i)),
+            Value::Text(_) | Value::Bytes(_) => PrismaValue::Decimal(
+                p_value
+                    .as_str()
+                    .expect("text/bytes as str")
+                    .parse()
+                    .map_err(|err: rust_decimal::Error| SqlError::ColumnReadFailure(err.into()))?,
+            ),
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Decimal value not stored as decimal, int or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::BigInt => match p_value {
+            Value::Null => PrismaValue::Null,
+            Value::Integer(i) => PrismaValue::BigInt(i),
+            Value::Bytes(bytes) => PrismaValue::BigInt(interpret_bytes_as_i64(&bytes)),
+            Value::Text(txt) => PrismaValue::BigInt(
+                i64::from_str(txt.trim_start_matches('\0')).map_err(|err| SqlError::ConversionError(err.into()))?,
+            ),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "BigInt value not stored as int or text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Bytes => match p_value {
+            Value::Null => PrismaValue::Null,
+            Value::Bytes(bytes) => PrismaValue::Bytes(bytes.into_owned()),
+            Value::Text(text) => PrismaValue::Bytes(text.into_owned().into_bytes()),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not stored as bytes");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
         TypeIdentifier::String => match p_value {
             Value::Uuid(uuid) => PrismaValue::String(uuid.to_string()),
             Value::Json(json_value) => {
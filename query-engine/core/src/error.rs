@@ -2,6 +2,7 @@ use crate::{InterpreterError, QueryGraphBuilderError, QueryGraphError, QueryPars
 use connector::error::ConnectorError;
 use failure::Fail;
 use prisma_models::DomainError;
+use std::time::Duration;
 
 // TODO: Cleanup unused errors after refactorings.
 #[derive(Debug, Fail)]
@@ -32,6 +33,9 @@ pub enum CoreError {
 
     #[fail(display = "{}", _0)]
     InterpreterError(InterpreterError),
+
+    #[fail(display = "Query timed out after {:?}", _0)]
+    QueryTimeout(Duration),
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -133,6 +137,21 @@ impl From<CoreError> for user_facing_errors::Error {
             })
             .unwrap()
             .into(),
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::QueryLimitError(err))
+            | CoreError::InterpreterError(InterpreterError::QueryLimitError(err)) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::QueryLimitExceeded {
+                    details: err.to_string(),
+                })
+                .unwrap()
+                .into()
+            }
+            CoreError::QueryTimeout(timeout) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::QueryTimedOut {
+                    timeout_ms: timeout.as_millis() as u64,
+                })
+                .unwrap()
+                .into()
+            }
             CoreError::QueryGraphBuilderError(QueryGraphBuilderError::InputError(details)) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputError { details })
                     .unwrap()
@@ -0,0 +1,224 @@
+//! Explanation of queries without executing them.
+//! The query graph is built and finalized as for an execution, but instead of interpreting it,
+//! the graph is rendered together with the statements the connector would issue for the reads.
+use crate::{
+    query_graph::{Node, QueryGraph, QueryGraphDependency},
+    AggregationQuery, Query, QueryType, ReadQuery,
+};
+use connector::{Connection, QueryArguments, StatementExplanation};
+use futures::future::{BoxFuture, FutureExt};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryPlan {
+    pub needs_transaction: bool,
+    pub root_nodes: Vec<String>,
+    pub result_nodes: Vec<String>,
+    pub nodes: Vec<PlanNode>,
+    pub edges: Vec<PlanEdge>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanNode {
+    pub id: String,
+    pub kind: PlanNodeKind,
+    pub description: String,
+
+    /// Only set for read nodes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read: Option<PlannedRead>,
+}
+
+#[derive(Debug, Serialize)]
+pub enum PlanNodeKind {
+    Read,
+    Write,
+    Raw,
+    Flow,
+    Computation,
+    Empty,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanEdge {
+    pub id: String,
+    pub source: String,
+    pub target: String,
+    pub dependency: String,
+
+    /// Fields of the parent result the transformation of the target node requires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_projection: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedRead {
+    pub name: String,
+    pub model: String,
+    pub statements: Vec<StatementExplanation>,
+
+    /// Reads of related records additionally filter on the records of the parent read,
+    /// which are only known during execution and are therefore missing in the statements.
+    pub depends_on_parent: bool,
+    pub nested: Vec<PlannedRead>,
+}
+
+pub async fn explain(conn: &dyn Connection, query: QueryType, database_plan: bool) -> crate::Result<QueryPlan> {
+    match query {
+        QueryType::Graph(mut graph) => {
+            graph.finalize()?;
+            explain_graph(conn, &graph, database_plan).await
+        }
+
+        QueryType::Raw { query, parameters, .. } => Ok(QueryPlan {
+            needs_transaction: false,
+            root_nodes: vec!["0".to_owned()],
+            result_nodes: vec!["0".to_owned()],
+            nodes: vec![PlanNode {
+                id: "0".to_owned(),
+                kind: PlanNodeKind::Raw,
+                description: format!("Raw query: {} ({:?})", query, parameters),
+                read: None,
+            }],
+            edges: vec![],
+        }),
+    }
+}
+
+async fn explain_graph(conn: &dyn Connection, graph: &QueryGraph, database_plan: bool) -> crate::Result<QueryPlan> {
+    let mut nodes = vec![];
+
+    for node in graph.nodes() {
+        let (kind, read) = match graph.node_content(&node) {
+            Some(Node::Query(Query::Read(rq))) => {
+                (PlanNodeKind::Read, Some(explain_read(conn, rq, database_plan).await?))
+            }
+            Some(Node::Query(Query::Write(_))) => (PlanNodeKind::Write, None),
+            Some(Node::Flow(_)) => (PlanNodeKind::Flow, None),
+            Some(Node::Computation(_)) => (PlanNodeKind::Computation, None),
+            Some(Node::Empty) | None => (PlanNodeKind::Empty, None),
+        };
+
+        let description = graph
+            .node_content(&node)
+            .map(|content| format!("{}", content))
+            .unwrap_or_else(String::new);
+
+        nodes.push(PlanNode {
+            id: node.id(),
+            kind,
+            description,
+            read,
+        });
+    }
+
+    let edges = graph
+        .edges()
+        .into_iter()
+        .map(|edge| {
+            let content = graph.edge_content(&edge);
+            let parent_projection = match content {
+                Some(QueryGraphDependency::ParentProjection(projection, _)) => {
+                    Some(projection.names().map(ToOwned::to_owned).collect())
+                }
+                _ => None,
+            };
+
+            PlanEdge {
+                id: edge.id(),
+                source: graph.edge_source(&edge).id(),
+                target: graph.edge_target(&edge).id(),
+                dependency: content.map(|dep| format!("{}", dep)).unwrap_or_else(String::new),
+                parent_projection,
+            }
+        })
+        .collect();
+
+    Ok(QueryPlan {
+        needs_transaction: graph.needs_transaction(),
+        root_nodes: graph.root_nodes().iter().map(|node| node.id()).collect(),
+        result_nodes: graph.result_nodes().iter().map(|node| node.id()).collect(),
+        nodes,
+        edges,
+    })
+}
+
+fn explain_read<'a>(
+    conn: &'a dyn Connection,
+    query: &'a ReadQuery,
+    database_plan: bool,
+) -> BoxFuture<'a, crate::Result<PlannedRead>> {
+    let fut = async move {
+        let (statements, depends_on_parent, nested) = match query {
+            ReadQuery::RecordQuery(q) => {
+                let args = q.filter.clone().map(QueryArguments::from).unwrap_or_default();
+                let statements = conn
+                    .explain_many_records(&q.model, args, &q.selected_fields, database_plan)
+                    .await?;
+
+                (statements, false, q.nested.as_slice())
+            }
+
+            ReadQuery::ManyRecordsQuery(q) => {
+                let statements = conn
+                    .explain_many_records(&q.model, q.args.clone(), &q.selected_fields, database_plan)
+                    .await?;
+
+                (statements, false, q.nested.as_slice())
+            }
+
+            ReadQuery::RelatedRecordsQuery(q) => {
+                // Distinct and pagination of related records happen in memory, see `read_related` in the interpreter.
+                let mut args = q.args.clone();
+
+                args.take = None;
+                args.skip = None;
+                args.distinct = None;
+
+                let statements = conn
+                    .explain_many_records(&q.parent_field.related_model(), args, &q.selected_fields, database_plan)
+                    .await?;
+
+                (statements, true, q.nested.as_slice())
+            }
+
+            ReadQuery::AggregateRecordsQuery(q) => {
+                let mut statements = vec![];
+
+                for aggregation in q.queries.iter() {
+                    match aggregation {
+                        AggregationQuery::Count(_, args) => {
+                            let statement = conn
+                                .explain_count_by_model(&q.model, args.clone(), database_plan)
+                                .await?;
+
+                            statements.push(statement);
+                        }
+                    }
+                }
+
+                (statements, false, &[][..])
+            }
+        };
+
+        let mut nested_reads = Vec::with_capacity(nested.len());
+
+        for nested_query in nested.iter() {
+            nested_reads.push(explain_read(conn, nested_query, database_plan).await?);
+        }
+
+        Ok(PlannedRead {
+            name: query.name().to_owned(),
+            model: query.model().name.clone(),
+            statements,
+            depends_on_parent,
+            nested: nested_reads,
+        })
+    };
+
+    fut.boxed()
+}
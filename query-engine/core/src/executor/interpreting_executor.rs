@@ -1,13 +1,23 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, Response, Responses};
+use super::{explain, pipeline::QueryPipeline, QueryExecutor, QueryPlan};
+use crate::{
+    CoreError, Operation, ParsedOperation, QueryGraphBuilder, QueryInterpreter, QueryLimits, QuerySchemaRef, Response,
+    Responses,
+};
 use async_trait::async_trait;
-use connector::{ConnectionLike, Connector};
+use connector::{Connection, ConnectionLike, Connector};
+use futures::future::{self, FutureExt};
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
     connector: C,
     primary_connector: &'static str,
     force_transactions: bool,
+    limits: QueryLimits,
 }
 
 // Todo:
@@ -21,6 +31,59 @@ where
             connector,
             primary_connector,
             force_transactions,
+            limits: QueryLimits::default(),
+        }
+    }
+
+    /// Applies the limits to every executed operation.
+    pub fn with_limits(mut self, limits: QueryLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Runs the interpretation future, aborting it if it doesn't finish within the timeout.
+    /// A statement still running in the database session is cancelled on the database side as well.
+    async fn with_timeout<F, T>(&self, timeout: Option<Duration>, conn: &dyn Connection, fut: F) -> crate::Result<T>
+    where
+        F: Future<Output = crate::Result<T>> + Send,
+    {
+        let timeout = match timeout {
+            Some(timeout) => timeout,
+            None => return fut.await,
+        };
+
+        // The session is looked up next to the query instead of before it, so that it doesn't
+        // delay the query. Postgres receives both without waiting for the lookup to return.
+        let session = Mutex::new(None);
+        let lookup = async {
+            match conn.session_id().await {
+                Ok(session_id) => *session.lock().unwrap() = session_id,
+                Err(err) => warn!("Failed to read the session of a query with a timeout: {}", err),
+            }
+        };
+
+        let query = future::join(lookup, fut).map(|(_, result)| result);
+
+        match tokio::time::timeout(timeout, query).await {
+            Ok(result) => result,
+            Err(_) => {
+                let session = *session.lock().unwrap();
+
+                if let Some(session) = session {
+                    match self.connector.cancel_session(session).await {
+                        // The connection must not be released before the cancelled statement ended,
+                        // or the cancellation could hit the statement of the next user of the connection.
+                        Ok(()) => {
+                            if let Err(err) = conn.session_id().await {
+                                warn!("Failed to wait for the statement of timed out query to end: {}", err);
+                            }
+                        }
+                        Err(err) => warn!("Failed to cancel the statement of timed out query: {}", err),
+                    }
+                }
+
+                Err(CoreError::QueryTimeout(timeout))
+            }
         }
     }
 }
@@ -30,11 +93,30 @@ impl<C> QueryExecutor for InterpretingExecutor<C>
 where
     C: Connector + Send + Sync,
 {
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<Responses> {
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        timeout: Option<Duration>,
+    ) -> crate::Result<Responses> {
+        // Parse and validate the query document.
+        let operation = QueryGraphBuilder::new(Arc::clone(&query_schema)).parse(operation)?;
+
+        self.execute_parsed(operation, query_schema, timeout).await
+    }
+
+    async fn execute_parsed(
+        &self,
+        operation: ParsedOperation,
+        query_schema: QuerySchemaRef,
+        timeout: Option<Duration>,
+    ) -> crate::Result<Responses> {
         let conn = self.connector.get_connection().await?;
 
-        // Parse, validate, and extract query graphs from query document.
-        let (query, info) = QueryGraphBuilder::new(query_schema).build(operation)?;
+        // Extract query graphs from the parsed query document.
+        let (query, info) = QueryGraphBuilder::new(query_schema)
+            .with_limits(self.limits)
+            .build_parsed(operation)?;
 
         // Create pipelines for all separate queries
         let mut responses = Responses::with_capacity(1);
@@ -43,19 +125,25 @@ where
         let result = if needs_transaction {
             let tx = conn.start_transaction().await?;
 
-            let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-            let result = QueryPipeline::new(query, interpreter, info).execute().await;
+            let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()))
+                .with_record_limit(self.limits.max_records);
+            let pipeline = QueryPipeline::new(query, interpreter, info);
+            let result = self.with_timeout(timeout, conn.as_ref(), pipeline.execute()).await;
 
             if result.is_ok() {
                 tx.commit().await?;
-            } else {
-                tx.rollback().await?;
+            } else if let Err(err) = tx.rollback().await {
+                // The error of the query, e.g. its timeout, is what the client needs to see.
+                warn!("Failed to roll back the transaction of a failed query: {}", err);
             }
 
             result?
         } else {
-            let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
-            QueryPipeline::new(query, interpreter, info).execute().await?
+            let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()))
+                .with_record_limit(self.limits.max_records);
+            let pipeline = QueryPipeline::new(query, interpreter, info);
+
+            self.with_timeout(timeout, conn.as_ref(), pipeline.execute()).await?
         };
 
         match result {
@@ -66,6 +154,18 @@ where
         Ok(responses)
     }
 
+    async fn explain(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        database_plan: bool,
+    ) -> crate::Result<QueryPlan> {
+        let conn = self.connector.get_connection().await?;
+        let (query, _) = QueryGraphBuilder::new(query_schema).build(operation)?;
+
+        explain::explain(conn.as_ref(), query, database_plan).await
+    }
+
     fn primary_connector(&self) -> &'static str {
         self.primary_connector
     }
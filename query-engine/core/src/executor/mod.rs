@@ -5,17 +5,48 @@
 //!
 //! What the executor module DOES NOT DO:
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
+mod explain;
 mod interpreting_executor;
 mod pipeline;
 
+pub use explain::{PlanEdge, PlanNode, PlanNodeKind, PlannedRead, QueryPlan};
 pub use interpreting_executor::*;
 
-use crate::{query_document::Operation, response_ir::Responses, schema::QuerySchemaRef};
+use crate::{
+    query_document::Operation, query_graph_builder::ParsedOperation, response_ir::Responses, schema::QuerySchemaRef,
+};
 use async_trait::async_trait;
+use std::time::Duration;
 
 #[async_trait]
 pub trait QueryExecutor {
-    async fn execute(&self, query_doc: Operation, query_schema: QuerySchemaRef) -> crate::Result<Responses>;
+    /// Executes the operation. If a `timeout` is given and exceeded, the execution is aborted,
+    /// running statements are cancelled where the database supports it, and open transactions are rolled back.
+    async fn execute(
+        &self,
+        query_doc: Operation,
+        query_schema: QuerySchemaRef,
+        timeout: Option<Duration>,
+    ) -> crate::Result<Responses>;
+
+    /// Executes an operation that has already been parsed and validated against the query schema,
+    /// see `QueryGraphBuilder::parse`. Otherwise the same as `execute`.
+    async fn execute_parsed(
+        &self,
+        operation: ParsedOperation,
+        query_schema: QuerySchemaRef,
+        timeout: Option<Duration>,
+    ) -> crate::Result<Responses>;
+
+    /// Builds the query graph for the given operation and explains it instead of executing it.
+    /// No writes are performed. If `database_plan` is set, the database is asked for its own
+    /// execution plan of every read statement.
+    async fn explain(
+        &self,
+        query_doc: Operation,
+        query_schema: QuerySchemaRef,
+        database_plan: bool,
+    ) -> crate::Result<QueryPlan>;
 
     fn primary_connector(&self) -> &'static str;
 }
@@ -1,4 +1,4 @@
-use crate::{CoreError, QueryGraphBuilderError, QueryGraphError};
+use crate::{CoreError, QueryGraphBuilderError, QueryGraphError, QueryLimitError};
 use connector::error::ConnectorError;
 use prisma_models::DomainError;
 use std::fmt;
@@ -22,6 +22,9 @@ pub enum InterpreterError {
     /// Wraps errors coming from the connector during execution.
     ConnectorError(ConnectorError),
 
+    /// The execution exceeded one of the configured query limits.
+    QueryLimitError(QueryLimitError),
+
     Generic(String),
 }
 
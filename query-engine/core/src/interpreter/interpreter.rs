@@ -3,12 +3,13 @@ use super::{
     query_interpreters::{read, write},
     InterpretationResult, InterpreterError,
 };
-use crate::{Query, QueryResult};
+use crate::{Query, QueryLimitError, QueryResult};
 use connector::ConnectionLike;
 use crossbeam_queue::SegQueue;
 use futures::future::{BoxFuture, FutureExt};
 use im::HashMap;
 use prisma_models::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[derive(Debug, Clone)]
 pub enum ExpressionResult {
@@ -113,9 +114,35 @@ impl Env {
         }
     }
 }
+/// Counts the records read during the interpretation of one operation, failing once the limit is exceeded.
+#[derive(Debug, Default)]
+pub struct RecordBudget {
+    limit: Option<usize>,
+    used: AtomicUsize,
+}
+
+impl RecordBudget {
+    pub fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn consume(&self, records: usize) -> InterpretationResult<()> {
+        let used = self.used.fetch_add(records, Ordering::Relaxed) + records;
+
+        match self.limit {
+            Some(limit) if used > limit => Err(InterpreterError::QueryLimitError(QueryLimitError::Records { limit })),
+            _ => Ok(()),
+        }
+    }
+}
+
 pub struct QueryInterpreter<'conn, 'tx> {
     pub(crate) conn: ConnectionLike<'conn, 'tx>,
     log: SegQueue<String>,
+    record_budget: RecordBudget,
 }
 
 impl<'conn, 'tx> QueryInterpreter<'conn, 'tx>
@@ -133,7 +160,17 @@ where
             log.push("\n".to_string());
         }
 
-        Self { conn, log }
+        Self {
+            conn,
+            log,
+            record_budget: RecordBudget::default(),
+        }
+    }
+
+    /// Limits the number of records the interpreter reads from the database.
+    pub fn with_record_limit(mut self, limit: Option<usize>) -> Self {
+        self.record_budget = RecordBudget::new(limit);
+        self
     }
 
     pub fn interpret(
@@ -201,7 +238,7 @@ where
                     match query {
                         Query::Read(read) => {
                             self.log_line(level, || format!("READ {}", read));
-                            Ok(read::execute(&self.conn, read, None)
+                            Ok(read::execute(&self.conn, read, None, &self.record_budget)
                                 .await
                                 .map(|res| ExpressionResult::Query(res))?)
                         }
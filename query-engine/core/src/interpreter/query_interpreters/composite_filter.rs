@@ -0,0 +1,198 @@
+//! Composite values are stored as Json, which connectors can't filter on.
+//! Before a query is handed to the connector, all composite filters are evaluated in memory
+//! and replaced by a filter on the IDs of the matching records.
+//!
+//! This reads every record of the model that the rest of the enclosing conjunction allows, in
+//! batches of `BATCH_SIZE` records, so the cost of a composite filter grows with the number of
+//! records in that scope, not with the number of matches. Combine composite filters with
+//! filters on regular fields to keep that scope small.
+use crate::{interpreter::InterpretationResult, query_ast::*};
+use connector::{
+    filter::{CompositeFilter, Filter},
+    ConnectionLike, QueryArguments, ReadOperations, RecordFilter, ScalarCompare,
+};
+use futures::future::{BoxFuture, FutureExt};
+use prisma_models::{Field, ModelProjection, PrismaValue};
+use std::sync::Arc;
+
+/// Number of records read at once while evaluating a composite filter.
+const BATCH_SIZE: usize = 1000;
+
+/// Resolves the composite filters of the given read query.
+pub async fn resolve_read_query<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    mut query: ReadQuery,
+) -> InterpretationResult<ReadQuery> {
+    match query {
+        ReadQuery::RecordQuery(ref mut q) => q.filter = resolve_opt(tx, q.filter.take()).await?,
+        ReadQuery::ManyRecordsQuery(ref mut q) => q.args.filter = resolve_opt(tx, q.args.filter.take()).await?,
+        ReadQuery::RelatedRecordsQuery(ref mut q) => q.args.filter = resolve_opt(tx, q.args.filter.take()).await?,
+        ReadQuery::AggregateRecordsQuery(ref mut q) => {
+            for aggregation_query in q.queries.iter_mut() {
+                match aggregation_query {
+                    AggregationQuery::Count(_, ref mut args) => {
+                        args.filter = resolve_opt(tx, args.filter.take()).await?
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(query)
+}
+
+/// Resolves the composite filters of the given write query.
+pub async fn resolve_write_query<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    mut query: WriteQuery,
+) -> InterpretationResult<WriteQuery> {
+    match query {
+        WriteQuery::UpdateRecord(ref mut q) => resolve_record_filter(tx, &mut q.record_filter).await?,
+        WriteQuery::UpdateManyRecords(ref mut q) => resolve_record_filter(tx, &mut q.record_filter).await?,
+        WriteQuery::DeleteManyRecords(ref mut q) => resolve_record_filter(tx, &mut q.record_filter).await?,
+        WriteQuery::DeleteRecord(ref mut q) => {
+            if let Some(ref mut record_filter) = q.record_filter {
+                resolve_record_filter(tx, record_filter).await?
+            }
+        }
+        _ => (),
+    };
+
+    Ok(query)
+}
+
+async fn resolve_record_filter<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    record_filter: &mut RecordFilter,
+) -> InterpretationResult<()> {
+    let filter = std::mem::replace(&mut record_filter.filter, Filter::empty());
+    record_filter.filter = resolve(tx, filter).await?;
+
+    Ok(())
+}
+
+/// Resolves all composite filters contained in the given filter.
+pub fn resolve<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, filter: Filter) -> BoxFuture<'a, InterpretationResult<Filter>> {
+    let fut = async move {
+        let filter = match filter {
+            Filter::And(filters) => Filter::And(resolve_conjunction(tx, filters).await?),
+            Filter::Or(filters) => Filter::Or(resolve_all(tx, filters).await?),
+            Filter::Not(filters) => Filter::Not(resolve_all(tx, filters).await?),
+            Filter::Relation(mut relation_filter) => {
+                let nested_filter = resolve(tx, *relation_filter.nested_filter).await?;
+                relation_filter.nested_filter = Box::new(nested_filter);
+
+                Filter::Relation(relation_filter)
+            }
+            Filter::Composite(composite_filter) => resolve_composite(tx, composite_filter, Filter::empty()).await?,
+            filter => filter,
+        };
+
+        Ok(filter)
+    };
+
+    fut.boxed()
+}
+
+/// Resolves all composite filters contained in the given filter, if present.
+pub async fn resolve_opt<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    filter: Option<Filter>,
+) -> InterpretationResult<Option<Filter>> {
+    match filter {
+        Some(filter) => Ok(Some(resolve(tx, filter).await?)),
+        None => Ok(None),
+    }
+}
+
+async fn resolve_all<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    filters: Vec<Filter>,
+) -> InterpretationResult<Vec<Filter>> {
+    let mut resolved = Vec::with_capacity(filters.len());
+
+    for filter in filters {
+        resolved.push(resolve(tx, filter).await?);
+    }
+
+    Ok(resolved)
+}
+
+/// Resolves the filters of a conjunction. The other filters of the conjunction must hold for a
+/// record anyway, so they scope the records the composite filters are evaluated on.
+async fn resolve_conjunction<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    filters: Vec<Filter>,
+) -> InterpretationResult<Vec<Filter>> {
+    let (composite_filters, other_filters): (Vec<Filter>, Vec<Filter>) = filters
+        .into_iter()
+        .partition(|filter| matches!(filter, Filter::Composite(_)));
+
+    let mut resolved = resolve_all(tx, other_filters).await?;
+    let scope = Filter::And(resolved.clone());
+
+    for filter in composite_filters {
+        if let Filter::Composite(composite_filter) = filter {
+            resolved.push(resolve_composite(tx, composite_filter, scope.clone()).await?);
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// Reads the composite field of the records of the model matching `scope` and filters on the
+/// IDs of the records that match the composite filter. Large ID lists are split into multiple
+/// queries by the connector, see `QueryArguments::batched`.
+async fn resolve_composite<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    filter: CompositeFilter,
+    scope: Filter,
+) -> InterpretationResult<Filter> {
+    let model = filter.field.model();
+    let model_id = model.primary_identifier();
+    let selected_fields = model_id
+        .clone()
+        .merge(ModelProjection::from(Field::Scalar(Arc::clone(&filter.field))));
+
+    let mut ids: Vec<Vec<PrismaValue>> = Vec::new();
+    let mut skip = 0;
+
+    loop {
+        let args = QueryArguments {
+            filter: Some(scope.clone()),
+            skip: Some(skip as i64),
+            take: Some(BATCH_SIZE as i64),
+            ..Default::default()
+        };
+
+        let mut records = tx.get_many_records(&model, args, &selected_fields).await?;
+        let batch_len = records.records.len();
+
+        let index = records
+            .field_names
+            .iter()
+            .position(|name| name == filter.field.db_name())
+            .expect("Invariant violation: Composite field is always selected.");
+
+        records.records.retain(|record| filter.matches(&record.values[index]));
+
+        ids.extend(
+            records
+                .projections(&model_id)?
+                .into_iter()
+                .map(|id| id.values().collect::<Vec<_>>()),
+        );
+
+        if batch_len < BATCH_SIZE {
+            break;
+        }
+
+        skip += BATCH_SIZE;
+    }
+
+    if ids.is_empty() {
+        Ok(Filter::BoolFilter(false))
+    } else {
+        Ok(model_id.is_in(ids))
+    }
+}
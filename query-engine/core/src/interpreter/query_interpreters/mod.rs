@@ -1,3 +1,4 @@
+mod composite_filter;
 mod nested_pagination;
 mod nested_read;
 pub mod read;
@@ -1,9 +1,11 @@
+use crate::interpreter::InterpretationResult;
 use connector::QueryArguments;
-use prisma_models::{ManyRecords, RecordProjection};
+use prisma_models::{ManyRecords, ModelProjection, RecordProjection};
 
 pub struct NestedPagination {
     skip: Option<i64>,
     take: Option<i64>,
+    distinct: Option<ModelProjection>,
     needs_reversing: bool,
 }
 
@@ -12,13 +14,19 @@ impl NestedPagination {
         NestedPagination {
             skip: args.skip.clone(),
             take: args.take_abs(),
+            distinct: args.distinct.clone(),
             needs_reversing: args.needs_reversed_order(),
         }
     }
 
-    pub fn apply_pagination(&self, many_records: &mut ManyRecords) {
+    pub fn apply_pagination(&self, many_records: &mut ManyRecords) -> InterpretationResult<()> {
+        // Distinct has to be applied before pagination, as it changes the records counted per parent.
+        if let Some(ref distinct) = self.distinct {
+            many_records.distinct(distinct)?;
+        }
+
         if !self.must_apply_pagination() {
-            return;
+            return Ok(());
         }
 
         // replacement for SQL order by
@@ -64,6 +72,8 @@ impl NestedPagination {
         if self.needs_reversing {
             many_records.records.reverse();
         }
+
+        Ok(())
     }
 
     fn must_apply_pagination(&self) -> bool {
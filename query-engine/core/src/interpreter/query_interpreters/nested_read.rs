@@ -1,4 +1,3 @@
-use crate::interpreter::query_interpreters::nested_pagination::NestedPagination;
 use crate::{interpreter::InterpretationResult, query_ast::*};
 use connector::{self, filter::Filter, ConnectionLike, QueryArguments, ReadOperations, ScalarCompare};
 use prisma_models::{ManyRecords, ModelProjection, RecordProjection, RelationFieldRef};
@@ -9,7 +8,6 @@ pub async fn m2m<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: &RelatedRecordsQuery,
     parent_result: Option<&'a ManyRecords>,
-    paginator: NestedPagination,
 ) -> InterpretationResult<ManyRecords> {
     let parent_field = &query.parent_field;
     let child_link_id = parent_field.related_field().linking_fields();
@@ -79,7 +77,6 @@ pub async fn m2m<'a, 'b>(
     }
 
     scalars.records.extend(additional_records);
-    paginator.apply_pagination(&mut scalars);
 
     Ok(scalars)
 }
@@ -92,7 +89,6 @@ pub async fn one2m<'a, 'b>(
     parent_result: Option<&'a ManyRecords>,
     query_args: QueryArguments,
     selected_fields: &ModelProjection,
-    paginator: NestedPagination,
 ) -> InterpretationResult<ManyRecords> {
     let parent_model_id = parent_field.model().primary_identifier();
     let parent_link_id = parent_field.linking_fields();
@@ -196,6 +192,5 @@ pub async fn one2m<'a, 'b>(
         ));
     }
 
-    paginator.apply_pagination(&mut scalars);
     Ok(scalars)
 }
@@ -1,7 +1,11 @@
 use super::*;
 use crate::interpreter::query_interpreters::nested_pagination::NestedPagination;
-use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
-use connector::{self, ConnectionLike, ReadOperations};
+use crate::{
+    interpreter::{InterpretationResult, RecordBudget},
+    query_ast::*,
+    result_ast::*,
+};
+use connector::{self, ConnectionLike, QueryArguments, ReadOperations};
 use futures::future::{BoxFuture, FutureExt};
 use prisma_models::ManyRecords;
 
@@ -9,12 +13,13 @@ pub fn execute<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: ReadQuery,
     parent_result: Option<&'a ManyRecords>,
+    budget: &'a RecordBudget,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
-        match query {
-            ReadQuery::RecordQuery(q) => read_one(tx, q).await,
-            ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
-            ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result).await,
+        match composite_filter::resolve_read_query(tx, query).await? {
+            ReadQuery::RecordQuery(q) => read_one(tx, q, budget).await,
+            ReadQuery::ManyRecordsQuery(q) => read_many(tx, q, budget).await,
+            ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result, budget).await,
             ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
         }
     };
@@ -26,6 +31,7 @@ pub fn execute<'a, 'b>(
 fn read_one<'conn, 'tx>(
     tx: &'conn ConnectionLike<'conn, 'tx>,
     query: RecordQuery,
+    budget: &'conn RecordBudget,
 ) -> BoxFuture<'conn, InterpretationResult<QueryResult>> {
     let fut = async move {
         let model = query.model;
@@ -35,8 +41,10 @@ fn read_one<'conn, 'tx>(
 
         match scalars {
             Some(record) => {
+                budget.consume(1)?;
+
                 let records: ManyRecords = record.into();
-                let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&records)).await?;
+                let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&records), budget).await?;
 
                 Ok(QueryResult::RecordSelection(RecordSelection {
                     name: query.name,
@@ -64,14 +72,19 @@ fn read_one<'conn, 'tx>(
 fn read_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: ManyRecordsQuery,
+    budget: &'a RecordBudget,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
-        let scalars = tx
+        let mut scalars = tx
             .get_many_records(&query.model, query.args.clone(), &query.selected_fields)
             .await?;
 
+        // All records read from the database count, also the ones discarded in memory.
+        budget.consume(scalars.records.len())?;
+        apply_inmemory_distinct(&query.args, &mut scalars)?;
+
         let model_id = query.model.primary_identifier();
-        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
+        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars), budget).await?;
 
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
@@ -91,17 +104,20 @@ fn read_related<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     mut query: RelatedRecordsQuery,
     parent_result: Option<&'a ManyRecords>,
+    budget: &'a RecordBudget,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
         let relation = query.parent_field.relation();
         let is_m2m = relation.is_many_to_many();
         let paginator = NestedPagination::new_from_query_args(&query.args);
 
+        // Distinct and pagination apply per parent record, hence they happen in memory.
         query.args.take = None;
         query.args.skip = None;
+        query.args.distinct = None;
 
-        let scalars = if is_m2m {
-            nested_read::m2m(tx, &query, parent_result, paginator).await?
+        let mut scalars = if is_m2m {
+            nested_read::m2m(tx, &query, parent_result).await?
         } else {
             nested_read::one2m(
                 tx,
@@ -110,14 +126,17 @@ fn read_related<'a, 'b>(
                 parent_result,
                 query.args.clone(),
                 &query.selected_fields,
-                paginator,
             )
             .await?
         };
 
+        // All records read from the database count, also the ones discarded in memory.
+        budget.consume(scalars.records.len())?;
+        paginator.apply_pagination(&mut scalars)?;
+
         let model = query.parent_field.related_model();
         let model_id = model.primary_identifier();
-        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
+        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars), budget).await?;
 
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
@@ -132,6 +151,35 @@ fn read_related<'a, 'b>(
     fut.boxed()
 }
 
+/// Distinct is processed in memory. As a consequence, the connector didn't apply `skip` and `take`
+/// and pagination has to happen here as well, after the records have been made distinct.
+fn apply_inmemory_distinct(args: &QueryArguments, records: &mut ManyRecords) -> InterpretationResult<()> {
+    if let Some(ref distinct) = args.distinct {
+        records.distinct(distinct)?;
+    }
+
+    if !args.requires_inmemory_pagination() {
+        return Ok(());
+    }
+
+    let skip = args.skip.unwrap_or(0) as usize;
+    let take = args.take_abs().map(|t| t as usize).unwrap_or(usize::MAX);
+    let reversed = args.needs_reversed_order();
+
+    // A negative `take` takes the records from the end.
+    if reversed {
+        records.reverse();
+    }
+
+    records.records = records.records.drain(..).skip(skip).take(take).collect();
+
+    if reversed {
+        records.reverse();
+    }
+
+    Ok(())
+}
+
 async fn aggregate<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     aggregate: AggregateRecordsQuery,
@@ -157,12 +205,13 @@ fn process_nested<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     nested: Vec<ReadQuery>,
     parent_result: Option<&'a ManyRecords>,
+    budget: &'a RecordBudget,
 ) -> BoxFuture<'a, InterpretationResult<Vec<QueryResult>>> {
     let fut = async move {
         let mut results = Vec::with_capacity(nested.len());
 
         for query in nested {
-            let result = execute(tx, query, parent_result).await?;
+            let result = execute(tx, query, parent_result, budget).await?;
             results.push(result);
         }
 
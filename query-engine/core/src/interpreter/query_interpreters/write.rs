@@ -1,3 +1,4 @@
+use super::composite_filter;
 use crate::{
     interpreter::{InterpretationResult, InterpreterError},
     query_ast::*,
@@ -10,7 +11,7 @@ pub async fn execute<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     write_query: WriteQuery,
 ) -> InterpretationResult<QueryResult> {
-    match write_query {
+    match composite_filter::resolve_write_query(tx, write_query).await? {
         WriteQuery::CreateRecord(q) => create_one(tx, q).await,
         WriteQuery::UpdateRecord(q) => update_one(tx, q).await,
         WriteQuery::DeleteRecord(q) => delete_one(tx, q).await,
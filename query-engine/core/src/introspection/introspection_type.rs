@@ -0,0 +1,107 @@
+use crate::schema::*;
+use std::sync::Arc;
+
+/// Names of the built-in scalars of the query schema.
+pub const SCALAR_NAMES: &[&str] = &["String", "Int", "Float", "Boolean", "DateTime", "Json", "UUID"];
+
+/// A type of the query schema as seen by GraphQL introspection.
+/// In contrast to the query schema types, types are nullable unless wrapped in `NonNull`.
+#[derive(Debug, Clone)]
+pub enum IntrospectionType {
+    Scalar(&'static str),
+    Object(ObjectTypeStrongRef),
+    InputObject(InputObjectTypeStrongRef),
+    Enum(EnumTypeRef),
+    List(Box<IntrospectionType>),
+    NonNull(Box<IntrospectionType>),
+}
+
+impl IntrospectionType {
+    pub fn from_output(typ: &OutputType) -> Self {
+        let base = match typ {
+            OutputType::Opt(inner) => return Self::from_output(inner).nullable(),
+            OutputType::List(inner) => Self::List(Box::new(Self::from_output(inner))),
+            OutputType::Object(obj) => Self::Object(obj.into_arc()),
+            OutputType::Enum(et) => Self::Enum(Arc::clone(et)),
+            OutputType::Scalar(scalar) => Self::from_scalar(scalar),
+        };
+
+        Self::NonNull(Box::new(base))
+    }
+
+    pub fn from_input(typ: &InputType) -> Self {
+        let base = match typ {
+            InputType::Opt(inner) => return Self::from_input(inner).nullable(),
+
+            // Nullability has no representation in GraphQL.
+            InputType::Null(inner) => return Self::from_input(inner),
+            InputType::List(inner) => Self::List(Box::new(Self::from_input(inner))),
+            InputType::Object(obj) => Self::InputObject(obj.into_arc()),
+            InputType::Enum(et) => Self::Enum(Arc::clone(et)),
+            InputType::Scalar(scalar) => Self::from_scalar(scalar),
+        };
+
+        Self::NonNull(Box::new(base))
+    }
+
+    fn from_scalar(scalar: &ScalarType) -> Self {
+        match scalar {
+            ScalarType::String => Self::Scalar("String"),
+            ScalarType::Int => Self::Scalar("Int"),
+            ScalarType::Float => Self::Scalar("Float"),
+            ScalarType::Boolean => Self::Scalar("Boolean"),
+            ScalarType::DateTime => Self::Scalar("DateTime"),
+            ScalarType::Json | ScalarType::JsonList => Self::Scalar("Json"),
+            ScalarType::UUID => Self::Scalar("UUID"),
+            ScalarType::Decimal => Self::Scalar("Decimal"),
+            ScalarType::BigInt => Self::Scalar("BigInt"),
+            ScalarType::Bytes => Self::Scalar("Bytes"),
+            ScalarType::Enum(et) => Self::Enum(Arc::clone(et)),
+        }
+    }
+
+    fn nullable(self) -> Self {
+        match self {
+            Self::NonNull(inner) => *inner,
+            typ => typ,
+        }
+    }
+
+    /// The `__TypeKind` of the type.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Scalar(_) => "SCALAR",
+            Self::Object(_) => "OBJECT",
+            Self::InputObject(_) => "INPUT_OBJECT",
+            Self::Enum(_) => "ENUM",
+            Self::List(_) => "LIST",
+            Self::NonNull(_) => "NON_NULL",
+        }
+    }
+
+    /// Wrapping types (lists and non-null types) don't have a name.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Scalar(name) => Some(*name),
+            Self::Object(obj) => Some(obj.name()),
+            Self::InputObject(obj) => Some(obj.name.as_str()),
+            Self::Enum(et) => Some(et.name()),
+            Self::List(_) | Self::NonNull(_) => None,
+        }
+    }
+
+    /// Object types mapping to a model are described by the documentation of the model.
+    pub fn description(&self) -> Option<&str> {
+        match self {
+            Self::Object(obj) => obj.model().and_then(|model| model.documentation.as_deref()),
+            _ => None,
+        }
+    }
+
+    pub fn of_type(&self) -> Option<&IntrospectionType> {
+        match self {
+            Self::List(inner) | Self::NonNull(inner) => Some(&**inner),
+            _ => None,
+        }
+    }
+}
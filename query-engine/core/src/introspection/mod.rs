@@ -0,0 +1,434 @@
+//! GraphQL introspection of the query schema.
+//!
+//! The `__schema` and `__type` meta fields are resolved on the query type without touching the database,
+//! the `__typename` meta field is available on every object type.
+mod introspection_type;
+
+pub use introspection_type::*;
+
+use crate::{
+    query_document::{Operation, QueryParserError, QueryParserResult, QueryValue, Selection},
+    response_ir::{Item, Map, Responses},
+    schema::*,
+};
+use indexmap::IndexMap;
+use prisma_models::{dml, PrismaValue};
+use std::sync::Arc;
+
+pub const TYPENAME_FIELD: &str = "__typename";
+pub const SCHEMA_FIELD: &str = "__schema";
+pub const TYPE_FIELD: &str = "__type";
+
+/// Schema field backing `__typename` selections, which resolve to the name of the enclosing object type.
+pub fn typename_field() -> FieldRef {
+    Arc::new(Field {
+        name: TYPENAME_FIELD.to_owned(),
+        arguments: vec![],
+        field_type: Arc::new(OutputType::string()),
+        query_builder: None,
+        description: None,
+    })
+}
+
+/// Resolves introspection operations against a query schema.
+pub struct IntrospectionResolver {
+    query_schema: QuerySchemaRef,
+
+    /// All named types reachable from the query and mutation type, by name.
+    types: IndexMap<String, IntrospectionType>,
+}
+
+impl IntrospectionResolver {
+    pub fn new(query_schema: QuerySchemaRef) -> Self {
+        let mut types = IndexMap::new();
+
+        Self::collect_output_type(&query_schema.query, &mut types);
+        Self::collect_output_type(&query_schema.mutation, &mut types);
+
+        for name in SCALAR_NAMES {
+            types
+                .entry(name.to_string())
+                .or_insert(IntrospectionType::Scalar(*name));
+        }
+
+        Self { query_schema, types }
+    }
+
+    /// True if the operation selects one of the meta fields of the root types.
+    pub fn is_introspection(operation: &Operation) -> bool {
+        match operation {
+            Operation::Read(selection) => selection.name().starts_with("__"),
+            Operation::Write(selection) => selection.name() == TYPENAME_FIELD,
+        }
+    }
+
+    pub fn resolve(&self, operation: Operation) -> crate::Result<Responses> {
+        let (selection, root_type) = match operation {
+            Operation::Read(selection) => (selection, self.query_schema.query()),
+            Operation::Write(selection) => (selection, self.query_schema.mutation()),
+        };
+
+        let item = self.resolve_root_field(&selection, root_type.name()).map_err(|err| {
+            QueryParserError::ObjectValidationError {
+                object_name: root_type.name().to_owned(),
+                inner: Box::new(err),
+            }
+        })?;
+
+        let mut responses = Responses::with_capacity(1);
+        responses.insert_data(response_key(&selection), item);
+
+        Ok(responses)
+    }
+
+    fn resolve_root_field(&self, selection: &Selection, root_type_name: &str) -> QueryParserResult<Item> {
+        let item = match selection.name() {
+            TYPENAME_FIELD => check_arguments(selection, &[]).map(|_| string(root_type_name)),
+            SCHEMA_FIELD if root_type_name == self.query_schema.query().name() => {
+                check_arguments(selection, &[]).and_then(|_| self.resolve_schema(selection.nested_selections()))
+            }
+            TYPE_FIELD if root_type_name == self.query_schema.query().name() => check_arguments(selection, &["name"])
+                .and_then(|_| {
+                    let name = match argument(selection, "name") {
+                        Some(QueryValue::String(name)) => name,
+                        Some(value) => {
+                            return Err(QueryParserError::ArgumentValidationError {
+                                argument: "name".to_owned(),
+                                inner: Box::new(QueryParserError::ValueParseError(format!(
+                                    "Expected a string, got {:?}.",
+                                    value
+                                ))),
+                            })
+                        }
+                        None => {
+                            return Err(QueryParserError::ArgumentValidationError {
+                                argument: "name".to_owned(),
+                                inner: Box::new(QueryParserError::RequiredValueNotSetError),
+                            })
+                        }
+                    };
+
+                    match self.types.get(name) {
+                        Some(typ) => self.resolve_type(typ, selection.nested_selections()),
+                        None => Ok(Item::null()),
+                    }
+                }),
+            _ => Err(QueryParserError::FieldNotFoundError),
+        };
+
+        item.map_err(|err| field_error(selection, err))
+    }
+
+    fn resolve_schema(&self, selections: &[Selection]) -> QueryParserResult<Item> {
+        resolve_object("__Schema", selections, |selection| {
+            let item = match selection.name() {
+                "description" => Item::null(),
+                "types" => {
+                    let types = self
+                        .types
+                        .values()
+                        .map(|typ| self.resolve_type(typ, selection.nested_selections()))
+                        .collect::<QueryParserResult<Vec<_>>>()?;
+
+                    Item::list(types)
+                }
+                "queryType" => self.resolve_type(
+                    &IntrospectionType::Object(self.query_schema.query()),
+                    selection.nested_selections(),
+                )?,
+                "mutationType" => self.resolve_type(
+                    &IntrospectionType::Object(self.query_schema.mutation()),
+                    selection.nested_selections(),
+                )?,
+                "subscriptionType" => Item::null(),
+
+                // Directives are not supported by the protocol adapter.
+                "directives" => Item::list(vec![]),
+                _ => return Err(QueryParserError::FieldNotFoundError),
+            };
+
+            Ok(item)
+        })
+    }
+
+    fn resolve_type(&self, typ: &IntrospectionType, selections: &[Selection]) -> QueryParserResult<Item> {
+        resolve_object("__Type", selections, |selection| {
+            let nested = selection.nested_selections();
+
+            let item = match selection.name() {
+                "kind" => Item::Value(PrismaValue::Enum(typ.kind().to_owned())),
+                "name" => optional_string(typ.name()),
+                "description" => optional_string(typ.description()),
+                "specifiedByUrl" => Item::null(),
+                "fields" => {
+                    check_arguments(selection, &["includeDeprecated"])?;
+
+                    match typ {
+                        IntrospectionType::Object(obj) => {
+                            let fields = obj
+                                .get_fields()
+                                .iter()
+                                .map(|field| self.resolve_field(field, nested))
+                                .collect::<QueryParserResult<Vec<_>>>()?;
+
+                            Item::list(fields)
+                        }
+                        _ => Item::null(),
+                    }
+                }
+                "interfaces" => match typ {
+                    IntrospectionType::Object(_) => Item::list(vec![]),
+                    _ => Item::null(),
+                },
+                "possibleTypes" => Item::null(),
+                "enumValues" => {
+                    check_arguments(selection, &["includeDeprecated"])?;
+
+                    match typ {
+                        IntrospectionType::Enum(et) => {
+                            let values = et
+                                .values()
+                                .into_iter()
+                                .map(|value| self.resolve_enum_value(value, nested))
+                                .collect::<QueryParserResult<Vec<_>>>()?;
+
+                            Item::list(values)
+                        }
+                        _ => Item::null(),
+                    }
+                }
+                "inputFields" => match typ {
+                    IntrospectionType::InputObject(obj) => {
+                        let fields = obj
+                            .get_fields()
+                            .iter()
+                            .map(|field| {
+                                self.resolve_input_value(
+                                    &field.name,
+                                    field.description.as_deref(),
+                                    &field.field_type,
+                                    &field.default_value,
+                                    nested,
+                                )
+                            })
+                            .collect::<QueryParserResult<Vec<_>>>()?;
+
+                        Item::list(fields)
+                    }
+                    _ => Item::null(),
+                },
+                "ofType" => match typ.of_type() {
+                    Some(inner) => self.resolve_type(inner, nested)?,
+                    None => Item::null(),
+                },
+                _ => return Err(QueryParserError::FieldNotFoundError),
+            };
+
+            Ok(item)
+        })
+    }
+
+    fn resolve_field(&self, field: &FieldRef, selections: &[Selection]) -> QueryParserResult<Item> {
+        resolve_object("__Field", selections, |selection| {
+            let nested = selection.nested_selections();
+
+            let item = match selection.name() {
+                "name" => string(&field.name),
+                "description" => optional_string(field.description.as_deref()),
+                "args" => {
+                    let args = field
+                        .arguments
+                        .iter()
+                        .map(|arg| {
+                            self.resolve_input_value(&arg.name, None, &arg.argument_type, &arg.default_value, nested)
+                        })
+                        .collect::<QueryParserResult<Vec<_>>>()?;
+
+                    Item::list(args)
+                }
+                "type" => self.resolve_type(&IntrospectionType::from_output(&field.field_type), nested)?,
+                "isDeprecated" => Item::Value(PrismaValue::Boolean(false)),
+                "deprecationReason" => Item::null(),
+                _ => return Err(QueryParserError::FieldNotFoundError),
+            };
+
+            Ok(item)
+        })
+    }
+
+    fn resolve_input_value(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        typ: &InputType,
+        default_value: &Option<dml::DefaultValue>,
+        selections: &[Selection],
+    ) -> QueryParserResult<Item> {
+        resolve_object("__InputValue", selections, |selection| {
+            let item = match selection.name() {
+                "name" => string(name),
+                "description" => optional_string(description),
+                "type" => self.resolve_type(&IntrospectionType::from_input(typ), selection.nested_selections())?,
+                "defaultValue" => optional_string(default_value.as_ref().and_then(default_value_literal).as_deref()),
+                _ => return Err(QueryParserError::FieldNotFoundError),
+            };
+
+            Ok(item)
+        })
+    }
+
+    fn resolve_enum_value(&self, value: String, selections: &[Selection]) -> QueryParserResult<Item> {
+        resolve_object("__EnumValue", selections, |selection| {
+            let item = match selection.name() {
+                "name" => string(&value),
+                "description" => Item::null(),
+                "isDeprecated" => Item::Value(PrismaValue::Boolean(false)),
+                "deprecationReason" => Item::null(),
+                _ => return Err(QueryParserError::FieldNotFoundError),
+            };
+
+            Ok(item)
+        })
+    }
+
+    /// Named types are registered before their fields are traversed, which stops the recursion on cyclic references.
+    fn collect_output_type(typ: &OutputType, types: &mut IndexMap<String, IntrospectionType>) {
+        match typ {
+            OutputType::Opt(inner) | OutputType::List(inner) => Self::collect_output_type(inner, types),
+            OutputType::Enum(et) | OutputType::Scalar(ScalarType::Enum(et)) => {
+                Self::collect_named_type(IntrospectionType::Enum(Arc::clone(et)), types);
+            }
+            OutputType::Scalar(_) => (),
+            OutputType::Object(obj) => {
+                let obj = obj.into_arc();
+
+                if Self::collect_named_type(IntrospectionType::Object(Arc::clone(&obj)), types) {
+                    for field in obj.get_fields() {
+                        for arg in field.arguments.iter() {
+                            Self::collect_input_type(&arg.argument_type, types);
+                        }
+
+                        Self::collect_output_type(&field.field_type, types);
+                    }
+                }
+            }
+        }
+    }
+
+    fn collect_input_type(typ: &InputType, types: &mut IndexMap<String, IntrospectionType>) {
+        match typ {
+            InputType::Opt(inner) | InputType::Null(inner) | InputType::List(inner) => {
+                Self::collect_input_type(inner, types)
+            }
+            InputType::Enum(et) | InputType::Scalar(ScalarType::Enum(et)) => {
+                Self::collect_named_type(IntrospectionType::Enum(Arc::clone(et)), types);
+            }
+            InputType::Scalar(_) => (),
+            InputType::Object(obj) => {
+                let obj = obj.into_arc();
+
+                if Self::collect_named_type(IntrospectionType::InputObject(Arc::clone(&obj)), types) {
+                    for field in obj.get_fields() {
+                        Self::collect_input_type(&field.field_type, types);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns true if the type wasn't collected before.
+    fn collect_named_type(typ: IntrospectionType, types: &mut IndexMap<String, IntrospectionType>) -> bool {
+        let name = typ.name().expect("Named types have a name.").to_owned();
+
+        if types.contains_key(&name) {
+            false
+        } else {
+            types.insert(name, typ);
+            true
+        }
+    }
+}
+
+/// Resolves the selections on an introspection object type. `__typename` is resolved here for all object types.
+fn resolve_object<F>(object_name: &str, selections: &[Selection], resolve_field: F) -> QueryParserResult<Item>
+where
+    F: Fn(&Selection) -> QueryParserResult<Item>,
+{
+    let wrap = |err| QueryParserError::ObjectValidationError {
+        object_name: object_name.to_owned(),
+        inner: Box::new(err),
+    };
+
+    if selections.is_empty() {
+        return Err(wrap(QueryParserError::AtLeastOneSelectionError));
+    }
+
+    let mut map = Map::with_capacity(selections.len());
+
+    for selection in selections {
+        let item = match selection.name() {
+            TYPENAME_FIELD => string(object_name),
+            _ => resolve_field(selection).map_err(|err| wrap(field_error(selection, err)))?,
+        };
+
+        map.insert(response_key(selection), item);
+    }
+
+    Ok(Item::Map(map))
+}
+
+fn check_arguments(selection: &Selection, allowed: &[&str]) -> QueryParserResult<()> {
+    match selection
+        .arguments()
+        .iter()
+        .find(|(name, _)| !allowed.contains(&name.as_str()))
+    {
+        Some((name, _)) => Err(QueryParserError::ArgumentValidationError {
+            argument: name.clone(),
+            inner: Box::new(QueryParserError::ArgumentNotFoundError),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn argument<'a>(selection: &'a Selection, name: &str) -> Option<&'a QueryValue> {
+    selection
+        .arguments()
+        .iter()
+        .find_map(|(arg_name, value)| if arg_name == name { Some(value) } else { None })
+}
+
+fn field_error(selection: &Selection, err: QueryParserError) -> QueryParserError {
+    QueryParserError::FieldValidationError {
+        field_name: selection.name().to_owned(),
+        inner: Box::new(err),
+    }
+}
+
+fn response_key(selection: &Selection) -> String {
+    selection.alias().clone().unwrap_or_else(|| selection.name().to_owned())
+}
+
+fn string(s: &str) -> Item {
+    Item::Value(PrismaValue::String(s.to_owned()))
+}
+
+fn optional_string(s: Option<&str>) -> Item {
+    s.map(string).unwrap_or_else(Item::null)
+}
+
+/// Default values are rendered as GraphQL literals. Generated defaults, like `now()`, have no literal.
+fn default_value_literal(default_value: &dml::DefaultValue) -> Option<String> {
+    match default_value {
+        dml::DefaultValue::Single(PrismaValue::String(s)) => serde_json::to_string(s).ok(),
+        dml::DefaultValue::Single(value @ PrismaValue::Decimal(_))
+        | dml::DefaultValue::Single(value @ PrismaValue::BigInt(_))
+        | dml::DefaultValue::Single(value @ PrismaValue::Bytes(_)) => serde_json::to_string(&value.to_string()).ok(),
+        dml::DefaultValue::Single(value @ PrismaValue::Int(_))
+        | dml::DefaultValue::Single(value @ PrismaValue::Float(_))
+        | dml::DefaultValue::Single(value @ PrismaValue::Boolean(_))
+        | dml::DefaultValue::Single(value @ PrismaValue::Enum(_))
+        | dml::DefaultValue::Single(value @ PrismaValue::Null) => Some(value.to_string()),
+        _ => None,
+    }
+}
@@ -9,6 +9,7 @@ extern crate debug_stub_derive;
 pub mod error;
 pub mod executor;
 pub mod interpreter;
+pub mod introspection;
 pub mod query_ast;
 pub mod query_document;
 pub mod query_graph;
@@ -21,6 +22,7 @@ pub mod schema_builder;
 pub use error::*;
 pub use executor::*;
 pub use interpreter::*;
+pub use introspection::*;
 pub use query_ast::*;
 pub use query_document::*;
 pub use query_graph::*;
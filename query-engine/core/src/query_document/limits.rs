@@ -0,0 +1,116 @@
+//! Limits bounding the amount of work a single request can cause.
+//! The shape of a request is checked statically on the parsed document before any query graph is built,
+//! the number of records is tracked while the request is interpreted.
+use super::{ParsedField, ParsedInputValue};
+use prisma_value::PrismaValue;
+use std::fmt;
+
+/// Configurable limits for requests. `None` means unlimited.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct QueryLimits {
+    /// Maximum nesting of selections, the root field of an operation being at depth 1.
+    pub max_depth: Option<usize>,
+
+    /// Maximum number of nested relation selections in one operation.
+    pub max_relation_reads: Option<usize>,
+
+    /// Maximum absolute value of any `take` argument.
+    pub max_take: Option<i64>,
+
+    /// Maximum number of records read from the database while executing one operation.
+    pub max_records: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryLimitError {
+    Depth { field: String, limit: usize },
+    RelationReads { limit: usize },
+    Take { field: String, take: i64, limit: i64 },
+    Records { limit: usize },
+}
+
+impl fmt::Display for QueryLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Depth { field, limit } => write!(
+                f,
+                "The selection of `{}` exceeds the maximum selection depth of {}.",
+                field, limit
+            ),
+            Self::RelationReads { limit } => write!(
+                f,
+                "The query selects more than the maximum of {} nested relations.",
+                limit
+            ),
+            Self::Take { field, take, limit } => write!(
+                f,
+                "The `take` value of {} on `{}` exceeds the maximum of {}.",
+                take, field, limit
+            ),
+            Self::Records { limit } => write!(f, "The query reads more than the maximum of {} records.", limit),
+        }
+    }
+}
+
+impl QueryLimits {
+    /// Statically checks the root field of an operation and its nested selections against the limits.
+    pub fn check(&self, field: &ParsedField) -> Result<(), QueryLimitError> {
+        let mut relation_reads = 0;
+
+        self.check_field(field, 1, &mut relation_reads)
+    }
+
+    fn check_field(
+        &self,
+        field: &ParsedField,
+        depth: usize,
+        relation_reads: &mut usize,
+    ) -> Result<(), QueryLimitError> {
+        match self.max_depth {
+            Some(limit) if depth > limit => {
+                return Err(QueryLimitError::Depth {
+                    field: field.name.clone(),
+                    limit,
+                })
+            }
+            _ => (),
+        };
+
+        if let Some(limit) = self.max_take {
+            let take = field.arguments.iter().find_map(|arg| match arg.value {
+                ParsedInputValue::Single(PrismaValue::Int(take)) if arg.name == "take" => Some(take),
+                _ => None,
+            });
+
+            match take {
+                Some(take) if take.abs() > limit => {
+                    return Err(QueryLimitError::Take {
+                        field: field.name.clone(),
+                        take,
+                        limit,
+                    })
+                }
+                _ => (),
+            };
+        }
+
+        let nested_objects = field
+            .nested_fields
+            .iter()
+            .flat_map(|nested| nested.fields.iter())
+            .filter(|nested_field| nested_field.nested_fields.is_some());
+
+        for nested_field in nested_objects {
+            *relation_reads += 1;
+
+            match self.max_relation_reads {
+                Some(limit) if *relation_reads > limit => return Err(QueryLimitError::RelationReads { limit }),
+                _ => (),
+            };
+
+            self.check_field(nested_field, depth + 1, relation_reads)?;
+        }
+
+        Ok(())
+    }
+}
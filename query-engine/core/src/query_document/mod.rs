@@ -15,6 +15,7 @@
 //!    - it can have a number of nested selections (selection set in GQL).
 //! - Arguments contain concrete values and complex subtypes that are parsed and validated by the query builders, and then used for querying data (input types in GQL).
 mod error;
+mod limits;
 mod operation;
 mod parse_ast;
 mod parser;
@@ -23,6 +24,7 @@ mod selection;
 mod transformers;
 
 pub use error::*;
+pub use limits::*;
 pub use operation::*;
 pub use parse_ast::*;
 pub use parser::*;
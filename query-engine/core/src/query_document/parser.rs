@@ -1,5 +1,5 @@
 use super::*;
-use crate::schema::*;
+use crate::{introspection::*, schema::*};
 use chrono::prelude::*;
 use prisma_value::PrismaValue;
 use rust_decimal::{prelude::ToPrimitive, Decimal};
@@ -38,6 +38,7 @@ impl QueryDocumentParser {
             .map(|selection| {
                 let parsed_field = match schema_object.find_field(selection.name()) {
                     Some(ref field) => Self::parse_field(selection, field),
+                    None if selection.name() == TYPENAME_FIELD => Self::parse_field(selection, &typename_field()),
                     None => Err(QueryParserError::FieldValidationError {
                         field_name: selection.name().into(),
                         inner: Box::new(QueryParserError::FieldNotFoundError),
@@ -187,6 +188,14 @@ impl QueryDocumentParser {
             },
             (QueryValue::Boolean(b), ScalarType::Boolean) => Ok(PrismaValue::Boolean(b)),
 
+            // Decimals and big ints are accepted as strings as well, as JSON numbers can't represent them exactly.
+            (QueryValue::String(s), ScalarType::Decimal)  => Self::parse_decimal(s.as_str()).map(PrismaValue::Decimal),
+            (QueryValue::Int(i), ScalarType::Decimal)     => Ok(PrismaValue::Decimal(Decimal::from(i))),
+            (QueryValue::Float(f), ScalarType::Decimal)   => Ok(PrismaValue::Decimal(f)),
+            (QueryValue::String(s), ScalarType::BigInt)   => Self::parse_big_int(s.as_str()).map(PrismaValue::BigInt),
+            (QueryValue::Int(i), ScalarType::BigInt)      => Ok(PrismaValue::BigInt(i)),
+            (QueryValue::String(s), ScalarType::Bytes)    => Self::parse_bytes(s.as_str()).map(PrismaValue::Bytes),
+
             // All other combinations are invalid.
             (qv, _)                                       => Err(QueryParserError::ValueTypeMismatchError { have: qv, want: InputType::Scalar(scalar_type.clone()) }),
         }
@@ -228,6 +237,21 @@ impl QueryDocumentParser {
         serde_json::from_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid json: {}", err)))
     }
 
+    pub fn parse_decimal(s: &str) -> QueryParserResult<Decimal> {
+        s.parse::<Decimal>()
+            .map_err(|err| QueryParserError::ValueParseError(format!("Invalid Decimal: {}", err)))
+    }
+
+    pub fn parse_big_int(s: &str) -> QueryParserResult<i64> {
+        s.parse::<i64>()
+            .map_err(|err| QueryParserError::ValueParseError(format!("Invalid BigInt: {}", err)))
+    }
+
+    pub fn parse_bytes(s: &str) -> QueryParserResult<Vec<u8>> {
+        prisma_value::decode_bytes(s)
+            .map_err(|_| QueryParserError::ValueParseError("Invalid Bytes: expected a base64 encoded string".into()))
+    }
+
     pub fn parse_uuid(s: &str) -> QueryParserResult<Uuid> {
         Uuid::parse_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid UUID: {}", err)))
     }
@@ -268,6 +292,13 @@ impl QueryDocumentParser {
                     raw, ord.name
                 ))),
             },
+            EnumType::FieldRef(f) => match f.value_for(raw.as_str()) {
+                Some(field) => Ok(ParsedInputValue::ScalarField(field.clone())),
+                None => Err(QueryParserError::ValueParseError(format!(
+                    "Enum value '{}' is invalid for enum type {}",
+                    raw, f.name
+                ))),
+            },
         }
     }
 
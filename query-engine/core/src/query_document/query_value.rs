@@ -36,6 +36,9 @@ impl From<PrismaValue> for QueryValue {
             PrismaValue::Null => Self::Null,
             PrismaValue::Uuid(u) => Self::String(u.to_hyphenated().to_string()),
             PrismaValue::Json(s) => Self::String(s),
+            PrismaValue::Decimal(d) => Self::String(d.to_string()),
+            PrismaValue::BigInt(i) => Self::String(i.to_string()),
+            PrismaValue::Bytes(b) => Self::String(prisma_value::encode_bytes(&b)),
         }
     }
 }
@@ -267,6 +267,16 @@ impl QueryGraph {
                 .is_some()
     }
 
+    /// Returns all nodes of the graph, in order of creation.
+    pub fn nodes(&self) -> Vec<NodeRef> {
+        self.graph.node_indices().map(|node_ix| NodeRef { node_ix }).collect()
+    }
+
+    /// Returns all edges of the graph, in order of creation.
+    pub fn edges(&self) -> Vec<EdgeRef> {
+        self.graph.edge_indices().map(|edge_ix| EdgeRef { edge_ix }).collect()
+    }
+
     /// Returns all root nodes of the graph.
     /// A root node is defined by having no incoming edges.
     pub fn root_nodes(&self) -> Vec<NodeRef> {
@@ -6,6 +6,7 @@ use prisma_value::PrismaValue;
 // the query_document module, possibly already as part of the parser.
 pub struct QueryGraphBuilder {
     pub query_schema: QuerySchemaRef,
+    limits: QueryLimits,
 }
 
 pub enum QueryType {
@@ -17,6 +18,23 @@ pub enum QueryType {
     },
 }
 
+/// An operation that has been parsed and validated against the query schema, see `QueryGraphBuilder::parse`.
+#[derive(Debug, Clone)]
+pub struct ParsedOperation {
+    selection: Selection,
+    field: ParsedField,
+    is_write: bool,
+}
+
+impl ParsedOperation {
+    /// Whether the operation can be built and executed again. Writes can't: parsing fills in the
+    /// defaults of unset input fields, which for `@default(cuid())`, `@default(now())` and the like
+    /// are generated anew for every record.
+    pub fn is_reusable(&self) -> bool {
+        !self.is_write
+    }
+}
+
 impl QueryType {
     pub fn needs_transaction(&self) -> bool {
         match self {
@@ -57,50 +75,58 @@ impl From<Vec<ParsedArgument>> for RawArgs {
 
 impl QueryGraphBuilder {
     pub fn new(query_schema: QuerySchemaRef) -> Self {
-        Self { query_schema }
-    }
-
-    /// Maps an operation to a query.
-    pub fn build(self, operation: Operation) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
-        match operation {
-            Operation::Read(selection) => self.map_read_operation(selection),
-            Operation::Write(selection) => self.map_write_operation(selection),
+        Self {
+            query_schema,
+            limits: QueryLimits::default(),
         }
     }
 
-    /// Maps a read operation to one or more queries.
-    fn map_read_operation(&self, read_selection: Selection) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
-        let query_object = self.query_schema.query();
-        Self::process(read_selection, &query_object)
+    /// Limits the shape of the operations that are built. Checked on the parsed operation.
+    pub fn with_limits(mut self, limits: QueryLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
-    /// Maps a write operation to one or more queries.
-    fn map_write_operation(&self, write_selection: Selection) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
-        let mutation_object = self.query_schema.mutation();
-
-        let (mut graph, ir_ser) = Self::process(write_selection, &mutation_object)?;
+    /// Maps an operation to a query.
+    pub fn build(self, operation: Operation) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
+        let parsed = self.parse(operation)?;
+        self.build_parsed(parsed)
+    }
 
-        if let QueryType::Graph(ref mut graph) = graph {
-            graph.flag_transactional();
+    /// Parses and validates an operation against the query schema. The result can be kept and
+    /// built into a query again, as long as the query schema doesn't change.
+    pub fn parse(&self, operation: Operation) -> QueryGraphBuilderResult<ParsedOperation> {
+        let (selection, object, is_write) = match operation {
+            Operation::Read(selection) => (selection, self.query_schema.query(), false),
+            Operation::Write(selection) => (selection, self.query_schema.mutation(), true),
         };
 
-        Ok((graph, ir_ser))
+        let mut parsed_object = QueryDocumentParser::parse_object(&[selection.clone()], &object)?;
+        let field = parsed_object.fields.pop().unwrap();
+
+        Ok(ParsedOperation {
+            selection,
+            field,
+            is_write,
+        })
     }
 
-    fn process(
-        selection: Selection,
-        object: &ObjectTypeStrongRef,
-    ) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
-        let mut selections = vec![selection];
-        let mut parsed_object = QueryDocumentParser::parse_object(&selections, object)?;
+    /// Maps an already parsed operation to a query.
+    pub fn build_parsed(self, operation: ParsedOperation) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
+        let ParsedOperation {
+            selection,
+            field: parsed_field,
+            is_write,
+        } = operation;
 
-        let parsed_field = parsed_object.fields.pop().unwrap();
-        let result_info = Self::derive_serializer(&selections.pop().unwrap(), &parsed_field);
+        self.limits.check(&parsed_field)?;
+
+        let result_info = Self::derive_serializer(&selection, &parsed_field);
 
         let schema_field = parsed_field.schema_field.clone();
         let builder = schema_field.query_builder();
 
-        let query_type = match (builder, parsed_field.raw_query_type()) {
+        let mut query_type = match (builder, parsed_field.raw_query_type()) {
             (Some(builder), None) => Ok(QueryType::Graph(builder.build(parsed_field)?)),
             (_, Some(raw_type)) => {
                 let raw_args = RawArgs::from(parsed_field.arguments);
@@ -113,11 +139,17 @@ impl QueryGraphBuilder {
             }
             (None, None) => Err(QueryGraphBuilderError::SchemaError(format!(
                 "Expected attached query builder on {} object, root level field '{}'.",
-                object.name(),
+                if is_write { "Mutation" } else { "Query" },
                 parsed_field.name
             ))),
         }?;
 
+        if let QueryType::Graph(ref mut graph) = query_type {
+            if is_write {
+                graph.flag_transactional();
+            }
+        };
+
         Ok((query_type, result_info))
     }
 
@@ -1,4 +1,4 @@
-use crate::{QueryGraphError, QueryParserError};
+use crate::{QueryGraphError, QueryLimitError, QueryParserError};
 use prisma_models::{DomainError, RelationFieldRef};
 
 #[derive(Debug)]
@@ -40,6 +40,9 @@ pub enum QueryGraphBuilderError {
     RecordNotFound(String),
 
     QueryGraphError(QueryGraphError),
+
+    /// The query document exceeds one of the configured query limits.
+    QueryLimitError(QueryLimitError),
 }
 
 #[derive(Debug)]
@@ -94,3 +97,9 @@ impl From<QueryGraphError> for QueryGraphBuilderError {
         QueryGraphBuilderError::QueryGraphError(err)
     }
 }
+
+impl From<QueryLimitError> for QueryGraphBuilderError {
+    fn from(err: QueryLimitError) -> Self {
+        QueryGraphBuilderError::QueryLimitError(err)
+    }
+}
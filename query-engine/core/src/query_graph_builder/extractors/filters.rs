@@ -3,9 +3,14 @@ use crate::{
     query_document::{ParsedInputMap, ParsedInputValue},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{filter::Filter, RelationCompare, ScalarCompare};
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
-use std::{collections::BTreeMap, convert::TryInto};
+use connector::{
+    filter::{
+        CompositeCondition, CompositeFieldCondition, CompositeFieldFilter, CompositeFilter, Filter, ScalarCondition,
+    },
+    RelationCompare, ScalarCompare,
+};
+use prisma_models::{Field, InternalCompositeType, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
+use std::{collections::BTreeMap, convert::TryInto, sync::Arc};
 
 static FILTER_OPERATIONS: &'static [FilterOp] = &[
     FilterOp::NotIn,
@@ -157,6 +162,9 @@ pub fn extract_filter(
                     // Always try to resolve regular fields first. If that fails, try to resolve compound fields.
                     match model.fields().find_from_all(&field_name) {
                         Ok(field) => match field {
+                            Field::Scalar(field) if field.composite_type.is_some() => {
+                                handle_composite_field(field, value)
+                            }
                             Field::Scalar(field) => handle_scalar_field(field, value, &op),
                             Field::Relation(field) => handle_relation_field(field, value, &op),
                         },
@@ -203,6 +211,77 @@ fn handle_scalar_field(
     })
 }
 
+/// Composite filters are evaluated in memory, see `CompositeFilter`.
+fn handle_composite_field(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+    let composite_type = field.composite_type.as_ref().unwrap();
+    let value: Option<ParsedInputMap> = value.try_into()?;
+
+    Ok(Filter::from(CompositeFilter {
+        field: Arc::clone(field),
+        condition: extract_composite_condition(value, composite_type)?,
+    }))
+}
+
+fn extract_composite_condition(
+    value: Option<ParsedInputMap>,
+    composite_type: &InternalCompositeType,
+) -> QueryGraphBuilderResult<CompositeCondition> {
+    let value_map = match value {
+        Some(value_map) => value_map,
+        None => return Ok(CompositeCondition::IsNull),
+    };
+
+    let filters = value_map
+        .into_iter()
+        .map(|(key, value): (String, ParsedInputValue)| {
+            let op = FilterOp::find_op(key.as_str());
+            let field_name = key.trim_end_matches(op.suffix());
+            let field = composite_type
+                .find_field(field_name)
+                .ok_or(QueryGraphBuilderError::AssertionError(format!(
+                    "Unable to resolve field {} on composite type {}",
+                    field_name, composite_type.name
+                )))?;
+
+            let condition = match field.composite_type {
+                Some(ref nested) => {
+                    CompositeFieldCondition::Composite(extract_composite_condition(value.try_into()?, nested)?)
+                }
+                None => CompositeFieldCondition::Scalar(scalar_condition(value.try_into()?, &op)),
+            };
+
+            Ok(CompositeFieldFilter {
+                field: field.clone(),
+                condition,
+            })
+        })
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    Ok(CompositeCondition::Fields(filters))
+}
+
+fn scalar_condition(value: PrismaValue, op: &FilterOp) -> ScalarCondition {
+    match (op, value) {
+        (FilterOp::In, PrismaValue::Null) => ScalarCondition::Equals(PrismaValue::Null),
+        (FilterOp::In, PrismaValue::List(values)) => ScalarCondition::In(values),
+        (FilterOp::NotIn, PrismaValue::Null) => ScalarCondition::NotEquals(PrismaValue::Null),
+        (FilterOp::NotIn, PrismaValue::List(values)) => ScalarCondition::NotIn(values),
+        (FilterOp::Not, val) => ScalarCondition::NotEquals(val),
+        (FilterOp::Lt, val) => ScalarCondition::LessThan(val),
+        (FilterOp::Lte, val) => ScalarCondition::LessThanOrEquals(val),
+        (FilterOp::Gt, val) => ScalarCondition::GreaterThan(val),
+        (FilterOp::Gte, val) => ScalarCondition::GreaterThanOrEquals(val),
+        (FilterOp::Contains, val) => ScalarCondition::Contains(val),
+        (FilterOp::NotContains, val) => ScalarCondition::NotContains(val),
+        (FilterOp::StartsWith, val) => ScalarCondition::StartsWith(val),
+        (FilterOp::NotStartsWith, val) => ScalarCondition::NotStartsWith(val),
+        (FilterOp::EndsWith, val) => ScalarCondition::EndsWith(val),
+        (FilterOp::NotEndsWith, val) => ScalarCondition::NotEndsWith(val),
+        (FilterOp::Field, val) => ScalarCondition::Equals(val),
+        (_, _) => unreachable!(),
+    }
+}
+
 fn handle_relation_field(
     field: &RelationFieldRef,
     value: ParsedInputValue,
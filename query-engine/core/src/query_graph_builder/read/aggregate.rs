@@ -1,5 +1,7 @@
 use super::*;
-use crate::{query_document::ParsedField, AggregateRecordsQuery, AggregationQuery, ReadQuery};
+use crate::{
+    introspection::TYPENAME_FIELD, query_document::ParsedField, AggregateRecordsQuery, AggregationQuery, ReadQuery,
+};
 use prisma_models::ModelRef;
 
 pub struct AggregateRecordsBuilder {
@@ -34,6 +36,7 @@ impl Builder<ReadQuery> for AggregateRecordsBuilder {
 
         let queries: Vec<_> = nested_fields
             .into_iter()
+            .filter(|field| field.name != TYPENAME_FIELD)
             .map(|field| Self::resolve_query(field, &model))
             .collect::<QueryGraphBuilderResult<_>>()?;
 
@@ -9,7 +9,8 @@ pub use one::*;
 pub use related::*;
 
 use super::*;
-use crate::{query_document::ParsedField, ReadQuery};
+use crate::{introspection::TYPENAME_FIELD, query_document::ParsedField, ReadQuery};
+use connector::QueryArguments;
 use prisma_models::{Field, ModelProjection, ModelRef, RelationFieldRef};
 use std::sync::Arc;
 
@@ -65,6 +66,7 @@ pub fn collect_selected_fields(from: &[ParsedField], model: &ModelRef) -> ModelP
 
 pub fn collect_nested_queries(from: Vec<ParsedField>, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
     from.into_iter()
+        .filter(|selected_field| selected_field.name != TYPENAME_FIELD)
         .filter_map(|selected_field| {
             let model_field = model.fields().find_from_all(&selected_field.name).unwrap();
             match model_field {
@@ -115,3 +117,11 @@ pub fn merge_relation_selections(
 
     selected_fields.merge(ModelProjection::union(nested))
 }
+
+/// Distinct is processed in memory, which requires the fields to distinct on to be present in the result.
+pub fn merge_distinct_selections(selected_fields: ModelProjection, args: &QueryArguments) -> ModelProjection {
+    match args.distinct {
+        Some(ref distinct) => selected_fields.merge(distinct.clone()),
+        None => selected_fields,
+    }
+}
@@ -30,6 +30,7 @@ impl Builder<ReadQuery> for ReadRelatedRecordsBuilder {
         let nested = collect_nested_queries(sub_selections, &self.model)?;
         let parent_field = self.parent;
         let selected_fields = merge_relation_selections(selected_fields, Some(parent_field.clone()), &nested);
+        let selected_fields = merge_distinct_selections(selected_fields, &args);
 
         Ok(ReadQuery::RelatedRecordsQuery(RelatedRecordsQuery {
             name,
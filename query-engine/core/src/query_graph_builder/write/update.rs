@@ -23,7 +23,13 @@ pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     let data_argument = field.arguments.lookup("data").unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
 
-    let update_node = update_record_node(graph, filter, Arc::clone(&model), data_map)?;
+    let update_node = update_record_node(graph, filter.clone(), Arc::clone(&model), data_map)?;
+    let update_args = match graph.node_content(&update_node) {
+        Some(Node::Query(Query::Write(WriteQuery::UpdateRecord(ur)))) => ur.args.clone(),
+        _ => unreachable!(),
+    };
+
+    utils::insert_update_actions(graph, &model, filter, &update_args, &update_node)?;
 
     let read_query = ReadOneRecordBuilder::new(field, model.clone()).build()?;
     let read_node = graph.create_node(Query::Read(read_query));
@@ -72,13 +78,17 @@ pub fn update_many_records(
     let mut args = update_args.args;
     args.update_datetimes(Arc::clone(&model));
 
-    let record_filter = filter.into();
+    let record_filter = filter.clone().into();
     let update_many = WriteQuery::UpdateManyRecords(UpdateManyRecords {
-        model,
+        model: Arc::clone(&model),
         record_filter,
-        args,
+        args: args.clone(),
     });
-    graph.create_node(Query::Write(update_many));
+    let update_many_node = graph.create_node(Query::Write(update_many));
+
+    // Referential actions may add writes after the update, so its count needs to be the result.
+    graph.add_result_node(&update_many_node);
+    utils::insert_update_actions(graph, &model, filter, &args, &update_many_node)?;
 
     Ok(())
 }
@@ -3,9 +3,9 @@ use crate::{
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
     ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{Filter, QueryArguments, WriteArgs};
+use connector::{Filter, QueryArguments, RecordFilter, WriteArgs};
 use itertools::Itertools;
-use prisma_models::{ModelProjection, ModelRef, RelationFieldRef};
+use prisma_models::{dml::ReferentialAction, ModelProjection, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
 use std::sync::Arc;
 
 /// Coerces single values (`ParsedInputValue::Single` and `ParsedInputValue::Map`) into a vector.
@@ -257,17 +257,28 @@ pub fn insert_existing_1to1_related_model_checks(
     Ok(())
 }
 
-/// Inserts checks into the graph that check all required, non-list relations pointing to
-/// the given `model`. Those checks fail at runtime (edges to the `Empty` node) if one or more
-/// records are found. Checks are inserted between `parent_node` and `child_node`.
+/// Inserts checks and referential actions into the graph for all non-list relations pointing to
+/// the given `model`. Checks fail at runtime (edges to the `Empty` node) if one or more
+/// records are found. Checks and actions are inserted between `parent_node` and `child_node`.
+///
+/// The `onDelete` action of the referencing relation field decides what happens:
+/// - No action given: Required relations are checked, optional ones are left to the database.
+/// - `Restrict` and `NoAction`: Referencing records are checked.
+/// - `Cascade`: Referencing records are deleted, including their own referential actions.
+/// - `SetNull` and `SetDefault`: The referencing fields of the records are updated.
+///
+/// Emulating the actions in the graph makes them work regardless of whether the database enforces
+/// its foreign keys. Cascades that lead back to a model that is already being deleted are left
+/// to the database, as emulating them would never terminate.
 ///
 /// This function is usually part of a delete (`deleteOne` or `deleteMany`).
 /// Expects `parent_node` to return one or more IDs (for records of `model`) to be checked.
 ///
 /// ## Example for a standard delete scenario
-/// - We have 2 relations, from `A` and `B` to `model`.
+/// - We have 2 required relations, from `A` and `B` to `model`.
 /// - This function inserts the nodes and edges in between `Find Record IDs` (`parent_node`) and
 ///   `Delete` (`child_node`) into the graph (but not the edge from `Find` to `Delete`, assumed already existing here).
+/// - Referential actions are chained after the checks, each as a `Find Connected Model` node followed by its write.
 ///
 /// ```text
 ///    ┌────────────────────┐
@@ -302,51 +313,353 @@ pub fn insert_deletion_checks(
     model: &ModelRef,
     parent_node: &NodeRef,
     child_node: &NodeRef,
+) -> QueryGraphBuilderResult<()> {
+    insert_referential_actions(graph, model, parent_node, child_node, &mut vec![])
+}
+
+fn insert_referential_actions(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    parent_node: &NodeRef,
+    child_node: &NodeRef,
+    deleted_models: &mut Vec<String>,
 ) -> QueryGraphBuilderResult<()> {
     let internal_model = model.internal_data_model();
-    let relation_fields = internal_model.fields_requiring_model(model);
-    let mut check_nodes = vec![];
-
-    if relation_fields.len() > 0 {
-        let noop_node = graph.create_node(Node::Empty);
-
-        // We know that the relation can't be a list and must be required on the related model for `model` (see fields_requiring_model).
-        // For all requiring models (RM), we use the field on `model` to query for existing RM records and error out if at least one exists.
-        for rf in relation_fields {
-            let relation_field = rf.related_field();
-            let child_model_identifier = relation_field.related_model().primary_identifier();
-            let read_node = insert_find_children_by_parent_node(graph, parent_node, &relation_field, Filter::empty())?;
-
-            graph.create_edge(
-                &read_node,
-                &noop_node,
-                QueryGraphDependency::ParentProjection(
-                    child_model_identifier,
-                    Box::new(move |noop_node, child_ids| {
-                        if !child_ids.is_empty() {
-                            return Err(QueryGraphBuilderError::RelationViolation((relation_field).into()));
-                        }
-
-                        Ok(noop_node)
-                    }),
-                ),
-            )?;
-
-            check_nodes.push(read_node);
+    let relation_fields = internal_model.fields_referencing_model(model);
+    let (check_fields, action_fields): (Vec<_>, Vec<_>) = relation_fields
+        .into_iter()
+        .filter(|rf| match rf.relation_info.on_delete {
+            None => rf.is_required,
+            Some(ReferentialAction::Cascade) => !deleted_models.contains(&rf.model().name),
+            Some(_) => true,
+        })
+        .partition(|rf| match rf.relation_info.on_delete {
+            None | Some(ReferentialAction::Restrict) | Some(ReferentialAction::NoAction) => true,
+            _ => false,
+        });
+
+    if check_fields.is_empty() && action_fields.is_empty() {
+        return Ok(());
+    }
+
+    deleted_models.push(model.name.clone());
+
+    let noop_node = graph.create_node(Node::Empty);
+    let mut stages: Vec<(NodeRef, NodeRef)> = vec![];
+
+    // For all referencing models (RM), we use the field on `model` to query for existing RM records and error out if at least one exists.
+    for rf in check_fields {
+        let relation_field = rf.related_field();
+        let child_model_identifier = relation_field.related_model().primary_identifier();
+        let read_node = insert_find_children_by_parent_node(graph, parent_node, &relation_field, Filter::empty())?;
+
+        graph.create_edge(
+            &read_node,
+            &noop_node,
+            QueryGraphDependency::ParentProjection(
+                child_model_identifier,
+                Box::new(move |noop_node, child_ids| {
+                    if !child_ids.is_empty() {
+                        return Err(QueryGraphBuilderError::RelationViolation((relation_field).into()));
+                    }
+
+                    Ok(noop_node)
+                }),
+            ),
+        )?;
+
+        stages.push((read_node, read_node));
+    }
+
+    for rf in action_fields {
+        let relation_field = rf.related_field();
+        let read_node = insert_find_children_by_parent_node(graph, parent_node, &relation_field, Filter::empty())?;
+
+        let write_node = match rf.relation_info.on_delete {
+            Some(ReferentialAction::Cascade) => insert_cascading_delete(graph, &rf, &read_node, deleted_models)?,
+            Some(action) => insert_referencing_fields_update(graph, &rf, action, &read_node)?,
+            None => unreachable!("Relations without referential action are only checked."),
+        };
+
+        stages.push((read_node, write_node));
+    }
+
+    deleted_models.pop();
+
+    // Chains all stages with execution order dependencies, so every node only depends on its ancestors.
+    let last_stage = stages.into_iter().fold1(|(_, prev_last), (next_first, next_last)| {
+        graph
+            .create_edge(&prev_last, &next_first, QueryGraphDependency::ExecutionOrder)
+            .unwrap();
+
+        (next_first, next_last)
+    });
+
+    // Checks are already connected to the empty node, the last action write is not.
+    if let Some((first, last)) = last_stage {
+        if first != last {
+            graph.create_edge(&last, &noop_node, QueryGraphDependency::ExecutionOrder)?;
         }
+    }
 
-        // Connects all `Find Connected Model` nodes with execution order dependency from the example in the docs.
-        check_nodes.into_iter().fold1(|prev, next| {
-            graph
-                .create_edge(&prev, &next, QueryGraphDependency::ExecutionOrder)
-                .unwrap();
+    // Edge from empty node to the child (delete).
+    graph.create_edge(&noop_node, child_node, QueryGraphDependency::ExecutionOrder)?;
 
-            next
-        });
+    Ok(())
+}
+
+/// Deletes the records found by `read_node`, which reference the deleted records through `referencing_field`.
+/// Returns the delete node.
+fn insert_cascading_delete(
+    graph: &mut QueryGraph,
+    referencing_field: &RelationFieldRef,
+    read_node: &NodeRef,
+    deleted_models: &mut Vec<String>,
+) -> QueryGraphBuilderResult<NodeRef> {
+    let child_model = referencing_field.model();
+    let delete_many_node = graph.create_node(Query::Write(WriteQuery::DeleteManyRecords(DeleteManyRecords {
+        model: Arc::clone(&child_model),
+        record_filter: RecordFilter::empty(),
+    })));
+
+    insert_referential_actions(graph, &child_model, read_node, &delete_many_node, deleted_models)?;
+
+    graph.create_edge(
+        read_node,
+        &delete_many_node,
+        QueryGraphDependency::ParentProjection(
+            child_model.primary_identifier(),
+            Box::new(move |mut delete_many_node, child_ids| {
+                if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) = delete_many_node {
+                    dmr.record_filter = child_ids.into();
+                }
+
+                Ok(delete_many_node)
+            }),
+        ),
+    )?;
+
+    Ok(delete_many_node)
+}
+
+/// Sets the referencing fields of the records found by `read_node` to null or their default values,
+/// depending on `action`. Returns the update node.
+///
+/// Defaults that can only be computed by the database, like `autoincrement()`, can't be emulated
+/// and result in an error.
+fn insert_referencing_fields_update(
+    graph: &mut QueryGraph,
+    referencing_field: &RelationFieldRef,
+    action: ReferentialAction,
+    read_node: &NodeRef,
+) -> QueryGraphBuilderResult<NodeRef> {
+    let set_default = action == ReferentialAction::SetDefault;
+    let values: Vec<(ScalarFieldRef, PrismaValue)> = referencing_field
+        .linking_fields()
+        .scalar_fields()
+        .map(|sf| {
+            let value = match &sf.default_value {
+                Some(default) if set_default => default.get().ok_or_else(|| {
+                    QueryGraphBuilderError::InputError(format!(
+                        "The default value of field `{}` on model `{}` can't be set by the `SetDefault` referential action of relation `{}`, because it is generated by the database.",
+                        sf.name,
+                        referencing_field.model().name,
+                        referencing_field.relation().name
+                    ))
+                })?,
+                _ => PrismaValue::Null,
+            };
+
+            Ok((sf, value))
+        })
+        .collect::<QueryGraphBuilderResult<_>>()?;
+
+    insert_referencing_records_update(graph, referencing_field, values, read_node)
+}
 
-        // Edge from empty node to the child (delete).
-        graph.create_edge(&noop_node, child_node, QueryGraphDependency::ExecutionOrder)?;
+/// Writes the given `values` to the records found by `read_node`. Returns the update node.
+fn insert_referencing_records_update(
+    graph: &mut QueryGraph,
+    referencing_field: &RelationFieldRef,
+    values: Vec<(ScalarFieldRef, PrismaValue)>,
+    read_node: &NodeRef,
+) -> QueryGraphBuilderResult<NodeRef> {
+    let child_model = referencing_field.model();
+    let update_node = update_records_node_placeholder(graph, Filter::empty(), Arc::clone(&child_model));
+
+    graph.create_edge(
+        read_node,
+        &update_node,
+        QueryGraphDependency::ParentProjection(
+            child_model.primary_identifier(),
+            Box::new(move |mut update_node, child_ids| {
+                if let Node::Query(Query::Write(ref mut wq)) = update_node {
+                    wq.inject_projection_into_args(values.into());
+                }
+
+                if let Node::Query(Query::Write(WriteQuery::UpdateManyRecords(ref mut ur))) = update_node {
+                    ur.record_filter = child_ids.into();
+                }
+
+                Ok(update_node)
+            }),
+        ),
+    )?;
+
+    Ok(update_node)
+}
+
+/// Inserts checks and referential actions into the graph for all non-list relations that reference
+/// fields of `model` changed by `args`, analogous to `insert_deletion_checks`.
+/// `update_node` is the node updating the records of `model` matching `filter`.
+///
+/// The `onUpdate` action of the referencing relation field decides what happens:
+/// - No action given: Nothing, the database takes care of the foreign keys.
+/// - `Restrict` and `NoAction`: Referencing records are checked, the update fails if any exist.
+/// - `Cascade`: The referencing fields of the records are updated to the new values.
+/// - `SetNull` and `SetDefault`: The referencing fields of the records are updated.
+///
+/// Referencing records are found before `update_node` runs, and written after it, so the writes
+/// also succeed if the database cascades the update itself.
+///
+/// ## Example
+/// - `A` restricts updates, `B` cascades them.
+/// - This function inserts all nodes and edges in between `Find Record IDs` and `Update`, as well as the
+///   update of the referencing `B` records after `Update`.
+///
+/// ```text
+///    ┌────────────────────┐
+///    │ Find Record IDs to │
+/// ┌──│       Update       │
+/// │  └────────────────────┘
+/// │             │
+/// │             ▼
+/// │  ┌────────────────────┐
+/// │  │Find Connected Model│
+/// │  │         A          │──┐
+/// │  └────────────────────┘  │
+/// │             │            │
+/// │             ▼            │
+/// │  ┌────────────────────┐  │
+/// └─▶│Find Connected Model│  │ Fail if > 0
+///    │         B          │──┼──────┐
+///    └────────────────────┘  │      │
+///               │            │      │
+///               ▼            │      │
+///    ┌────────────────────┐  │      │
+///    │       Empty        │◀─┘      │
+///    └────────────────────┘         │
+///               │                   │
+///               ▼                   │
+///    ┌────────────────────┐         │
+///    │       Update       │         │
+///    └────────────────────┘         │
+///               │                   │
+///               ▼                   │
+///    ┌────────────────────┐         │
+///    │      Update B      │◀────────┘
+///    └────────────────────┘
+/// ```
+pub fn insert_update_actions(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    filter: Filter,
+    args: &WriteArgs,
+    update_node: &NodeRef,
+) -> QueryGraphBuilderResult<()> {
+    let internal_model = model.internal_data_model();
+    let relation_fields: Vec<_> = internal_model
+        .fields_referencing_model(model)
+        .into_iter()
+        .filter(|rf| rf.relation_info.on_update.is_some())
+        .filter(|rf| {
+            rf.related_field()
+                .linking_fields()
+                .scalar_fields()
+                .any(|sf| args.has_arg_for(sf.db_name()))
+        })
+        .collect();
+
+    if relation_fields.is_empty() {
+        return Ok(());
     }
 
+    let projection = relation_fields.iter().fold(model.primary_identifier(), |acc, rf| {
+        acc.merge(rf.related_field().linking_fields())
+    });
+
+    let read_parents_node = graph.create_node(read_ids_infallible(Arc::clone(model), projection, filter));
+    let noop_node = graph.create_node(Node::Empty);
+    let mut read_nodes: Vec<NodeRef> = vec![];
+    let mut last_is_checked = false;
+
+    for rf in relation_fields {
+        let relation_field = rf.related_field();
+        let read_node =
+            insert_find_children_by_parent_node(graph, &read_parents_node, &relation_field, Filter::empty())?;
+
+        let values: Vec<(ScalarFieldRef, PrismaValue)> = match rf.relation_info.on_update {
+            Some(ReferentialAction::Cascade) => rf
+                .linking_fields()
+                .scalar_fields()
+                .zip(relation_field.linking_fields().scalar_fields())
+                .filter_map(|(child_field, parent_field)| {
+                    args.get_field_value(parent_field.db_name())
+                        .map(|value| (child_field, value.clone()))
+                })
+                .collect(),
+            Some(action @ ReferentialAction::SetNull) | Some(action @ ReferentialAction::SetDefault) => {
+                let write_node = insert_referencing_fields_update(graph, &rf, action, &read_node)?;
+                graph.create_edge(update_node, &write_node, QueryGraphDependency::ExecutionOrder)?;
+                read_nodes.push(read_node);
+                last_is_checked = false;
+
+                continue;
+            }
+            _ => {
+                let child_model_identifier = relation_field.related_model().primary_identifier();
+
+                graph.create_edge(
+                    &read_node,
+                    &noop_node,
+                    QueryGraphDependency::ParentProjection(
+                        child_model_identifier,
+                        Box::new(move |noop_node, child_ids| {
+                            if !child_ids.is_empty() {
+                                return Err(QueryGraphBuilderError::RelationViolation((relation_field).into()));
+                            }
+
+                            Ok(noop_node)
+                        }),
+                    ),
+                )?;
+
+                read_nodes.push(read_node);
+                last_is_checked = true;
+
+                continue;
+            }
+        };
+
+        let write_node = insert_referencing_records_update(graph, &rf, values, &read_node)?;
+        graph.create_edge(update_node, &write_node, QueryGraphDependency::ExecutionOrder)?;
+        read_nodes.push(read_node);
+        last_is_checked = false;
+    }
+
+    // Chains all reads with execution order dependencies, so all of them run before the update.
+    for (previous, next) in read_nodes.iter().tuple_windows() {
+        graph.create_edge(previous, next, QueryGraphDependency::ExecutionOrder)?;
+    }
+
+    // Checks are already connected to the empty node, the other reads are not.
+    if let Some(last) = read_nodes.last() {
+        if !last_is_checked {
+            graph.create_edge(last, &noop_node, QueryGraphDependency::ExecutionOrder)?;
+        }
+    }
+
+    graph.create_edge(&noop_node, update_node, QueryGraphDependency::ExecutionOrder)?;
+
     Ok(())
 }
@@ -1,7 +1,7 @@
 use super::*;
 use crate::query_document::{ParsedInputMap, ParsedInputValue};
 use connector::WriteArgs;
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef};
+use prisma_models::{Field, InternalCompositeField, InternalCompositeType, ModelRef, PrismaValue, RelationFieldRef};
 use std::{convert::TryInto, sync::Arc};
 
 #[derive(Default, Debug)]
@@ -38,6 +38,17 @@ impl WriteArgsParser {
                         args.args.insert(sf.db_name().clone(), set_value)
                     }
 
+                    Field::Scalar(sf) if sf.composite_type.is_some() => {
+                        let composite_type = sf.composite_type.as_ref().unwrap();
+                        let value: Option<ParsedInputMap> = v.try_into()?;
+                        let value = match value {
+                            Some(map) => PrismaValue::Json(composite_to_json(map, composite_type)?.to_string()),
+                            None => PrismaValue::Null,
+                        };
+
+                        args.args.insert(sf.db_name().clone(), value)
+                    }
+
                     Field::Scalar(sf) => {
                         let value: PrismaValue = v.try_into()?;
                         args.args.insert(sf.db_name().clone(), value)
@@ -54,3 +65,57 @@ impl WriteArgsParser {
         )
     }
 }
+
+/// Converts the input for a composite field into the Json document that is stored,
+/// checking it against the declared fields of the composite type.
+fn composite_to_json(
+    mut map: ParsedInputMap,
+    composite_type: &InternalCompositeType,
+) -> QueryGraphBuilderResult<serde_json::Value> {
+    let mut object = serde_json::Map::new();
+
+    for field in composite_type.fields.iter() {
+        match map.remove(&field.name) {
+            Some(value) => {
+                object.insert(field.name.clone(), composite_field_to_json(value, field)?);
+            }
+            None if field.is_required => {
+                return Err(QueryGraphBuilderError::InputError(format!(
+                    "Missing a value for the required field `{}` of composite type `{}`.",
+                    field.name, composite_type.name
+                )))
+            }
+            None => (),
+        }
+    }
+
+    if let Some(unknown_field) = map.keys().next() {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "Composite type `{}` has no field `{}`.",
+            composite_type.name, unknown_field
+        )));
+    }
+
+    Ok(serde_json::Value::Object(object))
+}
+
+fn composite_field_to_json(
+    value: ParsedInputValue,
+    field: &InternalCompositeField,
+) -> QueryGraphBuilderResult<serde_json::Value> {
+    match (value, &field.composite_type) {
+        (ParsedInputValue::Map(map), Some(composite_type)) => composite_to_json(map, composite_type),
+        (ParsedInputValue::List(values), Some(_)) => {
+            let values = values
+                .into_iter()
+                .map(|value| composite_field_to_json(value, field))
+                .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+            Ok(serde_json::Value::Array(values))
+        }
+        (value, _) => {
+            let value: PrismaValue = value.try_into()?;
+            Ok(field.scalar_to_json(value))
+        }
+    }
+}
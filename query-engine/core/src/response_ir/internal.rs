@@ -1,10 +1,11 @@
 use super::*;
 use crate::{
+    introspection::TYPENAME_FIELD,
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
     AggregationQueryResult, CoreError, EnumType, QueryResult, RecordAggregation, RecordSelection,
 };
 use indexmap::IndexMap;
-use prisma_models::{InternalEnum, PrismaValue, RecordProjection};
+use prisma_models::{InternalCompositeField, InternalCompositeType, InternalEnum, PrismaValue, RecordProjection};
 use rust_decimal::prelude::ToPrimitive;
 use std::{borrow::Borrow, collections::HashMap};
 
@@ -40,7 +41,7 @@ pub fn serialize_internal(
 ) -> crate::Result<CheckedItemsWithParents> {
     match result {
         QueryResult::RecordSelection(rs) => serialize_record_selection(rs, typ, is_list, is_optional),
-        QueryResult::RecordAggregation(ra) => serialize_aggregation(ra),
+        QueryResult::RecordAggregation(ra) => serialize_aggregation(ra, typ),
 
         QueryResult::Count(c) => {
             // Todo needs a real implementation or needs to move to RecordAggregation
@@ -59,9 +60,18 @@ pub fn serialize_internal(
     }
 }
 
-fn serialize_aggregation(record_aggregation: RecordAggregation) -> crate::Result<CheckedItemsWithParents> {
+fn serialize_aggregation(
+    record_aggregation: RecordAggregation,
+    typ: &OutputTypeRef,
+) -> crate::Result<CheckedItemsWithParents> {
     let mut envelope = CheckedItemsWithParents::new();
-    let mut inner_map: Map = IndexMap::with_capacity(record_aggregation.results.len());
+    let mut inner_map: Map = IndexMap::with_capacity(record_aggregation.fields.len());
+
+    if record_aggregation.fields.iter().any(|field| field == TYPENAME_FIELD) {
+        if let Some(obj) = typ.as_object_type() {
+            inner_map.insert(TYPENAME_FIELD.to_owned(), typename_item(&obj));
+        }
+    }
 
     for result in record_aggregation.results {
         match result {
@@ -178,9 +188,9 @@ fn serialize_objects(
     let scalar_db_field_names = result.scalars.field_names;
 
     let model = result.model_id.model();
-    let field_names: Vec<_> = scalar_db_field_names
+    let scalar_fields: Vec<_> = scalar_db_field_names
         .iter()
-        .filter_map(|f| model.map_scalar_db_field_name(f).map(|x| x.name.clone()))
+        .filter_map(|f| model.map_scalar_db_field_name(f))
         .collect();
 
     // Write all fields, nested and list fields unordered into a map, afterwards order all into the final order.
@@ -196,17 +206,23 @@ fn serialize_objects(
         let values = record.values;
         let mut object = HashMap::with_capacity(values.len());
 
-        for (val, scalar_field_name) in values.into_iter().zip(field_names.iter()) {
-            let field = typ.find_field(scalar_field_name).unwrap();
+        for (val, scalar_field) in values.into_iter().zip(scalar_fields.iter()) {
+            let field = typ.find_field(&scalar_field.name).unwrap();
 
-            if !field.field_type.is_object() {
-                object.insert(scalar_field_name.to_owned(), serialize_scalar(val, &field.field_type)?);
+            if let Some(ref composite_type) = scalar_field.composite_type {
+                object.insert(scalar_field.name.clone(), serialize_composite(val, composite_type)?);
+            } else if !field.field_type.is_object() {
+                object.insert(scalar_field.name.clone(), serialize_scalar(val, &field.field_type)?);
             }
         }
 
         // Write nested results
         write_nested_items(&record_id, &mut nested_mapping, &mut object, &typ);
 
+        if result.fields.iter().any(|field| field == TYPENAME_FIELD) {
+            object.insert(TYPENAME_FIELD.to_owned(), typename_item(&typ));
+        }
+
         let map = result
             .fields
             .iter()
@@ -294,6 +310,73 @@ fn process_nested_results(
     Ok(nested_mapping)
 }
 
+fn typename_item(typ: &ObjectTypeStrongRef) -> Item {
+    Item::Value(PrismaValue::String(typ.name().to_owned()))
+}
+
+/// Serializes the stored Json document of a composite field into a map with the fields of the composite type.
+/// Composite values are always serialized as a whole, in the order the fields are declared in.
+fn serialize_composite(value: PrismaValue, composite_type: &InternalCompositeType) -> crate::Result<Item> {
+    let json = match value {
+        PrismaValue::Null => return Ok(Item::Value(PrismaValue::Null)),
+        PrismaValue::Json(s) | PrismaValue::String(s) => serde_json::from_str(&s).map_err(|err| {
+            CoreError::SerializationError(format!(
+                "Unable to parse the stored value of composite type '{}': {}",
+                composite_type.name, err
+            ))
+        })?,
+        pv => {
+            return Err(CoreError::SerializationError(format!(
+                "Attempted to serialize '{}' as a value of composite type '{}'",
+                pv, composite_type.name
+            )))
+        }
+    };
+
+    serialize_composite_json(&json, composite_type)
+}
+
+fn serialize_composite_json(json: &serde_json::Value, composite_type: &InternalCompositeType) -> crate::Result<Item> {
+    let object = match json {
+        serde_json::Value::Null => return Ok(Item::Value(PrismaValue::Null)),
+        serde_json::Value::Object(object) => object,
+        _ => {
+            return Err(CoreError::SerializationError(format!(
+                "Attempted to serialize '{}' as a value of composite type '{}'",
+                json, composite_type.name
+            )))
+        }
+    };
+
+    let mut map: Map = IndexMap::with_capacity(composite_type.fields.len());
+
+    for field in composite_type.fields.iter() {
+        let value = object.get(&field.name).unwrap_or(&serde_json::Value::Null);
+        let item = match (value, field.is_list) {
+            (serde_json::Value::Array(values), true) => Item::list(
+                values
+                    .iter()
+                    .map(|value| serialize_composite_field_value(value, field))
+                    .collect::<crate::Result<Vec<Item>>>()?,
+            ),
+            (serde_json::Value::Null, true) => Item::list(Vec::new()),
+            (value, _) => serialize_composite_field_value(value, field)?,
+        };
+
+        map.insert(field.name.clone(), item);
+    }
+
+    Ok(Item::Map(map))
+}
+
+fn serialize_composite_field_value(value: &serde_json::Value, field: &InternalCompositeField) -> crate::Result<Item> {
+    match (&field.composite_type, &field.internal_enum) {
+        (Some(composite_type), _) => serialize_composite_json(value, composite_type),
+        (None, Some(internal_enum)) if !value.is_null() => convert_enum(field.scalar_from_json(value), internal_enum),
+        (None, _) => Ok(Item::Value(field.scalar_from_json(value))),
+    }
+}
+
 fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> crate::Result<Item> {
     match (&value, typ.as_ref()) {
         (PrismaValue::Null, OutputType::Opt(_)) => Ok(Item::Value(PrismaValue::Null)),
@@ -353,6 +436,15 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
         (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
         (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
 
+        (ScalarType::Decimal, PrismaValue::Decimal(d)) => PrismaValue::Decimal(d),
+        (ScalarType::Decimal, PrismaValue::Float(f)) => PrismaValue::Decimal(f),
+        (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::Decimal(i.into()),
+
+        (ScalarType::BigInt, PrismaValue::BigInt(i)) => PrismaValue::BigInt(i),
+        (ScalarType::BigInt, PrismaValue::Int(i)) => PrismaValue::BigInt(i),
+
+        (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
+
         (st, pv) => {
             return Err(CoreError::SerializationError(format!(
                 "Attempted to serialize scalar '{}' with incompatible type '{:?}'",
@@ -1,60 +1,32 @@
-//! This serves as a skeleton for future capability work.
+pub use connector::{ConnectorCapability, IdCapability, ScalarListsCapability};
 
+/// The capabilities of the connector the query schema is built for.
 #[derive(Debug)]
 pub struct SupportedCapabilities {
     pub capabilities: Vec<ConnectorCapability>,
 }
 
 impl SupportedCapabilities {
+    pub fn new(capabilities: Vec<ConnectorCapability>) -> Self {
+        SupportedCapabilities { capabilities }
+    }
+
     pub fn empty() -> Self {
         SupportedCapabilities { capabilities: vec![] }
     }
 
+    /// `ScalarListsCapability::ScalarLists` is satisfied by any kind of scalar list support.
     pub fn has(&self, capability: ConnectorCapability) -> bool {
         match capability {
-            ConnectorCapability::ScalarLists(l) => self
+            ConnectorCapability::ScalarLists(ScalarListsCapability::ScalarLists) => self
                 .capabilities
                 .iter()
-                .find(|_| match l {
-                    ScalarListsCapability::EmbeddedScalarLists => true,
-                    ScalarListsCapability::NonEmbeddedScalarList => true,
-                    _ => false,
-                })
-                .is_some(),
-            capability => self.capabilities.iter().find(|c| **c == capability).is_some(),
+                .any(|c| matches!(c, ConnectorCapability::ScalarLists(_))),
+            capability => self.capabilities.contains(&capability),
         }
     }
-}
-
-#[allow(dead_code)]
-#[derive(Debug, PartialEq)]
-pub enum ConnectorCapability {
-    ScalarLists(ScalarListsCapability),
-    IdCapability(IdCapability),
-    EmbeddedTypes,
-    JoinRelationsFilter,
-    ImportExport,
-    TransactionalExecution,
-    SupportsExistingDatabases,
-    Migrations,
-    RawAccess,
-    Introspection,
-    JoinRelationLinks,
-    MongoJoinRelationLinks,
-    RelationLinkList,
-    RelationLinkTable,
-}
-
-#[derive(Debug, PartialEq)]
-pub enum ScalarListsCapability {
-    ScalarLists, // Not sure if this is required, the scala code is not expressive here.
-    EmbeddedScalarLists,
-    NonEmbeddedScalarList,
-}
 
-#[derive(Debug, PartialEq)]
-pub enum IdCapability {
-    IntId,
-    UuidId,
-    IdSequence,
+    pub fn supports_scalar_lists(&self) -> bool {
+        self.has(ConnectorCapability::ScalarLists(ScalarListsCapability::ScalarLists))
+    }
 }
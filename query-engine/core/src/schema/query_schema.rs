@@ -128,6 +128,10 @@ impl ObjectType {
         &self.name
     }
 
+    pub fn model(&self) -> Option<&ModelRef> {
+        self.model.as_ref()
+    }
+
     pub fn get_fields(&self) -> &Vec<FieldRef> {
         self.fields.get().unwrap()
     }
@@ -152,12 +156,20 @@ pub struct Field {
     pub arguments: Vec<Argument>,
     pub field_type: OutputTypeRef,
     pub query_builder: Option<SchemaQueryBuilder>,
+
+    /// Documentation of the model field this field is mapped from, if any.
+    pub description: Option<String>,
 }
 
 impl Field {
     pub fn query_builder(&self) -> Option<&SchemaQueryBuilder> {
         self.query_builder.as_ref()
     }
+
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
+    }
 }
 
 /// Todo rework description.
@@ -291,6 +303,16 @@ pub struct InputField {
     pub name: String,
     pub field_type: InputType,
     pub default_value: Option<dml::DefaultValue>,
+
+    /// Documentation of the model field this field writes to, if any.
+    pub description: Option<String>,
+}
+
+impl InputField {
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -358,6 +380,18 @@ impl InputType {
     pub fn uuid() -> InputType {
         InputType::Scalar(ScalarType::UUID)
     }
+
+    pub fn decimal() -> InputType {
+        InputType::Scalar(ScalarType::Decimal)
+    }
+
+    pub fn big_int() -> InputType {
+        InputType::Scalar(ScalarType::BigInt)
+    }
+
+    pub fn bytes() -> InputType {
+        InputType::Scalar(ScalarType::Bytes)
+    }
 }
 
 #[derive(Debug)]
@@ -410,6 +444,18 @@ impl OutputType {
         OutputType::Scalar(ScalarType::UUID)
     }
 
+    pub fn decimal() -> OutputType {
+        OutputType::Scalar(ScalarType::Decimal)
+    }
+
+    pub fn big_int() -> OutputType {
+        OutputType::Scalar(ScalarType::BigInt)
+    }
+
+    pub fn bytes() -> OutputType {
+        OutputType::Scalar(ScalarType::Bytes)
+    }
+
     /// Attempts to recurse through the type until an object type is found.
     /// Returns Some(ObjectTypeStrongRef) if ab object type is found, None otherwise.
     pub fn as_object_type(&self) -> Option<ObjectTypeStrongRef> {
@@ -450,6 +496,9 @@ pub enum ScalarType {
     Json,
     JsonList,
     UUID,
+    Decimal,
+    BigInt,
+    Bytes,
 }
 
 impl From<EnumType> for OutputType {
@@ -14,6 +14,7 @@ struct StaticFilterArguments {
     pub string: &'static [FilterArgument],
     pub multi_relation: &'static [FilterArgument],
     pub one_relation: &'static [FilterArgument],
+    pub composite: &'static [FilterArgument],
 }
 
 static FILTER_ARGUMENTS: StaticFilterArguments = StaticFilterArguments {
@@ -104,6 +105,11 @@ static FILTER_ARGUMENTS: StaticFilterArguments = StaticFilterArguments {
         suffix: "",
         is_list: false,
     }],
+
+    composite: &[FilterArgument {
+        suffix: "",
+        is_list: false,
+    }], // All records where the composite value matches the given filter (or is null).
 };
 
 pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
@@ -113,18 +119,37 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
         ModelField::Relation(_) if field.is_list() => vec![&args.multi_relation],
         ModelField::Scalar(_) if field.is_list() => vec![],
         ModelField::Relation(_) => vec![&args.one_relation],
-        ModelField::Scalar(sf) => match sf.type_identifier {
-            TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
-            TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
-            TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Boolean => vec![&args.base],
-            TypeIdentifier::Enum(_) => vec![&args.base, &args.inclusion],
-            TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Json => vec![&args.base],
-        },
+        ModelField::Scalar(sf) if sf.composite_type.is_some() => vec![&args.composite],
+        ModelField::Scalar(sf) => type_filters(&sf.type_identifier),
     };
 
+    flatten(filters)
+}
+
+/// Filters for the scalar fields of composite types.
+pub fn get_scalar_filters<'a>(type_identifier: &TypeIdentifier) -> Vec<&'a FilterArgument> {
+    flatten(type_filters(type_identifier))
+}
+
+fn type_filters(type_identifier: &TypeIdentifier) -> Vec<&'static &'static [FilterArgument]> {
+    let args = &FILTER_ARGUMENTS;
+
+    match type_identifier {
+        TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
+        TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
+        TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Boolean => vec![&args.base],
+        TypeIdentifier::Enum(_) => vec![&args.base, &args.inclusion],
+        TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Json => vec![&args.base],
+        TypeIdentifier::Decimal => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::BigInt => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Bytes => vec![&args.base, &args.inclusion],
+    }
+}
+
+fn flatten<'a>(filters: Vec<&'static &'static [FilterArgument]>) -> Vec<&'a FilterArgument> {
     filters
         .into_iter()
         .map(|l| l.iter().collect::<Vec<&'a FilterArgument>>())
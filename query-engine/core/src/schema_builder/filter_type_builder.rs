@@ -1,4 +1,5 @@
 use super::*;
+use prisma_models::InternalCompositeType;
 
 /// Filter object and scalar filter object type builder.
 #[derive(Debug)]
@@ -124,6 +125,14 @@ impl<'a> FilterObjectTypeBuilder<'a> {
     }
 
     fn map_input_field(&self, field: ScalarFieldRef) -> Vec<InputField> {
+        if let Some(ref composite_type) = field.composite_type {
+            return self.map_composite_filter_input_field(&field, composite_type);
+        }
+
+        if field.type_identifier == TypeIdentifier::Json && !self.capabilities.has(ConnectorCapability::Json) {
+            return vec![];
+        }
+
         get_field_filters(&ModelField::Scalar(Arc::clone(&field)))
             .into_iter()
             .map(|arg| {
@@ -145,6 +154,70 @@ impl<'a> FilterObjectTypeBuilder<'a> {
             .collect()
     }
 
+    /// Maps composite fields to (filter) input fields. The filter is evaluated in memory.
+    fn map_composite_filter_input_field(
+        &self,
+        field: &ScalarFieldRef,
+        composite_type: &InternalCompositeType,
+    ) -> Vec<InputField> {
+        let filter_object = self.composite_filter_object_type(composite_type);
+
+        get_field_filters(&ModelField::Scalar(Arc::clone(field)))
+            .into_iter()
+            .map(|arg| {
+                let field_name = format!("{}{}", field.name, arg.suffix);
+                let obj = InputType::object(Weak::clone(&filter_object));
+                let typ = if field.is_required { obj } else { InputType::null(obj) };
+
+                input_field(field_name, InputType::opt(typ), None)
+            })
+            .collect()
+    }
+
+    /// Builds "<x>WhereInput" for the composite type <x>, with filters on all non-list fields.
+    fn composite_filter_object_type(&self, composite_type: &InternalCompositeType) -> InputObjectTypeRef {
+        let name = format!("{}WhereInput", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .filter(|field| !field.is_list)
+            .flat_map(|field| match field.composite_type {
+                Some(ref nested) => {
+                    let obj = InputType::object(self.composite_filter_object_type(nested));
+                    let typ = if field.is_required { obj } else { InputType::null(obj) };
+
+                    vec![input_field(field.name.clone(), InputType::opt(typ), None)]
+                }
+                None => get_scalar_filters(&field.type_identifier)
+                    .into_iter()
+                    .map(|arg| {
+                        let field_name = format!("{}{}", field.name, arg.suffix);
+                        let mapped = self.map_scalar_input_type(&field.type_identifier, field.internal_enum.as_ref());
+
+                        match (arg.is_list, field.is_required) {
+                            (true, true) => input_field(field_name, InputType::opt(InputType::list(mapped)), None),
+                            (true, false) => input_field(
+                                field_name,
+                                InputType::opt(InputType::null(InputType::list(mapped))),
+                                None,
+                            ),
+                            (false, true) => input_field(field_name, InputType::opt(mapped), None),
+                            (false, false) => input_field(field_name, InputType::opt(InputType::null(mapped)), None),
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
     /// Maps relations to (filter) input fields.
     fn map_relation_filter_input_field(&self, field: RelationFieldRef) -> Vec<InputField> {
         let related_model = field.related_model();
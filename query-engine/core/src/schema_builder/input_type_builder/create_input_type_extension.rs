@@ -87,7 +87,13 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
                             let input_object = Arc::new(init_input_object_type(input_name.clone()));
                             self.cache(input_name, Arc::clone(&input_object));
 
-                            let mut fields = vec![self.nested_create_input_field(Arc::clone(&rf))];
+                            let mut fields = vec![];
+
+                            // Records of views can only be connected, never created.
+                            if !related_model.is_view {
+                                fields.push(self.nested_create_input_field(Arc::clone(&rf)));
+                            }
+
                             let nested_connect = self.nested_connect_input_field(Arc::clone(&rf));
                             append_opt(&mut fields, nested_connect);
 
@@ -103,7 +109,7 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
                         input_field(rf.name.clone(), InputType::opt(InputType::null(input_type)), None)
                     };
 
-                    Some(input_field)
+                    Some(input_field.with_description(rf.documentation.clone()))
                 }
             })
             .collect()
@@ -1,4 +1,5 @@
 use super::*;
+use prisma_models::{InternalCompositeType, InternalEnum};
 
 /// Generic extension also used by the FilterInputTypeBuilder.
 pub trait InputBuilderExtensions {
@@ -7,7 +8,23 @@ pub trait InputBuilderExtensions {
     }
 
     fn map_required_input_type(&self, field: &ScalarFieldRef) -> InputType {
-        let typ = match field.type_identifier {
+        let typ = match field.composite_type {
+            Some(ref composite_type) => InputType::object(self.map_composite_input_type(composite_type)),
+            None => self.map_scalar_input_type(&field.type_identifier, field.internal_enum.as_ref()),
+        };
+
+        let typ = if field.is_list { InputType::list(typ) } else { typ };
+        let typ = if !field.is_required { InputType::null(typ) } else { typ };
+
+        typ
+    }
+
+    fn map_scalar_input_type(
+        &self,
+        type_identifier: &TypeIdentifier,
+        internal_enum: Option<&InternalEnum>,
+    ) -> InputType {
+        match type_identifier {
             TypeIdentifier::String => InputType::string(),
             TypeIdentifier::Int => InputType::int(),
             TypeIdentifier::Float => InputType::float(),
@@ -15,22 +32,25 @@ pub trait InputBuilderExtensions {
             TypeIdentifier::UUID => InputType::uuid(),
             TypeIdentifier::DateTime => InputType::date_time(),
             TypeIdentifier::Json => InputType::json(),
-            TypeIdentifier::Enum(_) => self.map_enum_input_type(&field),
-        };
-
-        let typ = if field.is_list { InputType::list(typ) } else { typ };
-        let typ = if !field.is_required { InputType::null(typ) } else { typ };
-
-        typ
+            TypeIdentifier::Decimal => InputType::decimal(),
+            TypeIdentifier::BigInt => InputType::big_int(),
+            TypeIdentifier::Bytes => InputType::bytes(),
+            TypeIdentifier::Enum(_) => self.map_enum_input_type(internal_enum),
+        }
     }
 
-    fn map_enum_input_type(&self, field: &ScalarFieldRef) -> InputType {
-        let internal_enum = field
-            .internal_enum
-            .as_ref()
-            .expect("A field with TypeIdentifier Enum must always have an enum.");
+    fn map_enum_input_type(&self, internal_enum: Option<&InternalEnum>) -> InputType {
+        let internal_enum = internal_enum.expect("A field with TypeIdentifier Enum must always have an enum.");
 
         let et: EnumType = internal_enum.clone().into();
         et.into()
     }
+
+    /// Composite values are only ever written through the input type builder, which overrides this.
+    fn map_composite_input_type(&self, composite_type: &InternalCompositeType) -> InputObjectTypeRef {
+        unreachable!(
+            "Invariant violation: Composite type {} can't be used as an input here.",
+            composite_type.name
+        )
+    }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use prisma_models::InternalCompositeType;
 
 mod create_input_type_extension;
 mod input_builder_extensions;
@@ -30,12 +31,15 @@ pub trait InputTypeBuilderBase<'a>: CachedBuilder<InputObjectType> + InputBuilde
             .map(|f| {
                 let default = if with_defaults { f.default_value.clone() } else { None };
                 input_field(f.name.clone(), field_mapper(Arc::clone(f)), default)
+                    .with_description(f.documentation.clone())
             })
             .collect();
 
+        // Without scalar list support, list fields are not writable.
+        let supports_scalar_lists = self.get_capabilities().supports_scalar_lists();
         let mut list_fields: Vec<InputField> = prefiltered_fields
             .into_iter()
-            .filter(|f| f.is_list)
+            .filter(|f| f.is_list && supports_scalar_lists)
             .map(|f| {
                 let name = f.name.clone();
                 let set_name = format!("{}{}{}Input", model_name, input_object_name, f.name);
@@ -51,7 +55,7 @@ pub trait InputTypeBuilderBase<'a>: CachedBuilder<InputObjectType> + InputBuilde
                 };
 
                 let set_input_type = InputType::opt(InputType::object(input_object));
-                input_field(name, set_input_type, None)
+                input_field(name, set_input_type, None).with_description(f.documentation.clone())
             })
             .collect();
 
@@ -180,6 +184,8 @@ pub trait InputTypeBuilderBase<'a>: CachedBuilder<InputObjectType> + InputBuilde
     }
 
     fn get_filter_object_builder(&self) -> Arc<FilterObjectTypeBuilder<'a>>;
+
+    fn get_capabilities(&self) -> &'a SupportedCapabilities;
 }
 
 /// Central builder for input types.
@@ -189,6 +195,7 @@ pub trait InputTypeBuilderBase<'a>: CachedBuilder<InputObjectType> + InputBuilde
 #[derive(Debug)]
 pub struct InputTypeBuilder<'a> {
     internal_data_model: InternalDataModelRef,
+    capabilities: &'a SupportedCapabilities,
     input_type_cache: TypeRefCache<InputObjectType>,
     filter_object_builder: Weak<FilterObjectTypeBuilder<'a>>,
 }
@@ -209,19 +216,58 @@ impl<'a> InputTypeBuilderBase<'a> for InputTypeBuilder<'a> {
             .upgrade()
             .expect("Invariant violation: Expected input type builder reference to be valid")
     }
+
+    fn get_capabilities(&self) -> &'a SupportedCapabilities {
+        self.capabilities
+    }
+}
+
+impl<'a> InputBuilderExtensions for InputTypeBuilder<'a> {
+    /// Builds the "<x>Input" input object type, mirroring the fields of the composite type.
+    fn map_composite_input_type(&self, composite_type: &InternalCompositeType) -> InputObjectTypeRef {
+        let name = format!("{}Input", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .map(|field| {
+                let typ = match field.composite_type {
+                    Some(ref composite_type) => InputType::object(self.map_composite_input_type(composite_type)),
+                    None => self.map_scalar_input_type(&field.type_identifier, field.internal_enum.as_ref()),
+                };
+
+                let typ = if field.is_list { InputType::list(typ) } else { typ };
+                let typ = if field.is_required {
+                    typ
+                } else {
+                    InputType::opt(InputType::null(typ))
+                };
+
+                input_field(field.name.clone(), typ, None)
+            })
+            .collect();
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
 }
 
-impl<'a> InputBuilderExtensions for InputTypeBuilder<'a> {}
 impl<'a> CreateInputTypeBuilderExtension<'a> for InputTypeBuilder<'a> {}
 impl<'a> UpdateInputTypeBuilderExtension<'a> for InputTypeBuilder<'a> {}
 
 impl<'a> InputTypeBuilder<'a> {
     pub fn new(
         internal_data_model: InternalDataModelRef,
+        capabilities: &'a SupportedCapabilities,
         filter_object_builder: Weak<FilterObjectTypeBuilder<'a>>,
     ) -> Self {
         InputTypeBuilder {
             internal_data_model,
+            capabilities,
             input_type_cache: TypeRefCache::new(),
             filter_object_builder,
         }
@@ -88,27 +88,34 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
                             let input_object = Arc::new(init_input_object_type(input_name.clone()));
                             self.cache(input_name, Arc::clone(&input_object));
 
-                            let mut fields = vec![self.nested_create_input_field(Arc::clone(&rf))];
+                            let mut fields = vec![];
+
+                            // Records of views can only be (dis)connected, never written to.
+                            if !related_model.is_view {
+                                fields.push(self.nested_create_input_field(Arc::clone(&rf)));
+                            }
 
                             append_opt(&mut fields, self.nested_connect_input_field(Arc::clone(&rf)));
                             append_opt(&mut fields, self.nested_set_input_field(Arc::clone(&rf)));
                             append_opt(&mut fields, self.nested_disconnect_input_field(Arc::clone(&rf)));
-                            append_opt(&mut fields, self.nested_delete_input_field(Arc::clone(&rf)));
-                            fields.push(self.nested_update_input_field(Arc::clone(&rf)));
-                            append_opt(&mut fields, self.nested_update_many_field(Arc::clone(&rf)));
-                            append_opt(&mut fields, self.nested_delete_many_field(Arc::clone(&rf)));
-                            append_opt(&mut fields, self.nested_upsert_field(Arc::clone(&rf)));
+
+                            if !related_model.is_view {
+                                append_opt(&mut fields, self.nested_delete_input_field(Arc::clone(&rf)));
+                                fields.push(self.nested_update_input_field(Arc::clone(&rf)));
+                                append_opt(&mut fields, self.nested_update_many_field(Arc::clone(&rf)));
+                                append_opt(&mut fields, self.nested_delete_many_field(Arc::clone(&rf)));
+                                append_opt(&mut fields, self.nested_upsert_field(Arc::clone(&rf)));
+                            }
 
                             input_object.set_fields(fields);
                             Arc::downgrade(&input_object)
                         }
                     };
 
-                    Some(input_field(
-                        rf.name.clone(),
-                        InputType::opt(InputType::object(input_object)),
-                        None,
-                    ))
+                    Some(
+                        input_field(rf.name.clone(), InputType::opt(InputType::object(input_object)), None)
+                            .with_description(rf.documentation.clone()),
+                    )
                 }
             })
             .collect()
@@ -1,5 +1,5 @@
 use super::*;
-use prisma_models::OrderBy;
+use prisma_models::{InternalCompositeType, InternalEnum, OrderBy};
 
 #[derive(Debug)]
 pub struct ObjectTypeBuilder<'a> {
@@ -77,7 +77,7 @@ impl<'a> ObjectTypeBuilder<'a> {
             .all
             .iter()
             .filter(|f| match f {
-                ModelField::Scalar(_) => true,
+                ModelField::Scalar(sf) => !sf.is_list || self.capabilities.supports_scalar_lists(),
                 ModelField::Relation(_) => self.with_relations,
             })
             .map(|f| self.map_field(f))
@@ -91,6 +91,7 @@ impl<'a> ObjectTypeBuilder<'a> {
             self.map_output_type(&model_field),
             None,
         )
+        .with_description(model_field.documentation().map(|doc| doc.to_owned()))
     }
 
     fn map_output_type(&self, model_field: &ModelField) -> OutputType {
@@ -104,15 +105,9 @@ impl<'a> ObjectTypeBuilder<'a> {
                     related_model_obj
                 }
             }
-            ModelField::Scalar(sf) => match sf.type_identifier {
-                TypeIdentifier::String => OutputType::string(),
-                TypeIdentifier::Float => OutputType::float(),
-                TypeIdentifier::Boolean => OutputType::boolean(),
-                TypeIdentifier::Enum(_) => Self::map_enum_field(sf).into(),
-                TypeIdentifier::Json => OutputType::json(),
-                TypeIdentifier::DateTime => OutputType::date_time(),
-                TypeIdentifier::UUID => OutputType::uuid(),
-                TypeIdentifier::Int => OutputType::int(),
+            ModelField::Scalar(sf) => match sf.composite_type {
+                Some(ref composite_type) => OutputType::object(self.map_composite_object_type(composite_type)),
+                None => Self::map_scalar_output_type(&sf.type_identifier, sf.internal_enum.as_ref()),
             },
         };
 
@@ -125,12 +120,69 @@ impl<'a> ObjectTypeBuilder<'a> {
         }
     }
 
+    /// Builds the object type of a composite type, named after the composite type.
+    pub fn map_composite_object_type(&self, composite_type: &InternalCompositeType) -> ObjectTypeRef {
+        return_cached!(self.get_cache(), &composite_type.name);
+
+        let object_type = Arc::new(ObjectType::new(composite_type.name.clone(), None));
+        self.cache(composite_type.name.clone(), Arc::clone(&object_type));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .map(|composite_field| {
+                let output_type = match composite_field.composite_type {
+                    Some(ref composite_type) => OutputType::object(self.map_composite_object_type(composite_type)),
+                    None => Self::map_scalar_output_type(
+                        &composite_field.type_identifier,
+                        composite_field.internal_enum.as_ref(),
+                    ),
+                };
+
+                let output_type = if composite_field.is_list {
+                    OutputType::list(output_type)
+                } else if !composite_field.is_required {
+                    OutputType::opt(output_type)
+                } else {
+                    output_type
+                };
+
+                field(composite_field.name.clone(), vec![], output_type, None)
+            })
+            .collect();
+
+        object_type.set_fields(fields);
+        Arc::downgrade(&object_type)
+    }
+
+    fn map_scalar_output_type(type_identifier: &TypeIdentifier, internal_enum: Option<&InternalEnum>) -> OutputType {
+        match type_identifier {
+            TypeIdentifier::String => OutputType::string(),
+            TypeIdentifier::Float => OutputType::float(),
+            TypeIdentifier::Boolean => OutputType::boolean(),
+            TypeIdentifier::Enum(_) => {
+                let internal_enum = internal_enum.expect(
+                    "Invariant violation: Enum fields are expected to have an internal_enum associated with them.",
+                );
+
+                EnumType::from(internal_enum.clone()).into()
+            }
+            TypeIdentifier::Json => OutputType::json(),
+            TypeIdentifier::DateTime => OutputType::date_time(),
+            TypeIdentifier::UUID => OutputType::uuid(),
+            TypeIdentifier::Int => OutputType::int(),
+            TypeIdentifier::Decimal => OutputType::decimal(),
+            TypeIdentifier::BigInt => OutputType::big_int(),
+            TypeIdentifier::Bytes => OutputType::bytes(),
+        }
+    }
+
     /// Builds "many records where" arguments based on the given model and field.
     pub fn many_records_field_arguments(&self, field: &ModelField) -> Vec<Argument> {
         match field {
             ModelField::Scalar(_) => vec![],
             ModelField::Relation(rf) if rf.is_list && !rf.related_model().is_embedded => {
-                self.many_records_arguments(&rf.related_model())
+                self.many_records_arguments(&rf.related_model(), true)
             }
             ModelField::Relation(rf) if rf.is_list && rf.related_model().is_embedded => vec![],
             ModelField::Relation(rf) if !rf.is_list => vec![],
@@ -139,18 +191,25 @@ impl<'a> ObjectTypeBuilder<'a> {
     }
 
     /// Builds "many records where" arguments solely based on the given model.
-    pub fn many_records_arguments(&self, model: &ModelRef) -> Vec<Argument> {
+    /// The "distinct" argument is only added if `include_distinct` is set.
+    pub fn many_records_arguments(&self, model: &ModelRef, include_distinct: bool) -> Vec<Argument> {
         let unique_input_type = InputType::opt(InputType::object(
             self.input_type_builder.into_arc().where_unique_object_type(model),
         ));
 
-        vec![
+        let mut args = vec![
             self.where_argument(&model),
             self.order_by_argument(&model),
             argument("cursor", unique_input_type.clone(), None),
             argument("take", InputType::opt(InputType::int()), None),
             argument("skip", InputType::opt(InputType::int()), None),
-        ]
+        ];
+
+        if include_distinct {
+            args.push(self.distinct_argument(&model));
+        }
+
+        args
     }
 
     /// Builds "where" argument.
@@ -169,7 +228,7 @@ impl<'a> ObjectTypeBuilder<'a> {
             .fields()
             .scalar()
             .into_iter()
-            .filter(|field| !field.is_list)
+            .filter(|field| !field.is_list && field.composite_type.is_none())
             .map(|field| {
                 vec![
                     (
@@ -197,17 +256,20 @@ impl<'a> ObjectTypeBuilder<'a> {
         argument("orderBy", InputType::opt(enum_type.into()), None)
     }
 
-    pub fn map_enum_field(scalar_field: &Arc<ScalarField>) -> EnumType {
-        match scalar_field.type_identifier {
-            TypeIdentifier::Enum(_) => {
-                let internal_enum = scalar_field.internal_enum.as_ref().expect(
-                    "Invariant violation: Enum fields are expected to have an internal_enum associated with them.",
-                );
+    /// Builds "distinct" argument.
+    pub fn distinct_argument(&self, model: &ModelRef) -> Argument {
+        let enum_values: Vec<_> = model
+            .fields()
+            .scalar()
+            .into_iter()
+            .filter(|field| !field.is_list && field.composite_type.is_none())
+            .map(|field| (field.name.clone(), field))
+            .collect();
 
-                internal_enum.clone().into()
-            }
-            _ => panic!("Invariant violation: map_enum_field can only be called on scalar enum fields."),
-        }
+        let enum_name = format!("{}DistinctFieldEnum", capitalize(&model.name));
+        let enum_type = field_ref_enum_type(enum_name, enum_values);
+
+        argument("distinct", InputType::opt(InputType::list(enum_type.into())), None)
     }
 
     pub fn batch_payload_object_type(&self) -> ObjectTypeRef {
@@ -229,7 +291,7 @@ impl<'a> ObjectTypeBuilder<'a> {
         return_cached!(self.get_cache(), &name);
 
         let object = ObjectTypeStrongRef::new(ObjectType::new(&name, Some(ModelRef::clone(model))));
-        let arguments = self.many_records_arguments(model);
+        let arguments = self.many_records_arguments(model, false);
         let fields = vec![field("count", arguments, OutputType::int(), None)];
 
         object.set_fields(fields);
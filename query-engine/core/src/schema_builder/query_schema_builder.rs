@@ -66,6 +66,7 @@ impl<'a> QuerySchemaBuilder<'a> {
         let filter_object_type_builder = Arc::new(FilterObjectTypeBuilder::new(capabilities));
         let input_type_builder = Arc::new(InputTypeBuilder::new(
             Arc::clone(internal_data_model),
+            capabilities,
             Arc::downgrade(&filter_object_type_builder),
         ));
 
@@ -159,6 +160,8 @@ impl<'a> QuerySchemaBuilder<'a> {
         let non_embedded_models = self.non_embedded_models();
         let mut fields: Vec<Field> = non_embedded_models
             .into_iter()
+            // Views are read-only.
+            .filter(|model| !model.is_view)
             .map(|model| {
                 let mut vec = vec![self.create_item_field(Arc::clone(&model))];
 
@@ -227,7 +230,7 @@ impl<'a> QuerySchemaBuilder<'a> {
 
     /// Builds a "multiple" query arity items field (e.g. "users", "posts", ...) for given model.
     fn all_items_field(&self, model: ModelRef) -> Field {
-        let args = self.object_type_builder.many_records_arguments(&model);
+        let args = self.object_type_builder.many_records_arguments(&model, true);
         let field_name = self.pluralize_internal(
             camel_case(pluralize(model.name.clone())),
             format!("findMany{}", model.name.clone()),
@@ -49,6 +49,17 @@ where
     })
 }
 
+/// Field reference enum type convenience wrapper function.
+pub fn field_ref_enum_type<T>(name: T, values: Vec<(String, ScalarFieldRef)>) -> EnumType
+where
+    T: Into<String>,
+{
+    EnumType::FieldRef(FieldRefEnumType {
+        name: name.into(),
+        values,
+    })
+}
+
 /// Argument convenience wrapper function.
 pub fn argument<T>(name: T, arg_type: InputType, default_value: Option<dml::DefaultValue>) -> Argument
 where
@@ -76,6 +87,7 @@ where
         arguments,
         field_type: Arc::new(field_type),
         query_builder,
+        description: None,
     }
 }
 
@@ -88,6 +100,7 @@ where
         name: name.into(),
         field_type,
         default_value,
+        description: None,
     }
 }
 
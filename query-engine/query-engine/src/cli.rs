@@ -2,6 +2,7 @@ use crate::{
     context::PrismaContext,
     dmmf,
     error::PrismaError,
+    exec_loader,
     opt::{CliOpt, PrismaOpt, Subcommand},
     request_handlers::{graphql::*, PrismaRequest, RequestHandler},
     PrismaResult,
@@ -24,16 +25,23 @@ pub struct ExecuteRequest {
 
 pub struct DmmfRequest {
     datamodel: Datamodel,
+    config: Configuration,
     build_mode: BuildMode,
     enable_raw_queries: bool,
 }
 
+pub struct JsonSchemaRequest {
+    datamodel: Datamodel,
+    openapi: bool,
+}
+
 pub struct GetConfigRequest {
     config: Configuration,
 }
 
 pub enum CliCommand {
     Dmmf(DmmfRequest),
+    JsonSchema(JsonSchemaRequest),
     GetConfig(GetConfigRequest),
     ExecuteRequest(ExecuteRequest),
 }
@@ -58,13 +66,26 @@ impl TryFrom<&PrismaOpt> for CliCommand {
 
                     Ok(CliCommand::Dmmf(DmmfRequest {
                         datamodel: opts.datamodel(true)?,
+                        config: opts.configuration(true)?,
                         build_mode,
                         enable_raw_queries: opts.enable_raw_queries,
                     }))
                 }
-                CliOpt::GetConfig(input) => Ok(CliCommand::GetConfig(GetConfigRequest {
-                    config: opts.configuration(input.ignore_env_var_errors)?,
+                CliOpt::JsonSchema(input) => Ok(CliCommand::JsonSchema(JsonSchemaRequest {
+                    datamodel: opts.datamodel(true)?,
+                    openapi: input.openapi,
                 })),
+                CliOpt::GetConfig(input) => {
+                    let generator_schemas = match input.generator_schemas {
+                        Some(ref generator_schemas) => serde_json::from_str(generator_schemas)?,
+                        None => Vec::new(),
+                    };
+
+                    Ok(CliCommand::GetConfig(GetConfigRequest {
+                        config: opts
+                            .configuration_with_generator_schemas(input.ignore_env_var_errors, generator_schemas)?,
+                    }))
+                }
                 CliOpt::ExecuteRequest(input) => Ok(CliCommand::ExecuteRequest(ExecuteRequest {
                     query: input.query.clone(),
                     enable_raw_queries: opts.enable_raw_queries,
@@ -81,6 +102,7 @@ impl CliCommand {
     pub async fn execute(self) -> PrismaResult<()> {
         match self {
             CliCommand::Dmmf(request) => Self::dmmf(request),
+            CliCommand::JsonSchema(request) => Self::json_schema(request),
             CliCommand::GetConfig(input) => Self::get_config(input.config),
             CliCommand::ExecuteRequest(request) => Self::execute_request(request).await,
         }
@@ -91,7 +113,13 @@ impl CliCommand {
 
         // temporary code duplication
         let internal_data_model = template.build("".into());
-        let capabilities = SupportedCapabilities::empty();
+
+        // The DMMF is rendered without a database connection,
+        // so the capabilities of the latest database version are used.
+        let capabilities = match request.config.datasources.first() {
+            Some(data_source) => SupportedCapabilities::new(exec_loader::static_capabilities(&**data_source)?),
+            None => SupportedCapabilities::empty(),
+        };
 
         let schema_builder = QuerySchemaBuilder::new(
             &internal_data_model,
@@ -110,6 +138,18 @@ impl CliCommand {
         Ok(())
     }
 
+    fn json_schema(request: JsonSchemaRequest) -> PrismaResult<()> {
+        let json_schema = if request.openapi {
+            datamodel::json::json_schema::render_to_openapi_components(&request.datamodel)
+        } else {
+            datamodel::json::json_schema::render_to_json_schema(&request.datamodel)
+        };
+
+        println!("{}", json_schema);
+
+        Ok(())
+    }
+
     fn get_config(config: Configuration) -> PrismaResult<()> {
         let json = datamodel::json::mcf::config_to_mcf_json_value(&config);
         let serialized = serde_json::to_string(&json)?;
@@ -1,12 +1,12 @@
-use crate::{exec_loader, PrismaError, PrismaResult};
+use crate::{exec_loader, request_handlers::QueryDocumentCache, PrismaError, PrismaResult};
 use query_core::{
     schema::{QuerySchemaRef, SupportedCapabilities},
-    BuildMode, QueryExecutor, QuerySchemaBuilder,
+    BuildMode, IntrospectionResolver, QueryExecutor, QueryLimits, QuerySchemaBuilder,
 };
 // use prisma_models::InternalDataModelRef;
 use datamodel::{Configuration, Datamodel};
 use prisma_models::DatamodelConverter;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 /// Prisma request context containing all immutable state of the process.
 /// There is usually only one context initialized per process.
@@ -17,11 +17,21 @@ pub struct PrismaContext {
     dm: Datamodel,
     /// Central query executor.
     pub executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+    /// Timeout for queries that don't set their own.
+    query_timeout: Option<Duration>,
+    /// Cache of converted query documents.
+    document_cache: QueryDocumentCache,
+    /// Resolver for GraphQL introspection queries, if introspection is enabled.
+    introspection: Option<IntrospectionResolver>,
 }
 
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    enable_introspection: bool,
+    query_timeout: Option<Duration>,
+    query_limits: QueryLimits,
+    query_cache_size: usize,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -37,14 +47,55 @@ impl ContextBuilder {
         self
     }
 
+    pub fn enable_introspection(mut self, val: bool) -> Self {
+        self.enable_introspection = val;
+        self
+    }
+
+    pub fn query_timeout(mut self, val: Option<Duration>) -> Self {
+        self.query_timeout = val;
+        self
+    }
+
+    pub fn query_limits(mut self, val: QueryLimits) -> Self {
+        self.query_limits = val;
+        self
+    }
+
+    pub fn query_cache_size(mut self, val: usize) -> Self {
+        self.query_cache_size = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        let mut context = PrismaContext::new(
+            self.config,
+            self.datamodel,
+            self.legacy,
+            self.enable_raw_queries,
+            self.query_limits,
+        )
+        .await?;
+        context.query_timeout = self.query_timeout;
+        context.document_cache = QueryDocumentCache::new(self.query_cache_size);
+
+        if !self.enable_introspection {
+            context.introspection = None;
+        }
+
+        Ok(context)
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
-    async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
+    async fn new(
+        config: Configuration,
+        dm: Datamodel,
+        legacy: bool,
+        enable_raw_queries: bool,
+        query_limits: QueryLimits,
+    ) -> PrismaResult<Self> {
         let template = DatamodelConverter::convert(&dm);
 
         // We only support one data source at the moment, so take the first one (default not exposed yet).
@@ -54,7 +105,7 @@ impl PrismaContext {
             .ok_or_else(|| PrismaError::ConfigurationError("No valid data source found".into()))?;
 
         // Load executor
-        let (db_name, executor) = exec_loader::load(&**data_source).await?;
+        let (db_name, capabilities, executor) = exec_loader::load(&**data_source, query_limits).await?;
 
         // Build internal data model
         let internal_data_model = template.build(db_name);
@@ -62,17 +113,21 @@ impl PrismaContext {
         // Construct query schema
         let build_mode = if legacy { BuildMode::Legacy } else { BuildMode::Modern };
 
-        let capabilities = SupportedCapabilities::empty(); // todo connector capabilities.
+        let capabilities = SupportedCapabilities::new(capabilities);
 
         let schema_builder =
             QuerySchemaBuilder::new(&internal_data_model, &capabilities, build_mode, enable_raw_queries);
 
         let query_schema: QuerySchemaRef = Arc::new(schema_builder.build());
+        let introspection = IntrospectionResolver::new(Arc::clone(&query_schema));
 
         Ok(Self {
             query_schema,
             dm,
             executor,
+            query_timeout: None,
+            document_cache: QueryDocumentCache::new(QueryDocumentCache::DEFAULT_CAPACITY),
+            introspection: Some(introspection),
         })
     }
 
@@ -80,6 +135,10 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            enable_introspection: true,
+            query_timeout: None,
+            query_limits: QueryLimits::default(),
+            query_cache_size: QueryDocumentCache::DEFAULT_CAPACITY,
             datamodel,
             config,
         }
@@ -93,6 +152,18 @@ impl PrismaContext {
         &self.dm
     }
 
+    pub fn query_timeout(&self) -> Option<Duration> {
+        self.query_timeout
+    }
+
+    pub fn document_cache(&self) -> &QueryDocumentCache {
+        &self.document_cache
+    }
+
+    pub fn introspection(&self) -> Option<&IntrospectionResolver> {
+        self.introspection.as_ref()
+    }
+
     pub fn primary_connector(&self) -> &'static str {
         self.executor.primary_connector()
     }
@@ -87,6 +87,9 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Decimal => "Decimal",
+                    ScalarType::BigInt => "BigInt",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::JsonList => "Json",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
@@ -164,6 +167,9 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Decimal => "Decimal",
+                    ScalarType::BigInt => "BigInt",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::JsonList => "Json",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
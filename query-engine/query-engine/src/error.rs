@@ -1,5 +1,5 @@
 use connector::error::ConnectorError;
-use datamodel::error::ErrorCollection;
+use datamodel::{error::ErrorCollection, SchemaFiles};
 use failure::{Error, Fail};
 use graphql_parser::query::ParseError as GqlParseError;
 use query_core::{response_ir, CoreError};
@@ -23,7 +23,7 @@ pub enum PrismaError {
     ConnectorError(ConnectorError),
 
     #[fail(display = "{}", _0)]
-    ConversionError(ErrorCollection, String),
+    ConversionError(ErrorCollection, SchemaFiles),
 
     #[fail(display = "{}", _0)]
     IOError(Error),
@@ -40,6 +40,13 @@ pub enum PrismaError {
 
     #[fail(display = "{}", _0)]
     QueryConversionError(String),
+
+    /// The message is part of the automatic persisted queries protocol, clients match on it.
+    #[fail(display = "PersistedQueryNotFound")]
+    PersistedQueryNotFound,
+
+    #[fail(display = "The provided hash does not match the query.")]
+    PersistedQueryHashMismatch,
 }
 
 impl PrismaError {
@@ -52,8 +59,8 @@ impl PrismaError {
                 user_facing_error: Some(err),
                 ..
             }) => err.into(),
-            PrismaError::ConversionError(errors, dml_string) => {
-                let mut full_error = errors.to_pretty_string("schema.prisma", &dml_string);
+            PrismaError::ConversionError(errors, files) => {
+                let mut full_error = errors.to_pretty_string_files(&files);
                 write!(full_error, "\nValidation Error Count: {}", errors.to_iter().len())?;
 
                 user_facing_errors::Error::from(
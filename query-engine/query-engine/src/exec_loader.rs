@@ -1,28 +1,54 @@
 use crate::{PrismaError, PrismaResult};
-use connector::Connector;
+use connector::{Connector, ConnectorCapability};
 use datamodel::{
     configuration::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     Source,
 };
-use query_core::executor::{InterpretingExecutor, QueryExecutor};
+use query_core::{
+    executor::{InterpretingExecutor, QueryExecutor},
+    QueryLimits,
+};
 use std::{collections::HashMap, path::PathBuf};
 use url::Url;
 
 #[cfg(feature = "sql")]
 use sql_connector::*;
 
+type Executor = Box<dyn QueryExecutor + Send + Sync + 'static>;
+
 pub async fn load(
     source: &(dyn Source + Send + Sync),
-) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+    limits: QueryLimits,
+) -> PrismaResult<(String, Vec<ConnectorCapability>, Executor)> {
     match source.connector_type() {
         #[cfg(feature = "sql")]
-        SQLITE_SOURCE_NAME => sqlite(source).await,
+        SQLITE_SOURCE_NAME => sqlite(source, limits).await,
 
         #[cfg(feature = "sql")]
-        MYSQL_SOURCE_NAME => mysql(source).await,
+        MYSQL_SOURCE_NAME => mysql(source, limits).await,
 
         #[cfg(feature = "sql")]
-        POSTGRES_SOURCE_NAME => postgres(source).await,
+        POSTGRES_SOURCE_NAME => postgres(source, limits).await,
+
+        x => Err(PrismaError::ConfigurationError(format!(
+            "Unsupported connector type: {}",
+            x
+        ))),
+    }
+}
+
+/// The capabilities of the connector for the source, without connecting to the database.
+/// The latest version of the database is assumed.
+pub fn static_capabilities(source: &dyn Source) -> PrismaResult<Vec<ConnectorCapability>> {
+    match source.connector_type() {
+        #[cfg(feature = "sql")]
+        SQLITE_SOURCE_NAME => Ok(capabilities::sqlite_capabilities(None)),
+
+        #[cfg(feature = "sql")]
+        MYSQL_SOURCE_NAME => Ok(capabilities::mysql_capabilities(None)),
+
+        #[cfg(feature = "sql")]
+        POSTGRES_SOURCE_NAME => Ok(capabilities::postgres_capabilities(None)),
 
         x => Err(PrismaError::ConfigurationError(format!(
             "Unsupported connector type: {}",
@@ -34,21 +60,25 @@ pub async fn load(
 #[cfg(feature = "sql")]
 async fn sqlite(
     source: &(dyn Source + Send + Sync),
-) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+    limits: QueryLimits,
+) -> PrismaResult<(String, Vec<ConnectorCapability>, Executor)> {
     trace!("Loading SQLite connector...");
 
     let sqlite = Sqlite::from_source(source).await?;
     let path = PathBuf::from(sqlite.file_path());
     let db_name = path.file_stem().unwrap().to_str().unwrap().to_owned(); // Safe due to previous validations.
 
+    let capabilities = sqlite.capabilities().await?;
+
     trace!("Loaded SQLite connector.");
-    Ok((db_name, sql_executor("sqlite", sqlite, false)))
+    Ok((db_name, capabilities, sql_executor("sqlite", sqlite, false, limits)))
 }
 
 #[cfg(feature = "sql")]
 async fn postgres(
     source: &(dyn Source + Send + Sync),
-) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+    limits: QueryLimits,
+) -> PrismaResult<(String, Vec<ConnectorCapability>, Executor)> {
     trace!("Loading Postgres connector...");
 
     let url = Url::parse(&source.url().value)?;
@@ -66,14 +96,21 @@ async fn postgres(
         .and_then(|flag| flag.parse().ok())
         .unwrap_or(false);
 
+    let capabilities = psql.capabilities().await?;
+
     trace!("Loaded Postgres connector.");
-    Ok((db_name, sql_executor("postgres", psql, force_transactions)))
+    Ok((
+        db_name,
+        capabilities,
+        sql_executor("postgres", psql, force_transactions, limits),
+    ))
 }
 
 #[cfg(feature = "sql")]
 async fn mysql(
     source: &(dyn Source + Send + Sync),
-) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+    limits: QueryLimits,
+) -> PrismaResult<(String, Vec<ConnectorCapability>, Executor)> {
     trace!("Loading MySQL connector...");
 
     let mysql = Mysql::from_source(source).await?;
@@ -86,8 +123,10 @@ async fn mysql(
 
     let db_name = db_name.next().expect(err_str).to_owned();
 
+    let capabilities = mysql.capabilities().await?;
+
     trace!("Loaded MySQL connector.");
-    Ok((db_name, sql_executor("mysql", mysql, false)))
+    Ok((db_name, capabilities, sql_executor("mysql", mysql, false, limits)))
 }
 
 #[cfg(feature = "sql")]
@@ -95,13 +134,10 @@ fn sql_executor<T>(
     primary_connector: &'static str,
     connector: T,
     force_transactions: bool,
-) -> Box<dyn QueryExecutor + Send + Sync + 'static>
+    limits: QueryLimits,
+) -> Executor
 where
     T: Connector + Send + Sync + 'static,
 {
-    Box::new(InterpretingExecutor::new(
-        connector,
-        primary_connector,
-        force_transactions,
-    ))
+    Box::new(InterpretingExecutor::new(connector, primary_connector, force_transactions).with_limits(limits))
 }
@@ -9,7 +9,7 @@ use once_cell::sync::Lazy;
 use opt::*;
 use request_handlers::{PrismaRequest, PrismaResponse, RequestHandler};
 use server::{HttpServer, HttpServerBuilder};
-use std::{convert::TryFrom, error::Error, net::SocketAddr, process};
+use std::{convert::TryFrom, error::Error, net::SocketAddr, process, time::Duration};
 use structopt::StructOpt;
 use tracing::subscriber;
 use tracing_subscriber::{EnvFilter, FmtSubscriber};
@@ -70,7 +70,11 @@ async fn main() -> Result<(), AnyError> {
                     HttpServer::builder(config, datamodel)
                         .legacy(opts.legacy)
                         .enable_raw_queries(opts.enable_raw_queries)
-                        .enable_playground(opts.enable_playground),
+                        .enable_playground(opts.enable_playground)
+                        .enable_introspection(!opts.disable_introspection)
+                        .query_timeout(opts.query_timeout.map(Duration::from_millis))
+                        .query_limits(opts.query_limits())
+                        .query_cache_size(opts.query_cache_size),
                 )
             };
 
@@ -1,7 +1,8 @@
 use crate::{error::PrismaError, PrismaResult};
-use datamodel::{Configuration, Datamodel};
+use datamodel::{Configuration, Datamodel, GeneratorOptionSchema, SchemaFiles};
+use query_core::QueryLimits;
 use serde::Deserialize;
-use std::{ffi::OsStr, fs::File, io::Read};
+use std::ffi::OsStr;
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -30,12 +31,24 @@ pub struct ExecuteRequestInput {
 pub struct GetConfigInput {
     #[structopt(long)]
     pub ignore_env_var_errors: bool,
+    /// Json list of option schemas of generators, used to validate the options of their generator blocks.
+    #[structopt(long)]
+    pub generator_schemas: Option<String>,
+}
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct JsonSchemaInput {
+    /// Output the OpenAPI `components.schemas` instead of a JSON Schema document.
+    #[structopt(long)]
+    pub openapi: bool,
 }
 
 #[derive(Debug, StructOpt, Clone)]
 pub enum CliOpt {
     /// Output the DMMF from the loaded data model.
     Dmmf,
+    /// Output a JSON Schema of the models and enums of the loaded data model.
+    JsonSchema(JsonSchemaInput),
     /// Get the configuration from the given data model.
     GetConfig(GetConfigInput),
     /// Executes one request and then terminates.
@@ -51,9 +64,10 @@ pub struct PrismaOpt {
     /// The port the query engine should bind to.
     #[structopt(long, short, env, default_value = "4466")]
     pub port: u16,
-    /// Path to the Prisma datamodel file
-    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_file))]
-    datamodel_path: Option<String>,
+    /// Path to the Prisma datamodel file, or to a directory of `.prisma` files.
+    /// Several paths are separated like in `PATH`, their files are merged into one datamodel.
+    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_files))]
+    datamodel_path: Option<SchemaFiles>,
     /// Base64 encoded Prisma datamodel
     #[structopt(long, env = "PRISMA_DML", parse(try_from_str = parse_base64_string))]
     datamodel: Option<String>,
@@ -69,6 +83,28 @@ pub struct PrismaOpt {
     /// Enables the GraphQL playground
     #[structopt(long, short = "g")]
     pub enable_playground: bool,
+    /// Disables GraphQL introspection queries (`__schema`, `__type`)
+    #[structopt(long)]
+    pub disable_introspection: bool,
+    /// Default timeout in milliseconds after which queries are cancelled.
+    /// Can be overridden per request with the `x-query-timeout` header.
+    #[structopt(long, env = "PRISMA_QUERY_TIMEOUT")]
+    pub query_timeout: Option<u64>,
+    /// Number of converted query documents kept in the cache. `0` disables the cache and persisted queries.
+    #[structopt(long, env = "PRISMA_QUERY_CACHE_SIZE", default_value = "1000")]
+    pub query_cache_size: usize,
+    /// Maximum nesting depth of selections in a query.
+    #[structopt(long, env = "PRISMA_MAX_QUERY_DEPTH")]
+    pub max_query_depth: Option<usize>,
+    /// Maximum number of nested relation selections in a query.
+    #[structopt(long, env = "PRISMA_MAX_RELATION_READS")]
+    pub max_relation_reads: Option<usize>,
+    /// Maximum absolute value of the `take` argument.
+    #[structopt(long, env = "PRISMA_MAX_TAKE")]
+    pub max_take: Option<i64>,
+    /// Maximum number of records read from the database for a single query.
+    #[structopt(long, env = "PRISMA_MAX_RECORDS")]
+    pub max_records: Option<usize>,
     #[structopt(subcommand)]
     pub subcommand: Option<Subcommand>,
 }
@@ -80,47 +116,57 @@ struct SourceOverride {
 }
 
 impl PrismaOpt {
-    fn datamodel_str(&self) -> PrismaResult<&str> {
-        let res = self
-            .datamodel
-            .as_ref()
-            .map(|dm| dm.as_str())
-            .or(self.datamodel_path.as_ref().map(|dm| dm.as_str()))
-            .ok_or_else(|| {
-                PrismaError::ConfigurationError(
-                    "Datamodel should be provided either as path or base64-encoded string.".into(),
-                )
-            })?;
-
-        Ok(res)
+    fn datamodel_files(&self) -> PrismaResult<SchemaFiles> {
+        match (&self.datamodel, &self.datamodel_path) {
+            (Some(datamodel), _) => Ok(SchemaFiles::single("schema.prisma", datamodel)),
+            (None, Some(files)) => Ok(files.clone()),
+            (None, None) => Err(PrismaError::ConfigurationError(
+                "Datamodel should be provided either as path or base64-encoded string.".into(),
+            )),
+        }
+    }
+
+    pub fn query_limits(&self) -> QueryLimits {
+        QueryLimits {
+            max_depth: self.max_query_depth,
+            max_relation_reads: self.max_relation_reads,
+            max_take: self.max_take,
+            max_records: self.max_records,
+        }
     }
 
     pub fn datamodel(&self, ignore_env_errors: bool) -> PrismaResult<Datamodel> {
-        let datamodel_str = self.datamodel_str()?;
+        let files = self.datamodel_files()?;
 
         let datamodel = if ignore_env_errors {
-            datamodel::parse_datamodel_and_ignore_env_errors(datamodel_str)
+            datamodel::parse_datamodel_and_ignore_env_errors(files.source())
         } else {
-            datamodel::parse_datamodel(datamodel_str)
+            datamodel::parse_datamodel_files(&files)
         };
 
         match datamodel {
-            Err(errors) => Err(PrismaError::ConversionError(errors, datamodel_str.to_string())),
-            _ => Ok(datamodel?),
+            Err(errors) => Err(PrismaError::ConversionError(errors, files)),
+            Ok(validated) => Ok(validated.subject),
         }
     }
 
     pub fn configuration(&self, ignore_env_errors: bool) -> PrismaResult<Configuration> {
-        let datamodel_str = self.datamodel_str()?;
+        self.configuration_with_generator_schemas(ignore_env_errors, Vec::new())
+    }
 
-        let config_result = if ignore_env_errors {
-            datamodel::parse_configuration_and_ignore_env_errors(datamodel_str)
-        } else {
-            datamodel::parse_configuration(datamodel_str)
-        };
+    /// Loads the configuration, validating generator blocks against the given option schemas as well.
+    pub fn configuration_with_generator_schemas(
+        &self,
+        ignore_env_errors: bool,
+        generator_schemas: Vec<GeneratorOptionSchema>,
+    ) -> PrismaResult<Configuration> {
+        let files = self.datamodel_files()?;
+
+        let config_result =
+            datamodel::parse_configuration_with_generator_schemas(files.source(), generator_schemas, ignore_env_errors);
 
         match config_result {
-            Err(errors) => Err(PrismaError::ConversionError(errors, datamodel_str.to_string())),
+            Err(errors) => Err(PrismaError::ConversionError(errors, files)),
             Ok(mut configuration) => {
                 if let Some(ref overwrites) = self.overwrite_datasources {
                     let datasource_overwrites: Vec<SourceOverride> = serde_json::from_str(&overwrites)?;
@@ -157,12 +203,8 @@ fn parse_base64_string(s: &str) -> PrismaResult<String> {
     }
 }
 
-fn load_datamodel_file(path: &OsStr) -> String {
-    let mut f = File::open(path).expect(&format!("Could not open datamodel file {:?}", path));
-    let mut datamodel = String::new();
-
-    f.read_to_string(&mut datamodel)
-        .expect(&format!("Could not read datamodel file: {:?}", path));
+fn load_datamodel_files(paths: &OsStr) -> SchemaFiles {
+    let paths: Vec<_> = std::env::split_paths(paths).collect();
 
-    datamodel
+    SchemaFiles::load(&paths).expect(&format!("Could not read datamodel files: {:?}", paths))
 }
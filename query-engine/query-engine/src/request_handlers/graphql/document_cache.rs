@@ -0,0 +1,183 @@
+use query_core::{Operation, ParsedOperation};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+/// Key of a cached document: The SHA-256 hash of the GraphQL query, hex encoded, plus the selected operation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DocumentKey {
+    hash: String,
+    operation_name: Option<String>,
+}
+
+impl DocumentKey {
+    pub fn new(hash: String, operation_name: Option<String>) -> Self {
+        Self { hash, operation_name }
+    }
+
+    /// Hashes a GraphQL query the same way clients hash persisted queries.
+    pub fn hash_query(query: &str) -> String {
+        format!("{:x}", Sha256::digest(query.as_bytes()))
+    }
+}
+
+/// A cached GraphQL document.
+#[derive(Debug, Clone)]
+pub struct CachedDocument {
+    pub operation: Operation,
+    /// The operation parsed and validated against the query schema, once it has been executed.
+    /// Introspection queries and operations that failed validation are never parsed, writes are
+    /// parsed again on every request because parsing generates their default values.
+    pub parsed: Option<ParsedOperation>,
+}
+
+impl CachedDocument {
+    pub fn new(operation: Operation) -> Self {
+        Self {
+            operation,
+            parsed: None,
+        }
+    }
+}
+
+/// LRU cache of operations converted from GraphQL documents, together with the result of validating
+/// them against the query schema, so that repeated query shapes don't need to be parsed and validated
+/// again. Also backs persisted queries, where clients only send the hash of a query that has been
+/// sent before.
+///
+/// The cache belongs to a context, which serves a single query schema, so keys don't need to include
+/// the schema.
+pub struct QueryDocumentCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    validations: AtomicU64,
+}
+
+#[derive(Default)]
+struct Entries {
+    tick: u64,
+    operations: HashMap<DocumentKey, (u64, CachedDocument)>,
+    /// Keys by the tick they were last used at, least recently used first.
+    recency: BTreeMap<u64, DocumentKey>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryDocumentCacheMetrics {
+    pub capacity: usize,
+    pub size: usize,
+    pub hits: u64,
+    pub misses: u64,
+    pub hit_rate: f64,
+    /// Number of operations that had to be validated against the query schema.
+    pub validations: u64,
+}
+
+impl QueryDocumentCache {
+    pub const DEFAULT_CAPACITY: usize = 1000;
+
+    /// A capacity of `0` disables caching.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(Entries::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            validations: AtomicU64::new(0),
+        }
+    }
+
+    pub fn get(&self, key: &DocumentKey) -> Option<CachedDocument> {
+        let mut entries = self.entries.lock().unwrap();
+        let tick = entries.tick + 1;
+
+        let operation = match entries.operations.get_mut(key) {
+            Some((last_used, operation)) => {
+                let previously_used = std::mem::replace(last_used, tick);
+                Some((previously_used, operation.clone()))
+            }
+            None => None,
+        };
+
+        match operation {
+            Some((previously_used, operation)) => {
+                entries.tick = tick;
+                entries.recency.remove(&previously_used);
+                entries.recency.insert(tick, key.clone());
+
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(operation)
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    pub fn insert(&self, key: DocumentKey, operation: CachedDocument) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+
+        entries.tick += 1;
+        let tick = entries.tick;
+
+        if let Some((previously_used, _)) = entries.operations.remove(&key) {
+            entries.recency.remove(&previously_used);
+        } else if entries.operations.len() >= self.capacity {
+            let least_recently_used = entries.recency.keys().next().cloned();
+
+            if let Some(evicted) = least_recently_used.and_then(|tick| entries.recency.remove(&tick)) {
+                entries.operations.remove(&evicted);
+            }
+        }
+
+        entries.recency.insert(tick, key.clone());
+        entries.operations.insert(key, (tick, operation));
+    }
+
+    /// Stores the validated form of a cached operation. Doesn't count as a use of the entry.
+    pub fn insert_parsed(&self, key: &DocumentKey, parsed: ParsedOperation) {
+        let mut entries = self.entries.lock().unwrap();
+
+        if let Some((_, document)) = entries.operations.get_mut(key) {
+            document.parsed = Some(parsed);
+        }
+    }
+
+    /// Records that an operation was validated against the query schema, because no validated form was cached.
+    pub fn record_validation(&self) {
+        self.validations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn metrics(&self) -> QueryDocumentCacheMetrics {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let lookups = hits + misses;
+        let hit_rate = if lookups == 0 {
+            0.0
+        } else {
+            hits as f64 / lookups as f64
+        };
+
+        QueryDocumentCacheMetrics {
+            capacity: self.capacity,
+            size: self.entries.lock().unwrap().operations.len(),
+            hits,
+            misses,
+            hit_rate,
+            validations: self.validations.load(Ordering::Relaxed),
+        }
+    }
+}
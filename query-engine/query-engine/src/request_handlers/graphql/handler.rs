@@ -1,21 +1,48 @@
-use super::protocol_adapter::GraphQLProtocolAdapter;
+use super::{
+    document_cache::{CachedDocument, DocumentKey, QueryDocumentCache},
+    protocol_adapter::GraphQLProtocolAdapter,
+};
 use crate::{context::PrismaContext, PrismaError, PrismaRequest, PrismaResponse, PrismaResult, RequestHandler};
 use async_trait::async_trait;
 use futures::{future, FutureExt};
 use graphql_parser as gql;
 use indexmap::IndexMap;
 use query_core::{
-    response_ir, BatchDocument, CompactedDocument, CoreError, Item, Operation, QueryDocument, QueryValue, Responses,
+    response_ir, BatchDocument, CompactedDocument, CoreError, IntrospectionResolver, Item, Operation, QueryDocument,
+    QueryGraphBuilder, QueryPlan, QueryValue, Responses,
 };
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, convert::TryFrom, panic::AssertUnwindSafe, sync::Arc};
+use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc, time::Duration};
+
+/// Header overriding the query timeout of the server for a single request, in milliseconds.
+/// A value of `0` disables the timeout.
+pub const QUERY_TIMEOUT_HEADER: &str = "x-query-timeout";
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SingleQuery {
-    query: String,
+    /// Can be omitted if the hash of a persisted query is sent instead.
+    #[serde(default)]
+    query: Option<String>,
     operation_name: Option<String>,
     variables: HashMap<String, String>,
+    #[serde(default)]
+    extensions: Option<QueryExtensions>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryExtensions {
+    persisted_query: Option<PersistedQuery>,
+}
+
+/// Automatic persisted query: The client sends the SHA-256 hash of the query and only sends the full
+/// query again if the engine answers with `PersistedQueryNotFound`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedQuery {
+    version: u32,
+    sha256_hash: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -34,9 +61,10 @@ pub enum GraphQlBody {
 impl From<String> for SingleQuery {
     fn from(query: String) -> Self {
         SingleQuery {
-            query,
+            query: Some(query),
             operation_name: None,
             variables: HashMap::new(),
+            extensions: None,
         }
     }
 }
@@ -47,25 +75,55 @@ impl From<&str> for SingleQuery {
     }
 }
 
-impl TryFrom<GraphQlBody> for QueryDocument {
-    type Error = PrismaError;
+impl SingleQuery {
+    /// Converts the query into an operation. Converted queries are cached by the hash of the query,
+    /// which also allows clients to only send the hash of a persisted query.
+    fn into_operation(self, cache: &QueryDocumentCache) -> PrismaResult<Operation> {
+        self.into_cached_document(cache).map(|(_, document)| document.operation)
+    }
 
-    fn try_from(body: GraphQlBody) -> PrismaResult<Self> {
-        match body {
-            GraphQlBody::Single(body) => {
-                let gql_doc = gql::parse_query(&body.query)?;
-                let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)?;
+    /// Looks the query up in the cache, converting and caching it on a miss.
+    fn into_cached_document(self, cache: &QueryDocumentCache) -> PrismaResult<(DocumentKey, CachedDocument)> {
+        let persisted_hash = self
+            .extensions
+            .and_then(|extensions| extensions.persisted_query)
+            .map(|persisted_query| persisted_query.sha256_hash);
 
-                Ok(QueryDocument::Single(operation))
+        let hash = match (&self.query, persisted_hash) {
+            (Some(query), Some(hash)) if DocumentKey::hash_query(query) != hash => {
+                return Err(PrismaError::PersistedQueryHashMismatch)
             }
+            (_, Some(hash)) => hash,
+            (Some(query), None) => DocumentKey::hash_query(query),
+            (None, None) => return Err(PrismaError::QueryConversionError("No query provided.".into())),
+        };
+
+        let key = DocumentKey::new(hash, self.operation_name.clone());
+
+        if let Some(document) = cache.get(&key) {
+            return Ok((key, document));
+        }
+
+        let query = self.query.ok_or(PrismaError::PersistedQueryNotFound)?;
+        let gql_doc = gql::parse_query(&query)?;
+        let operation = GraphQLProtocolAdapter::convert(gql_doc, self.operation_name)?;
+        let document = CachedDocument::new(operation);
+
+        cache.insert(key.clone(), document.clone());
+
+        Ok((key, document))
+    }
+}
+
+impl GraphQlBody {
+    fn into_document(self, cache: &QueryDocumentCache) -> PrismaResult<QueryDocument> {
+        match self {
+            GraphQlBody::Single(body) => Ok(QueryDocument::Single(body.into_operation(cache)?)),
             GraphQlBody::Multi(bodies) => {
                 let operations: PrismaResult<Vec<Operation>> = bodies
                     .batch
                     .into_iter()
-                    .map(|body| {
-                        let gql_doc = gql::parse_query(&body.query)?;
-                        GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)
-                    })
+                    .map(|body| body.into_operation(cache))
                     .collect();
 
                 Ok(QueryDocument::Multi(BatchDocument::new(operations?)))
@@ -88,11 +146,31 @@ impl RequestHandler for GraphQlRequestHandler {
         let request = req.into();
         debug!("Incoming GraphQL query: {:?}", request.body);
 
-        match QueryDocument::try_from(request.body) {
-            Ok(QueryDocument::Single(query)) => handle_single_query(query, ctx.clone()).await,
+        let timeout = query_timeout(&request.headers, ctx);
+
+        // Single queries are executed from the cached document, which can hold the validated operation.
+        let body = match request.body {
+            GraphQlBody::Single(query) => {
+                return match query.into_cached_document(ctx.document_cache()) {
+                    Ok((key, document)) => handle_single_query(document, Some(key), timeout, ctx.clone()).await,
+                    Err(err) => {
+                        let mut responses = response_ir::Responses::default();
+                        responses.insert_error(err);
+
+                        PrismaResponse::Single(responses)
+                    }
+                };
+            }
+            body => body,
+        };
+
+        match body.into_document(ctx.document_cache()) {
+            Ok(QueryDocument::Single(query)) => {
+                handle_single_query(CachedDocument::new(query), None, timeout, ctx.clone()).await
+            }
             Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-                BatchDocument::Multi(batch) => handle_batch(batch, ctx).await,
-                BatchDocument::Compact(compacted) => handle_compacted(compacted, ctx).await,
+                BatchDocument::Multi(batch) => handle_batch(batch, timeout, ctx).await,
+                BatchDocument::Compact(compacted) => handle_compacted(compacted, timeout, ctx).await,
             },
             Err(err) => {
                 let mut responses = response_ir::Responses::default();
@@ -104,10 +182,87 @@ impl RequestHandler for GraphQlRequestHandler {
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+/// The timeout of the request header, falling back to the server default.
+fn query_timeout(headers: &HashMap<String, String>, ctx: &PrismaContext) -> Option<Duration> {
+    let header = headers
+        .get(QUERY_TIMEOUT_HEADER)
+        .and_then(|value| value.trim().parse::<u64>().ok());
+
+    match header {
+        Some(0) => None,
+        Some(millis) => Some(Duration::from_millis(millis)),
+        None => ctx.query_timeout(),
+    }
+}
+
+/// Response of an explain request. Operations that can't be explained yield the usual error response.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum ExplainResponse {
+    Plan { plan: QueryPlan },
+    Error(response_ir::Responses),
+    Multi(Vec<ExplainResponse>),
+}
+
+/// Explains the operations of the body instead of executing them.
+pub async fn handle_explain(body: GraphQlBody, database_plan: bool, ctx: &Arc<PrismaContext>) -> ExplainResponse {
+    debug!("Incoming GraphQL query to explain: {:?}", body);
+
+    match body.into_document(ctx.document_cache()) {
+        Ok(QueryDocument::Single(operation)) => explain_operation(operation, database_plan, ctx).await,
+        Ok(QueryDocument::Multi(batch)) => {
+            // Batches are explained uncompacted, one plan per operation.
+            let operations = match batch {
+                BatchDocument::Multi(operations) => operations,
+                BatchDocument::Compact(compacted) => vec![compacted.operation],
+            };
+
+            let mut responses = vec![];
+
+            for operation in operations {
+                responses.push(explain_operation(operation, database_plan, ctx).await);
+            }
+
+            ExplainResponse::Multi(responses)
+        }
+        Err(err) => {
+            let mut responses = response_ir::Responses::default();
+            responses.insert_error(err);
+
+            ExplainResponse::Error(responses)
+        }
+    }
+}
+
+async fn explain_operation(operation: Operation, database_plan: bool, ctx: &PrismaContext) -> ExplainResponse {
+    let result = ctx
+        .executor
+        .explain(operation, Arc::clone(ctx.query_schema()), database_plan)
+        .await;
+
+    match result {
+        Ok(plan) => ExplainResponse::Plan { plan },
+        Err(err) => {
+            debug!("{}", err);
+
+            let mut responses = response_ir::Responses::default();
+            let err: PrismaError = err.into();
+
+            responses.insert_error(err);
+            ExplainResponse::Error(responses)
+        }
+    }
+}
+
+async fn handle_single_query(
+    document: CachedDocument,
+    key: Option<DocumentKey>,
+    timeout: Option<Duration>,
+    ctx: Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    let responses = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
+    let responses = match AssertUnwindSafe(handle_graphql_query(document, key, timeout, &*ctx))
         .catch_unwind()
         .await
     {
@@ -130,11 +285,16 @@ async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> Prism
     PrismaResponse::Single(responses)
 }
 
-async fn handle_batch(queries: Vec<Operation>, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_batch(queries: Vec<Operation>, timeout: Option<Duration>, ctx: &Arc<PrismaContext>) -> PrismaResponse {
     let mut futures = Vec::with_capacity(queries.len());
 
     for operation in queries.into_iter() {
-        futures.push(tokio::spawn(handle_single_query(operation, ctx.clone())));
+        futures.push(tokio::spawn(handle_single_query(
+            CachedDocument::new(operation),
+            None,
+            timeout,
+            ctx.clone(),
+        )));
     }
 
     let responses = future::join_all(futures)
@@ -146,7 +306,11 @@ async fn handle_batch(queries: Vec<Operation>, ctx: &Arc<PrismaContext>) -> Pris
     PrismaResponse::Multi(responses)
 }
 
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_compacted(
+    document: CompactedDocument,
+    timeout: Option<Duration>,
+    ctx: &Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
     let plural_name = document.plural_name();
@@ -154,8 +318,9 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     let keys = document.keys;
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
+    let document = CachedDocument::new(document.operation);
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
+    match AssertUnwindSafe(handle_graphql_query(document, None, timeout, ctx))
         .catch_unwind()
         .await
     {
@@ -217,13 +382,48 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<response_ir::Responses> {
+/// Executes the operation of the document. The operation is validated against the query schema unless the
+/// document already holds the validated operation, which is then stored in the cache entry of `key`, if given
+/// and the operation can be reused.
+async fn handle_graphql_query(
+    document: CachedDocument,
+    key: Option<DocumentKey>,
+    timeout: Option<Duration>,
+    ctx: &PrismaContext,
+) -> PrismaResult<response_ir::Responses> {
+    let to_prisma_error = |err: CoreError| -> PrismaError {
+        debug!("{}", err);
+        err.into()
+    };
+
+    let parsed = match document.parsed {
+        Some(parsed) => parsed,
+        None if IntrospectionResolver::is_introspection(&document.operation) => {
+            return match ctx.introspection() {
+                Some(resolver) => Ok(resolver.resolve(document.operation)?),
+                None => Err(PrismaError::UnsupportedFeatureError(
+                    "Introspection query",
+                    "Introspection is disabled on this server.".into(),
+                )),
+            };
+        }
+        None => {
+            ctx.document_cache().record_validation();
+
+            let parsed = QueryGraphBuilder::new(Arc::clone(ctx.query_schema()))
+                .parse(document.operation)
+                .map_err(|err| to_prisma_error(err.into()))?;
+
+            if let Some(key) = key.filter(|_| parsed.is_reusable()) {
+                ctx.document_cache().insert_parsed(&key, parsed.clone());
+            }
+
+            parsed
+        }
+    };
+
     ctx.executor
-        .execute(query_doc, Arc::clone(ctx.query_schema()))
+        .execute_parsed(parsed, Arc::clone(ctx.query_schema()), timeout)
         .await
-        .map_err(|err| {
-            debug!("{}", err);
-            let ce: CoreError = err.into();
-            ce.into()
-        })
+        .map_err(to_prisma_error)
 }
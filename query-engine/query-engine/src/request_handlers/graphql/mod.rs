@@ -1,7 +1,9 @@
+mod document_cache;
 mod handler;
 mod protocol_adapter;
 mod schema_renderer;
 
+pub use document_cache::*;
 pub use handler::*;
 pub use protocol_adapter::*;
 pub use schema_renderer::*;
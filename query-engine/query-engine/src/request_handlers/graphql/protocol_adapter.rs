@@ -1,10 +1,17 @@
 use crate::{error::PrismaError, PrismaResult};
 use graphql_parser::query::{
-    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+    Definition, Document, Field as GqlField, FragmentDefinition, OperationDefinition, Selection as GqlSelection,
+    SelectionSet, Value,
 };
 use query_core::query_document::*;
 use rust_decimal::Decimal;
-use std::{collections::BTreeMap, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
+
+/// Fragment definitions of a document by name.
+type Fragments = HashMap<String, FragmentDefinition>;
 
 /// Protocol adapter for GraphQL -> Query Document.
 ///
@@ -14,9 +21,10 @@ use std::{collections::BTreeMap, str::FromStr};
 /// - If the JSON payload specifies an operation name, only that specific operation is picked and the rest ignored.
 /// - Fields on the queries are mapped to `Field`s, including arguments.
 /// - Concrete values (e.g. in arguments) are mapped to `QueryValue`s.
+/// - Fragment spreads and inline fragments are inlined into the selection they appear in. Type conditions are
+///   ignored, as all types of the schema are concrete object types. Fields with the same response key are merged.
 ///
 /// Currently unsupported features:
-/// - Fragments in any form.
 /// - Variables.
 /// - Subscription queries.
 /// - Query names are ignored
@@ -24,6 +32,17 @@ pub struct GraphQLProtocolAdapter;
 
 impl GraphQLProtocolAdapter {
     pub fn convert(gql_doc: Document, operation: Option<String>) -> PrismaResult<Operation> {
+        let fragments: Fragments = gql_doc
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                Definition::Fragment(f) => Some((f.name.clone(), f.clone())),
+                Definition::Operation(_) => None,
+            })
+            .collect();
+
+        Self::check_fragment_cycles(&fragments)?;
+
         let mut operations: Vec<Operation> = match operation {
             Some(ref op) => gql_doc
                 .definitions
@@ -32,12 +51,12 @@ impl GraphQLProtocolAdapter {
                 .ok_or_else(|| {
                     PrismaError::QueryConversionError(format!("Operation '{}' does not match any query.", op))
                 })
-                .and_then(Self::convert_definition),
+                .and_then(|def| Self::convert_definition(def, &fragments)),
 
             None => gql_doc
                 .definitions
                 .into_iter()
-                .map(Self::convert_definition)
+                .map(|def| Self::convert_definition(def, &fragments))
                 .collect::<PrismaResult<Vec<Vec<Operation>>>>()
                 .map(|r| r.into_iter().flatten().collect::<Vec<Operation>>()),
         }?;
@@ -50,31 +69,29 @@ impl GraphQLProtocolAdapter {
         Ok(operation)
     }
 
-    fn convert_definition(def: Definition) -> PrismaResult<Vec<Operation>> {
+    fn convert_definition(def: Definition, fragments: &Fragments) -> PrismaResult<Vec<Operation>> {
         match def {
-            Definition::Fragment(f) => Err(PrismaError::UnsupportedFeatureError(
-                "Fragment definition",
-                format!("Fragment '{}', at position {}.", f.name, f.position),
-            )),
+            // Fragments are inlined where they are spread.
+            Definition::Fragment(_) => Ok(vec![]),
             Definition::Operation(op) => match op {
                 OperationDefinition::Subscription(s) => Err(PrismaError::UnsupportedFeatureError(
                     "Subscription query",
                     format!("At position {}.", s.position),
                 )),
-                OperationDefinition::SelectionSet(s) => Self::convert_query(s),
-                OperationDefinition::Query(q) => Self::convert_query(q.selection_set),
-                OperationDefinition::Mutation(m) => Self::convert_mutation(m.selection_set),
+                OperationDefinition::SelectionSet(s) => Self::convert_query(s, fragments),
+                OperationDefinition::Query(q) => Self::convert_query(q.selection_set, fragments),
+                OperationDefinition::Mutation(m) => Self::convert_mutation(m.selection_set, fragments),
             },
         }
     }
 
-    fn convert_query(selection_set: SelectionSet) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set)
+    fn convert_query(selection_set: SelectionSet, fragments: &Fragments) -> PrismaResult<Vec<Operation>> {
+        Self::convert_selection_set(selection_set, fragments)
             .map(|fields| fields.into_iter().map(|field| Operation::Read(field)).collect())
     }
 
-    fn convert_mutation(selection_set: SelectionSet) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| {
+    fn convert_mutation(selection_set: SelectionSet, fragments: &Fragments) -> PrismaResult<Vec<Operation>> {
+        Self::convert_selection_set(selection_set, fragments).map(|fields| {
             fields
                 .into_iter()
                 .map(|selection| Operation::Write(selection))
@@ -82,42 +99,119 @@ impl GraphQLProtocolAdapter {
         })
     }
 
-    fn convert_selection_set(selection_set: SelectionSet) -> PrismaResult<Vec<Selection>> {
-        selection_set
-            .items
+    fn convert_selection_set(selection_set: SelectionSet, fragments: &Fragments) -> PrismaResult<Vec<Selection>> {
+        let mut fields = vec![];
+        Self::collect_fields(selection_set, fragments, &mut fields)?;
+
+        Self::merge_fields(fields)
             .into_iter()
-            .map(|item| match item {
-                GqlSelection::Field(f) => {
-                    let arguments: Vec<(String, QueryValue)> = f
-                        .arguments
-                        .into_iter()
-                        .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
-                        .collect::<PrismaResult<Vec<_>>>()?;
-
-                    let mut builder = Selection::builder(f.name);
-                    builder.set_arguments(arguments);
-                    builder.nested_selections(Self::convert_selection_set(f.selection_set)?);
-
-                    if let Some(alias) = f.alias {
-                        builder.alias(alias);
-                    };
-
-                    Ok(builder.build())
-                }
+            .map(|f| {
+                let arguments: Vec<(String, QueryValue)> = f
+                    .arguments
+                    .into_iter()
+                    .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
+                    .collect::<PrismaResult<Vec<_>>>()?;
 
-                GqlSelection::FragmentSpread(fs) => Err(PrismaError::UnsupportedFeatureError(
-                    "Fragment spread",
-                    format!("Fragment '{}', at position {}.", fs.fragment_name, fs.position),
-                )),
+                let mut builder = Selection::builder(f.name);
+                builder.set_arguments(arguments);
+                builder.nested_selections(Self::convert_selection_set(f.selection_set, fragments)?);
 
-                GqlSelection::InlineFragment(i) => Err(PrismaError::UnsupportedFeatureError(
-                    "Inline fragment",
-                    format!("At position {}.", i.position),
-                )),
+                if let Some(alias) = f.alias {
+                    builder.alias(alias);
+                };
+
+                Ok(builder.build())
             })
             .collect()
     }
 
+    /// Collects the fields of a selection set, inlining all fragments.
+    fn collect_fields(
+        selection_set: SelectionSet,
+        fragments: &Fragments,
+        fields: &mut Vec<GqlField>,
+    ) -> PrismaResult<()> {
+        for item in selection_set.items {
+            match item {
+                GqlSelection::Field(f) => fields.push(f),
+                GqlSelection::FragmentSpread(fs) => {
+                    let fragment = fragments.get(&fs.fragment_name).ok_or_else(|| {
+                        PrismaError::QueryConversionError(format!(
+                            "Unknown fragment '{}', at position {}.",
+                            fs.fragment_name, fs.position
+                        ))
+                    })?;
+
+                    Self::collect_fields(fragment.selection_set.clone(), fragments, fields)?;
+                }
+                GqlSelection::InlineFragment(i) => Self::collect_fields(i.selection_set, fragments, fields)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Merges fields with the same response key (alias or name) by combining their selections.
+    fn merge_fields(fields: Vec<GqlField>) -> Vec<GqlField> {
+        let mut merged: Vec<GqlField> = Vec::with_capacity(fields.len());
+
+        for field in fields {
+            let key = field.alias.clone().unwrap_or_else(|| field.name.clone());
+            let existing = merged.iter_mut().find(|f| f.alias.as_ref().unwrap_or(&f.name) == &key);
+
+            match existing {
+                Some(existing) => existing.selection_set.items.extend(field.selection_set.items),
+                None => merged.push(field),
+            }
+        }
+
+        merged
+    }
+
+    /// Fragments spreading themselves, directly or through other fragments, can't be inlined.
+    fn check_fragment_cycles(fragments: &Fragments) -> PrismaResult<()> {
+        fragments
+            .values()
+            .map(|fragment| Self::check_fragment_path(fragment, fragments, &mut vec![fragment.name.as_str()]))
+            .collect()
+    }
+
+    fn check_fragment_path<'a>(
+        fragment: &'a FragmentDefinition,
+        fragments: &'a Fragments,
+        path: &mut Vec<&'a str>,
+    ) -> PrismaResult<()> {
+        let mut spreads = vec![];
+        Self::collect_spreads(&fragment.selection_set, &mut spreads);
+
+        for spread in spreads {
+            if path.contains(&spread) {
+                return Err(PrismaError::QueryConversionError(format!(
+                    "Fragment '{}' spreads itself.",
+                    spread
+                )));
+            }
+
+            if let Some(next) = fragments.get(spread) {
+                path.push(spread);
+                Self::check_fragment_path(next, fragments, path)?;
+                path.pop();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn collect_spreads<'a>(selection_set: &'a SelectionSet, spreads: &mut Vec<&'a str>) {
+        for item in selection_set.items.iter() {
+            match item {
+                GqlSelection::Field(f) => Self::collect_spreads(&f.selection_set, spreads),
+                GqlSelection::FragmentSpread(fs) => spreads.push(&fs.fragment_name),
+                GqlSelection::InlineFragment(i) => Self::collect_spreads(&i.selection_set, spreads),
+            }
+        }
+    }
+
     /// Checks if the given GraphQL definition matches the operation name that should be executed.
     fn matches_operation(def: &Definition, operation: &str) -> bool {
         let check = |n: Option<&String>| n.filter(|name| name.as_str() == operation).is_some();
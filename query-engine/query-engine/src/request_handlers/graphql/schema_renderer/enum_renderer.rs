@@ -10,7 +10,7 @@ impl<'a> Renderer for GqlEnumRenderer<'a> {
             return ("".to_owned(), ctx);
         }
 
-        let values = self.format_enum_values();
+        let values = self.enum_type.values();
         let rendered = format!("enum {} {{\n{}\n}}", self.enum_type.name(), values.join("\n"));
 
         ctx.add(self.enum_type.name().to_owned(), rendered.clone());
@@ -22,11 +22,4 @@ impl<'a> GqlEnumRenderer<'a> {
     pub fn new(enum_type: &EnumType) -> GqlEnumRenderer {
         GqlEnumRenderer { enum_type }
     }
-
-    fn format_enum_values(&self) -> Vec<String> {
-        match self.enum_type {
-            EnumType::Internal(i) => i.external_values(),
-            EnumType::OrderBy(ord) => ord.values(),
-        }
-    }
 }
@@ -55,6 +55,9 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Decimal => "Decimal",
+                    ScalarType::BigInt => "BigInt",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::JsonList => "Json",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
@@ -96,6 +99,9 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Decimal => "Decimal",
+                    ScalarType::BigInt => "BigInt",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::JsonList => "Json",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
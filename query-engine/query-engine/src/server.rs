@@ -2,7 +2,7 @@ use super::dmmf;
 use crate::{
     context::PrismaContext,
     request_handlers::{
-        graphql::{GraphQLSchemaRenderer, GraphQlBody, GraphQlRequestHandler},
+        graphql::{self, GraphQLSchemaRenderer, GraphQlBody, GraphQlRequestHandler, QueryDocumentCache},
         PrismaRequest, RequestHandler,
     },
     PrismaResult,
@@ -11,10 +11,13 @@ use datamodel::{Configuration, Datamodel};
 use hyper::header;
 use hyper::service::{make_service_fn, service_fn};
 use hyper::{Body, Error, Method, Request, Response, Server, StatusCode};
-use query_core::schema::QuerySchemaRenderer;
+use query_core::{schema::QuerySchemaRenderer, QueryLimits};
 use serde_json::json;
 use std::net::SocketAddr;
-use std::{sync::Arc, time::Instant};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 #[derive(RustEmbed)]
 #[folder = "static_files"]
@@ -38,6 +41,10 @@ pub struct HttpServerBuilder {
     legacy_mode: bool,
     enable_raw_queries: bool,
     enable_playground: bool,
+    enable_introspection: bool,
+    query_timeout: Option<Duration>,
+    query_limits: QueryLimits,
+    query_cache_size: usize,
 }
 
 impl HttpServerBuilder {
@@ -56,10 +63,34 @@ impl HttpServerBuilder {
         self
     }
 
+    pub fn enable_introspection(mut self, val: bool) -> Self {
+        self.enable_introspection = val;
+        self
+    }
+
+    pub fn query_timeout(mut self, val: Option<Duration>) -> Self {
+        self.query_timeout = val;
+        self
+    }
+
+    pub fn query_limits(mut self, val: QueryLimits) -> Self {
+        self.query_limits = val;
+        self
+    }
+
+    pub fn query_cache_size(mut self, val: usize) -> Self {
+        self.query_cache_size = val;
+        self
+    }
+
     pub async fn build_and_run(self, address: SocketAddr) -> PrismaResult<()> {
         let ctx = PrismaContext::builder(self.config, self.datamodel)
             .legacy(self.legacy_mode)
             .enable_raw_queries(self.enable_raw_queries)
+            .enable_introspection(self.enable_introspection)
+            .query_timeout(self.query_timeout)
+            .query_limits(self.query_limits)
+            .query_cache_size(self.query_cache_size)
             .build()
             .await?;
 
@@ -77,6 +108,10 @@ impl HttpServer {
             legacy_mode: false,
             enable_raw_queries: false,
             enable_playground: false,
+            enable_introspection: true,
+            query_timeout: None,
+            query_limits: QueryLimits::default(),
+            query_cache_size: QueryDocumentCache::DEFAULT_CAPACITY,
         }
     }
 
@@ -136,12 +171,35 @@ impl HttpServer {
                 }
             }
 
+            (&Method::POST, "/explain") => {
+                let database_plan = req
+                    .uri()
+                    .query()
+                    .map(|query| {
+                        url::form_urlencoded::parse(query.as_bytes())
+                            .any(|(key, value)| key == "database_plan" && value == "true")
+                    })
+                    .unwrap_or(false);
+
+                let bytes = hyper::body::to_bytes(req.into_body()).await?;
+
+                match serde_json::from_slice(bytes.as_ref()) {
+                    Ok(body) => Self::explain_handler(body, database_plan, ctx).await,
+                    Err(_) => {
+                        let mut bad_request = Response::default();
+                        *bad_request.status_mut() = StatusCode::BAD_REQUEST;
+                        bad_request
+                    }
+                }
+            }
+
             (&Method::GET, "/") if ctx.enable_playground => Self::playground_handler(),
             (&Method::GET, "/status") => Self::status_handler(),
 
             (&Method::GET, "/sdl") => Self::sdl_handler(ctx),
             (&Method::GET, "/dmmf") => Self::dmmf_handler(ctx),
             (&Method::GET, "/server_info") => Self::server_info_handler(ctx),
+            (&Method::GET, "/metrics") => Self::metrics_handler(ctx),
 
             _ => {
                 let mut not_found = Response::default();
@@ -167,6 +225,19 @@ impl HttpServer {
             .unwrap()
     }
 
+    /// Explains the query graph of the request and the statements issued for reads, without executing it.
+    /// The database's own plans are included if the `database_plan=true` query parameter is set.
+    async fn explain_handler(body: GraphQlBody, database_plan: bool, cx: Arc<RequestContext>) -> Response<Body> {
+        let result = graphql::handle_explain(body, database_plan, cx.context()).await;
+        let bytes = serde_json::to_vec(&result).unwrap();
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(bytes))
+            .unwrap()
+    }
+
     fn status_handler() -> Response<Body> {
         let body_data = json!({"status": "ok"});
         let bytes = serde_json::to_vec(&body_data).unwrap();
@@ -214,6 +285,21 @@ impl HttpServer {
             .unwrap()
     }
 
+    /// Runtime metrics of the engine, currently the hit rates of the query document cache.
+    fn metrics_handler(cx: Arc<RequestContext>) -> Response<Body> {
+        let json = json!({
+            "queryDocumentCache": cx.context.document_cache().metrics(),
+        });
+
+        let bytes = serde_json::to_vec(&json).unwrap();
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(bytes))
+            .unwrap()
+    }
+
     /// Simple status endpoint
     fn server_info_handler(cx: Arc<RequestContext>) -> Response<Body> {
         let json = json!({
@@ -1,4 +1,13 @@
+mod composite_filters;
+mod distinct;
 mod dmmf;
 mod execute_raw;
+mod explain;
+mod introspection;
+mod persisted_queries;
+mod query_limits;
+mod referential_actions;
+mod scalar_types;
 mod test_api;
+mod timeout;
 mod type_mappings;
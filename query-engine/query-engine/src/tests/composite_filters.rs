@@ -0,0 +1,69 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static DATAMODEL: &str = indoc! {r#"
+    model User {
+        id      Int      @id
+        name    String
+        address Address?
+    }
+
+    type Address {
+        street String
+        city   String
+    }
+"#};
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn composite_filters_are_scoped_by_the_other_filters(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(DATAMODEL).await?;
+
+    for (id, name, city) in &[(1, "Ada", "Berlin"), (2, "Grace", "Berlin"), (3, "Ada", "Paris")] {
+        query_engine
+            .request(format!(
+                r#"mutation {{ createOneUser(data: {{ id: {}, name: "{}", address: {{ street: "Main", city: "{}" }} }}) {{ id }} }}"#,
+                id, name, city
+            ))
+            .await;
+    }
+
+    let result = query_engine
+        .request(r#"query { findManyUser(where: { name: "Ada", address: { city: "Berlin" } }) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 1 }]), result["data"]["findManyUser"]);
+
+    let result = query_engine
+        .request(r#"query { findManyUser(where: { OR: [{ name: "Grace" }, { address: { city: "Paris" } }] }, orderBy: id_ASC) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 2 }, { "id": 3 }]), result["data"]["findManyUser"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn composite_filters_read_all_batches(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(DATAMODEL).await?;
+
+    for id in 1..=1005 {
+        let city = if id % 500 == 0 { "Berlin" } else { "Paris" };
+
+        query_engine
+            .request(format!(
+                r#"mutation {{ createOneUser(data: {{ id: {}, name: "User", address: {{ street: "Main", city: "{}" }} }}) {{ id }} }}"#,
+                id, city
+            ))
+            .await;
+    }
+
+    let result = query_engine
+        .request(r#"query { findManyUser(where: { address: { city: "Berlin" } }, orderBy: id_ASC) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 500 }, { "id": 1000 }]), result["data"]["findManyUser"]);
+
+    Ok(())
+}
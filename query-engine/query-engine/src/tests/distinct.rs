@@ -0,0 +1,104 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id       Int    @id
+        title    String
+        priority Int
+    }
+"};
+
+async fn create_todos(query_engine: &QueryEngine) -> anyhow::Result<()> {
+    let todos = [
+        (1, "a", 3),
+        (2, "b", 1),
+        (3, "a", 2),
+        (4, "c", 3),
+        (5, "b", 2),
+        (6, "d", 1),
+    ];
+
+    for (id, title, priority) in todos.iter() {
+        let mutation = format!(
+            r#"mutation {{ createOneTodo(data: {{ id: {}, title: "{}", priority: {} }}) {{ id }} }}"#,
+            id, title, priority
+        );
+
+        query_engine.request(mutation).await;
+    }
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn distinct_with_pagination_is_processed_in_memory(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(distinct: [title], skip: 1, take: 2) {
+                id
+            }
+        }
+    "#};
+
+    let explanation = query_engine.explain(query, false).await;
+    let statement = explanation["plan"]["nodes"][0]["read"]["statements"][0]["statement"]
+        .as_str()
+        .unwrap();
+
+    assert!(!statement.contains("LIMIT"));
+    assert!(!statement.contains("OFFSET"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn distinct_with_pagination_keeps_the_first_record_of_each_group(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    create_todos(&query_engine).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(distinct: [title], skip: 1, take: 2) {
+                id
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 2 }, { "id": 4 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(distinct: [title], orderBy: priority_ASC, where: { id: { not: 2 } }, take: 3) {
+                id
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 6 }, { "id": 3 }, { "id": 5 }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(distinct: [title], take: -2) {
+                id
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 4 }, { "id": 6 }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
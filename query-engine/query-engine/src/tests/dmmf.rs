@@ -1,6 +1,7 @@
 use prisma_models::DatamodelConverter;
 use query_core::{BuildMode, QuerySchema, QuerySchemaBuilder, SupportedCapabilities};
 use serial_test::serial;
+use sql_connector::capabilities;
 use std::sync::Arc;
 
 // Tests in this file run serially because the function `get_query_schema` depends on setting an env var.
@@ -97,13 +98,192 @@ fn must_not_fail_on_missing_env_vars_in_a_datasource() {
         .expect("finding BlogCreateInput");
 }
 
+#[test]
+#[serial]
+fn dmmf_reflects_the_capabilities_of_the_connector() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost:5432/db"
+        }
+
+        model Post {
+            id   String   @id
+            tags String[]
+            meta Json
+        }
+    "#;
+
+    let output_fields = |dmmf: &crate::dmmf::DataModelMetaFormat, name: &str| -> Vec<String> {
+        let typ = dmmf.schema.output_types.iter().find(|typ| typ.name == name).unwrap();
+        typ.fields.iter().map(|field| field.name.clone()).collect()
+    };
+
+    let input_fields = |dmmf: &crate::dmmf::DataModelMetaFormat, name: &str| -> Vec<String> {
+        let typ = dmmf.schema.input_types.iter().find(|typ| typ.name == name).unwrap();
+        typ.fields.iter().map(|field| field.name.clone()).collect()
+    };
+
+    let capabilities = SupportedCapabilities::new(capabilities::postgres_capabilities(None));
+    let (query_schema, datamodel) = get_query_schema_with_capabilities(dm, &capabilities);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    assert_eq!(output_fields(&dmmf, "Post"), &["id", "tags", "meta"]);
+    assert!(input_fields(&dmmf, "PostCreateInput").contains(&"tags".to_owned()));
+    assert!(input_fields(&dmmf, "PostWhereInput").contains(&"meta".to_owned()));
+
+    // Postgres versions before 9.4 don't support filtering on JSON.
+    let capabilities = SupportedCapabilities::new(capabilities::postgres_capabilities(Some("9.3.25")));
+    let (query_schema, datamodel) = get_query_schema_with_capabilities(dm, &capabilities);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    assert!(!input_fields(&dmmf, "PostWhereInput").contains(&"meta".to_owned()));
+
+    // SQLite stores Json values as text, which can be filtered on.
+    let capabilities = SupportedCapabilities::new(capabilities::sqlite_capabilities(None));
+    let (query_schema, datamodel) = get_query_schema_with_capabilities(dm, &capabilities);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    assert!(input_fields(&dmmf, "PostWhereInput").contains(&"meta".to_owned()));
+
+    // Without scalar list support, list fields are neither readable nor writable.
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    assert_eq!(output_fields(&dmmf, "Post"), &["id", "meta"]);
+    assert!(!input_fields(&dmmf, "PostCreateInput").contains(&"tags".to_owned()));
+}
+
+#[test]
+#[serial]
+fn dmmf_exposes_views_as_read_only() {
+    let dm = r#"
+        model Post {
+            id       Int    @id
+            authorId Int
+            author   Author @relation(fields: [authorId], references: [id])
+        }
+
+        view Author {
+            id    Int    @id
+            posts Post[]
+        }
+    "#;
+
+    let output_fields = |dmmf: &crate::dmmf::DataModelMetaFormat, name: &str| -> Vec<String> {
+        let typ = dmmf.schema.output_types.iter().find(|typ| typ.name == name).unwrap();
+        typ.fields.iter().map(|field| field.name.clone()).collect()
+    };
+
+    let input_fields = |dmmf: &crate::dmmf::DataModelMetaFormat, name: &str| -> Vec<String> {
+        let typ = dmmf.schema.input_types.iter().find(|typ| typ.name == name).unwrap();
+        typ.fields.iter().map(|field| field.name.clone()).collect()
+    };
+
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let query_fields = output_fields(&dmmf, "Query");
+    let mutation_fields = output_fields(&dmmf, "Mutation");
+
+    assert!(query_fields.contains(&"findManyAuthor".to_owned()));
+    assert!(mutation_fields.contains(&"createOnePost".to_owned()));
+    assert!(!mutation_fields.iter().any(|field| field.ends_with("Author")));
+    assert_eq!(input_fields(&dmmf, "AuthorCreateOneWithoutPostsInput"), &["connect"]);
+}
+
+#[test]
+#[serial]
+fn dmmf_exposes_composite_types_as_typed_objects() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://localhost:5432/db"
+        }
+
+        model User {
+            id      Int      @id
+            address Address?
+        }
+
+        type Address {
+            street String
+            city   String?
+            geo    Geo
+        }
+
+        type Geo {
+            lat Float
+            lng Float
+        }
+    "#;
+
+    let output_fields = |dmmf: &crate::dmmf::DataModelMetaFormat, name: &str| -> Vec<String> {
+        let typ = dmmf.schema.output_types.iter().find(|typ| typ.name == name).unwrap();
+        typ.fields.iter().map(|field| field.name.clone()).collect()
+    };
+
+    let input_fields = |dmmf: &crate::dmmf::DataModelMetaFormat, name: &str| -> Vec<String> {
+        let typ = dmmf.schema.input_types.iter().find(|typ| typ.name == name).unwrap();
+        typ.fields.iter().map(|field| field.name.clone()).collect()
+    };
+
+    let capabilities = SupportedCapabilities::new(capabilities::postgres_capabilities(None));
+    let (query_schema, datamodel) = get_query_schema_with_capabilities(dm, &capabilities);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    assert_eq!(dmmf.data_model["types"][0]["name"], "Address");
+    assert_eq!(dmmf.data_model["types"][1]["name"], "Geo");
+
+    assert_eq!(output_fields(&dmmf, "Address"), &["street", "city", "geo"]);
+    assert_eq!(output_fields(&dmmf, "Geo"), &["lat", "lng"]);
+    assert_eq!(input_fields(&dmmf, "AddressInput"), &["street", "city", "geo"]);
+
+    let user_where_fields = input_fields(&dmmf, "UserWhereInput");
+    assert!(user_where_fields.contains(&"address".to_owned()));
+    assert!(!user_where_fields.contains(&"address_not".to_owned()));
+
+    let address_where_fields = input_fields(&dmmf, "AddressWhereInput");
+    assert!(address_where_fields.contains(&"city_contains".to_owned()));
+    assert!(address_where_fields.contains(&"geo".to_owned()));
+    assert_eq!(input_fields(&dmmf, "GeoWhereInput")[0], "lat");
+}
+
+#[test]
+#[serial]
+fn dmmf_exposes_views_without_unique_criteria_through_find_many_only() {
+    let dm = r#"
+        view UserSummary {
+            name      String
+            postCount Int
+        }
+    "#;
+
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let query_type = dmmf.schema.output_types.iter().find(|typ| typ.name == "Query").unwrap();
+    let query_fields: Vec<&str> = query_type.fields.iter().map(|field| field.name.as_str()).collect();
+
+    assert!(query_fields.contains(&"findManyUserSummary"));
+    assert!(!query_fields.contains(&"findOneUserSummary"));
+}
+
 fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Datamodel) {
-    let dm = datamodel::parse_datamodel_and_ignore_env_errors(datamodel_string).unwrap();
+    get_query_schema_with_capabilities(datamodel_string, &SupportedCapabilities::empty())
+}
+
+fn get_query_schema_with_capabilities(
+    datamodel_string: &str,
+    supported_capabilities: &SupportedCapabilities,
+) -> (QuerySchema, datamodel::dml::Datamodel) {
+    let dm = datamodel::parse_datamodel_and_ignore_env_errors(datamodel_string)
+        .unwrap()
+        .subject;
     let internal_dm_template = DatamodelConverter::convert(&dm);
     let internal_ref = internal_dm_template.build("db".to_owned());
-    let supported_capabilities = SupportedCapabilities::empty();
     (
-        QuerySchemaBuilder::new(&internal_ref, &supported_capabilities, BuildMode::Modern, false).build(),
+        QuerySchemaBuilder::new(&internal_ref, supported_capabilities, BuildMode::Modern, false).build(),
         dm,
     )
 }
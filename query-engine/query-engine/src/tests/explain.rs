@@ -0,0 +1,109 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    String @id @default(cuid())
+        title String
+        tags  Tag[]
+    }
+
+    model Tag {
+        id      String @id @default(cuid())
+        name    String
+        todo_id String
+        todo    Todo   @relation(fields: [todo_id], references: [id])
+    }
+"};
+
+#[test_each_connector]
+async fn explaining_a_read_renders_the_statements(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(where: { title: "foo" }) {
+                title
+                tags { name }
+            }
+        }
+    "#};
+
+    let explanation = query_engine.explain(query, false).await;
+    let nodes = explanation["plan"]["nodes"].as_array().unwrap();
+
+    assert_eq!(1, nodes.len());
+    assert_eq!("Read", nodes[0]["kind"]);
+
+    let read = &nodes[0]["read"];
+    let statement = read["statements"][0]["statement"].as_str().unwrap();
+
+    assert!(statement.starts_with("SELECT"));
+    assert!(statement.contains("Todo"));
+    assert_eq!(json!(["foo"]), read["statements"][0]["parameters"]);
+    assert!(read["statements"][0].get("databasePlan").is_none());
+
+    let nested = &read["nested"][0];
+
+    assert_eq!("tags", nested["name"]);
+    assert_eq!(true, nested["dependsOnParent"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn explaining_a_read_can_include_the_database_plan(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo {
+                title
+            }
+        }
+    "#};
+
+    let explanation = query_engine.explain(query, true).await;
+    let statement = &explanation["plan"]["nodes"][0]["read"]["statements"][0];
+
+    assert!(statement["databasePlan"].is_array());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn explaining_a_write_does_not_execute_it(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let mutation = indoc! {r#"
+        mutation {
+            createOneTodo(data: { title: "foo" }) {
+                id
+            }
+        }
+    "#};
+
+    let explanation = query_engine.explain(mutation, false).await;
+    let nodes = explanation["plan"]["nodes"].as_array().unwrap();
+
+    assert_eq!(true, explanation["plan"]["needsTransaction"]);
+    assert!(nodes.iter().any(|node| node["kind"] == "Write"));
+    assert!(explanation["plan"]["edges"].as_array().unwrap().len() > 0);
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo {
+                title
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
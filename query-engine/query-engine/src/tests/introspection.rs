@@ -0,0 +1,208 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    /// Something that needs to be done.
+    model Todo {
+        id    String @id @default(cuid())
+        /// What needs to be done.
+        title String
+        done  Boolean @default(false)
+    }
+"};
+
+/// The introspection query sent by common GraphQL clients, shortened.
+static INTROSPECTION_QUERY: &str = indoc! {"
+    query IntrospectionQuery {
+        __schema {
+            queryType { name }
+            mutationType { name }
+            subscriptionType { name }
+            types { ...FullType }
+            directives { name args { ...InputValue } }
+        }
+    }
+
+    fragment FullType on __Type {
+        kind
+        name
+        description
+        fields(includeDeprecated: true) {
+            name
+            args { ...InputValue }
+            type { ...TypeRef }
+            isDeprecated
+        }
+        inputFields { ...InputValue }
+        enumValues(includeDeprecated: true) { name }
+    }
+
+    fragment InputValue on __InputValue {
+        name
+        type { ...TypeRef }
+        defaultValue
+    }
+
+    fragment TypeRef on __Type {
+        kind
+        name
+        ofType { kind name ofType { kind name } }
+    }
+"};
+
+#[test_each_connector]
+async fn the_introspection_query_of_graphql_clients_works(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let result = query_engine.request(INTROSPECTION_QUERY).await;
+
+    let schema = &result["data"]["__schema"];
+
+    assert_eq!(json!({ "name": "Query" }), schema["queryType"]);
+    assert_eq!(json!({ "name": "Mutation" }), schema["mutationType"]);
+    assert_eq!(json!(null), schema["subscriptionType"]);
+
+    let types = schema["types"].as_array().unwrap();
+    let todo = types.iter().find(|typ| typ["name"] == "Todo").unwrap();
+
+    assert_eq!("OBJECT", todo["kind"]);
+    assert_eq!("Something that needs to be done.", todo["description"]);
+
+    let title = todo["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|field| field["name"] == "title")
+        .unwrap();
+
+    assert_eq!(
+        json!({ "kind": "NON_NULL", "name": null, "ofType": { "kind": "SCALAR", "name": "String", "ofType": null } }),
+        title["type"]
+    );
+
+    let order_by = types.iter().find(|typ| typ["name"] == "TodoOrderByInput").unwrap();
+    assert_eq!("ENUM", order_by["kind"]);
+
+    let create_input = types.iter().find(|typ| typ["name"] == "TodoCreateInput").unwrap();
+    assert_eq!("INPUT_OBJECT", create_input["kind"]);
+
+    let done = create_input["inputFields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|field| field["name"] == "done")
+        .unwrap();
+
+    assert_eq!(
+        json!({ "kind": "SCALAR", "name": "Boolean", "ofType": null }),
+        done["type"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn single_types_can_be_introspected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let result = query_engine
+        .request(r#"query { __type(name: "Todo") { name fields { name } } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "__type": { "name": "Todo", "fields": [{ "name": "id" }, { "name": "title" }, { "name": "done" }] } } }),
+        result
+    );
+
+    let result = query_engine
+        .request(r#"query { __type(name: "Unknown") { name } }"#)
+        .await;
+
+    assert_eq!(json!({ "data": { "__type": null } }), result);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn field_descriptions_come_from_the_field_documentation(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let result = query_engine
+        .request(r#"query { __type(name: "Todo") { fields { name description } } }"#)
+        .await;
+
+    assert_eq!(
+        json!([
+            { "name": "id", "description": null },
+            { "name": "title", "description": "What needs to be done." },
+            { "name": "done", "description": null },
+        ]),
+        result["data"]["__type"]["fields"]
+    );
+
+    let result = query_engine
+        .request(r#"query { __type(name: "TodoCreateInput") { inputFields { name description } } }"#)
+        .await;
+
+    let title = result["data"]["__type"]["inputFields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|field| field["name"] == "title")
+        .unwrap();
+
+    assert_eq!("What needs to be done.", title["description"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn typename_can_be_selected_on_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let result = query_engine
+        .request(r#"mutation { createOneTodo(data: { title: "Write tests" }) { __typename title } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "createOneTodo": { "__typename": "Todo", "title": "Write tests" } } }),
+        result
+    );
+
+    let result = query_engine
+        .request("query { findManyTodo { title __typename } }")
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "title": "Write tests", "__typename": "Todo" }] } }),
+        result
+    );
+
+    let result = query_engine.request("query { __typename }").await;
+
+    assert_eq!(json!({ "data": { "__typename": "Query" } }), result);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn introspection_can_be_disabled(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with(&TODO, |builder| builder.enable_introspection(false))
+        .await?;
+
+    let result = query_engine.request(INTROSPECTION_QUERY).await;
+
+    assert_eq!(json!(null), result["data"]["__schema"]);
+    assert!(result["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("Introspection is disabled"));
+
+    let result = query_engine.request("query { findManyTodo { __typename } }").await;
+
+    assert_eq!(json!({ "data": { "findManyTodo": [] } }), result);
+
+    Ok(())
+}
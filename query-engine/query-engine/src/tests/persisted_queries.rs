@@ -0,0 +1,135 @@
+use super::test_api::*;
+use crate::request_handlers::DocumentKey;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    String @id @default(cuid())
+        title String
+    }
+"};
+
+static FIND_TODOS: &str = "query { findManyTodo { title } }";
+
+fn persisted_query(hash: &str) -> serde_json::Value {
+    json!({ "persistedQuery": { "version": 1, "sha256Hash": hash } })
+}
+
+#[test_each_connector]
+async fn unknown_persisted_queries_are_reported_to_the_client(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let hash = DocumentKey::hash_query(FIND_TODOS);
+
+    let result = query_engine
+        .request_json(json!({ "variables": {}, "extensions": persisted_query(&hash) }))
+        .await;
+
+    assert_eq!("PersistedQueryNotFound", result["errors"][0]["error"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn persisted_queries_can_be_sent_by_hash_once_registered(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let hash = DocumentKey::hash_query(FIND_TODOS);
+
+    let result = query_engine
+        .request_json(json!({ "query": FIND_TODOS, "variables": {}, "extensions": persisted_query(&hash) }))
+        .await;
+
+    assert_eq!(json!([]), result["data"]["findManyTodo"]);
+
+    let result = query_engine
+        .request_json(json!({ "variables": {}, "extensions": persisted_query(&hash) }))
+        .await;
+
+    assert_eq!(json!([]), result["data"]["findManyTodo"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn persisted_queries_with_a_wrong_hash_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let hash = DocumentKey::hash_query("query { findManyTodo { id } }");
+
+    let result = query_engine
+        .request_json(json!({ "query": FIND_TODOS, "variables": {}, "extensions": persisted_query(&hash) }))
+        .await;
+
+    assert!(result["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("does not match the query"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn repeated_queries_are_served_from_the_document_cache(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    query_engine.request(FIND_TODOS).await;
+    query_engine.request(FIND_TODOS).await;
+    query_engine.request(FIND_TODOS).await;
+
+    let metrics = query_engine.document_cache_metrics();
+
+    assert_eq!(1, metrics.size);
+    assert_eq!(1, metrics.misses);
+    assert_eq!(2, metrics.hits);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cache_hits_skip_parsing_and_validation(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let first = query_engine.request(FIND_TODOS).await;
+    let second = query_engine.request(FIND_TODOS).await;
+    let third = query_engine.request(FIND_TODOS).await;
+
+    assert_eq!(first, second);
+    assert_eq!(first, third);
+
+    let metrics = query_engine.document_cache_metrics();
+
+    assert_eq!(2, metrics.hits);
+    assert_eq!(1, metrics.validations);
+
+    // Invalid operations are validated again on every request.
+    let invalid = "query { findManyTodo { doesNotExist } }";
+
+    query_engine.request(invalid).await;
+    let result = query_engine.request(invalid).await;
+
+    assert!(result["errors"][0]["error"].is_string());
+    assert_eq!(3, query_engine.document_cache_metrics().validations);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cached_creates_generate_new_defaults_on_every_request(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let create_todo = r#"mutation { createOneTodo(data: { title: "Write tests" }) { id } }"#;
+
+    let first = query_engine.request(create_todo).await;
+    let second = query_engine.request(create_todo).await;
+
+    let first_id = first["data"]["createOneTodo"]["id"].as_str().unwrap();
+    let second_id = second["data"]["createOneTodo"]["id"].as_str().unwrap();
+
+    assert_ne!(first_id, second_id);
+
+    let metrics = query_engine.document_cache_metrics();
+
+    assert_eq!(1, metrics.hits);
+    assert_eq!(2, metrics.validations);
+
+    Ok(())
+}
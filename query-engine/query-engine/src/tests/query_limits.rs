@@ -0,0 +1,183 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::QueryLimits;
+use serde_json::json;
+use test_macros::*;
+
+static BLOG: &str = indoc! {"
+    model User {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id        Int       @id
+        title     String
+        author_id Int
+        author    User      @relation(fields: [author_id], references: [id])
+        comments  Comment[]
+    }
+
+    model Comment {
+        id      Int    @id
+        text    String
+        post_id Int
+        post    Post   @relation(fields: [post_id], references: [id])
+    }
+"};
+
+static CREATE_USER: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: {
+            id: 1,
+            name: "Alice",
+            posts: { create: [
+                { id: 1, title: "One", comments: { create: [{ id: 1, text: "a" }, { id: 2, text: "b" }] } },
+                { id: 2, title: "Two" }
+            ] }
+        }) { id }
+    }
+"#};
+
+fn error_code(result: &serde_json::Value) -> Option<&str> {
+    result["errors"][0]["user_facing_error"]["error_code"].as_str()
+}
+
+#[test_each_connector]
+async fn selections_deeper_than_the_limit_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let limits = QueryLimits {
+        max_depth: Some(2),
+        ..Default::default()
+    };
+
+    let query_engine = api.create_engine_with_limits(&BLOG, limits).await?;
+    query_engine.request(CREATE_USER).await;
+
+    let result = query_engine.request("query { findManyUser { posts { title } } }").await;
+
+    assert_eq!(
+        json!([{ "posts": [{ "title": "One" }, { "title": "Two" }] }]),
+        result["data"]["findManyUser"]
+    );
+
+    let result = query_engine
+        .request("query { findManyUser { posts { comments { text } } } }")
+        .await;
+
+    assert_eq!(Some("P2022"), error_code(&result));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn too_many_relation_reads_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let limits = QueryLimits {
+        max_relation_reads: Some(1),
+        ..Default::default()
+    };
+
+    let query_engine = api.create_engine_with_limits(&BLOG, limits).await?;
+
+    let result = query_engine
+        .request("query { findManyUser { posts { id } a: posts { id } } }")
+        .await;
+
+    assert_eq!(Some("P2022"), error_code(&result));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn take_values_above_the_limit_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let limits = QueryLimits {
+        max_take: Some(10),
+        ..Default::default()
+    };
+
+    let query_engine = api.create_engine_with_limits(&BLOG, limits).await?;
+
+    let result = query_engine.request("query { findManyUser(take: -11) { id } }").await;
+    assert_eq!(Some("P2022"), error_code(&result));
+
+    let result = query_engine
+        .request("query { findManyUser(take: 10) { posts(take: 100) { id } } }")
+        .await;
+
+    assert_eq!(Some("P2022"), error_code(&result));
+
+    let result = query_engine.request("query { findManyUser(take: 10) { id } }").await;
+    assert_eq!(json!([]), result["data"]["findManyUser"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn reading_more_records_than_the_limit_fails(api: &TestApi) -> anyhow::Result<()> {
+    let limits = QueryLimits {
+        max_records: Some(3),
+        ..Default::default()
+    };
+
+    let query_engine = api.create_engine_with_limits(&BLOG, limits).await?;
+    query_engine.request(CREATE_USER).await;
+
+    // One user and two posts.
+    let result = query_engine.request("query { findManyUser { posts { id } } }").await;
+    assert_eq!(
+        json!([{ "posts": [{ "id": 1 }, { "id": 2 }] }]),
+        result["data"]["findManyUser"]
+    );
+
+    // One user, two posts and two comments.
+    let result = query_engine
+        .request("query { findManyUser { posts { comments { id } } } }")
+        .await;
+
+    assert_eq!(Some("P2022"), error_code(&result));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn records_discarded_in_memory_count_against_the_limit(api: &TestApi) -> anyhow::Result<()> {
+    let limits = QueryLimits {
+        max_records: Some(3),
+        ..Default::default()
+    };
+
+    let query_engine = api.create_engine_with_limits(&BLOG, limits).await?;
+
+    query_engine
+        .request(indoc! {r#"
+            mutation {
+                createOneUser(data: {
+                    id: 1,
+                    name: "Alice",
+                    posts: { create: [
+                        { id: 1, title: "A" },
+                        { id: 2, title: "A" },
+                        { id: 3, title: "A" },
+                        { id: 4, title: "B" }
+                    ] }
+                }) { id }
+            }
+        "#})
+        .await;
+
+    // The cursor makes the engine read all four posts and deduplicate and paginate them in memory.
+    let result = query_engine
+        .request("query { findManyPost(cursor: { id: 1 }, distinct: [title], take: 2) { id } }")
+        .await;
+
+    assert_eq!(Some("P2022"), error_code(&result));
+
+    // Nested pagination happens in memory, after reading the posts of all users.
+    let result = query_engine
+        .request("query { findManyUser { posts(take: 1) { id } } }")
+        .await;
+
+    assert_eq!(Some("P2022"), error_code(&result));
+
+    Ok(())
+}
@@ -0,0 +1,209 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+#[test_each_connector]
+async fn on_delete_cascade_deletes_the_referencing_records(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id], onDelete: Cascade)
+        }
+    "#};
+
+    let query_engine = api.create_engine(&dm).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }, { id: 2 }] } }) { id } }"#)
+        .await;
+
+    let result = query_engine
+        .request(r#"mutation { deleteOneUser(where: { id: 1 }) { id } }"#)
+        .await;
+
+    assert_eq!(json!({ "id": 1 }), result["data"]["deleteOneUser"]);
+
+    let result = query_engine.request(r#"query { findManyPost { id } }"#).await;
+
+    assert_eq!(json!([]), result["data"]["findManyPost"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn on_delete_set_null_nulls_the_referencing_fields(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int   @id
+            userId Int?
+            user   User? @relation(fields: [userId], references: [id], onDelete: SetNull)
+        }
+    "#};
+
+    let query_engine = api.create_engine(&dm).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }] } }) { id } }"#)
+        .await;
+
+    query_engine
+        .request(r#"mutation { deleteOneUser(where: { id: 1 }) { id } }"#)
+        .await;
+
+    let result = query_engine.request(r#"query { findManyPost { id userId } }"#).await;
+
+    assert_eq!(json!([{ "id": 1, "userId": null }]), result["data"]["findManyPost"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn on_delete_restrict_prevents_deleting_referenced_records(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int   @id
+            userId Int?
+            user   User? @relation(fields: [userId], references: [id], onDelete: Restrict)
+        }
+    "#};
+
+    let query_engine = api.create_engine(&dm).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }] } }) { id } }"#)
+        .await;
+
+    let result = query_engine
+        .request(r#"mutation { deleteOneUser(where: { id: 1 }) { id } }"#)
+        .await;
+
+    assert!(result["errors"][0].is_object());
+
+    let result = query_engine.request(r#"query { findManyUser { id } }"#).await;
+
+    assert_eq!(json!([{ "id": 1 }]), result["data"]["findManyUser"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn on_update_cascade_updates_the_referencing_fields(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id], onUpdate: Cascade)
+        }
+    "#};
+
+    let query_engine = api.create_engine(&dm).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }, { id: 2 }] } }) { id } }"#)
+        .await;
+
+    let result = query_engine
+        .request(r#"mutation { updateOneUser(where: { id: 1 }, data: { id: 2 }) { id } }"#)
+        .await;
+
+    assert_eq!(json!({ "id": 2 }), result["data"]["updateOneUser"]);
+
+    let result = query_engine.request(r#"query { findManyPost { id userId } }"#).await;
+
+    assert_eq!(
+        json!([{ "id": 1, "userId": 2 }, { "id": 2, "userId": 2 }]),
+        result["data"]["findManyPost"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn on_update_restrict_prevents_updating_referenced_fields(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int   @id
+            userId Int?
+            user   User? @relation(fields: [userId], references: [id], onUpdate: Restrict)
+        }
+    "#};
+
+    let query_engine = api.create_engine(&dm).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }] } }) { id } }"#)
+        .await;
+
+    let result = query_engine
+        .request(r#"mutation { updateManyUser(data: { id: 2 }) { count } }"#)
+        .await;
+
+    assert!(result["errors"][0].is_object());
+
+    let result = query_engine.request(r#"query { findManyUser { id } }"#).await;
+
+    assert_eq!(json!([{ "id": 1 }]), result["data"]["findManyUser"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn on_delete_set_default_fails_for_database_generated_defaults(api: &TestApi) -> anyhow::Result<()> {
+    let dm = indoc! {r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id     Int   @id
+            userId Int?  @default(dbgenerated())
+            user   User? @relation(fields: [userId], references: [id], onDelete: SetDefault)
+        }
+    "#};
+
+    let query_engine = api.create_engine(&dm).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }] } }) { id } }"#)
+        .await;
+
+    let result = query_engine
+        .request(r#"mutation { deleteOneUser(where: { id: 1 }) { id } }"#)
+        .await;
+
+    assert!(result["errors"][0].is_object());
+
+    let result = query_engine.request(r#"query { findManyPost { id userId } }"#).await;
+
+    assert_eq!(json!([{ "id": 1, "userId": 1 }]), result["data"]["findManyPost"]);
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static SCALARS: &str = indoc! {"
+    model Scalars {
+        id      Int     @id
+        decimal Decimal
+        bigInt  BigInt
+        bytes   Bytes
+    }
+"};
+
+#[test_each_connector]
+async fn decimal_big_int_and_bytes_values_round_trip_as_strings(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SCALARS).await?;
+
+    let mutation = indoc! {r#"
+        mutation {
+            createOneScalars(data: { id: 1, decimal: "1.5", bigInt: "9007199254740993", bytes: "AQID" }) {
+                decimal
+                bigInt
+                bytes
+            }
+        }
+    "#};
+
+    let result = query_engine.request(mutation).await;
+
+    assert_eq!(
+        json!({ "decimal": "1.5", "bigInt": "9007199254740993", "bytes": "AQID" }),
+        result["data"]["createOneScalars"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn decimal_and_big_int_values_can_be_filtered(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SCALARS).await?;
+
+    query_engine
+        .request(r#"mutation { createOneScalars(data: { id: 1, decimal: 1.5, bigInt: 1, bytes: "AQID" }) { id } }"#)
+        .await;
+
+    query_engine
+        .request(r#"mutation { createOneScalars(data: { id: 2, decimal: 2.5, bigInt: 2, bytes: "BAUG" }) { id } }"#)
+        .await;
+
+    let result = query_engine
+        .request(r#"query { findManyScalars(where: { decimal_gt: "2", bigInt_in: ["1", "2"] }) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 2 }]), result["data"]["findManyScalars"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn invalid_bytes_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SCALARS).await?;
+
+    let result = query_engine
+        .request(
+            r#"mutation { createOneScalars(data: { id: 1, decimal: 1, bigInt: 1, bytes: "not base64!" }) { id } }"#,
+        )
+        .await;
+
+    assert!(result["errors"][0].is_object());
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use crate::{
-    context::PrismaContext,
-    request_handlers::{GraphQlBody, GraphQlRequestHandler, RequestHandler, SingleQuery},
+    context::{ContextBuilder, PrismaContext},
+    request_handlers::{
+        handle_explain, GraphQlBody, GraphQlRequestHandler, QueryDocumentCacheMetrics, RequestHandler, SingleQuery,
+    },
     PrismaRequest, PrismaResponse,
 };
 use migration_connector::*;
@@ -13,6 +15,7 @@ use quaint::{
     connector::ConnectionInfo,
     visitor::{self, Visitor},
 };
+use query_core::QueryLimits;
 use sql_migration_connector::SqlMigrationConnector;
 use std::{collections::HashMap, sync::Arc};
 use test_setup::*;
@@ -27,8 +30,30 @@ impl QueryEngine {
     }
 
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
+        self.request_with_headers(body, HashMap::new()).await
+    }
+
+    pub async fn request_with_headers(
+        &self,
+        body: impl Into<SingleQuery>,
+        headers: HashMap<String, String>,
+    ) -> serde_json::Value {
         let request = PrismaRequest {
             body: GraphQlBody::Single(body.into()),
+            headers,
+            path: String::new(),
+        };
+
+        match GraphQlRequestHandler.handle(request, &self.context).await {
+            PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Sends a raw request body, e.g. to use protocol extensions.
+    pub async fn request_json(&self, body: serde_json::Value) -> serde_json::Value {
+        let request = PrismaRequest {
+            body: serde_json::from_value(body).unwrap(),
             headers: HashMap::new(),
             path: String::new(),
         };
@@ -38,6 +63,16 @@ impl QueryEngine {
             _ => unreachable!(),
         }
     }
+
+    pub fn document_cache_metrics(&self) -> QueryDocumentCacheMetrics {
+        self.context.document_cache().metrics()
+    }
+
+    pub async fn explain(&self, body: impl Into<SingleQuery>, database_plan: bool) -> serde_json::Value {
+        let response = handle_explain(GraphQlBody::Single(body.into()), database_plan, &self.context).await;
+
+        serde_json::to_value(response).unwrap()
+    }
 }
 
 pub struct TestApi {
@@ -48,7 +83,59 @@ pub struct TestApi {
 
 impl TestApi {
     pub async fn create_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
-        let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
+        self.create_engine_with_limits(datamodel, QueryLimits::default()).await
+    }
+
+    pub async fn create_engine_with_limits(&self, datamodel: &str, limits: QueryLimits) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with(datamodel, |builder| builder.query_limits(limits))
+            .await
+    }
+
+    /// Creates an engine with additional settings applied to the context.
+    pub async fn create_engine_with<F>(&self, datamodel: &str, configure: F) -> anyhow::Result<QueryEngine>
+    where
+        F: FnOnce(ContextBuilder) -> ContextBuilder,
+    {
+        self.create_engine_from_config(&self.config, datamodel, configure).await
+    }
+
+    /// Creates an engine whose connection pool holds at most `connection_limit` connections.
+    pub async fn create_engine_with_connection_limit(
+        &self,
+        datamodel: &str,
+        connection_limit: usize,
+    ) -> anyhow::Result<QueryEngine> {
+        let url_start = self.config.find("url").unwrap();
+        let url_start = url_start + self.config[url_start..].find('"').unwrap() + 1;
+        let url_end = url_start + self.config[url_start..].find('"').unwrap();
+        let separator = if self.config[url_start..url_end].contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+
+        let config = format!(
+            "{}{}connection_limit={}{}",
+            &self.config[..url_end],
+            separator,
+            connection_limit,
+            &self.config[url_end..]
+        );
+
+        self.create_engine_from_config(&config, datamodel, |builder| builder)
+            .await
+    }
+
+    async fn create_engine_from_config<F>(
+        &self,
+        config: &str,
+        datamodel: &str,
+        configure: F,
+    ) -> anyhow::Result<QueryEngine>
+    where
+        F: FnOnce(ContextBuilder) -> ContextBuilder,
+    {
+        let datamodel_string = format!("{}\n\n{}", config, datamodel);
         let migration_id = "test-cli-migration".to_owned();
 
         let infer_input = InferMigrationStepsInput {
@@ -69,14 +156,11 @@ impl TestApi {
 
         self.migration_api.apply_migration(&apply_input).await?;
 
-        let dml = datamodel::parse_datamodel(&datamodel_string).unwrap();
+        let dml = datamodel::parse_datamodel(&datamodel_string).unwrap().subject;
         let config = datamodel::parse_configuration(&datamodel_string).unwrap();
 
-        let context = PrismaContext::builder(config, dml)
-            .enable_raw_queries(true)
-            .build()
-            .await
-            .unwrap();
+        let builder = PrismaContext::builder(config, dml).enable_raw_queries(true);
+        let context = configure(builder).build().await.unwrap();
 
         Ok(QueryEngine {
             context: Arc::new(context),
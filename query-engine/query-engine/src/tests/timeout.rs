@@ -0,0 +1,109 @@
+use super::test_api::*;
+use crate::request_handlers::QUERY_TIMEOUT_HEADER;
+use indoc::indoc;
+use serde_json::json;
+use std::collections::HashMap;
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    String @id @default(cuid())
+        title String
+    }
+"};
+
+fn query_raw(query: &str) -> String {
+    format!(r#"mutation {{ queryRaw(query: "{}", parameters: "[]") }}"#, query)
+}
+
+fn timeout_header(millis: u64) -> HashMap<String, String> {
+    let mut headers = HashMap::new();
+    headers.insert(QUERY_TIMEOUT_HEADER.to_owned(), millis.to_string());
+
+    headers
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn a_timed_out_query_is_cancelled_on_postgres(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let result = query_engine
+        .request_with_headers(query_raw("SELECT pg_sleep(30)"), timeout_header(200))
+        .await;
+
+    assert_eq!(
+        Some("P2021"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    // The sleeping statement must not show up as active anymore after the cancellation.
+    let result = query_engine
+        .request(query_raw(
+            "SELECT COUNT(*)::int AS count FROM pg_stat_activity WHERE state = 'active' AND query LIKE 'SELECT pg_sleep%'",
+        ))
+        .await;
+
+    assert_eq!(json!([{ "count": 0 }]), result["data"]["queryRaw"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn a_timed_out_query_is_cancelled_when_the_pool_has_no_connection_left(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_with_connection_limit(&TODO, 1).await?;
+
+    let result = query_engine
+        .request_with_headers(query_raw("SELECT pg_sleep(30)"), timeout_header(200))
+        .await;
+
+    assert_eq!(
+        Some("P2021"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    let result = query_engine.request("query { findManyTodo { title } }").await;
+
+    assert_eq!(json!([]), result["data"]["findManyTodo"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn a_timed_out_query_is_cancelled_on_mysql(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let result = query_engine
+        .request_with_headers(query_raw("SELECT SLEEP(30)"), timeout_header(200))
+        .await;
+
+    assert_eq!(
+        Some("P2021"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    let result = query_engine
+        .request(query_raw(
+            "SELECT COUNT(*) AS count FROM information_schema.processlist WHERE info LIKE 'SELECT SLEEP%'",
+        ))
+        .await;
+
+    assert_eq!(json!([{ "count": 0 }]), result["data"]["queryRaw"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn queries_within_the_timeout_succeed(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let result = query_engine
+        .request_with_headers(
+            r#"mutation { createOneTodo(data: { title: "foo" }) { title } }"#,
+            timeout_header(10_000),
+        )
+        .await;
+
+    assert_eq!(json!({ "title": "foo" }), result["data"]["createOneTodo"]);
+
+    Ok(())
+}
@@ -34,11 +34,13 @@ impl TestApi {
     pub async fn introspect_and_start_query_engine(&self) -> anyhow::Result<(DatamodelAssertions, QueryEngine)> {
         let datasource = self.datasource();
 
-        let introspection_result = introspection_core::RpcImpl::introspect_internal(datasource)
+        let introspection_result = introspection_core::RpcImpl::introspect_internal(datasource, false)
             .await
             .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
 
-        let dml = datamodel::parse_datamodel(&introspection_result.datamodel).unwrap();
+        let dml = datamodel::parse_datamodel(&introspection_result.datamodel)
+            .unwrap()
+            .subject;
         let config = datamodel::parse_configuration(&introspection_result.datamodel).unwrap();
 
         let context = PrismaContext::builder(config, dml.clone())